@@ -1,6 +1,10 @@
-use crate::platform::{Bogae, Geoul, Iyagi, Nuri, Sam, TickFrame};
+use std::collections::BTreeMap;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
+
+use crate::platform::{Bogae, Geoul, Iyagi, Nuri, Sam, StateHash, TickFrame};
 use crate::signals::SignalSink;
 use crate::signals::TickId;
+use crate::signals::VecSignalSink;
 
 pub struct EngineLoop<S, I, N, G, B>
 where
@@ -15,6 +19,156 @@ where
     pub nuri: N,
     pub geoul: G,
     pub bogae: B,
+    control: EngineControl,
+}
+
+/// "N마디 뒤에 할 일" 줄. 꺼내는 순서는 (마디, 넣은 순서)로 정해지므로
+/// 같은 입력이면 늘 같은 순서로 불린다.
+#[derive(Clone, Debug)]
+pub struct Scheduler<T> {
+    queue: BTreeMap<(TickId, u64), T>,
+    next_seq: u64,
+}
+
+impl<T> Default for Scheduler<T> {
+    fn default() -> Self {
+        Self {
+            queue: BTreeMap::new(),
+            next_seq: 0,
+        }
+    }
+}
+
+impl<T> Scheduler<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `at` 마디에 `action`을 건다.
+    pub fn schedule(&mut self, at: TickId, action: T) {
+        self.queue.insert((at, self.next_seq), action);
+        self.next_seq += 1;
+    }
+
+    /// `tick`까지 닿은 일을 모두 꺼낸다. 지난 마디에 걸렸던 일도 함께 나온다.
+    pub fn take_due(&mut self, tick: TickId) -> Vec<T> {
+        let later = match tick.checked_add(1) {
+            Some(next) => self.queue.split_off(&(next, 0)),
+            None => BTreeMap::new(),
+        };
+        std::mem::replace(&mut self.queue, later)
+            .into_values()
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+}
+
+/// 멈춤 손잡이로 본 루프 상태. 마디 경계에서만 바뀐다.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EngineStatus {
+    pub ticks_done: u64,
+    pub last_tick: Option<TickId>,
+    pub state_hash: Option<StateHash>,
+}
+
+#[derive(Default)]
+struct ControlState {
+    paused: bool,
+    steps: u64,
+    finished: bool,
+    status: EngineStatus,
+}
+
+/// 다른 스레드에서 `run_ticks`의 마디 진행을 멈추고(`pause`), 풀고(`resume`),
+/// 한 마디씩 넘기는(`step_one`) 손잡이. 루프는 마디를 시작하기 전에만 손잡이를 보고,
+/// 상태는 마디를 마친 뒤에만 고쳐 쓰므로 멈춘 동안 읽는 값은 늘 마디 경계의 값이다.
+#[derive(Clone, Default)]
+pub struct EngineControl {
+    shared: Arc<(Mutex<ControlState>, Condvar)>,
+}
+
+impl EngineControl {
+    pub fn pause(&self) {
+        self.lock().paused = true;
+    }
+
+    pub fn resume(&self) {
+        let mut state = self.lock();
+        state.paused = false;
+        state.steps = 0;
+        self.shared.1.notify_all();
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.lock().paused
+    }
+
+    /// 멈춘 루프를 한 마디 넘기고, 그 마디가 끝날 때까지 기다려 상태를 돌려준다.
+    /// 멈추지 않았으면 다음 마디가 끝나기를 기다린다. 루프가 이미 끝났으면 바로 돌아온다.
+    pub fn step_one(&self) -> EngineStatus {
+        let mut state = self.lock();
+        let target = state.status.ticks_done + 1;
+        if state.paused {
+            state.steps += 1;
+            self.shared.1.notify_all();
+        }
+        while state.status.ticks_done < target && !state.finished {
+            state = self
+                .shared
+                .1
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        state.status
+    }
+
+    pub fn status(&self) -> EngineStatus {
+        self.lock().status
+    }
+
+    fn lock(&self) -> MutexGuard<'_, ControlState> {
+        self.shared.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn begin_run(&self) {
+        self.lock().finished = false;
+    }
+
+    /// 멈춰 있으면 `resume`이나 `step_one`이 올 때까지 마디 시작을 미룬다.
+    fn wait_turn(&self) {
+        let mut state = self.lock();
+        while state.paused && state.steps == 0 {
+            state = self
+                .shared
+                .1
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        if state.paused {
+            state.steps -= 1;
+        }
+    }
+
+    fn finish_tick(&self, frame: &TickFrame) {
+        let mut state = self.lock();
+        state.status.ticks_done += 1;
+        state.status.last_tick = Some(frame.snapshot.tick_id);
+        state.status.state_hash = Some(frame.state_hash);
+        self.shared.1.notify_all();
+    }
+
+    fn end_run(&self) {
+        let mut state = self.lock();
+        state.finished = true;
+        self.shared.1.notify_all();
+    }
 }
 
 impl<S, I, N, G, B> EngineLoop<S, I, N, G, B>
@@ -32,9 +186,15 @@ where
             nuri,
             geoul,
             bogae,
+            control: EngineControl::default(),
         }
     }
 
+    /// `run_ticks`를 다른 스레드에서 멈추거나 한 마디씩 넘길 손잡이.
+    pub fn control(&self) -> EngineControl {
+        self.control.clone()
+    }
+
     /// ✅ 관문0: 한 틱 최소 루프
     /// Sam -> Iyagi -> Nuri -> Geoul -> Bogae
     pub fn tick_once(&mut self, tick_id: TickId, sink: &mut dyn SignalSink) -> TickFrame {
@@ -63,4 +223,26 @@ where
 
         frame
     }
+
+    /// `start`부터 `ticks`마디를 돌린다.
+    /// `control()` 손잡이가 멈춰 있으면 마디를 시작하기 전에 기다린다.
+    pub fn run_ticks(
+        &mut self,
+        start: TickId,
+        ticks: u64,
+        sink: &mut VecSignalSink,
+    ) -> Vec<TickFrame> {
+        let control = self.control.clone();
+        control.begin_run();
+        let mut frames = Vec::new();
+        for offset in 0..ticks {
+            control.wait_turn();
+            let tick_id = start.wrapping_add(offset);
+            let frame = self.tick_once(tick_id, sink);
+            control.finish_tick(&frame);
+            frames.push(frame);
+        }
+        control.end_run();
+        frames
+    }
 }
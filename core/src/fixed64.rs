@@ -277,6 +277,8 @@ mod tests {
             location: "test:div0",
             source_span: None,
             expr: None,
+            seed: None,
+            call_depth: 0,
         };
 
         let mut x = Fixed64::from_i64(5);
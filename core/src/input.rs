@@ -1,4 +1,7 @@
-use crate::platform::{KEY_A, KEY_D, KEY_S, KEY_W};
+use crate::platform::{InputSnapshot, KEY_A, KEY_D, KEY_S, KEY_W};
+use crate::signals::TickId;
+
+pub const CHORD_DEFAULT_MAX_GAP_MADI: TickId = 8;
 
 pub fn key_bit_from_name(name: &str) -> Option<u64> {
     match name.to_ascii_lowercase().as_str() {
@@ -22,6 +25,56 @@ pub fn is_key_just_pressed(prev_keys: u64, keys_pressed: u64, name: &str) -> boo
         .unwrap_or(false)
 }
 
+/// 마지막 스냅샷에서 `sequence`의 마지막 키가 막 눌렸고, 그 앞 단계들이
+/// 기본 간격(`CHORD_DEFAULT_MAX_GAP_MADI`) 안에서 순서대로 눌렸으면 true.
+pub fn chord_just_completed(history: &[InputSnapshot], sequence: &[&str]) -> bool {
+    chord_just_completed_within(history, sequence, CHORD_DEFAULT_MAX_GAP_MADI)
+}
+
+/// 단계 사이 간격(마디)을 `max_gap_madi` 이하로 제한하는 콤보 판정.
+/// 눌림 판정은 인접 스냅샷 쌍의 `is_key_just_pressed`만 사용한다.
+pub fn chord_just_completed_within(
+    history: &[InputSnapshot],
+    sequence: &[&str],
+    max_gap_madi: TickId,
+) -> bool {
+    if sequence.is_empty() || history.len() < 2 {
+        return false;
+    }
+    if sequence
+        .iter()
+        .any(|name| key_bit_from_name(name).is_none())
+    {
+        return false;
+    }
+    let just_pressed_at = |idx: usize, name: &str| -> bool {
+        idx > 0
+            && is_key_just_pressed(
+                history[idx - 1].keys_pressed,
+                history[idx].keys_pressed,
+                name,
+            )
+    };
+
+    let mut cursor = history.len() - 1;
+    if !just_pressed_at(cursor, sequence[sequence.len() - 1]) {
+        return false;
+    }
+    // 뒤에서부터 각 단계의 가장 최근 눌림을 고르면 간격이 최소가 된다.
+    for name in sequence[..sequence.len() - 1].iter().rev() {
+        let cursor_tick = history[cursor].tick_id;
+        let found = (1..cursor).rev().find(|&idx| {
+            just_pressed_at(idx, name)
+                && cursor_tick.saturating_sub(history[idx].tick_id) <= max_gap_madi
+        });
+        match found {
+            Some(idx) => cursor = idx,
+            None => return false,
+        }
+    }
+    true
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -54,4 +107,44 @@ mod tests {
         assert!(!is_key_just_pressed(prev, now, "w"));
         assert!(is_key_just_pressed(prev, now, "d"));
     }
+
+    fn snapshot(tick_id: TickId, keys_pressed: u64) -> InputSnapshot {
+        InputSnapshot {
+            tick_id,
+            dt: crate::fixed64::Fixed64::from_i64(1),
+            keys_pressed,
+            last_key_name: String::new(),
+            pointer_x_i32: 0,
+            pointer_y_i32: 0,
+            ai_injections: Vec::new(),
+            net_events: Vec::new(),
+            frame_source: crate::platform::InputSource::Person,
+            rng_seed: 0,
+        }
+    }
+
+    fn history(keys: &[u64]) -> Vec<InputSnapshot> {
+        keys.iter()
+            .enumerate()
+            .map(|(idx, keys)| snapshot(idx as TickId, *keys))
+            .collect()
+    }
+
+    #[test]
+    fn chord_detects_w_w_s_sequence() {
+        let frames = history(&[0, KEY_W, 0, KEY_W, 0, KEY_S]);
+        assert!(chord_just_completed_within(&frames, &["w", "w", "s"], 2));
+        assert!(chord_just_completed(&frames, &["w", "w", "s"]));
+        assert!(!chord_just_completed_within(&frames, &["w", "s", "s"], 2));
+        // 마지막 스냅샷에서 완성되지 않으면 false.
+        let held = history(&[0, KEY_W, 0, KEY_W, 0, KEY_S, KEY_S]);
+        assert!(!chord_just_completed_within(&held, &["w", "w", "s"], 2));
+    }
+
+    #[test]
+    fn chord_rejects_when_gap_too_large() {
+        let frames = history(&[0, KEY_W, 0, 0, 0, 0, KEY_W, 0, KEY_S]);
+        assert!(!chord_just_completed_within(&frames, &["w", "w", "s"], 2));
+        assert!(chord_just_completed_within(&frames, &["w", "w", "s"], 5));
+    }
 }
@@ -16,31 +16,43 @@ pub mod warp;
 pub use alrim::{
     AlrimHandler, AlrimLogEntry, AlrimLogger, AlrimLoop, VecAlrimLogger, ALRIM_MAX_PASSES,
 };
-pub use engine::EngineLoop;
+pub use engine::{EngineControl, EngineLoop, EngineStatus, Scheduler};
 pub use fixed64::Fixed64;
-pub use input::{is_key_just_pressed, is_key_pressed, key_bit_from_name};
-pub use nurigym::spec::{ActionSpec, ObservationSpec};
+pub use input::{
+    chord_just_completed, chord_just_completed_within, is_key_just_pressed, is_key_pressed,
+    key_bit_from_name, CHORD_DEFAULT_MAX_GAP_MADI,
+};
+pub use nurigym::spec::{ActionSpec, ObsNormalizer, ObservationSpec};
 pub use platform::{
-    Bogae, ComponentTag, DetSam, EntityId, Geoul, InMemoryGeoul, InputSnapshot, InputSource, Iyagi,
-    Nuri, NuriWorld, Patch, PatchOp, ResourceMapEntry, ResourceValue, Sam, Seulgi, SeulgiContext,
-    SeulgiIntent, SeulgiPacket, StateHash, TickFrame, KEY_A, KEY_D, KEY_S, KEY_W,
+    Bogae, BogaeView, Checkpoint, ComponentTag, DetSam, EntityError, EntityId, Geoul,
+    InMemoryGeoul, InputSnapshot, InputSource, Iyagi, Nuri, NuriWorld, Patch, PatchOp,
+    ResourceMapEntry, ResourceValue, Sam, Seulgi, SeulgiContext, SeulgiIntent, SeulgiPacket,
+    StateHash, TickFrame, KEY_A, KEY_D, KEY_S, KEY_W,
+};
+pub use realms::{
+    mix64, DetRng, MirrorDivergence, MultiRealmManager, Realm, RealmMessage, RealmSend,
+    RealmStepInput, RealmStepOutput, ThreadMode,
+};
+pub use resource::{
+    asset_handle_from_bundle_path, dedup, validate_bundle_path, AssetPathError, DedupEntry,
+    ResourceHandle,
 };
-pub use realms::{mix64, MultiRealmManager, Realm, RealmStepInput, RealmStepOutput, ThreadMode};
-pub use resource::{asset_handle_from_bundle_path, ResourceHandle};
-pub use seulgi::latency::{LatencyEvent, LatencyMode, LatencyPolicy};
+pub use seulgi::latency::{LatencyEvent, LatencyMode, LatencyPolicy, LatencySummary};
 pub use seulgi::safety::{SafetyDecision, SafetyMode, SafetyRule};
 pub use seulgi::{goal, intent};
 pub use signals::{
-    ArithmeticFaultKind, ExprTrace, FaultContext, Signal, SignalSink, SourceSpan, TickId,
-    VecSignalSink,
+    ArithmeticFaultKind, ExprTrace, FaultContext, FaultLimitStop, RateLimitedSink, Signal,
+    SignalRun, SignalSink, SourceSpan, TickId, VecSignalSink,
 };
 pub use units::{
-    base_unit_symbol_for_dim, canonical_unit_symbol, is_known_unit, resource_tag_with_unit,
-    set_unit_registry_symbols, unit_spec_from_symbol, Unit, UnitDim, UnitError, UnitSpec,
-    UnitValue,
+    base_unit_symbol_for_dim, canonical_unit_symbol, currency_format, format_value,
+    format_value_with, is_known_unit, resource_tag_with_unit, set_unit_registry_currency_formats,
+    set_unit_registry_symbols, unit_spec_from_symbol, validate_currency_formats, CurrencyFormat,
+    SymbolPlacement, Unit, UnitDim, UnitError, UnitSpec, UnitValue, CURRENCY_DECIMALS_MAX,
 };
 pub use warp::{
-    run_warp_bench, StepBatchSoA, WarpBackend, WarpBenchInput, WarpBenchOutput, WarpPolicy,
+    probe_gpu, run_warp_bench, run_warp_bench_with_probe, GpuProbe, StepBatchSoA, WarpBackend,
+    WarpBenchInput, WarpBenchOutput, WarpError, WarpPolicy, WarpStageBreakdown,
 };
 
 #[cfg(test)]
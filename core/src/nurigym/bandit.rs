@@ -25,6 +25,8 @@ pub struct BanditStep {
     pub reward: Fixed64,
     pub next_observation: [Fixed64; 2],
     pub done: bool,
+    /// 저절로 끝난 것이 아니라 max_steps에 걸려 끊긴 걸음. 이때도 `done`은 참이다.
+    pub truncated: bool,
 }
 
 impl BanditConfig {
@@ -84,6 +86,7 @@ impl BanditEnv {
             reward,
             next_observation: next_obs,
             done: done_after,
+            truncated: done_after,
         })
     }
 }
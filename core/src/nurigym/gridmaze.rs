@@ -31,6 +31,8 @@ pub struct GridMazeStep {
     pub reward: Fixed64,
     pub next_observation: [Fixed64; 2],
     pub done: bool,
+    /// 저절로 끝난 것이 아니라 max_steps에 걸려 끊긴 걸음. 이때도 `done`은 참이다.
+    pub truncated: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -119,6 +121,7 @@ impl GridMazeEnv {
             reward,
             next_observation: next_obs,
             done: done_after,
+            truncated: false,
         })
     }
 }
@@ -151,6 +154,7 @@ pub fn run_episode(
         let last_step = (idx as u64 + 1) >= config.max_steps;
         if last_step && !step.done {
             step.done = true;
+            step.truncated = true;
             step.reward = Fixed64::ZERO;
         }
         steps.push(step);
@@ -191,6 +195,7 @@ pub fn run_episode_with_layout(
         let last_step = (idx as u64 + 1) >= config.max_steps;
         if last_step && !step.done {
             step.done = true;
+            step.truncated = true;
             step.reward = Fixed64::ZERO;
         }
         steps.push(step);
@@ -30,6 +30,8 @@ pub struct PendulumStep {
     pub reward: Fixed64,
     pub next_observation: [Fixed64; 2],
     pub done: bool,
+    /// 저절로 끝난 것이 아니라 max_steps에 걸려 끊긴 걸음. 이때도 `done`은 참이다.
+    pub truncated: bool,
 }
 
 impl PendulumConfig {
@@ -59,6 +61,9 @@ impl PendulumState {
 }
 
 impl PendulumEnv {
+    /// 연속 행동 공간의 차원 수(토크 하나).
+    pub const CONTINUOUS_DIMS: u32 = 1;
+
     pub fn new(seed: u64, config: PendulumConfig) -> Self {
         Self {
             state: PendulumState::seeded(seed),
@@ -72,12 +77,29 @@ impl PendulumEnv {
     }
 
     pub fn step(&mut self, action: i64) -> Result<PendulumStep, String> {
+        let action = normalize_action(action)?;
+        self.step_scaled(Fixed64::from_i64(action), action)
+    }
+
+    /// 연속 행동: 한 차원짜리 `[토크 배율]`(보통 [-1, 1]로 잘린 값)을 쓴다.
+    /// 돌려주는 `action`은 0이며, 실제 값은 호출자가 기록한다.
+    pub fn step_continuous(&mut self, action: &[Fixed64]) -> Result<PendulumStep, String> {
+        let [torque_scale] = action else {
+            return Err(format!(
+                "E_NURIGYM_ACTION_INVALID pendulum continuous dims={} got={}",
+                Self::CONTINUOUS_DIMS,
+                action.len()
+            ));
+        };
+        self.step_scaled(*torque_scale, 0)
+    }
+
+    fn step_scaled(&mut self, act: Fixed64, action: i64) -> Result<PendulumStep, String> {
         if self.is_done() {
             return Err("E_NURIGYM_DONE pendulum already done".to_string());
         }
-        let action = normalize_action(action)?;
         let obs = self.state.observation();
-        apply_step(&mut self.state, action, &self.config);
+        apply_step(&mut self.state, act, &self.config);
         let next_obs = self.state.observation();
         let done_after = is_done(&self.state, &self.config);
         let reward = if done_after {
@@ -92,6 +114,7 @@ impl PendulumEnv {
             reward,
             next_observation: next_obs,
             done: done_after,
+            truncated: false,
         })
     }
 }
@@ -124,6 +147,7 @@ pub fn run_episode(
         let last_step = (idx as u64 + 1) >= config.max_steps;
         if last_step && !step.done {
             step.done = true;
+            step.truncated = true;
             step.reward = Fixed64::ZERO;
         }
         steps.push(step);
@@ -145,8 +169,7 @@ fn normalize_action(action: i64) -> Result<i64, String> {
     }
 }
 
-fn apply_step(state: &mut PendulumState, action: i64, config: &PendulumConfig) {
-    let act = Fixed64::from_i64(action);
+fn apply_step(state: &mut PendulumState, act: Fixed64, config: &PendulumConfig) {
     let torque = act.saturating_mul(config.torque);
     let accel = torque
         .saturating_sub(state.theta.saturating_mul(config.gravity))
@@ -1,11 +1,135 @@
+use crate::fixed64::Fixed64;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ObservationSpec {
     pub slot_count: u32,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct ActionSpec {
-    pub actions: Vec<String>,
+pub enum ActionSpec {
+    Discrete {
+        actions: Vec<String>,
+    },
+    Continuous {
+        dims: u32,
+        low: Vec<Fixed64>,
+        high: Vec<Fixed64>,
+    },
+}
+
+/// 연속 행동을 `[low, high]`로 자른 결과. `clamped[i]`는 i번째 차원이 잘렸는지 나타낸다.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContinuousAction {
+    pub raw: Vec<Fixed64>,
+    pub applied: Vec<Fixed64>,
+    pub clamped: Vec<bool>,
+}
+
+impl ContinuousAction {
+    pub fn any_clamped(&self) -> bool {
+        self.clamped.iter().any(|flag| *flag)
+    }
+}
+
+/// 관측 정규화용 누적 통계(Welford). Fixed64 raw 연산만 써서 같은 관측열이면 비트 단위로 같다.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ObsNormalizer {
+    pub slot_count: u32,
+    pub count: u64,
+    pub mean: Vec<Fixed64>,
+    pub m2: Vec<Fixed64>,
+}
+
+impl ObsNormalizer {
+    pub fn new(slot_count: u32) -> Self {
+        Self {
+            slot_count,
+            count: 0,
+            mean: vec![Fixed64::ZERO; slot_count as usize],
+            m2: vec![Fixed64::ZERO; slot_count as usize],
+        }
+    }
+
+    /// 저장해 둔 raw 상태(`to_detjson`의 `count`/`mean_raw`/`m2_raw`)로 되살린다.
+    pub fn from_raw_parts(
+        slot_count: u32,
+        count: u64,
+        mean_raw: &[i64],
+        m2_raw: &[i64],
+    ) -> Result<Self, String> {
+        if mean_raw.len() != slot_count as usize || m2_raw.len() != slot_count as usize {
+            return Err(format!(
+                "E_NURIGYM_OBS_NORM slot_count={} mean={} m2={}",
+                slot_count,
+                mean_raw.len(),
+                m2_raw.len()
+            ));
+        }
+        Ok(Self {
+            slot_count,
+            count,
+            mean: mean_raw
+                .iter()
+                .map(|raw| Fixed64::from_raw_i64(*raw))
+                .collect(),
+            m2: m2_raw
+                .iter()
+                .map(|raw| Fixed64::from_raw_i64(*raw))
+                .collect(),
+        })
+    }
+
+    /// 관측 벡터 하나를 반영한다. 길이가 slot_count와 다르면 오류.
+    pub fn update(&mut self, obs: &[Fixed64]) -> Result<(), String> {
+        if obs.len() != self.slot_count as usize {
+            return Err(format!(
+                "E_NURIGYM_OBS_INVALID slot_count={} got={}",
+                self.slot_count,
+                obs.len()
+            ));
+        }
+        self.count = self.count.saturating_add(1);
+        let n = Fixed64::from_i64(self.count.min(i64::MAX as u64) as i64);
+        for (idx, value) in obs.iter().enumerate() {
+            let delta = value.saturating_sub(self.mean[idx]);
+            let step = delta.try_div(n).unwrap_or(Fixed64::ZERO);
+            self.mean[idx] = self.mean[idx].saturating_add(step);
+            let delta_after = value.saturating_sub(self.mean[idx]);
+            self.m2[idx] = self.m2[idx].saturating_add(delta.saturating_mul(delta_after));
+        }
+        Ok(())
+    }
+
+    /// 모분산(m2 / count). 관측이 없으면 0.
+    pub fn variance(&self) -> Vec<Fixed64> {
+        if self.count == 0 {
+            return vec![Fixed64::ZERO; self.slot_count as usize];
+        }
+        let n = Fixed64::from_i64(self.count.min(i64::MAX as u64) as i64);
+        self.m2
+            .iter()
+            .map(|m2| m2.try_div(n).unwrap_or(Fixed64::ZERO))
+            .collect()
+    }
+
+    /// 사람이 읽는 mean/var와 함께 복원용 raw 상태를 남긴다.
+    pub fn to_detjson(&self) -> String {
+        let mut out = String::new();
+        out.push_str("{\"schema\":\"nurigym.obs_norm.v1\",\"slot_count\":");
+        out.push_str(&self.slot_count.to_string());
+        out.push_str(",\"count\":");
+        out.push_str(&self.count.to_string());
+        out.push_str(",\"mean\":");
+        push_fixed_list(&mut out, &self.mean);
+        out.push_str(",\"var\":");
+        push_fixed_list(&mut out, &self.variance());
+        out.push_str(",\"mean_raw\":");
+        push_raw_list(&mut out, &self.mean);
+        out.push_str(",\"m2_raw\":");
+        push_raw_list(&mut out, &self.m2);
+        out.push('}');
+        out
+    }
 }
 
 impl ObservationSpec {
@@ -13,6 +137,17 @@ impl ObservationSpec {
         Self { slot_count: 64 }
     }
 
+    /// 관측 칸 이름(`obs_0`, `obs_1`, ...). 열 순서를 고정하는 데 쓴다.
+    pub fn slot_names(&self) -> Vec<String> {
+        (0..self.slot_count)
+            .map(|idx| format!("obs_{}", idx))
+            .collect()
+    }
+
+    pub fn normalizer(&self) -> ObsNormalizer {
+        ObsNormalizer::new(self.slot_count)
+    }
+
     pub fn to_detjson(&self) -> String {
         format!(
             "{{\"schema\":\"nurigym.obs_spec.v1\",\"slot_count\":{}}}",
@@ -23,27 +158,146 @@ impl ObservationSpec {
 
 impl ActionSpec {
     pub fn empty() -> Self {
-        Self {
+        Self::Discrete {
             actions: Vec::new(),
         }
     }
 
+    pub fn discrete(actions: Vec<String>) -> Self {
+        Self::Discrete { actions }
+    }
+
+    pub fn continuous(low: Vec<Fixed64>, high: Vec<Fixed64>) -> Result<Self, String> {
+        let spec = Self::Continuous {
+            dims: low.len() as u32,
+            low,
+            high,
+        };
+        spec.validate()?;
+        Ok(spec)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            ActionSpec::Discrete { .. } => Ok(()),
+            ActionSpec::Continuous { dims, low, high } => {
+                if *dims == 0 {
+                    return Err("E_NURIGYM_ACTION_SPEC continuous dims must be > 0".to_string());
+                }
+                if low.len() != *dims as usize || high.len() != *dims as usize {
+                    return Err(format!(
+                        "E_NURIGYM_ACTION_SPEC continuous bounds len mismatch dims={} low={} high={}",
+                        dims,
+                        low.len(),
+                        high.len()
+                    ));
+                }
+                for (idx, (lo, hi)) in low.iter().zip(high.iter()).enumerate() {
+                    if lo.raw_i64() > hi.raw_i64() {
+                        return Err(format!(
+                            "E_NURIGYM_ACTION_SPEC continuous low>high dim={} low={} high={}",
+                            idx, lo, hi
+                        ));
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// 이산 행동 이름 목록. 연속 공간이면 비어 있다.
+    pub fn action_names(&self) -> &[String] {
+        match self {
+            ActionSpec::Discrete { actions } => actions,
+            ActionSpec::Continuous { .. } => &[],
+        }
+    }
+
+    /// 연속 행동을 차원별로 `[low, high]`에 맞춰 자른다. raw 비교라 결정적이다.
+    pub fn clamp_continuous(&self, action: &[Fixed64]) -> Result<ContinuousAction, String> {
+        let ActionSpec::Continuous { dims, low, high } = self else {
+            return Err("E_NURIGYM_ACTION_SPEC not a continuous action space".to_string());
+        };
+        if action.len() != *dims as usize {
+            return Err(format!(
+                "E_NURIGYM_ACTION_INVALID continuous dims={} got={}",
+                dims,
+                action.len()
+            ));
+        }
+        let mut applied = Vec::with_capacity(action.len());
+        let mut clamped = Vec::with_capacity(action.len());
+        for ((value, lo), hi) in action.iter().zip(low.iter()).zip(high.iter()) {
+            let next = if value.raw_i64() < lo.raw_i64() {
+                *lo
+            } else if value.raw_i64() > hi.raw_i64() {
+                *hi
+            } else {
+                *value
+            };
+            clamped.push(next != *value);
+            applied.push(next);
+        }
+        Ok(ContinuousAction {
+            raw: action.to_vec(),
+            applied,
+            clamped,
+        })
+    }
+
     pub fn to_detjson(&self) -> String {
         let mut out = String::new();
-        out.push_str("{\"schema\":\"nurigym.action_spec.v1\",\"actions\":[");
-        for (idx, item) in self.actions.iter().enumerate() {
-            if idx > 0 {
-                out.push(',');
+        match self {
+            ActionSpec::Discrete { actions } => {
+                out.push_str("{\"schema\":\"nurigym.action_spec.v1\",\"actions\":[");
+                for (idx, item) in actions.iter().enumerate() {
+                    if idx > 0 {
+                        out.push(',');
+                    }
+                    out.push('"');
+                    out.push_str(&escape_json(item));
+                    out.push('"');
+                }
+                out.push_str("]}");
+            }
+            ActionSpec::Continuous { dims, low, high } => {
+                out.push_str(
+                    "{\"schema\":\"nurigym.action_spec.v1\",\"kind\":\"continuous\",\"dims\":",
+                );
+                out.push_str(&dims.to_string());
+                out.push_str(",\"low\":");
+                push_fixed_list(&mut out, low);
+                out.push_str(",\"high\":");
+                push_fixed_list(&mut out, high);
+                out.push('}');
             }
-            out.push('"');
-            out.push_str(&escape_json(item));
-            out.push('"');
         }
-        out.push_str("]}");
         out
     }
 }
 
+fn push_fixed_list(out: &mut String, values: &[Fixed64]) {
+    out.push('[');
+    for (idx, value) in values.iter().enumerate() {
+        if idx > 0 {
+            out.push(',');
+        }
+        out.push_str(&value.to_string());
+    }
+    out.push(']');
+}
+
+fn push_raw_list(out: &mut String, values: &[Fixed64]) {
+    out.push('[');
+    for (idx, value) in values.iter().enumerate() {
+        if idx > 0 {
+            out.push(',');
+        }
+        out.push_str(&value.raw_i64().to_string());
+    }
+    out.push(']');
+}
+
 fn escape_json(input: &str) -> String {
     let mut out = String::with_capacity(input.len());
     for ch in input.chars() {
@@ -58,3 +312,80 @@ fn escape_json(input: &str) -> String {
     }
     out
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn discrete_action_spec_detjson_is_stable() {
+        let spec = ActionSpec::discrete(vec!["left".to_string(), "right".to_string()]);
+        assert_eq!(
+            spec.to_detjson(),
+            "{\"schema\":\"nurigym.action_spec.v1\",\"actions\":[\"left\",\"right\"]}"
+        );
+    }
+
+    #[test]
+    fn continuous_action_is_clamped_to_bounds() {
+        let spec = ActionSpec::continuous(
+            vec![Fixed64::from_i64(-2), Fixed64::from_i64(0)],
+            vec![Fixed64::from_i64(2), Fixed64::from_i64(1)],
+        )
+        .expect("valid spec");
+        let action = spec
+            .clamp_continuous(&[Fixed64::from_i64(5), Fixed64::from_i64(-3)])
+            .expect("dims match");
+        assert_eq!(action.applied, vec![Fixed64::from_i64(2), Fixed64::ZERO]);
+        assert_eq!(action.clamped, vec![true, true]);
+        assert!(action.any_clamped());
+
+        let inside = spec
+            .clamp_continuous(&[Fixed64::from_i64(1), Fixed64::ONE])
+            .expect("dims match");
+        assert_eq!(inside.applied, inside.raw);
+        assert!(!inside.any_clamped());
+        assert!(spec.clamp_continuous(&[Fixed64::ONE]).is_err());
+    }
+
+    #[test]
+    fn continuous_action_spec_rejects_inverted_bounds() {
+        assert!(ActionSpec::continuous(vec![Fixed64::ONE], vec![Fixed64::ZERO]).is_err());
+        assert!(ActionSpec::continuous(Vec::new(), Vec::new()).is_err());
+    }
+
+    #[test]
+    fn obs_normalizer_mean_is_reproducible() {
+        let spec = ObservationSpec { slot_count: 2 };
+        let stream = [
+            [Fixed64::from_i64(1), Fixed64::from_i64(-2)],
+            [Fixed64::from_i64(2), Fixed64::from_i64(4)],
+            [
+                Fixed64::from_i64(6),
+                Fixed64::from_raw_i64(Fixed64::ONE_RAW / 2),
+            ],
+        ];
+        let mut norm = spec.normalizer();
+        for obs in &stream {
+            norm.update(obs).expect("slot count");
+        }
+        assert_eq!(norm.count, 3);
+        assert_eq!(norm.mean[0], Fixed64::from_i64(3));
+        assert_eq!(norm.mean[1].to_string(), "0.833333");
+        assert_eq!(norm.variance()[0].to_string(), "4.666666");
+
+        let mut again = spec.normalizer();
+        for obs in &stream {
+            again.update(obs).expect("slot count");
+        }
+        assert_eq!(norm.to_detjson(), again.to_detjson());
+        assert!(norm.update(&[Fixed64::ONE]).is_err());
+
+        let mean_raw: Vec<i64> = norm.mean.iter().map(|v| v.raw_i64()).collect();
+        let m2_raw: Vec<i64> = norm.m2.iter().map(|v| v.raw_i64()).collect();
+        let restored =
+            ObsNormalizer::from_raw_parts(2, norm.count, &mean_raw, &m2_raw).expect("restore");
+        assert_eq!(restored, norm);
+        assert!(ObsNormalizer::from_raw_parts(3, 1, &mean_raw, &m2_raw).is_err());
+    }
+}
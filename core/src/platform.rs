@@ -570,6 +570,34 @@ impl StateHash {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EntityError {
+    NotFound(EntityId),
+}
+
+impl EntityError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            EntityError::NotFound(_) => "E_ENTITY_NOT_FOUND",
+        }
+    }
+}
+
+/// 거울 파일 없이 메모리에 잡아 둔 누리 상태. 성분, 자원, 다음 임자 id까지 통째로 담는다.
+/// 계획기가 갈래를 시험해 보고 `NuriWorld::restore`로 되돌릴 때 쓴다.
+#[derive(Clone, Debug)]
+pub struct Checkpoint {
+    world: NuriWorld,
+    state_hash: StateHash,
+}
+
+impl Checkpoint {
+    /// 잡아 둘 때의 state_hash.
+    pub fn state_hash(&self) -> StateHash {
+        self.state_hash
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct NuriWorld {
     // 결정성을 위해 HashMap 대신 BTreeMap 사용
@@ -589,6 +617,8 @@ impl NuriWorld {
         Self::default()
     }
 
+    /// 새 임자 id를 내준다. id는 0부터 하나씩 늘기만 하고, 지운 id도 한 실행 안에서는
+    /// 다시 쓰지 않는다. 그래서 같은 순서로 만들고 지우면 다시보기에서도 같은 id가 나온다.
     pub fn spawn(&mut self) -> EntityId {
         let id = self.next_entity;
         self.next_entity = self.next_entity.wrapping_add(1);
@@ -597,6 +627,19 @@ impl NuriWorld {
         entity
     }
 
+    /// 임자와 그 성분을 모두 지운다. 없는(이미 지웠거나 만든 적 없는) id는 오류다.
+    pub fn despawn(&mut self, entity: EntityId) -> Result<(), EntityError> {
+        if !self.ecs.locations.contains_key(&entity) {
+            return Err(EntityError::NotFound(entity));
+        }
+        self.ecs.remove_entity(entity);
+        Ok(())
+    }
+
+    pub fn contains_entity(&self, entity: EntityId) -> bool {
+        self.ecs.locations.contains_key(&entity)
+    }
+
     pub fn set_component_json(&mut self, entity: EntityId, tag: ComponentTag, json: String) {
         if entity.0 >= self.next_entity {
             self.next_entity = entity.0.wrapping_add(1);
@@ -677,6 +720,19 @@ impl NuriWorld {
             .collect()
     }
 
+    /// 지금 상태를 메모리에 잡아 둔다.
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            world: self.clone(),
+            state_hash: self.state_hash(),
+        }
+    }
+
+    /// 잡아 둔 상태로 통째로 되돌린다. 그 뒤에 만든 임자와 바꾼 자원은 모두 사라진다.
+    pub fn restore(&mut self, checkpoint: &Checkpoint) {
+        self.clone_from(&checkpoint.world);
+    }
+
     /// SSOT: state_hash는 BLAKE3(DetBin) 기반
     pub fn state_hash(&self) -> StateHash {
         let bytes = self.encode_canonical();
@@ -1097,6 +1153,35 @@ pub trait Bogae {
     fn render(&mut self, world: &NuriWorld, tick_id: TickId);
 }
 
+/// 보개 덧그림 설정이 계산된 누리 값(`살림.x` 같은 자원)을 참조할 때 쓰는 읽기 전용 창.
+/// `&NuriWorld`만 빌리고 읽기 메서드만 두므로 이 창을 거쳐서는 값을 바꿀 수 없다.
+#[derive(Clone, Copy, Debug)]
+pub struct BogaeView<'a> {
+    world: &'a NuriWorld,
+}
+
+impl<'a> BogaeView<'a> {
+    pub fn new(world: &'a NuriWorld) -> Self {
+        Self { world }
+    }
+
+    /// 자원 값을 읽는다. 일반 값, Fixed64, 자원 핸들 순서로 찾고 없으면 None이다.
+    pub fn read(&self, key: &str) -> Option<ResourceValue> {
+        self.world
+            .get_resource_value(key)
+            .or_else(|| {
+                self.world
+                    .get_resource_fixed64(key)
+                    .map(ResourceValue::Fixed64)
+            })
+            .or_else(|| {
+                self.world
+                    .get_resource_handle(key)
+                    .map(ResourceValue::ResourceHandle)
+            })
+    }
+}
+
 pub trait Geoul {
     fn record(&mut self, frame: &TickFrame);
     fn replay_next(&mut self) -> Option<TickFrame>;
@@ -1332,6 +1417,8 @@ impl Nuri for DetNuri {
                         location,
                         source_span: source_span.clone(),
                         expr: expr.clone(),
+                        seed: None,
+                        call_depth: 0,
                     };
                     match cur.try_div(*rhs) {
                         Ok(next) => self.world.set_resource_fixed64(tag.clone(), next),
@@ -1400,7 +1487,12 @@ impl Nuri for DetNuri {
                             crate::signals::ArithmeticFaultKind::DimensionMismatch { .. } => {
                                 Some("DIM_MISMATCH".to_string())
                             }
-                            _ => Some("DIV0".to_string()),
+                            crate::signals::ArithmeticFaultKind::DivByZero => {
+                                Some("DIV0".to_string())
+                            }
+                            crate::signals::ArithmeticFaultKind::Overflow => {
+                                Some("OVERFLOW".to_string())
+                            }
                         };
                         let diag_targets = if targets.is_empty() {
                             vec!["unknown".to_string()]
@@ -1421,7 +1513,7 @@ impl Nuri for DetNuri {
                             sam_hash: None,
                             source_span: ctx.source_span.clone(),
                             expr: ctx.expr.clone(),
-                            message: None,
+                            message: ctx.seed_context_label(),
                         };
                         sink.emit(Signal::Diag { event });
                         diag_seq += 1;
@@ -1460,8 +1552,73 @@ impl Geoul for InMemoryGeoul {
 
 #[cfg(test)]
 mod tests {
-    use super::{NuriWorld, ResourceMapEntry, ResourceValue};
-    use crate::Fixed64;
+    use super::{ComponentTag, EntityError, EntityId, NuriWorld, ResourceMapEntry, ResourceValue};
+    use crate::{DetRng, Fixed64};
+
+    #[test]
+    fn spawn_never_reuses_despawned_ids() {
+        let mut world = NuriWorld::new();
+        let a = world.spawn();
+        let b = world.spawn();
+        world.despawn(a).expect("despawn a");
+        assert!(!world.contains_entity(a));
+        assert!(world.contains_entity(b));
+        assert_eq!(world.spawn(), EntityId(2));
+        assert_eq!(world.despawn(a), Err(EntityError::NotFound(a)));
+        assert_eq!(
+            world.despawn(EntityId(99)).map_err(|err| err.code()),
+            Err("E_ENTITY_NOT_FOUND")
+        );
+    }
+
+    #[test]
+    fn seeded_spawn_despawn_sequence_is_replayable() {
+        fn run(seed: u64) -> (Vec<EntityId>, String) {
+            let mut rng = DetRng::new(seed);
+            let mut world = NuriWorld::new();
+            let mut live = Vec::new();
+            let mut spawned = Vec::new();
+            for _ in 0..64 {
+                if live.is_empty() || !rng.next_u64().is_multiple_of(3) {
+                    let id = world.spawn();
+                    live.push(id);
+                    spawned.push(id);
+                } else {
+                    let index = (rng.next_u64() % live.len() as u64) as usize;
+                    world.despawn(live.remove(index)).expect("despawn live");
+                }
+            }
+            (spawned, world.state_hash().to_hex())
+        }
+
+        let (ids_a, hash_a) = run(7);
+        let (ids_b, hash_b) = run(7);
+        assert_eq!(ids_a, ids_b);
+        assert_eq!(hash_a, hash_b);
+        assert!(ids_a.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn restore_rolls_back_to_checkpoint_hash() {
+        let mut world = NuriWorld::new();
+        let a = world.spawn();
+        world.set_component_json(a, ComponentTag("위치".to_string()), "{\"x\":1}".to_string());
+        world.set_resource_fixed64("점수".to_string(), Fixed64::from_i64(3));
+        let checkpoint = world.checkpoint();
+
+        let b = world.spawn();
+        world.set_component_json(a, ComponentTag("위치".to_string()), "{\"x\":9}".to_string());
+        world.set_component_json(b, ComponentTag("위치".to_string()), "{\"x\":2}".to_string());
+        world.set_resource_fixed64("점수".to_string(), Fixed64::from_i64(7));
+        world.despawn(a).expect("despawn a");
+        assert_ne!(world.state_hash(), checkpoint.state_hash());
+
+        world.restore(&checkpoint);
+        assert_eq!(world.state_hash(), checkpoint.state_hash());
+        assert!(world.contains_entity(a));
+        assert!(!world.contains_entity(b));
+        assert_eq!(world.spawn(), b);
+    }
 
     #[test]
     fn state_hash_filter_excludes_bogae_prefix() {
@@ -1,3 +1,5 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
 use std::thread::available_parallelism;
 
 use crate::{Fixed64, NuriWorld, ResourceHandle, StateHash};
@@ -6,6 +8,8 @@ use crate::{Fixed64, NuriWorld, ResourceHandle, StateHash};
 pub enum ThreadMode {
     Seq,
     Rayon(usize),
+    /// 스레드가 남은 realm을 하나씩 가져간다. 결과는 realm id 순으로 다시 모은다.
+    WorkStealing(usize),
     Auto,
 }
 
@@ -22,6 +26,22 @@ pub struct Realm {
 pub struct RealmStepInput {
     pub realm_id: usize,
     pub delta: i64,
+    /// 이 step에서 다른 realm으로 보내는 쪽지. 받는 쪽은 다음 step 시작에 본다.
+    pub send: Vec<RealmSend>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RealmSend {
+    pub to: usize,
+    pub payload: i64,
+}
+
+/// 배달된 쪽지. `seq`는 보낸 realm 안에서 매긴 순번이고, 받는 쪽에는 (from, seq) 순으로 온다.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RealmMessage {
+    pub from: usize,
+    pub seq: u64,
+    pub payload: i64,
 }
 
 #[derive(Clone, Debug)]
@@ -29,6 +49,8 @@ pub struct RealmStepOutput {
     pub realm_id: usize,
     pub madi: u64,
     pub state_hash: StateHash,
+    /// 이 step 시작에 배달된 쪽지.
+    pub received: Vec<RealmMessage>,
 }
 
 #[derive(Clone, Debug)]
@@ -36,6 +58,22 @@ pub struct MultiRealmManager {
     pub realms: Vec<Realm>,
     pub master_seed: u64,
     pub thread_mode: ThreadMode,
+    /// 켜면 매 step_batch마다 realm 0과 StateHash를 비교한다.
+    pub verify_mirror: bool,
+    /// 지금까지 끝난 step_batch 횟수.
+    pub tick: u64,
+    pub divergence: Option<MirrorDivergence>,
+    /// 지난 step에 보내 아직 배달하지 않은 쪽지 (받는 realm, 쪽지).
+    pending: Vec<(usize, RealmMessage)>,
+    /// realm마다 다음에 매길 쪽지 순번.
+    next_seq: Vec<u64>,
+}
+
+/// 미러 realm이 처음 어긋난 지점. tick은 0부터 센 step_batch 순번이다.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MirrorDivergence {
+    pub realm_id: usize,
+    pub tick: u64,
 }
 
 impl ThreadMode {
@@ -49,6 +87,13 @@ impl ThreadMode {
                     ThreadMode::Rayon(*n)
                 }
             }
+            ThreadMode::WorkStealing(n) => {
+                if *n <= 1 {
+                    ThreadMode::Seq
+                } else {
+                    ThreadMode::WorkStealing(*n)
+                }
+            }
             ThreadMode::Auto => {
                 let threads = available_parallelism()
                     .map(|count| count.get())
@@ -69,6 +114,7 @@ impl RealmStepOutput {
             realm_id: realm.id as usize,
             madi: realm.madi,
             state_hash: realm.state_hash,
+            received: Vec::new(),
         }
     }
 }
@@ -109,6 +155,44 @@ impl Realm {
         self.state_hash = self.world.state_hash();
         RealmStepOutput::from_realm(self)
     }
+
+    /// 입력 `count`개를 미리 합친 값으로 한 번에 반영한다. 합이 포화 없이 계산됐다면
+    /// `step_batch`와 같은 상태가 된다.
+    pub(crate) fn apply_reduced(&mut self, count: usize, value: Fixed64) -> RealmStepOutput {
+        if count == 0 {
+            return RealmStepOutput::from_realm(self);
+        }
+        self.world
+            .set_resource_fixed64("realm.value".to_string(), value);
+        for _ in 0..count {
+            self.rng = splitmix64(self.rng);
+        }
+        self.world
+            .set_resource_handle("realm.rng".to_string(), ResourceHandle::from_raw(self.rng));
+        self.madi = self.madi.wrapping_add(count as u64);
+        self.state_hash = self.world.state_hash();
+        RealmStepOutput::from_realm(self)
+    }
+
+    /// 받은 쪽지의 payload를 차례로 `realm.value`에 더한다. 마디는 넘기지 않는다.
+    pub fn receive(&mut self, messages: &[RealmMessage]) {
+        if messages.is_empty() {
+            return;
+        }
+        let mut value = self.value();
+        for message in messages {
+            value = value.saturating_add(Fixed64::from_i64(message.payload));
+        }
+        self.world
+            .set_resource_fixed64("realm.value".to_string(), value);
+        self.state_hash = self.world.state_hash();
+    }
+
+    pub(crate) fn value(&self) -> Fixed64 {
+        self.world
+            .get_resource_fixed64("realm.value")
+            .unwrap_or(Fixed64::ZERO)
+    }
 }
 
 impl MultiRealmManager {
@@ -122,9 +206,37 @@ impl MultiRealmManager {
             realms,
             master_seed,
             thread_mode,
+            verify_mirror: false,
+            tick: 0,
+            divergence: None,
+            pending: Vec::new(),
+            next_seq: vec![0; realm_count],
         }
     }
 
+    /// 모든 realm을 같은 seed로 만들고 미러 검증을 켠다.
+    pub fn new_mirrored(realm_count: usize, master_seed: u64, thread_mode: ThreadMode) -> Self {
+        let seed = mix64(master_seed, 0);
+        let realms = (0..realm_count)
+            .map(|id| Realm::new(id as u64, seed))
+            .collect();
+        Self {
+            realms,
+            master_seed,
+            thread_mode,
+            verify_mirror: true,
+            tick: 0,
+            divergence: None,
+            pending: Vec::new(),
+            next_seq: vec![0; realm_count],
+        }
+    }
+
+    /// 처음 어긋난 realm과 tick. 한 번 기록되면 이후 step에서 바뀌지 않는다.
+    pub fn mirror_divergence(&self) -> Option<MirrorDivergence> {
+        self.divergence
+    }
+
     pub fn realm_count(&self) -> usize {
         self.realms.len()
     }
@@ -150,8 +262,39 @@ impl MultiRealmManager {
                     self.realms.len()
                 ));
             }
+            if let Some(send) = input.send.iter().find(|send| send.to >= self.realms.len()) {
+                return Err(format!(
+                    "E_REALM_MESSAGE_TO_OUT_OF_RANGE from={} to={} realm_count={}",
+                    input.realm_id,
+                    send.to,
+                    self.realms.len()
+                ));
+            }
             buckets[input.realm_id].push(input);
         }
+        let mut outgoing = Vec::new();
+        for (from, bucket) in buckets.iter().enumerate() {
+            for send in bucket.iter().flat_map(|input| &input.send) {
+                let seq = self.next_seq[from];
+                self.next_seq[from] += 1;
+                let message = RealmMessage {
+                    from,
+                    seq,
+                    payload: send.payload,
+                };
+                outgoing.push((send.to, message));
+            }
+        }
+
+        let mut inboxes: Vec<Vec<RealmMessage>> = vec![Vec::new(); self.realms.len()];
+        let mut delivered = std::mem::replace(&mut self.pending, outgoing);
+        delivered.sort_by_key(|(to, message)| (*to, message.from, message.seq));
+        for (to, message) in delivered {
+            inboxes[to].push(message);
+        }
+        for (realm, inbox) in self.realms.iter_mut().zip(&inboxes) {
+            realm.receive(inbox);
+        }
 
         let mut outputs: Vec<RealmStepOutput> = self
             .realms
@@ -181,19 +324,138 @@ impl MultiRealmManager {
                         });
                 });
             }
+            ThreadMode::WorkStealing(threads) => {
+                for out in step_work_stealing(&mut self.realms, &buckets, threads)? {
+                    let idx = out.realm_id;
+                    outputs[idx] = out;
+                }
+            }
             ThreadMode::Auto => unreachable!("resolved thread mode"),
         }
 
+        for (out, inbox) in outputs.iter_mut().zip(inboxes) {
+            out.received = inbox;
+        }
+
+        if self.verify_mirror && self.divergence.is_none() {
+            if let Some(first) = outputs.first() {
+                if let Some(out) = outputs
+                    .iter()
+                    .find(|out| out.state_hash != first.state_hash)
+                {
+                    self.divergence = Some(MirrorDivergence {
+                        realm_id: out.realm_id,
+                        tick: self.tick,
+                    });
+                }
+            }
+        }
+        self.tick = self.tick.wrapping_add(1);
+
         Ok(outputs)
     }
 }
 
+fn step_work_stealing(
+    realms: &mut [Realm],
+    buckets: &[Vec<RealmStepInput>],
+    threads: usize,
+) -> Result<Vec<RealmStepOutput>, String> {
+    let cells: Vec<Mutex<&mut Realm>> = realms.iter_mut().map(Mutex::new).collect();
+    let next = AtomicUsize::new(0);
+    let collected = Mutex::new(Vec::with_capacity(cells.len()));
+    let workers = threads.min(cells.len()).max(1);
+    std::thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| loop {
+                let idx = next.fetch_add(1, Ordering::Relaxed);
+                let Some(cell) = cells.get(idx) else {
+                    break;
+                };
+                let out = {
+                    let mut realm = cell.lock().unwrap_or_else(|err| err.into_inner());
+                    realm.step_batch(&buckets[idx])
+                };
+                collected
+                    .lock()
+                    .unwrap_or_else(|err| err.into_inner())
+                    .push(out);
+            });
+        }
+    });
+    let mut outputs = collected
+        .into_inner()
+        .map_err(|_| "E_REALM_WORKER poisoned".to_string())?;
+    if outputs.len() != cells.len() {
+        return Err(format!(
+            "E_REALM_WORKER incomplete done={} realm_count={}",
+            outputs.len(),
+            cells.len()
+        ));
+    }
+    outputs.sort_by_key(|out| out.realm_id);
+    Ok(outputs)
+}
+
 pub fn mix64(master_seed: u64, realm_id: u64) -> u64 {
     let mut x = master_seed ^ realm_id.wrapping_mul(0x9e3779b97f4a7c15);
     x = splitmix64(x);
     x
 }
 
+const SPLITMIX_GAMMA: u64 = 0x9e3779b97f4a7c15;
+
+/// mix64 위에 올린 결정적 난수 흐름. 같은 state면 같은 수열, fork는 부모를 건드리지 않는다.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DetRng {
+    pub state: u64,
+}
+
+impl DetRng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// realm별 흐름. `MultiRealmManager`가 realm seed를 만드는 방식과 같다.
+    pub fn for_realm(master_seed: u64, realm_id: u64) -> Self {
+        Self::new(mix64(master_seed, realm_id))
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let out = splitmix64(self.state);
+        self.state = self.state.wrapping_add(SPLITMIX_GAMMA);
+        out
+    }
+
+    /// `[range.start, range.end)`에서 raw 단위로 고른다. 빈 범위면 start.
+    pub fn next_fixed(&mut self, range: std::ops::Range<Fixed64>) -> Fixed64 {
+        let start = range.start.raw_i64();
+        let end = range.end.raw_i64();
+        let value = self.next_u64();
+        if end <= start {
+            return range.start;
+        }
+        let span = (end as i128 - start as i128) as u128;
+        let offset = (value as u128) % span;
+        Fixed64::from_raw_i64((start as i128 + offset as i128) as i64)
+    }
+
+    /// 라벨로 하위 흐름을 만든다. 같은 부모 state와 라벨이면 항상 같은 흐름이다.
+    pub fn fork(&self, label: &str) -> DetRng {
+        DetRng::new(mix64(self.state, label_hash(label)))
+    }
+}
+
+fn label_hash(label: &str) -> u64 {
+    // FNV-1a 64
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in label.as_bytes() {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
 fn splitmix64(mut x: u64) -> u64 {
     x = x.wrapping_add(0x9e3779b97f4a7c15);
     let mut z = x;
@@ -201,3 +463,163 @@ fn splitmix64(mut x: u64) -> u64 {
     z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
     z ^ (z >> 31)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn uneven_inputs(realm_count: usize) -> Vec<RealmStepInput> {
+        let mut inputs = Vec::new();
+        for realm_id in 0..realm_count {
+            for step in 0..(realm_id * 3 + 1) {
+                inputs.push(RealmStepInput {
+                    realm_id,
+                    delta: (step as i64) - (realm_id as i64),
+                    send: Vec::new(),
+                });
+            }
+        }
+        inputs.reverse();
+        inputs
+    }
+
+    #[test]
+    fn det_rng_forks_are_independent_and_reproducible() {
+        let root = DetRng::for_realm(42, 3);
+        let take = |mut rng: DetRng| (0..8).map(|_| rng.next_u64()).collect::<Vec<_>>();
+
+        let a = take(root.fork("a"));
+        let b = take(root.fork("b"));
+        assert_ne!(a, b);
+        assert_eq!(a, take(root.fork("a")));
+        assert_eq!(b, take(root.fork("b")));
+        assert_ne!(a, take(DetRng::for_realm(42, 4).fork("a")));
+
+        let mut rng = root.fork("fixed");
+        let low = Fixed64::from_i64(-2);
+        let high = Fixed64::from_i64(3);
+        for _ in 0..32 {
+            let value = rng.next_fixed(low..high);
+            assert!(value >= low && value < high);
+        }
+        assert_eq!(rng.next_fixed(high..low), high);
+    }
+
+    #[test]
+    fn mirror_divergence_reports_first_realm_and_tick() {
+        let broadcast = |delta: i64| {
+            (0..3)
+                .map(|realm_id| RealmStepInput {
+                    realm_id,
+                    delta,
+                    send: Vec::new(),
+                })
+                .collect::<Vec<_>>()
+        };
+        let mut manager = MultiRealmManager::new_mirrored(3, 9, ThreadMode::Seq);
+        manager.step_batch(&broadcast(1)).expect("tick 0");
+        manager.step_batch(&broadcast(2)).expect("tick 1");
+        assert_eq!(manager.mirror_divergence(), None);
+
+        let mut perturbed = broadcast(3);
+        perturbed[2].delta = 4;
+        manager.step_batch(&perturbed).expect("tick 2");
+        manager.step_batch(&broadcast(5)).expect("tick 3");
+        assert_eq!(
+            manager.mirror_divergence(),
+            Some(MirrorDivergence {
+                realm_id: 2,
+                tick: 2
+            })
+        );
+
+        let mut independent = MultiRealmManager::new(3, 9, ThreadMode::Seq);
+        independent.step_batch(&broadcast(1)).expect("independent");
+        assert_eq!(independent.mirror_divergence(), None);
+    }
+
+    #[test]
+    fn work_stealing_matches_sequential_state_hash() {
+        let inputs = uneven_inputs(7);
+        let mut seq = MultiRealmManager::new(7, 42, ThreadMode::Seq);
+        let mut stealing = MultiRealmManager::new(7, 42, ThreadMode::WorkStealing(3));
+        for _ in 0..3 {
+            let seq_out = seq.step_batch(&inputs).expect("seq");
+            let steal_out = stealing.step_batch(&inputs).expect("work stealing");
+            let seq_hashes: Vec<StateHash> = seq_out.iter().map(|out| out.state_hash).collect();
+            let steal_hashes: Vec<StateHash> = steal_out.iter().map(|out| out.state_hash).collect();
+            assert_eq!(seq_hashes, steal_hashes);
+            assert_eq!(
+                steal_out.iter().map(|out| out.realm_id).collect::<Vec<_>>(),
+                (0..7).collect::<Vec<_>>()
+            );
+        }
+        assert_eq!(seq.state_hashes(), stealing.state_hashes());
+    }
+
+    #[test]
+    fn realm_messages_arrive_next_step_in_source_sequence_order() {
+        let send = |realm_id: usize, sends: &[(usize, i64)]| RealmStepInput {
+            realm_id,
+            delta: 0,
+            send: sends
+                .iter()
+                .map(|&(to, payload)| RealmSend { to, payload })
+                .collect(),
+        };
+        let step0 = vec![
+            send(2, &[(1, 9)]),
+            send(0, &[(1, 5)]),
+            send(0, &[(1, 7), (3, 1)]),
+        ];
+        let expected = vec![
+            RealmMessage {
+                from: 0,
+                seq: 0,
+                payload: 5,
+            },
+            RealmMessage {
+                from: 0,
+                seq: 1,
+                payload: 7,
+            },
+            RealmMessage {
+                from: 2,
+                seq: 0,
+                payload: 9,
+            },
+        ];
+
+        let mut reference: Option<Vec<StateHash>> = None;
+        for mode in [
+            ThreadMode::Seq,
+            ThreadMode::Rayon(4),
+            ThreadMode::WorkStealing(3),
+        ] {
+            let mut manager = MultiRealmManager::new(4, 11, mode);
+            let first = manager.step_batch(&step0).expect("step 0");
+            assert!(first.iter().all(|out| out.received.is_empty()));
+
+            let second = manager.step_batch(&[]).expect("step 1");
+            assert_eq!(second[1].received, expected);
+            assert_eq!(second[3].received.len(), 1);
+            assert!(second[0].received.is_empty());
+            assert_eq!(manager.realms[1].value(), Fixed64::from_i64(21));
+
+            let third = manager.step_batch(&[]).expect("step 2");
+            assert!(third.iter().all(|out| out.received.is_empty()));
+
+            let hashes = manager.state_hashes();
+            match &reference {
+                Some(reference) => assert_eq!(&hashes, reference),
+                None => reference = Some(hashes),
+            }
+        }
+
+        let mut manager = MultiRealmManager::new(2, 11, ThreadMode::Seq);
+        let err = manager
+            .step_batch(&[send(0, &[(5, 1)])])
+            .expect_err("unknown target");
+        assert!(err.starts_with("E_REALM_MESSAGE_TO_OUT_OF_RANGE"));
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use xxhash_rust::xxh3::{xxh3_64, xxh3_64_with_seed};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -19,9 +21,161 @@ impl ResourceHandle {
     pub fn to_hex(self) -> String {
         format!("{:016x}", self.0)
     }
+
+    /// 자원 바이트의 blake3 해시(hex). 경로가 달라도 내용이 같으면 값이 같다.
+    pub fn content_hash(bytes: &[u8]) -> String {
+        blake3::hash(bytes).to_hex().to_string()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DedupEntry {
+    pub canonical: ResourceHandle,
+    pub aliases: Vec<ResourceHandle>,
+}
+
+/// 내용 해시별로 핸들을 묶는다. 입력 순서상 첫 핸들이 정본, 나머지는 별칭이 된다.
+pub fn dedup<'a, I>(handles: I) -> BTreeMap<String, DedupEntry>
+where
+    I: IntoIterator<Item = (ResourceHandle, &'a [u8])>,
+{
+    let mut out: BTreeMap<String, DedupEntry> = BTreeMap::new();
+    for (handle, bytes) in handles {
+        let hash = ResourceHandle::content_hash(bytes);
+        match out.get_mut(&hash) {
+            Some(entry) => {
+                if entry.canonical != handle && !entry.aliases.contains(&handle) {
+                    entry.aliases.push(handle);
+                }
+            }
+            None => {
+                out.insert(
+                    hash,
+                    DedupEntry {
+                        canonical: handle,
+                        aliases: Vec::new(),
+                    },
+                );
+            }
+        }
+    }
+    out
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AssetPathError {
+    Empty,
+    Backslash(String),
+    Absolute(String),
+    ParentSegment(String),
+}
+
+impl AssetPathError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            AssetPathError::Empty => "ASSET_PATH_EMPTY",
+            AssetPathError::Backslash(_) => "ASSET_PATH_BACKSLASH",
+            AssetPathError::Absolute(_) => "ASSET_PATH_ABSOLUTE",
+            AssetPathError::ParentSegment(_) => "ASSET_PATH_TRAVERSAL",
+        }
+    }
+}
+
+/// 번들 루트 밖을 가리킬 수 있는 경로(`..`, 절대 경로, 역슬래시)는 거부한다.
+pub fn validate_bundle_path(path: &str) -> Result<(), AssetPathError> {
+    if path.is_empty() {
+        return Err(AssetPathError::Empty);
+    }
+    if path.contains('\\') {
+        return Err(AssetPathError::Backslash(path.to_string()));
+    }
+    let bytes = path.as_bytes();
+    let has_drive = bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':';
+    if path.starts_with('/') || has_drive {
+        return Err(AssetPathError::Absolute(path.to_string()));
+    }
+    if path.split('/').any(|segment| segment == "..") {
+        return Err(AssetPathError::ParentSegment(path.to_string()));
+    }
+    Ok(())
 }
 
-pub fn asset_handle_from_bundle_path(bundle_id: &str, path: &str) -> ResourceHandle {
+pub fn asset_handle_from_bundle_path(
+    bundle_id: &str,
+    path: &str,
+) -> Result<ResourceHandle, AssetPathError> {
+    validate_bundle_path(path)?;
     let key = format!("{bundle_id}::{path}");
-    ResourceHandle(xxh3_64_with_seed(key.as_bytes(), 0))
+    Ok(ResourceHandle(xxh3_64_with_seed(key.as_bytes(), 0)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundle_path_accepts_nested_path() {
+        let handle =
+            asset_handle_from_bundle_path("demo", "그림/캐릭터/주인공.png").expect("nested path");
+        let again =
+            asset_handle_from_bundle_path("demo", "그림/캐릭터/주인공.png").expect("nested path");
+        assert_eq!(handle, again);
+    }
+
+    #[test]
+    fn bundle_path_rejects_traversal() {
+        assert_eq!(
+            asset_handle_from_bundle_path("demo", "../../etc/passwd"),
+            Err(AssetPathError::ParentSegment(
+                "../../etc/passwd".to_string()
+            ))
+        );
+        assert_eq!(
+            asset_handle_from_bundle_path("demo", "그림/../../비밀.txt").map_err(|e| e.code()),
+            Err("ASSET_PATH_TRAVERSAL")
+        );
+    }
+
+    #[test]
+    fn bundle_path_rejects_absolute_and_backslash() {
+        assert_eq!(
+            asset_handle_from_bundle_path("demo", "/etc/passwd"),
+            Err(AssetPathError::Absolute("/etc/passwd".to_string()))
+        );
+        assert_eq!(
+            asset_handle_from_bundle_path("demo", "C:/secret.png").map_err(|e| e.code()),
+            Err("ASSET_PATH_ABSOLUTE")
+        );
+        assert_eq!(
+            asset_handle_from_bundle_path("demo", "그림\\주인공.png").map_err(|e| e.code()),
+            Err("ASSET_PATH_BACKSLASH")
+        );
+        assert_eq!(
+            asset_handle_from_bundle_path("demo", ""),
+            Err(AssetPathError::Empty)
+        );
+    }
+
+    #[test]
+    fn dedup_collapses_identical_bytes() {
+        let a = asset_handle_from_bundle_path("demo", "그림/주인공.png").expect("path");
+        let b = asset_handle_from_bundle_path("demo", "그림/복사/주인공.png").expect("path");
+        let c = asset_handle_from_bundle_path("demo", "그림/적.png").expect("path");
+        let same: &[u8] = b"PNG-BYTES";
+        let other: &[u8] = b"OTHER-BYTES";
+        assert_ne!(a, b);
+        assert_eq!(
+            ResourceHandle::content_hash(same),
+            ResourceHandle::content_hash(b"PNG-BYTES")
+        );
+
+        let groups = dedup([(a, same), (b, same), (c, other)]);
+        assert_eq!(groups.len(), 2);
+        let entry = &groups[&ResourceHandle::content_hash(same)];
+        assert_eq!(entry.canonical, a);
+        assert_eq!(entry.aliases, vec![b]);
+        let entry = &groups[&ResourceHandle::content_hash(other)];
+        assert_eq!(entry.canonical, c);
+        assert!(entry.aliases.is_empty());
+    }
 }
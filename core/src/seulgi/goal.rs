@@ -18,16 +18,41 @@ pub enum GoalCondition {
     Custom { predicate: String },
 }
 
+pub const DEFAULT_GOAL_PRIORITY: u8 = 128;
+
+/// `[우선순위 N]` 꼬리표(0~255)는 조건과 goal_id 계산에서 빠지고 priority로 간다.
 pub fn parse_dorok(raw: &str) -> TargetState {
     let normalized = normalize_korean_text(raw);
-    let text = normalize_goal_text(&normalized);
+    let (stripped, priority) = extract_priority_tag(&normalized);
+    let text = normalize_goal_text(&stripped);
     let condition = parse_goal_condition(&text);
     TargetState {
         agent_id: 0,
         goal_id: hash_to_id(&format!("goal:{}", text)),
         condition,
-        priority: 128,
+        priority: priority.unwrap_or(DEFAULT_GOAL_PRIORITY),
+    }
+}
+
+fn extract_priority_tag(text: &str) -> (String, Option<u8>) {
+    let Some(start) = text.find("[우선순위") else {
+        return (text.to_string(), None);
+    };
+    let Some(end) = text[start..].find(']').map(|idx| idx + start) else {
+        return (text.to_string(), None);
+    };
+    let inner = text[start + "[우선순위".len()..end].trim_start_matches([' ', '=', ':']);
+    let Ok(priority) = inner.trim().parse::<u8>() else {
+        return (text.to_string(), None);
+    };
+    let mut rest = String::new();
+    rest.push_str(text[..start].trim_end());
+    let tail = text[end + 1..].trim_start();
+    if !rest.is_empty() && !tail.is_empty() {
+        rest.push(' ');
     }
+    rest.push_str(tail);
+    (rest, Some(priority))
 }
 
 pub fn target_state_detjson(target: &TargetState) -> String {
@@ -407,6 +432,17 @@ mod tests {
         assert!(matches!(goal.condition, GoalCondition::StateEquals { .. }));
     }
 
+    #[test]
+    fn parse_goal_priority_tag() {
+        let tagged = parse_dorok("[우선순위 200] 상태=준비");
+        let plain = parse_dorok("상태=준비");
+        assert_eq!(tagged.priority, 200);
+        assert_eq!(plain.priority, DEFAULT_GOAL_PRIORITY);
+        assert_eq!(tagged.goal_id, plain.goal_id);
+        assert_eq!(tagged.condition, plain.condition);
+        assert_eq!(parse_dorok("사과를 줍도록 [우선순위=7]").priority, 7);
+    }
+
     #[test]
     fn parse_goal_custom() {
         let goal = parse_dorok("알 수 없는 조건");
@@ -109,7 +109,7 @@ impl Node {
 
 impl PartialEq for Node {
     fn eq(&self, other: &Self) -> bool {
-        self.state_key == other.state_key
+        self.cmp(other) == std::cmp::Ordering::Equal
     }
 }
 
@@ -121,18 +121,26 @@ impl PartialOrd for Node {
     }
 }
 
+/// `BinaryHeap`은 최대 힙이므로 비교를 뒤집어 f가 가장 작은 노드가 먼저 나오게 한다.
+/// f가 같으면 상태 키, 그다음 지나온 행동 id 순서로 결정적으로 고른다.
 impl Ord for Node {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         other
             .f_cost()
             .cmp(&self.f_cost())
             .then_with(|| self.state_key.cmp(&other.state_key))
+            .then_with(|| {
+                let other_ids = other.path.iter().map(|action| action.id);
+                other_ids.cmp(self.path.iter().map(|action| action.id))
+            })
     }
 }
 
 pub struct GoapPlanner;
 
 impl GoapPlanner {
+    /// 행동 비용 합이 가장 작은 계획을 A*로 찾는다. 비용이 같은 계획끼리는 `Node`의
+    /// 비교 순서대로 골라 같은 입력이면 늘 같은 계획이 나온다.
     pub fn plan(
         initial_state: &WorldState,
         goal: &TargetState,
@@ -141,7 +149,8 @@ impl GoapPlanner {
         let mut open_set = BinaryHeap::new();
         let mut closed_set: BTreeSet<Vec<(String, String)>> = BTreeSet::new();
 
-        let h_cost = Self::heuristic(initial_state, goal);
+        let goal_step_cost = Self::min_goal_step_cost(goal, available_actions);
+        let h_cost = Self::heuristic(initial_state, goal, goal_step_cost);
         let mut start_node = Node::new(initial_state.clone(), 0, h_cost);
         start_node.path = Vec::new();
         open_set.push(start_node);
@@ -178,7 +187,7 @@ impl GoapPlanner {
                 }
 
                 let new_g_cost = current.g_cost.saturating_add(action.cost);
-                let new_h_cost = Self::heuristic(&next_state, goal);
+                let new_h_cost = Self::heuristic(&next_state, goal, goal_step_cost);
 
                 let mut next_node = Node::new(next_state, new_g_cost, new_h_cost);
                 next_node.state_key = next_key;
@@ -192,6 +201,21 @@ impl GoapPlanner {
         Err(PlanError::NoSolution)
     }
 
+    /// 우선순위가 높은 목표부터 계획을 시도해 처음 풀리는 목표의 계획을 돌려준다.
+    /// 우선순위가 같으면 선언 순서(앞선 목표)가 이긴다.
+    pub fn plan_prioritized(
+        initial_state: &WorldState,
+        goals: &[TargetState],
+        available_actions: &[Action],
+    ) -> Result<Plan, PlanError> {
+        for idx in goal_priority_order(goals) {
+            if let Ok(plan) = Self::plan(initial_state, &goals[idx], available_actions) {
+                return Ok(plan);
+            }
+        }
+        Err(PlanError::NoSolution)
+    }
+
     pub fn validate_plan(plan: &Plan, initial_state: &WorldState, goal: &TargetState) -> bool {
         let mut state = initial_state.clone();
         for action in &plan.actions {
@@ -222,25 +246,41 @@ impl GoapPlanner {
         out
     }
 
-    fn heuristic(state: &WorldState, goal: &TargetState) -> u16 {
+    /// 목표를 아직 못 이룬 상태라면 마지막 한 걸음은 목표 값을 쓰는 행동이어야 하므로,
+    /// 그런 행동의 최소 비용은 남은 비용을 넘지 않는다(과대추정 없음 → 비용 최적).
+    fn heuristic(state: &WorldState, goal: &TargetState, goal_step_cost: u16) -> u16 {
+        if state.satisfies_goal(goal) {
+            0
+        } else {
+            goal_step_cost
+        }
+    }
+
+    fn min_goal_step_cost(goal: &TargetState, available_actions: &[Action]) -> u16 {
         match &goal.condition {
-            GoalCondition::StateEquals { key, value } => {
-                if state
-                    .variables
-                    .get(key)
-                    .map(|v| v == value)
-                    .unwrap_or(false)
-                {
-                    0
-                } else {
-                    100
-                }
-            }
+            GoalCondition::StateEquals { key, value } => available_actions
+                .iter()
+                .filter(|action| action.effects.iter().any(|(k, v)| k == key && v == value))
+                .map(|action| action.cost)
+                .min()
+                .unwrap_or(0),
             _ => 0,
         }
     }
 }
 
+/// 목표 인덱스를 (priority 내림차순, 선언 순서 오름차순)으로 정렬한다.
+pub fn goal_priority_order(goals: &[TargetState]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..goals.len()).collect();
+    order.sort_by(|&a, &b| {
+        goals[b]
+            .priority
+            .cmp(&goals[a].priority)
+            .then_with(|| a.cmp(&b))
+    });
+    order
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PlanError {
     NoSolution,
@@ -391,4 +431,110 @@ mod tests {
         assert_eq!(plan.actions.len(), 2);
         assert!(GoapPlanner::validate_plan(&plan, &initial, &goal));
     }
+
+    #[test]
+    fn plan_picks_cheapest_of_two_routes_and_reports_its_cost() {
+        let initial = WorldState::new();
+        let goal = door_goal(7, "open", 128);
+        let actions = vec![
+            Action {
+                id: 1,
+                name: "Break door".to_string(),
+                preconditions: vec![],
+                effects: vec![("door".to_string(), "open".to_string())],
+                cost: 90,
+            },
+            Action {
+                id: 2,
+                name: "Find key".to_string(),
+                preconditions: vec![],
+                effects: vec![("key".to_string(), "yes".to_string())],
+                cost: 20,
+            },
+            Action {
+                id: 3,
+                name: "Unlock door".to_string(),
+                preconditions: vec![("key".to_string(), "yes".to_string())],
+                effects: vec![("door".to_string(), "open".to_string())],
+                cost: 20,
+            },
+        ];
+
+        let plan = GoapPlanner::plan(&initial, &goal, &actions).unwrap();
+        assert_eq!(plan.total_cost, 40);
+        let ids: Vec<u64> = plan.actions.iter().map(|action| action.id).collect();
+        assert_eq!(ids, vec![2, 3]);
+        assert!(GoapPlanner::validate_plan(&plan, &initial, &goal));
+        assert!(plan_detjson(&plan).contains("\"total_cost\":40"));
+
+        let again = GoapPlanner::plan(&initial, &goal, &actions).unwrap();
+        assert_eq!(plan, again);
+    }
+
+    fn door_goal(goal_id: u64, value: &str, priority: u8) -> TargetState {
+        TargetState {
+            agent_id: 1,
+            goal_id,
+            condition: GoalCondition::StateEquals {
+                key: "door".to_string(),
+                value: value.to_string(),
+            },
+            priority,
+        }
+    }
+
+    fn door_actions() -> Vec<Action> {
+        vec![
+            Action {
+                id: 1,
+                name: "Open door".to_string(),
+                preconditions: vec![],
+                effects: vec![("door".to_string(), "open".to_string())],
+                cost: 1,
+            },
+            Action {
+                id: 2,
+                name: "Lock door".to_string(),
+                preconditions: vec![],
+                effects: vec![("door".to_string(), "locked".to_string())],
+                cost: 3,
+            },
+        ]
+    }
+
+    #[test]
+    fn prioritized_plan_prefers_higher_priority_goal() {
+        let mut initial = WorldState::new();
+        initial
+            .variables
+            .insert("door".to_string(), "closed".to_string());
+        let actions = door_actions();
+
+        let goals = vec![door_goal(10, "open", 50), door_goal(20, "locked", 200)];
+        let plan = GoapPlanner::plan_prioritized(&initial, &goals, &actions).unwrap();
+        assert_eq!(plan.goal_id, 20);
+
+        let swapped = vec![door_goal(10, "open", 200), door_goal(20, "locked", 50)];
+        let plan = GoapPlanner::plan_prioritized(&initial, &swapped, &actions).unwrap();
+        assert_eq!(plan.goal_id, 10);
+    }
+
+    #[test]
+    fn prioritized_plan_breaks_ties_by_declaration_order() {
+        let initial = WorldState::new();
+        let actions = door_actions();
+        let goals = vec![door_goal(20, "locked", 128), door_goal(10, "open", 128)];
+        assert_eq!(goal_priority_order(&goals), vec![0, 1]);
+        let plan = GoapPlanner::plan_prioritized(&initial, &goals, &actions).unwrap();
+        assert_eq!(plan.goal_id, 20);
+    }
+
+    #[test]
+    fn prioritized_plan_falls_back_to_solvable_goal() {
+        let initial = WorldState::new();
+        let actions = door_actions();
+        let goals = vec![door_goal(10, "open", 10), door_goal(30, "broken", 255)];
+        let plan = GoapPlanner::plan_prioritized(&initial, &goals, &actions).unwrap();
+        assert_eq!(plan.goal_id, 10);
+    }
 }
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use crate::fixed64::Fixed64;
 use crate::platform::{SeulgiIntent, SeulgiPacket};
 
@@ -22,6 +24,51 @@ impl IntentRecord {
     }
 }
 
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MergedIntents {
+    pub records: Vec<IntentRecord>,
+    pub dropped: u64,
+}
+
+/// 같은 에이전트·같은 target_madi·같은 의도는 하나로 합친다.
+/// 남는 기록은 가장 작은 recv_seq를 가진 것이고, 합쳐진 수는 `dropped`에 센다.
+pub fn merge_records(records: Vec<IntentRecord>) -> MergedIntents {
+    let mut merged: Vec<IntentRecord> = Vec::with_capacity(records.len());
+    let mut by_key: BTreeMap<(u64, u64, String), usize> = BTreeMap::new();
+    let mut dropped = 0u64;
+    for record in records {
+        let key = intent_dedup_key(&record);
+        match by_key.get(&key) {
+            Some(&idx) => {
+                if record.recv_seq < merged[idx].recv_seq {
+                    merged[idx] = record;
+                }
+                dropped += 1;
+            }
+            None => {
+                by_key.insert(key, merged.len());
+                merged.push(record);
+            }
+        }
+    }
+    MergedIntents {
+        records: merged,
+        dropped,
+    }
+}
+
+pub fn merge_packets(packets: &[SeulgiPacket]) -> MergedIntents {
+    merge_records(packets.iter().map(IntentRecord::from_packet).collect())
+}
+
+fn intent_dedup_key(record: &IntentRecord) -> (u64, u64, String) {
+    (
+        record.agent_id,
+        record.target_madi,
+        intent_to_detjson(&record.intent),
+    )
+}
+
 pub fn intent_kind(intent: &SeulgiIntent) -> &'static str {
     match intent {
         SeulgiIntent::None => "None",
@@ -53,7 +100,84 @@ pub fn intent_to_detjson(intent: &SeulgiIntent) -> String {
     out
 }
 
+/// 의도를 동작 이름·대상 개체·나머지 인자(핀)로 풀어 놓은 모양.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodedIntent {
+    pub action: &'static str,
+    pub target: Option<u64>,
+    pub pins: Vec<(&'static str, IntentPin)>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IntentPin {
+    Fixed(Fixed64),
+    Text(String),
+}
+
+pub fn decode_intent(intent: &SeulgiIntent) -> DecodedIntent {
+    let (target, pins) = match intent {
+        SeulgiIntent::None => (None, Vec::new()),
+        SeulgiIntent::MoveTo { x, y } => (
+            None,
+            vec![("x", IntentPin::Fixed(*x)), ("y", IntentPin::Fixed(*y))],
+        ),
+        SeulgiIntent::Attack { target_id } => (Some(*target_id), Vec::new()),
+        SeulgiIntent::Say { text } => (None, vec![("text", IntentPin::Text(text.clone()))]),
+    };
+    DecodedIntent {
+        action: intent_kind(intent),
+        target,
+        pins,
+    }
+}
+
+/// inspect 풀이용 묶음. 순서는 `intent_bundle_detjson`과 같다.
+pub fn decoded_bundle_detjson(records: &[IntentRecord]) -> String {
+    let mut items = records.to_vec();
+    items.sort_by_key(export_sort_key);
+
+    let mut out = String::new();
+    out.push('{');
+    push_kv_str(&mut out, "schema", "seulgi.intent_decoded.v1", true);
+    out.push_str(",\"items\":[");
+    for (idx, item) in items.iter().enumerate() {
+        if idx > 0 {
+            out.push(',');
+        }
+        let decoded = decode_intent(&item.intent);
+        out.push('{');
+        push_kv_num(&mut out, "accepted_madi", item.accepted_madi as i64, true);
+        push_kv_num(&mut out, "agent_id", item.agent_id as i64, false);
+        push_kv_num(&mut out, "recv_seq", item.recv_seq as i64, false);
+        push_kv_num(&mut out, "target_madi", item.target_madi as i64, false);
+        push_kv_str(&mut out, "action", decoded.action, false);
+        match decoded.target {
+            Some(target) => push_kv_num(&mut out, "target", target as i64, false),
+            None => out.push_str(",\"target\":null"),
+        }
+        out.push_str(",\"pins\":{");
+        for (pin_idx, (name, pin)) in decoded.pins.iter().enumerate() {
+            match pin {
+                IntentPin::Fixed(value) => push_kv_fixed(&mut out, name, *value, pin_idx == 0),
+                IntentPin::Text(text) => push_kv_str(&mut out, name, text, pin_idx == 0),
+            }
+        }
+        out.push_str("}}");
+    }
+    out.push_str("]}");
+    out
+}
+
 pub fn intent_bundle_detjson(records: &[IntentRecord]) -> String {
+    bundle_detjson(records, 0)
+}
+
+/// 병합 결과용 묶음. 합쳐진 의도가 있을 때만 `dropped`를 적는다.
+pub fn merged_bundle_detjson(merged: &MergedIntents) -> String {
+    bundle_detjson(&merged.records, merged.dropped)
+}
+
+fn bundle_detjson(records: &[IntentRecord], dropped: u64) -> String {
     let mut items = records.to_vec();
     // Export bundle ordering is intentionally metadata-rich and separate from runtime execution
     // ordering. Runtime execution ordering is hard-cut in SeulgiPacket::stable_sort_key.
@@ -62,6 +186,9 @@ pub fn intent_bundle_detjson(records: &[IntentRecord]) -> String {
     let mut out = String::new();
     out.push('{');
     push_kv_str(&mut out, "schema", "seulgi.intent_bundle.v1", true);
+    if dropped > 0 {
+        push_kv_num(&mut out, "dropped", dropped as i64, false);
+    }
     out.push_str(",\"items\":[");
     for (idx, item) in items.iter().enumerate() {
         if idx > 0 {
@@ -137,8 +264,12 @@ fn escape_json(input: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::{export_sort_key, IntentRecord};
-    use crate::platform::SeulgiIntent;
+    use super::{
+        decode_intent, decoded_bundle_detjson, export_sort_key, merge_packets,
+        merged_bundle_detjson, IntentPin, IntentRecord,
+    };
+    use crate::fixed64::Fixed64;
+    use crate::platform::{InputSource, SeulgiIntent, SeulgiPacket};
 
     #[test]
     fn intent_bundle_export_ordering_is_distinct_from_execution_ordering() {
@@ -162,4 +293,71 @@ mod tests {
         assert_eq!((rows[0].accepted_madi, rows[0].recv_seq), (5, 2));
         assert_eq!((rows[1].accepted_madi, rows[1].recv_seq), (10, 1));
     }
+
+    fn say_packet(agent_id: u64, recv_seq: u64, madi: u64, text: &str) -> SeulgiPacket {
+        SeulgiPacket {
+            agent_id,
+            recv_seq,
+            accepted_madi: madi,
+            target_madi: madi,
+            source: InputSource::Seulgi,
+            intent: SeulgiIntent::Say {
+                text: text.to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn merge_packets_drops_duplicate_intents() {
+        let first = vec![say_packet(1, 3, 5, "안녕"), say_packet(2, 1, 5, "안녕")];
+        let second = vec![say_packet(1, 4, 5, "안녕"), say_packet(1, 5, 6, "안녕")];
+        let packets: Vec<SeulgiPacket> = first.into_iter().chain(second).collect();
+
+        let merged = merge_packets(&packets);
+        assert_eq!(merged.dropped, 1);
+        assert_eq!(merged.records.len(), 3);
+        let agent_one: Vec<(u64, u64)> = merged
+            .records
+            .iter()
+            .filter(|record| record.agent_id == 1)
+            .map(|record| (record.recv_seq, record.target_madi))
+            .collect();
+        assert_eq!(agent_one, vec![(3, 5), (5, 6)]);
+        assert!(merged_bundle_detjson(&merged).contains("\"dropped\":1"));
+    }
+
+    #[test]
+    fn merge_packets_keeps_smallest_recv_seq() {
+        let packets = vec![say_packet(1, 9, 2, "가"), say_packet(1, 4, 2, "가")];
+        let merged = merge_packets(&packets);
+        assert_eq!(merged.dropped, 1);
+        assert_eq!(merged.records.len(), 1);
+        assert_eq!(merged.records[0].recv_seq, 4);
+    }
+
+    #[test]
+    fn decode_intent_splits_target_from_pins() {
+        let attack = decode_intent(&SeulgiIntent::Attack { target_id: 42 });
+        assert_eq!((attack.action, attack.target), ("Attack", Some(42)));
+        assert!(attack.pins.is_empty());
+
+        let x = Fixed64::from_i64(3);
+        let y = Fixed64::from_i64(-1);
+        let move_to = decode_intent(&SeulgiIntent::MoveTo { x, y });
+        assert_eq!(move_to.target, None);
+        assert_eq!(
+            move_to.pins,
+            vec![("x", IntentPin::Fixed(x)), ("y", IntentPin::Fixed(y))]
+        );
+
+        let records = vec![IntentRecord {
+            agent_id: 1,
+            recv_seq: 1,
+            accepted_madi: 0,
+            target_madi: 0,
+            intent: SeulgiIntent::Attack { target_id: 42 },
+        }];
+        assert!(decoded_bundle_detjson(&records)
+            .contains("\"action\":\"Attack\",\"target\":42,\"pins\":{}"));
+    }
 }
@@ -19,6 +19,16 @@ pub struct LatencyEvent {
     pub deliver_madi: u64,
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LatencySummary {
+    pub count: u64,
+    pub min: u64,
+    pub max: u64,
+    pub p50: u64,
+    pub p95: u64,
+    pub p99: u64,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ReplayHeader {
     pub seulgi_latency_madi: u64,
@@ -45,6 +55,35 @@ pub struct ScheduledPacket {
 pub const REPLAY_HEADER_DETERMINISTIC_CREATED_AT: &str = "1970-01-01T00:00:00Z";
 pub const LATENCY_DROP_POLICY_LATE_DROP: &str = "late_drop";
 
+impl LatencyPolicy {
+    /// 지연 표본(`deliver_madi - madi`)의 백분위를 nearest-rank 방식으로 계산한다.
+    /// 정수 연산만 쓰므로 같은 사건 목록이면 항상 같은 값이 나온다.
+    pub fn summarize(events: &[LatencyEvent]) -> LatencySummary {
+        if events.is_empty() {
+            return LatencySummary::default();
+        }
+        let mut samples: Vec<u64> = events
+            .iter()
+            .map(|event| event.deliver_madi.saturating_sub(event.madi))
+            .collect();
+        samples.sort_unstable();
+        LatencySummary {
+            count: samples.len() as u64,
+            min: samples[0],
+            max: samples[samples.len() - 1],
+            p50: nearest_rank(&samples, 50),
+            p95: nearest_rank(&samples, 95),
+            p99: nearest_rank(&samples, 99),
+        }
+    }
+}
+
+fn nearest_rank(sorted: &[u64], percentile: u64) -> u64 {
+    let n = sorted.len() as u64;
+    let rank = (percentile * n).div_ceil(100).max(1);
+    sorted[(rank - 1) as usize]
+}
+
 impl LatencySchedule {
     pub fn new(seulgi_latency_madi: u64, accept_madi: u64) -> Self {
         Self {
@@ -104,7 +143,7 @@ pub fn simulate(policy: LatencyPolicy, count: u64) -> Vec<LatencyEvent> {
 #[cfg(test)]
 mod tests {
     use super::{
-        build_replay_header, simulate, LatencyMode, LatencyPolicy, LatencySchedule,
+        build_replay_header, simulate, LatencyMode, LatencyPolicy, LatencySchedule, LatencySummary,
         ScheduledPacket, LATENCY_DROP_POLICY_LATE_DROP, REPLAY_HEADER_DETERMINISTIC_CREATED_AT,
     };
 
@@ -153,6 +192,35 @@ mod tests {
         assert_eq!(schedule, vec![(0, u64::MAX), (1, u64::MAX)]);
     }
 
+    #[test]
+    fn summarize_fixed_mode_percentiles_equal_fixed_latency() {
+        let policy = LatencyPolicy {
+            l_madi: 4,
+            mode: LatencyMode::Fixed,
+            seed: 0,
+        };
+        let summary = LatencyPolicy::summarize(&simulate(policy, 20));
+        assert_eq!(summary.p50, 4);
+        assert_eq!(summary.p95, 4);
+        assert_eq!(summary.p99, 4);
+        assert_eq!(summary.count, 20);
+        assert_eq!(LatencyPolicy::summarize(&[]), LatencySummary::default());
+    }
+
+    #[test]
+    fn summarize_jitter_mode_is_deterministic_for_seed() {
+        let policy = LatencyPolicy {
+            l_madi: 3,
+            mode: LatencyMode::Jitter,
+            seed: 7,
+        };
+        let first = LatencyPolicy::summarize(&simulate(policy, 100));
+        let second = LatencyPolicy::summarize(&simulate(policy, 100));
+        assert_eq!(first, second);
+        assert!(first.min >= 3 && first.max <= 6);
+        assert!(first.p50 <= first.p95 && first.p95 <= first.p99);
+    }
+
     #[test]
     fn schedule_marks_late_packet_by_current_madi() {
         let schedule = LatencySchedule::new(3, 2);
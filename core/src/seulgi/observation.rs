@@ -1,3 +1,5 @@
+use std::collections::BTreeSet;
+
 use crate::fixed64::Fixed64;
 use crate::seulgi::vision::VisionCone;
 
@@ -193,6 +195,28 @@ pub fn observation_from_detjson(json: &str) -> Result<Observation, String> {
     Ok(obs)
 }
 
+/// 관찰 안에서 같은 대상을 두 번 적은 경우를 걸러낸다.
+/// 보이는 물체·행위자의 id와 행위자 이름은 관찰 하나 안에서 유일해야 한다.
+pub fn validate_observation(obs: &Observation) -> Result<(), String> {
+    let mut object_ids = BTreeSet::new();
+    for object in &obs.visible_objects {
+        if !object_ids.insert(object.id) {
+            return Err(format!("E_OBS_DUP_ID visible_objects id={}", object.id));
+        }
+    }
+    let mut agent_ids = BTreeSet::new();
+    let mut agent_names = BTreeSet::new();
+    for agent in &obs.visible_agents {
+        if !agent_ids.insert(agent.id) {
+            return Err(format!("E_OBS_DUP_ID visible_agents id={}", agent.id));
+        }
+        if !agent_names.insert(agent.name.as_str()) {
+            return Err(format!("E_OBS_DUP_NAME {}", agent.name));
+        }
+    }
+    Ok(())
+}
+
 fn agent_state_detjson(state: &AgentState) -> String {
     let mut out = String::new();
     out.push('{');
@@ -726,6 +750,38 @@ mod tests {
         assert!(observer.equals(&obs, &parsed));
     }
 
+    #[test]
+    fn validate_observation_rejects_duplicate_agent_name() {
+        let agent = |id: u64, name: &str| Agent {
+            id,
+            position: (Fixed64::from_i64(0), Fixed64::from_i64(0)),
+            name: name.to_string(),
+        };
+        let mut obs = Observation {
+            agent_id: 1,
+            madi: 0,
+            timestamp_ms: 0,
+            self_state: AgentState {
+                position: (Fixed64::from_i64(0), Fixed64::from_i64(0)),
+                velocity: (Fixed64::from_i64(0), Fixed64::from_i64(0)),
+                health: 100,
+                status: "idle".to_string(),
+            },
+            visible_objects: Vec::new(),
+            visible_agents: vec![agent(2, "ally"), agent(3, "foe")],
+            world_state: WorldState {
+                gravity: Fixed64::from_i64(0),
+                time_of_day: 0,
+                weather: "clear".to_string(),
+            },
+        };
+        assert!(validate_observation(&obs).is_ok());
+
+        obs.visible_agents.push(agent(4, "ally"));
+        let err = validate_observation(&obs).expect_err("duplicate name");
+        assert_eq!(err, "E_OBS_DUP_NAME ally");
+    }
+
     #[test]
     fn vision_cone_distance_check() {
         let vision = VisionCone {
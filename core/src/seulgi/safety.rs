@@ -2,48 +2,324 @@
 pub enum SafetyMode {
     AllowList,
     DenyList,
+    AllOf,
+    AnyOf,
+    Explain,
+}
+
+impl SafetyMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SafetyMode::AllowList => "allowlist",
+            SafetyMode::DenyList => "denylist",
+            SafetyMode::AllOf => "all_of",
+            SafetyMode::AnyOf => "any_of",
+            SafetyMode::Explain => "explain",
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct SafetyRule {
     pub mode: SafetyMode,
     pub intents: Vec<String>,
+    pub children: Vec<SafetyRule>,
+}
+
+impl SafetyRule {
+    pub fn allow_list(intents: Vec<String>) -> Self {
+        Self {
+            mode: SafetyMode::AllowList,
+            intents,
+            children: Vec::new(),
+        }
+    }
+
+    pub fn deny_list(intents: Vec<String>) -> Self {
+        Self {
+            mode: SafetyMode::DenyList,
+            intents,
+            children: Vec::new(),
+        }
+    }
+
+    /// 모든 자식이 허용해야 허용한다. 선언 순서상 첫 거부가 결정을 낸다.
+    /// 자식이 없으면 허용.
+    pub fn all_of(children: Vec<SafetyRule>) -> Self {
+        Self {
+            mode: SafetyMode::AllOf,
+            intents: Vec::new(),
+            children,
+        }
+    }
+
+    /// 자식 중 하나라도 허용하면 허용한다. 선언 순서상 첫 허용이 결정을 내고,
+    /// 모두 거부하면 첫 거부 사유를 쓴다. 자식이 없으면 거부.
+    pub fn any_of(children: Vec<SafetyRule>) -> Self {
+        Self {
+            mode: SafetyMode::AnyOf,
+            intents: Vec::new(),
+            children,
+        }
+    }
+
+    /// 결정은 `inner`와 같고, 단락 평가 없이 모든 규칙의 평가 기록을 남긴다.
+    /// 기록의 규칙 경로는 `inner` 기준이다.
+    pub fn explain(inner: SafetyRule) -> Self {
+        Self {
+            mode: SafetyMode::Explain,
+            intents: Vec::new(),
+            children: vec![inner],
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct SafetyDecision {
     pub allowed: bool,
     pub reason: String,
+    /// 결정을 낸 잎 규칙의 경로(자식 인덱스 나열). all_of 통과처럼
+    /// 단일 규칙이 결정하지 않았으면 None.
+    pub rule_path: Option<Vec<usize>>,
+    pub explanation: Option<String>,
+    /// `SafetyMode::Explain`에서만 채워진다.
+    pub trace: Vec<SafetyTraceEntry>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SafetyTraceEntry {
+    pub rule_path: Vec<usize>,
+    pub mode: SafetyMode,
+    pub allowed: bool,
+    pub reason: String,
+}
+
+impl SafetyDecision {
+    fn new(allowed: bool, reason: impl Into<String>, rule_path: Option<Vec<usize>>) -> Self {
+        Self {
+            allowed,
+            reason: reason.into(),
+            rule_path,
+            explanation: None,
+            trace: Vec::new(),
+        }
+    }
+}
+
+pub fn format_rule_path(path: &[usize]) -> String {
+    if path.is_empty() {
+        return "root".to_string();
+    }
+    path.iter()
+        .map(|idx| idx.to_string())
+        .collect::<Vec<_>>()
+        .join(".")
 }
 
 pub fn check(rule: &SafetyRule, intent_kind: &str) -> SafetyDecision {
-    let listed = rule.intents.iter().any(|item| item == intent_kind);
-    match rule.mode {
-        SafetyMode::AllowList => {
-            if listed {
-                SafetyDecision {
-                    allowed: true,
-                    reason: "allowlist hit".to_string(),
+    if rule.mode == SafetyMode::Explain {
+        let mut trace = Vec::new();
+        let mut decision = match rule.children.first() {
+            Some(inner) => evaluate(inner, intent_kind, &mut Vec::new(), &mut Some(&mut trace)),
+            None => SafetyDecision::new(false, "explain empty", None),
+        };
+        decision.explanation = Some(explain_decision(&decision, intent_kind));
+        decision.trace = trace;
+        return decision;
+    }
+    evaluate(rule, intent_kind, &mut Vec::new(), &mut None)
+}
+
+fn explain_decision(decision: &SafetyDecision, intent_kind: &str) -> String {
+    let verdict = if decision.allowed { "허용" } else { "거부" };
+    match &decision.rule_path {
+        Some(path) => format!(
+            "규칙 {}이(가) '{}'을(를) {}: {}",
+            format_rule_path(path),
+            intent_kind,
+            verdict,
+            decision.reason
+        ),
+        None => format!("'{}' {}: {}", intent_kind, verdict, decision.reason),
+    }
+}
+
+fn evaluate(
+    rule: &SafetyRule,
+    intent_kind: &str,
+    path: &mut Vec<usize>,
+    trace: &mut Option<&mut Vec<SafetyTraceEntry>>,
+) -> SafetyDecision {
+    let decision = match rule.mode {
+        SafetyMode::AllowList | SafetyMode::DenyList => check_leaf(rule, intent_kind, path.clone()),
+        SafetyMode::AllOf => {
+            let mut first_deny = None;
+            for (idx, child) in rule.children.iter().enumerate() {
+                path.push(idx);
+                let decision = evaluate(child, intent_kind, path, trace);
+                path.pop();
+                if !decision.allowed && first_deny.is_none() {
+                    first_deny = Some(decision);
+                    // 기록이 없으면 기존처럼 첫 거부에서 멈춘다.
+                    if trace.is_none() {
+                        break;
+                    }
                 }
-            } else {
-                SafetyDecision {
-                    allowed: false,
-                    reason: "allowlist miss".to_string(),
+            }
+            match first_deny {
+                Some(deny) => {
+                    SafetyDecision::new(false, format!("all_of: {}", deny.reason), deny.rule_path)
                 }
+                None => SafetyDecision::new(true, "all_of pass", None),
             }
         }
-        SafetyMode::DenyList => {
-            if listed {
-                SafetyDecision {
-                    allowed: false,
-                    reason: "denylist hit".to_string(),
+        SafetyMode::AnyOf => {
+            let mut first_allow = None;
+            let mut first_deny = None;
+            for (idx, child) in rule.children.iter().enumerate() {
+                path.push(idx);
+                let decision = evaluate(child, intent_kind, path, trace);
+                path.pop();
+                if decision.allowed {
+                    if first_allow.is_none() {
+                        first_allow = Some(decision);
+                        if trace.is_none() {
+                            break;
+                        }
+                    }
+                } else if first_deny.is_none() {
+                    first_deny = Some(decision);
+                }
+            }
+            match (first_allow, first_deny) {
+                (Some(allow), _) => {
+                    SafetyDecision::new(true, format!("any_of: {}", allow.reason), allow.rule_path)
                 }
-            } else {
-                SafetyDecision {
-                    allowed: true,
-                    reason: "denylist miss".to_string(),
+                (None, Some(deny)) => {
+                    SafetyDecision::new(false, format!("any_of: {}", deny.reason), deny.rule_path)
                 }
+                (None, None) => SafetyDecision::new(false, "any_of empty", None),
             }
         }
+        // 중첩된 explain은 투명하게 안쪽 규칙을 같은 경로에서 평가한다.
+        SafetyMode::Explain => match rule.children.first() {
+            Some(inner) => return evaluate(inner, intent_kind, path, trace),
+            None => SafetyDecision::new(false, "explain empty", None),
+        },
+    };
+    if let Some(trace) = trace.as_deref_mut() {
+        trace.push(SafetyTraceEntry {
+            rule_path: path.clone(),
+            mode: rule.mode.clone(),
+            allowed: decision.allowed,
+            reason: decision.reason.clone(),
+        });
+    }
+    decision
+}
+
+fn check_leaf(rule: &SafetyRule, intent_kind: &str, path: Vec<usize>) -> SafetyDecision {
+    let listed = rule.intents.iter().any(|item| item == intent_kind);
+    let (allowed, reason) = match (&rule.mode, listed) {
+        (SafetyMode::AllowList, true) => (true, "allowlist hit"),
+        (SafetyMode::AllowList, false) => (false, "allowlist miss"),
+        (_, true) => (false, "denylist hit"),
+        (_, false) => (true, "denylist miss"),
+    };
+    SafetyDecision::new(allowed, reason, Some(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check, SafetyMode, SafetyRule};
+
+    fn names(items: &[&str]) -> Vec<String> {
+        items.iter().map(|item| item.to_string()).collect()
+    }
+
+    #[test]
+    fn any_of_allows_when_one_branch_allows() {
+        // 앞의 두 잎 규칙은 "공격"을 거부하고, 세 번째 all_of 가지만 허용하므로 any_of는 허용한다.
+        let deny_attack = SafetyRule::deny_list(names(&["공격"]));
+        let allow_move = SafetyRule::allow_list(names(&["이동"]));
+        let allow_attack_in_arena = SafetyRule::all_of(vec![
+            SafetyRule::allow_list(names(&["공격", "방어"])),
+            SafetyRule::deny_list(names(&["도망"])),
+        ]);
+        assert!(!check(&deny_attack, "공격").allowed);
+        assert!(!check(&allow_move, "공격").allowed);
+
+        let rule = SafetyRule::any_of(vec![deny_attack, allow_move, allow_attack_in_arena]);
+        let decision = check(&rule, "공격");
+        assert!(decision.allowed);
+        assert_eq!(decision.reason, "any_of: all_of pass");
+    }
+
+    #[test]
+    fn all_of_denies_when_one_leaf_denies() {
+        let rule = SafetyRule::all_of(vec![
+            SafetyRule::allow_list(names(&["이동", "공격"])),
+            SafetyRule::deny_list(names(&["공격"])),
+        ]);
+        let decision = check(&rule, "공격");
+        assert!(!decision.allowed);
+        assert_eq!(decision.reason, "all_of: denylist hit");
+        assert!(check(&rule, "이동").allowed);
+    }
+
+    #[test]
+    fn empty_composites_have_fixed_defaults() {
+        assert!(check(&SafetyRule::all_of(Vec::new()), "이동").allowed);
+        assert!(!check(&SafetyRule::any_of(Vec::new()), "이동").allowed);
+    }
+
+    #[test]
+    fn decision_reports_deciding_rule_path() {
+        let rule = SafetyRule::all_of(vec![
+            SafetyRule::allow_list(names(&["이동", "공격"])),
+            SafetyRule::any_of(vec![
+                SafetyRule::allow_list(names(&["이동"])),
+                SafetyRule::deny_list(names(&["공격"])),
+            ]),
+            SafetyRule::deny_list(names(&["공격"])),
+        ]);
+        let decision = check(&rule, "공격");
+        assert!(!decision.allowed);
+        assert_eq!(decision.reason, "all_of: any_of: allowlist miss");
+        assert_eq!(decision.rule_path, Some(vec![1, 0]));
+        assert!(decision.trace.is_empty());
+        assert!(decision.explanation.is_none());
+    }
+
+    #[test]
+    fn explain_mode_records_full_trace_without_changing_decision() {
+        let inner = SafetyRule::all_of(vec![
+            SafetyRule::allow_list(names(&["이동", "공격"])),
+            SafetyRule::deny_list(names(&["공격"])),
+            SafetyRule::deny_list(names(&["공격", "도망"])),
+        ]);
+        let plain = check(&inner, "공격");
+        let explained = check(&SafetyRule::explain(inner), "공격");
+        assert_eq!(explained.allowed, plain.allowed);
+        assert_eq!(explained.reason, plain.reason);
+        assert_eq!(explained.rule_path, Some(vec![1]));
+        let paths: Vec<(Vec<usize>, SafetyMode, bool)> = explained
+            .trace
+            .iter()
+            .map(|entry| (entry.rule_path.clone(), entry.mode.clone(), entry.allowed))
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                (vec![0], SafetyMode::AllowList, true),
+                (vec![1], SafetyMode::DenyList, false),
+                (vec![2], SafetyMode::DenyList, false),
+                (vec![], SafetyMode::AllOf, false),
+            ]
+        );
+        assert_eq!(
+            explained.explanation.as_deref(),
+            Some("규칙 1이(가) '공격'을(를) 거부: all_of: denylist hit")
+        );
     }
 }
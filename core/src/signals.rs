@@ -9,6 +9,19 @@ pub struct FaultContext {
     pub location: &'static str,
     pub source_span: Option<SourceSpan>,
     pub expr: Option<ExprTrace>,
+    /// 고장이 난 식을 감싼 씨앗 이름. 씨앗 밖이면 None.
+    pub seed: Option<String>,
+    /// 씨앗 호출 깊이. 씨앗 밖은 0, 씨앗 안에서 다른 씨앗을 부를 때마다 1씩 는다.
+    pub call_depth: u32,
+}
+
+impl FaultContext {
+    /// `--diag-jsonl` message에 싣는 씨앗 호출 맥락. 씨앗 밖 고장은 None.
+    pub fn seed_context_label(&self) -> Option<String> {
+        self.seed
+            .as_ref()
+            .map(|seed| format!("seed:{} depth:{}", seed, self.call_depth))
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -29,7 +42,19 @@ pub struct ExprTrace {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ArithmeticFaultKind {
     DivByZero,
-    DimensionMismatch { left: UnitDim, right: UnitDim },
+    DimensionMismatch {
+        left: UnitDim,
+        right: UnitDim,
+    },
+    /// 고정소수 범위를 넘은 값을 끝값으로 포화시켰다.
+    Overflow,
+}
+
+/// 산술고장 한도에 닿아 멈춘 지점. `faults`는 멈춘 시점까지 누적된 수다.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FaultLimitStop {
+    pub tick_id: TickId,
+    pub faults: usize,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -57,6 +82,44 @@ impl Signal {
             Signal::Diag { .. } => "diag",
         }
     }
+
+    /// 신호가 난 마디. 알림에는 마디가 없다.
+    pub fn tick_id(&self) -> Option<TickId> {
+        match self {
+            Signal::ArithmeticFault { ctx, .. } => Some(ctx.tick_id),
+            Signal::Alrim { .. } => None,
+            Signal::Diag { event } => Some(event.madi),
+        }
+    }
+
+    /// 마디 번호(진단은 순번까지)만 빼고 같은 신호인지 본다.
+    fn same_apart_from_tick(&self, other: &Signal) -> bool {
+        match (self, other) {
+            (
+                Signal::ArithmeticFault { ctx, kind },
+                Signal::ArithmeticFault {
+                    ctx: other_ctx,
+                    kind: other_kind,
+                },
+            ) => {
+                kind == other_kind
+                    && ctx.location == other_ctx.location
+                    && ctx.source_span == other_ctx.source_span
+                    && ctx.expr == other_ctx.expr
+                    && ctx.seed == other_ctx.seed
+                    && ctx.call_depth == other_ctx.call_depth
+            }
+            (Signal::Alrim { name }, Signal::Alrim { name: other_name }) => name == other_name,
+            (Signal::Diag { event }, Signal::Diag { event: other_event }) => {
+                DiagEvent {
+                    madi: other_event.madi,
+                    seq: other_event.seq,
+                    ..event.clone()
+                } == *other_event
+            }
+            _ => false,
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -87,6 +150,16 @@ pub struct VecSignalSink {
     pub diag_events: Vec<DiagEvent>,
 }
 
+impl VecSignalSink {
+    /// 지금까지 쌓인 산술고장 수(종류 무관).
+    pub fn arithmetic_fault_count(&self) -> usize {
+        self.signals
+            .iter()
+            .filter(|signal| matches!(signal, Signal::ArithmeticFault { .. }))
+            .count()
+    }
+}
+
 impl SignalSink for VecSignalSink {
     fn emit(&mut self, signal: Signal) {
         match &signal {
@@ -95,3 +168,53 @@ impl SignalSink for VecSignalSink {
         }
     }
 }
+
+/// 잇달아 들어온 같은 신호 한 묶음. `signal`은 묶음의 첫 신호다.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignalRun {
+    pub signal: Signal,
+    pub count: u64,
+    pub first_tick: Option<TickId>,
+    pub last_tick: Option<TickId>,
+}
+
+/// 같은 신호가 잇달아 들어오면 하나로 묶는 sink 싸개(선택 사항).
+/// 묶음마다 첫 신호만 안쪽 sink로 넘기고, 몇 번 왔는지와 처음/마지막 마디는 `runs`에 남긴다.
+/// 마디 번호(진단은 순번까지)만 다른 신호는 같은 것으로 본다.
+pub struct RateLimitedSink<S: SignalSink> {
+    pub inner: S,
+    pub runs: Vec<SignalRun>,
+}
+
+impl<S: SignalSink> RateLimitedSink<S> {
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            runs: Vec::new(),
+        }
+    }
+
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S: SignalSink> SignalSink for RateLimitedSink<S> {
+    fn emit(&mut self, signal: Signal) {
+        let tick = signal.tick_id();
+        if let Some(run) = self.runs.last_mut() {
+            if run.signal.same_apart_from_tick(&signal) {
+                run.count += 1;
+                run.last_tick = tick;
+                return;
+            }
+        }
+        self.runs.push(SignalRun {
+            signal: signal.clone(),
+            count: 1,
+            first_tick: tick,
+            last_tick: tick,
+        });
+        self.inner.emit(signal);
+    }
+}
@@ -0,0 +1,36 @@
+use crate::platform::{BogaeView, NuriWorld, ResourceValue};
+use crate::Fixed64;
+
+#[test]
+fn bogae_view_reads_existing_keys_and_returns_none_for_missing() {
+    let mut world = NuriWorld::new();
+    world.set_resource_fixed64("살림.x".to_string(), Fixed64::from_i64(7));
+    world.set_resource_value(
+        "살림.이름".to_string(),
+        ResourceValue::String("공".to_string()),
+    );
+
+    let view = BogaeView::new(&world);
+    assert_eq!(
+        view.read("살림.x"),
+        Some(ResourceValue::Fixed64(Fixed64::from_i64(7)))
+    );
+    assert_eq!(
+        view.read("살림.이름"),
+        Some(ResourceValue::String("공".to_string()))
+    );
+    assert_eq!(view.read("살림.y"), None);
+}
+
+#[test]
+fn bogae_view_only_borrows_world_shared() {
+    let mut world = NuriWorld::new();
+    world.set_resource_fixed64("살림.x".to_string(), Fixed64::from_i64(1));
+    let before = world.state_hash();
+
+    // 창은 공유 빌림이라 다른 읽기와 함께 살아 있을 수 있고, 복사해도 같은 누리를 본다.
+    let view = BogaeView::new(&world);
+    let copy = view;
+    assert_eq!(copy.read("살림.x"), view.read("살림.x"));
+    assert_eq!(world.state_hash(), before);
+}
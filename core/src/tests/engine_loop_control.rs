@@ -0,0 +1,78 @@
+use std::thread;
+
+use crate::{
+    platform::{
+        Bogae, DetNuri, DetSam, InMemoryGeoul, InputSnapshot, Iyagi, Nuri, NuriWorld, Origin,
+        Patch, PatchOp,
+    },
+    signals::VecSignalSink,
+    EngineLoop, Fixed64, TickId,
+};
+
+struct CountIyagi;
+
+impl Iyagi for CountIyagi {
+    fn run_startup(&mut self, _world: &NuriWorld) -> Patch {
+        Patch::default()
+    }
+
+    fn run_update(&mut self, world: &NuriWorld, _input: &InputSnapshot) -> Patch {
+        let x = world.get_resource_fixed64("x").unwrap_or(Fixed64::ZERO);
+        Patch {
+            ops: vec![PatchOp::SetResourceFixed64 {
+                tag: "x".to_string(),
+                value: x + Fixed64::from_i64(1),
+            }],
+            origin: Origin::system("test"),
+        }
+    }
+}
+
+struct NoBogae;
+
+impl Bogae for NoBogae {
+    fn render(&mut self, _world: &NuriWorld, _tick_id: TickId) {}
+}
+
+fn new_loop() -> EngineLoop<DetSam, CountIyagi, DetNuri, InMemoryGeoul, NoBogae> {
+    EngineLoop::new(
+        DetSam::new(Fixed64::from_i64(1)),
+        CountIyagi,
+        DetNuri::new(),
+        InMemoryGeoul::new(),
+        NoBogae,
+    )
+}
+
+#[test]
+fn engine_loop_step_one_matches_free_running_run() {
+    const TICKS: u64 = 5;
+
+    let mut free = new_loop();
+    let free_run = free.run_ticks(0, TICKS, &mut VecSignalSink::default());
+    let free_hash = free_run.last().expect("frame").state_hash;
+
+    let mut stepped = new_loop();
+    let control = stepped.control();
+    control.pause();
+    let stepped_run = thread::scope(|scope| {
+        let worker = scope.spawn(|| stepped.run_ticks(0, TICKS, &mut VecSignalSink::default()));
+        for tick in 0..TICKS {
+            let status = control.step_one();
+            assert_eq!(status.ticks_done, tick + 1);
+            assert_eq!(status.last_tick, Some(tick));
+            assert_eq!(control.status(), status);
+        }
+        worker.join().expect("engine thread")
+    });
+
+    let status = control.status();
+    assert!(control.is_paused());
+    assert_eq!(status.ticks_done, TICKS);
+    assert_eq!(status.state_hash, Some(free_hash));
+    assert_eq!(stepped_run.len(), free_run.len());
+    assert_eq!(
+        stepped.nuri.world().get_resource_fixed64("x"),
+        Some(Fixed64::from_i64(TICKS as i64))
+    );
+}
@@ -0,0 +1,16 @@
+use crate::{Scheduler, TickId};
+
+#[test]
+fn scheduler_takes_due_actions_in_tick_then_insertion_order() {
+    let mut scheduler = Scheduler::new();
+    scheduler.schedule(5, "c");
+    scheduler.schedule(2, "a");
+    scheduler.schedule(2, "b");
+    scheduler.schedule(9, "d");
+
+    assert!(scheduler.take_due(1).is_empty());
+    assert_eq!(scheduler.take_due(5), vec!["a", "b", "c"]);
+    assert_eq!(scheduler.len(), 1);
+    assert_eq!(scheduler.take_due(TickId::MAX), vec!["d"]);
+    assert!(scheduler.is_empty());
+}
@@ -1,6 +1,10 @@
 mod ai_injection_sort;
+mod bogae_view;
 mod closed_input_channel;
+mod engine_loop_control;
 mod engine_loop_fault;
+mod engine_scheduler;
 mod fixed64_lint_gate;
 mod net_event_sort;
 mod sam_volatility;
+mod signal_rate_limit;
@@ -0,0 +1,49 @@
+use crate::{
+    signals::{RateLimitedSink, Signal, SignalSink, VecSignalSink},
+    ArithmeticFaultKind, FaultContext, TickId,
+};
+
+fn div0(tick_id: TickId, location: &'static str) -> Signal {
+    Signal::ArithmeticFault {
+        ctx: FaultContext {
+            tick_id,
+            location,
+            source_span: None,
+            expr: None,
+            seed: None,
+            call_depth: 0,
+        },
+        kind: ArithmeticFaultKind::DivByZero,
+    }
+}
+
+#[test]
+fn identical_faults_collapse_into_one_run() {
+    let mut sink = RateLimitedSink::new(VecSignalSink::default());
+    for tick in 0..1000 {
+        sink.emit(div0(tick, "iyagi:loop/x_div0"));
+    }
+
+    assert_eq!(sink.runs.len(), 1);
+    let run = &sink.runs[0];
+    assert_eq!(run.count, 1000);
+    assert_eq!(run.first_tick, Some(0));
+    assert_eq!(run.last_tick, Some(999));
+    assert_eq!(run.signal, div0(0, "iyagi:loop/x_div0"));
+    assert_eq!(sink.inner.arithmetic_fault_count(), 1);
+}
+
+#[test]
+fn a_different_signal_starts_a_new_run() {
+    let mut sink = RateLimitedSink::new(VecSignalSink::default());
+    sink.emit(div0(1, "a"));
+    sink.emit(div0(2, "a"));
+    sink.emit(div0(3, "b"));
+    sink.emit(Signal::Alrim { name: "끝" });
+    sink.emit(div0(4, "a"));
+
+    let counts: Vec<u64> = sink.runs.iter().map(|run| run.count).collect();
+    assert_eq!(counts, vec![2, 1, 1, 1]);
+    assert_eq!(sink.runs[2].first_tick, None);
+    assert_eq!(sink.into_inner().signals.len(), 4);
+}
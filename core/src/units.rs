@@ -1,8 +1,10 @@
 use crate::fixed64::Fixed64;
+use serde::Deserialize;
 use std::collections::HashSet;
 use std::sync::OnceLock;
 
 static UNIT_SYMBOLS: OnceLock<HashSet<String>> = OnceLock::new();
+static CURRENCY_FORMATS: OnceLock<Vec<CurrencyFormat>> = OnceLock::new();
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct UnitDim {
@@ -669,6 +671,128 @@ impl UnitValue {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SymbolPlacement {
+    Prefix,
+    Suffix,
+}
+
+/// 돈 단위를 사람이 읽는 꼴로 쓰는 규칙(기호와 그 위치, 소수 자리, 세 자리 묶음 기호).
+/// 단위 곳간(`ddn.units.json`)의 `currency_formats` 항목 하나에 해당한다.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct CurrencyFormat {
+    pub unit: String,
+    pub symbol: String,
+    pub placement: SymbolPlacement,
+    pub decimals: u8,
+    #[serde(default)]
+    pub group_separator: Option<char>,
+}
+
+/// 돈 단위 쓰기 규칙이 가질 수 있는 가장 많은 소수 자리.
+pub const CURRENCY_DECIMALS_MAX: u8 = 9;
+
+/// 쓰기 규칙 목록을 검사한다. 소수 자리가 `CURRENCY_DECIMALS_MAX`를 넘으면 거부한다.
+pub fn validate_currency_formats(formats: &[CurrencyFormat]) -> Result<(), String> {
+    for format in formats {
+        if format.decimals > CURRENCY_DECIMALS_MAX {
+            return Err(format!(
+                "E_UNIT_CURRENCY_DECIMALS: {} decimals {} (최대 {})",
+                format.unit, format.decimals, CURRENCY_DECIMALS_MAX
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// 쓰기 규칙은 한 번만 정해진다. 같은 목록을 다시 넣는 것은 괜찮지만 다른 목록이면 오류다.
+pub fn set_unit_registry_currency_formats(formats: Vec<CurrencyFormat>) -> Result<(), String> {
+    validate_currency_formats(&formats)?;
+    let current = CURRENCY_FORMATS.get_or_init(|| formats.clone());
+    if *current != formats {
+        return Err("단위 곳간은 한 번만 초기화할 수 있습니다".to_string());
+    }
+    Ok(())
+}
+
+/// 단위 곳간에 있는 돈 단위의 쓰기 규칙. 곳간에 규칙이 없거나 곳간에서 빠진 단위는 `None`이다.
+pub fn currency_format(unit_symbol: &str) -> Option<CurrencyFormat> {
+    currency_format_in(
+        CURRENCY_FORMATS.get().map(Vec::as_slice).unwrap_or(&[]),
+        unit_symbol,
+    )
+}
+
+fn currency_format_in(formats: &[CurrencyFormat], unit_symbol: &str) -> Option<CurrencyFormat> {
+    if !is_known_unit(unit_symbol) {
+        return None;
+    }
+    formats
+        .iter()
+        .find(|format| format.unit == unit_symbol)
+        .cloned()
+}
+
+/// 값을 단위와 함께 쓴다. 돈 단위는 단위 곳간의 쓰기 규칙을 따르고(`1000@KRW` → `₩1,000`),
+/// 규칙이 없는 단위는 `<값>@<단위>`, 무차원 값은 값만 쓴다.
+pub fn format_value(value: UnitValue) -> String {
+    format_value_with(
+        value,
+        CURRENCY_FORMATS.get().map(Vec::as_slice).unwrap_or(&[]),
+    )
+}
+
+/// `format_value`와 같되 곳간 대신 주어진 쓰기 규칙 목록을 쓴다.
+pub fn format_value_with(value: UnitValue, formats: &[CurrencyFormat]) -> String {
+    if value.is_dimensionless() {
+        return value.value.to_string();
+    }
+    let Some(symbol) = value.display_symbol() else {
+        return format!("{}@{}", value.value, value.dim.format());
+    };
+    currency_format_in(formats, symbol)
+        .and_then(|format| format_currency(value.value, &format))
+        .unwrap_or_else(|| format!("{}@{}", value.value, symbol))
+}
+
+/// 소수 `decimals` 자리에서 반올림한다(반은 0에서 먼 쪽). 정수 연산만 쓰고,
+/// 자리 수가 너무 커서 넘치면 `None`이다.
+fn format_currency(value: Fixed64, format: &CurrencyFormat) -> Option<String> {
+    let scale = 10i128.checked_pow(u32::from(format.decimals))?;
+    let one = i128::from(Fixed64::ONE_RAW);
+    let raw = i128::from(value.raw_i64());
+    let scaled = raw.abs().checked_mul(scale)?.checked_add(one / 2)? / one;
+    let sign = if raw < 0 && scaled != 0 { "-" } else { "" };
+    let mut body = group_digits(&(scaled / scale).to_string(), format.group_separator);
+    if format.decimals > 0 {
+        body.push('.');
+        body.push_str(&format!(
+            "{:0width$}",
+            scaled % scale,
+            width = usize::from(format.decimals)
+        ));
+    }
+    Some(match format.placement {
+        SymbolPlacement::Prefix => format!("{sign}{}{body}", format.symbol),
+        SymbolPlacement::Suffix => format!("{sign}{body}{}", format.symbol),
+    })
+}
+
+fn group_digits(digits: &str, separator: Option<char>) -> String {
+    let Some(separator) = separator else {
+        return digits.to_string();
+    };
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+    for (index, ch) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            out.push(separator);
+        }
+        out.push(ch);
+    }
+    out
+}
+
 pub fn resource_tag_with_unit(name: &str, unit: Unit) -> String {
     format!("{name}@{}", unit.symbol())
 }
@@ -785,6 +909,109 @@ mod tests {
         );
     }
 
+    /// `ddn.units.json`의 `currency_formats`와 같은 규칙.
+    fn registry_currency_formats() -> Vec<CurrencyFormat> {
+        vec![
+            CurrencyFormat {
+                unit: "KRW".to_string(),
+                symbol: "\u{20A9}".to_string(),
+                placement: SymbolPlacement::Prefix,
+                decimals: 0,
+                group_separator: Some(','),
+            },
+            CurrencyFormat {
+                unit: "USD".to_string(),
+                symbol: "$".to_string(),
+                placement: SymbolPlacement::Prefix,
+                decimals: 2,
+                group_separator: Some(','),
+            },
+        ]
+    }
+
+    #[test]
+    fn krw_formats_with_leading_won_sign_and_no_decimals() {
+        let formats = registry_currency_formats();
+        let won = UnitValue::new(Fixed64::from_i64(1000), Unit::Krw);
+        assert_eq!(format_value_with(won, &formats), "\u{20A9}1,000");
+        let big = UnitValue::new(Fixed64::from_i64(1_234_567), Unit::Krw);
+        assert_eq!(format_value_with(big, &formats), "\u{20A9}1,234,567");
+        let negative = UnitValue::new(Fixed64::from_i64(-500), Unit::Krw);
+        assert_eq!(format_value_with(negative, &formats), "-\u{20A9}500");
+    }
+
+    #[test]
+    fn usd_formats_with_two_decimals() {
+        let formats = registry_currency_formats();
+        let ten = UnitValue::new(Fixed64::from_i64(10), Unit::Usd);
+        assert_eq!(format_value_with(ten, &formats), "$10.00");
+        let cents = Fixed64::from_i64(12345)
+            .try_div(Fixed64::from_i64(100))
+            .expect("div");
+        assert_eq!(
+            format_value_with(UnitValue::new(cents, Unit::Usd), &formats),
+            "$123.45"
+        );
+        let thousands = UnitValue::new(Fixed64::from_i64(2500), Unit::Usd);
+        assert_eq!(format_value_with(thousands, &formats), "$2,500.00");
+    }
+
+    #[test]
+    fn currency_without_registry_format_falls_back_to_value_at_symbol() {
+        let krw_only: Vec<CurrencyFormat> = registry_currency_formats()
+            .into_iter()
+            .filter(|format| format.unit == "KRW")
+            .collect();
+        let ten = UnitValue::new(Fixed64::from_i64(10), Unit::Usd);
+        assert_eq!(format_value_with(ten, &krw_only), "10@USD");
+        assert_eq!(currency_format_in(&krw_only, "USD"), None);
+    }
+
+    #[test]
+    fn non_currency_units_fall_back_to_value_at_symbol() {
+        let formats = registry_currency_formats();
+        let length = UnitValue::new(Fixed64::from_i64(3), Unit::Meter);
+        assert_eq!(format_value_with(length, &formats), "3@m");
+        assert_eq!(currency_format_in(&formats, "m"), None);
+    }
+
+    #[test]
+    fn currency_decimals_above_max_are_rejected() {
+        let mut formats = registry_currency_formats();
+        formats[1].decimals = CURRENCY_DECIMALS_MAX;
+        assert_eq!(validate_currency_formats(&formats), Ok(()));
+        formats[1].decimals = CURRENCY_DECIMALS_MAX + 1;
+        let err = validate_currency_formats(&formats).expect_err("decimals");
+        assert!(err.starts_with("E_UNIT_CURRENCY_DECIMALS: USD"), "{err}");
+    }
+
+    #[test]
+    fn currency_formatting_does_not_overflow_at_extremes() {
+        let mut formats = registry_currency_formats();
+        formats[1].decimals = CURRENCY_DECIMALS_MAX;
+        let max = UnitValue::new(Fixed64::MAX, Unit::Usd);
+        assert!(format_value_with(max, &formats).starts_with('$'));
+        formats[1].decimals = u8::MAX;
+        assert_eq!(
+            format_value_with(max, &formats),
+            format!("{}@USD", Fixed64::MAX)
+        );
+    }
+
+    #[test]
+    fn currency_formats_are_set_once() {
+        let formats = registry_currency_formats();
+        assert_eq!(set_unit_registry_currency_formats(formats.clone()), Ok(()));
+        assert_eq!(set_unit_registry_currency_formats(formats.clone()), Ok(()));
+        let err =
+            set_unit_registry_currency_formats(formats[..1].to_vec()).expect_err("second registry");
+        assert!(err.contains("한 번만"), "{err}");
+        let mut too_precise = formats;
+        too_precise[0].decimals = CURRENCY_DECIMALS_MAX + 1;
+        let err = set_unit_registry_currency_formats(too_precise).expect_err("decimals");
+        assert!(err.starts_with("E_UNIT_CURRENCY_DECIMALS"), "{err}");
+    }
+
     #[test]
     fn resource_tag_includes_unit_suffix() {
         let tag = resource_tag_with_unit("speed", Unit::Meter);
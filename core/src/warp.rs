@@ -1,12 +1,69 @@
-use crate::realms::{MultiRealmManager, RealmStepInput, ThreadMode};
+use crate::realms::{MultiRealmManager, Realm, RealmStepInput, ThreadMode};
+use crate::{Fixed64, StateHash};
+use std::time::Instant;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum WarpBackend {
     Off,
     Cpu,
+    /// SoA의 realm별 delta를 SIMD lane으로 합친다. x86_64에서 AVX2가 있으면 `std::arch`로,
+    /// 없으면 같은 값을 내는 스칼라 합으로 돈다.
+    CpuSimd,
     Gpu,
 }
 
+impl WarpBackend {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WarpBackend::Off => "off",
+            WarpBackend::Cpu => "cpu",
+            WarpBackend::CpuSimd => "cpu-simd",
+            WarpBackend::Gpu => "gpu",
+        }
+    }
+}
+
+/// GPU 장치 확인 결과.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GpuProbe {
+    Available,
+    Unavailable,
+}
+
+/// 운영체제가 드러내는 GPU 계산 장치를 찾는다. 리눅스는 `/dev/dri/renderD*`나 `/dev/nvidia*`,
+/// 윈도우는 System32의 CUDA/OpenCL 드라이버 DLL을 본다. 찾을 수단이 없는 플랫폼은 `Unavailable`이다.
+pub fn probe_gpu() -> GpuProbe {
+    if gpu_device_present() {
+        GpuProbe::Available
+    } else {
+        GpuProbe::Unavailable
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn gpu_device_present() -> bool {
+    let render_node = std::fs::read_dir("/dev/dri").is_ok_and(|entries| {
+        entries
+            .flatten()
+            .any(|entry| entry.file_name().to_string_lossy().starts_with("renderD"))
+    });
+    render_node || std::path::Path::new("/dev/nvidia0").exists()
+}
+
+#[cfg(windows)]
+fn gpu_device_present() -> bool {
+    let root = std::env::var_os("SystemRoot").unwrap_or_else(|| "C:\\Windows".into());
+    let system32 = std::path::Path::new(&root).join("System32");
+    ["nvcuda.dll", "OpenCL.dll"]
+        .iter()
+        .any(|dll| system32.join(dll).exists())
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android", windows)))]
+fn gpu_device_present() -> bool {
+    false
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum WarpPolicy {
     Strict,
@@ -19,6 +76,40 @@ pub struct StepBatchSoA {
     pub deltas: Vec<i64>,
 }
 
+/// SoA 배열 길이 불일치. 기준은 `realm_ids` 길이다.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WarpError {
+    RaggedSoA {
+        field: &'static str,
+        len: usize,
+        expected: usize,
+    },
+}
+
+impl WarpError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            WarpError::RaggedSoA { .. } => "E_WARP_SOA_LENGTH",
+        }
+    }
+
+    pub fn to_message(&self) -> String {
+        match self {
+            WarpError::RaggedSoA {
+                field,
+                len,
+                expected,
+            } => format!(
+                "{} field={} len={} expected={}",
+                self.code(),
+                field,
+                len,
+                expected
+            ),
+        }
+    }
+}
+
 impl StepBatchSoA {
     pub fn from_inputs(inputs: &[RealmStepInput]) -> Self {
         Self {
@@ -31,10 +122,21 @@ impl StepBatchSoA {
         self.realm_ids.len()
     }
 
-    pub fn to_inputs(&self) -> Result<Vec<RealmStepInput>, String> {
-        if self.realm_ids.len() != self.deltas.len() {
-            return Err("E_WARP_SOA_LENGTH realm_ids and deltas length mismatch".to_string());
+    /// 병렬 배열이 모두 `realm_ids`와 같은 길이인지 확인한다.
+    pub fn validate(&self) -> Result<(), WarpError> {
+        let expected = self.realm_ids.len();
+        if self.deltas.len() != expected {
+            return Err(WarpError::RaggedSoA {
+                field: "deltas",
+                len: self.deltas.len(),
+                expected,
+            });
         }
+        Ok(())
+    }
+
+    pub fn to_inputs(&self) -> Result<Vec<RealmStepInput>, String> {
+        self.validate().map_err(|err| err.to_message())?;
         Ok(self
             .realm_ids
             .iter()
@@ -42,6 +144,7 @@ impl StepBatchSoA {
             .map(|(realm_id, delta)| RealmStepInput {
                 realm_id: *realm_id,
                 delta: *delta,
+                send: Vec::new(),
             })
             .collect())
     }
@@ -61,6 +164,53 @@ pub struct WarpBenchOutput {
     pub gpu_ms: u64,
     pub realm_count: usize,
     pub step_count: u64,
+    /// 실제로 돈 backend. Strict에서 Gpu를 요청하거나 GPU 장치가 확인되지 않으면 Cpu가 된다.
+    pub backend_used: WarpBackend,
+    /// Fast에서 Gpu를 요청했지만 장치가 확인되지 않아 Cpu로 돌았다.
+    pub gpu_unavailable: bool,
+    pub state_hashes: Vec<StateHash>,
+    /// measure일 때만 채운다. 실제로 돈 backend의 단계별 시간(마이크로초).
+    pub breakdown: Option<WarpStageBreakdown>,
+}
+
+impl WarpBenchOutput {
+    /// 보고용 backend 이름. GPU가 없어 Cpu로 돌았으면 그 사정을 붙인다.
+    pub fn backend_label(&self) -> &'static str {
+        if self.gpu_unavailable {
+            "cpu (gpu unavailable)"
+        } else {
+            self.backend_used.as_str()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WarpStageBreakdown {
+    pub setup_us: u64,
+    pub kernel_us: u64,
+    pub reduction_us: u64,
+    pub teardown_us: u64,
+    pub total_us: u64,
+}
+
+impl WarpStageBreakdown {
+    pub fn stage_sum_us(&self) -> u64 {
+        self.setup_us
+            .saturating_add(self.kernel_us)
+            .saturating_add(self.reduction_us)
+            .saturating_add(self.teardown_us)
+    }
+}
+
+/// AVX2 레지스터 하나에 드는 i64 개수. Fast의 포화 경로도 이 폭으로 순서를 바꾼다.
+const LANE_WIDTH: usize = 4;
+const DELTA_EXACT_LIMIT: u64 = 1u64 << 31;
+const LANE_LEN_LIMIT: u64 = 1u64 << 32;
+
+#[derive(Clone, Debug)]
+enum StepPath {
+    Scalar(ThreadMode),
+    Simd(WarpPolicy, usize),
 }
 
 fn estimate_ms(realm_count: usize, steps: u64, divisor: u64) -> u64 {
@@ -75,54 +225,303 @@ fn run_steps(
     master_seed: u64,
     steps: u64,
     batch: &StepBatchSoA,
-    thread_mode: ThreadMode,
-) -> Result<(), String> {
-    let mut manager = MultiRealmManager::new(realm_count, master_seed, thread_mode);
-    let inputs = batch.to_inputs()?;
-    for _ in 0..steps {
-        manager.step_batch(&inputs)?;
+    path: StepPath,
+) -> Result<(Vec<StateHash>, WarpStageBreakdown), String> {
+    let start = Instant::now();
+    let mut breakdown = WarpStageBreakdown::default();
+    let mut manager = match &path {
+        StepPath::Scalar(thread_mode) => {
+            MultiRealmManager::new(realm_count, master_seed, thread_mode.clone())
+        }
+        StepPath::Simd(..) => MultiRealmManager::new(realm_count, master_seed, ThreadMode::Seq),
+    };
+    let state_hashes = match path {
+        StepPath::Scalar(_) => {
+            let inputs = batch.to_inputs()?;
+            let kernel = stage_mark(&mut breakdown.setup_us, start);
+            for _ in 0..steps {
+                manager.step_batch(&inputs)?;
+            }
+            let reduction = stage_mark(&mut breakdown.kernel_us, kernel);
+            let hashes = manager.state_hashes();
+            stage_mark(&mut breakdown.reduction_us, reduction);
+            hashes
+        }
+        StepPath::Simd(policy, threads) => {
+            let lanes = SoaLanes::build(batch, realm_count)?;
+            let pool = if threads > 1 {
+                let pool = rayon::ThreadPoolBuilder::new()
+                    .num_threads(threads)
+                    .build()
+                    .map_err(|err| format!("E_REALM_THREADPOOL {}", err))?;
+                Some(pool)
+            } else {
+                None
+            };
+            let kernel = stage_mark(&mut breakdown.setup_us, start);
+            for _ in 0..steps {
+                match &pool {
+                    Some(pool) => pool.install(|| {
+                        use rayon::prelude::*;
+                        manager
+                            .realms
+                            .par_iter_mut()
+                            .zip(lanes.per_realm.par_iter())
+                            .for_each(|(realm, deltas)| step_realm_simd(realm, deltas, &policy));
+                    }),
+                    None => {
+                        for (realm, deltas) in manager.realms.iter_mut().zip(lanes.per_realm.iter())
+                        {
+                            step_realm_simd(realm, deltas, &policy);
+                        }
+                    }
+                }
+            }
+            let reduction = stage_mark(&mut breakdown.kernel_us, kernel);
+            let hashes = manager.state_hashes();
+            stage_mark(&mut breakdown.reduction_us, reduction);
+            hashes
+        }
+    };
+    let teardown = Instant::now();
+    drop(manager);
+    stage_mark(&mut breakdown.teardown_us, teardown);
+    breakdown.total_us = start.elapsed().as_micros() as u64;
+    Ok((state_hashes, breakdown))
+}
+
+/// `since`부터 지금까지를 `slot`에 적고 다음 단계의 시작 시각을 돌려준다.
+fn stage_mark(slot: &mut u64, since: Instant) -> Instant {
+    let now = Instant::now();
+    *slot = now.duration_since(since).as_micros() as u64;
+    now
+}
+
+/// realm별로 모은 delta 열. 입력 순서는 유지한다.
+struct SoaLanes {
+    per_realm: Vec<Vec<i64>>,
+}
+
+impl SoaLanes {
+    fn build(batch: &StepBatchSoA, realm_count: usize) -> Result<Self, String> {
+        batch.validate().map_err(|err| err.to_message())?;
+        let mut per_realm = vec![Vec::new(); realm_count];
+        for (realm_id, delta) in batch.realm_ids.iter().zip(batch.deltas.iter()) {
+            let Some(slot) = per_realm.get_mut(*realm_id) else {
+                return Err(format!(
+                    "E_REALM_ID_OUT_OF_RANGE realm_id={} realm_count={}",
+                    realm_id, realm_count
+                ));
+            };
+            slot.push(*delta);
+        }
+        Ok(Self { per_realm })
+    }
+}
+
+/// realm 하나의 delta 합과 크기 합. 둘 다 i64 lane에서 wrapping으로 더하므로
+/// SIMD와 스칼라가 언제나 같은 값을 낸다. `exact`일 때만 합을 믿는다.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct LaneSums {
+    sum: i64,
+    abs_sum: i64,
+    mag_bits: u64,
+    len: u64,
+}
+
+impl LaneSums {
+    fn add_scalar(&mut self, deltas: &[i64]) {
+        for delta in deltas {
+            let mag = delta.unsigned_abs();
+            self.sum = self.sum.wrapping_add(*delta);
+            self.abs_sum = self.abs_sum.wrapping_add(mag as i64);
+            self.mag_bits |= mag;
+        }
+        self.len += deltas.len() as u64;
+    }
+
+    /// 모든 |delta|가 2^31 아래이고 개수가 2^32 아래면 두 합 모두 넘치지 않는다.
+    fn exact(&self) -> bool {
+        self.mag_bits < DELTA_EXACT_LIMIT && self.len < LANE_LEN_LIMIT
+    }
+}
+
+fn lane_sums(deltas: &[i64]) -> LaneSums {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if std::arch::is_x86_feature_detected!("avx2") {
+            // SAFETY: 바로 위에서 AVX2 지원을 확인했다.
+            return unsafe { lane_sums_avx2(deltas) };
+        }
+    }
+    lane_sums_scalar(deltas)
+}
+
+fn lane_sums_scalar(deltas: &[i64]) -> LaneSums {
+    let mut sums = LaneSums::default();
+    sums.add_scalar(deltas);
+    sums
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn lane_sums_avx2(deltas: &[i64]) -> LaneSums {
+    use std::arch::x86_64::{
+        __m256i, _mm256_add_epi64, _mm256_cmpgt_epi64, _mm256_loadu_si256, _mm256_or_si256,
+        _mm256_setzero_si256, _mm256_storeu_si256, _mm256_sub_epi64, _mm256_xor_si256,
+    };
+    let zero = _mm256_setzero_si256();
+    let mut sum = zero;
+    let mut abs_sum = zero;
+    let mut mag_bits = zero;
+    let chunks = deltas.chunks_exact(LANE_WIDTH);
+    let tail = chunks.remainder();
+    for chunk in chunks {
+        let value = _mm256_loadu_si256(chunk.as_ptr() as *const __m256i);
+        // 음수 lane은 sign이 모두 1이라 (value ^ sign) - sign이 2의 보수 크기가 된다.
+        let sign = _mm256_cmpgt_epi64(zero, value);
+        let mag = _mm256_sub_epi64(_mm256_xor_si256(value, sign), sign);
+        sum = _mm256_add_epi64(sum, value);
+        abs_sum = _mm256_add_epi64(abs_sum, mag);
+        mag_bits = _mm256_or_si256(mag_bits, mag);
+    }
+    let mut sum_lanes = [0i64; LANE_WIDTH];
+    let mut abs_lanes = [0i64; LANE_WIDTH];
+    let mut bit_lanes = [0i64; LANE_WIDTH];
+    _mm256_storeu_si256(sum_lanes.as_mut_ptr() as *mut __m256i, sum);
+    _mm256_storeu_si256(abs_lanes.as_mut_ptr() as *mut __m256i, abs_sum);
+    _mm256_storeu_si256(bit_lanes.as_mut_ptr() as *mut __m256i, mag_bits);
+    let mut sums = LaneSums {
+        len: (deltas.len() - tail.len()) as u64,
+        ..LaneSums::default()
+    };
+    for ((lane_sum, lane_abs), lane_bits) in sum_lanes.iter().zip(&abs_lanes).zip(&bit_lanes) {
+        sums.sum = sums.sum.wrapping_add(*lane_sum);
+        sums.abs_sum = sums.abs_sum.wrapping_add(*lane_abs);
+        sums.mag_bits |= *lane_bits as u64;
     }
-    Ok(())
+    sums.add_scalar(tail);
+    sums
 }
 
+fn step_realm_simd(realm: &mut Realm, deltas: &[i64], policy: &WarpPolicy) {
+    if deltas.is_empty() {
+        return;
+    }
+    let current = realm.value().raw_i64() as i128;
+    let sums = lane_sums(deltas);
+    let one = Fixed64::ONE_RAW as i128;
+    // 중간 값이 한 번도 포화하지 않으면 더하는 순서와 상관없이 스칼라와 같은 값이 된다.
+    if sums.exact() && current.abs() + sums.abs_sum as i128 * one <= i64::MAX as i128 {
+        let total = current + sums.sum as i128 * one;
+        realm.apply_reduced(deltas.len(), Fixed64::from_raw_i64(total as i64));
+        return;
+    }
+    match policy {
+        WarpPolicy::Strict => {
+            let inputs: Vec<RealmStepInput> = deltas
+                .iter()
+                .map(|delta| RealmStepInput {
+                    realm_id: realm.id as usize,
+                    delta: *delta,
+                    send: Vec::new(),
+                })
+                .collect();
+            realm.step_batch(&inputs);
+        }
+        WarpPolicy::Fast => {
+            // lane 순서로 다시 묶어 한 걸음씩 포화 덧셈한다. 포화가 끼면 스칼라와 값이 다를 수 있다.
+            let mut value = realm.value();
+            for lane in 0..LANE_WIDTH.min(deltas.len()) {
+                for delta in deltas.iter().skip(lane).step_by(LANE_WIDTH) {
+                    value = value.saturating_add(Fixed64::from_i64(*delta));
+                }
+            }
+            realm.apply_reduced(deltas.len(), value);
+        }
+    }
+}
+
+/// `require_gpu`면 GPU 장치가 없을 때 Cpu로 물러서지 않고 오류를 낸다.
 pub fn run_warp_bench(
     input: WarpBenchInput,
     backend: WarpBackend,
     policy: WarpPolicy,
     threads: usize,
     measure: bool,
+    require_gpu: bool,
 ) -> Result<WarpBenchOutput, String> {
+    let probe = if matches!(backend, WarpBackend::Gpu) {
+        probe_gpu()
+    } else {
+        GpuProbe::Unavailable
+    };
+    run_warp_bench_with_probe(input, backend, policy, threads, measure, require_gpu, probe)
+}
+
+pub fn run_warp_bench_with_probe(
+    input: WarpBenchInput,
+    backend: WarpBackend,
+    policy: WarpPolicy,
+    threads: usize,
+    measure: bool,
+    require_gpu: bool,
+    probe: GpuProbe,
+) -> Result<WarpBenchOutput, String> {
+    input
+        .step_batch
+        .validate()
+        .map_err(|err| err.to_message())?;
     if input.realm_count == 0 {
         return Err("E_WARP_INPUT realm_count must be > 0".to_string());
     }
 
+    if require_gpu {
+        if !matches!(backend, WarpBackend::Gpu) {
+            return Err(format!(
+                "E_WARP_REQUIRE_GPU --require-gpu는 --backend gpu에서만 씁니다 (backend={})",
+                backend.as_str()
+            ));
+        }
+        if probe != GpuProbe::Available {
+            return Err(
+                "E_WARP_GPU_UNAVAILABLE GPU 장치를 확인하지 못했습니다 (--require-gpu라 cpu로 물러서지 않음)"
+                    .to_string(),
+            );
+        }
+    }
+
     let mut cpu_ms = estimate_ms(input.realm_count, input.steps, 1);
-    let use_gpu = matches!(backend, WarpBackend::Gpu) && matches!(policy, WarpPolicy::Fast);
+    let gpu_requested = matches!(backend, WarpBackend::Gpu) && matches!(policy, WarpPolicy::Fast);
+    let gpu_unavailable = gpu_requested && probe != GpuProbe::Available;
+    let use_gpu = gpu_requested && !gpu_unavailable;
     let mut gpu_ms = if use_gpu {
         estimate_ms(input.realm_count, input.steps, 4)
     } else {
         cpu_ms
     };
+    let cpu_path = if matches!(backend, WarpBackend::CpuSimd) {
+        StepPath::Simd(policy.clone(), threads)
+    } else {
+        StepPath::Scalar(ThreadMode::Seq)
+    };
+    let backend_used = if use_gpu {
+        WarpBackend::Gpu
+    } else if matches!(backend, WarpBackend::CpuSimd) {
+        WarpBackend::CpuSimd
+    } else {
+        WarpBackend::Cpu
+    };
 
+    let (mut state_hashes, mut breakdown) = run_steps(
+        input.realm_count,
+        input.master_seed,
+        input.steps,
+        &input.step_batch,
+        cpu_path,
+    )?;
     if measure {
-        let start = std::time::Instant::now();
-        run_steps(
-            input.realm_count,
-            input.master_seed,
-            input.steps,
-            &input.step_batch,
-            ThreadMode::Seq,
-        )?;
-        let elapsed = start.elapsed();
-        cpu_ms = elapsed.as_millis().max(1) as u64;
-    } else {
-        run_steps(
-            input.realm_count,
-            input.master_seed,
-            input.steps,
-            &input.step_batch,
-            ThreadMode::Seq,
-        )?;
+        cpu_ms = (breakdown.total_us / 1000).max(1);
     }
 
     if use_gpu {
@@ -131,25 +530,15 @@ pub fn run_warp_bench(
         } else {
             ThreadMode::Rayon(threads)
         };
+        (state_hashes, breakdown) = run_steps(
+            input.realm_count,
+            input.master_seed,
+            input.steps,
+            &input.step_batch,
+            StepPath::Scalar(thread_mode),
+        )?;
         if measure {
-            let start = std::time::Instant::now();
-            run_steps(
-                input.realm_count,
-                input.master_seed,
-                input.steps,
-                &input.step_batch,
-                thread_mode,
-            )?;
-            let elapsed = start.elapsed();
-            gpu_ms = elapsed.as_millis().max(1) as u64;
-        } else {
-            run_steps(
-                input.realm_count,
-                input.master_seed,
-                input.steps,
-                &input.step_batch,
-                thread_mode,
-            )?;
+            gpu_ms = (breakdown.total_us / 1000).max(1);
         }
     } else if measure {
         gpu_ms = cpu_ms;
@@ -160,5 +549,260 @@ pub fn run_warp_bench(
         gpu_ms,
         realm_count: input.realm_count,
         step_count: input.steps,
+        backend_used,
+        gpu_unavailable,
+        state_hashes,
+        breakdown: measure.then_some(breakdown),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bench_input(deltas: &[(usize, i64)]) -> WarpBenchInput {
+        let inputs: Vec<RealmStepInput> = deltas
+            .iter()
+            .map(|(realm_id, delta)| RealmStepInput {
+                realm_id: *realm_id,
+                delta: *delta,
+                send: Vec::new(),
+            })
+            .collect();
+        WarpBenchInput {
+            master_seed: 77,
+            realm_count: 4,
+            steps: 5,
+            step_batch: StepBatchSoA::from_inputs(&inputs),
+        }
+    }
+
+    #[test]
+    fn cpu_simd_matches_cpu_under_strict() {
+        let mut deltas = Vec::new();
+        for idx in 0..37 {
+            deltas.push((idx % 4, (idx as i64) * 3 - 40));
+        }
+        deltas.push((2, i64::MAX));
+        deltas.push((2, -5));
+        deltas.push((3, i64::MIN));
+        let cpu = run_warp_bench(
+            bench_input(&deltas),
+            WarpBackend::Cpu,
+            WarpPolicy::Strict,
+            1,
+            false,
+            false,
+        )
+        .expect("cpu");
+        for threads in [1, 4] {
+            let simd = run_warp_bench(
+                bench_input(&deltas),
+                WarpBackend::CpuSimd,
+                WarpPolicy::Strict,
+                threads,
+                false,
+                false,
+            )
+            .expect("cpu simd");
+            assert_eq!(simd.backend_used, WarpBackend::CpuSimd);
+            assert_eq!(simd.backend_label(), "cpu-simd");
+            assert_eq!(cpu.state_hashes, simd.state_hashes, "threads={threads}");
+            assert_eq!(cpu.step_count, simd.step_count);
+        }
+        assert_eq!(cpu.backend_used, WarpBackend::Cpu);
+
+        let gpu_strict = run_warp_bench(
+            bench_input(&deltas),
+            WarpBackend::Gpu,
+            WarpPolicy::Strict,
+            2,
+            false,
+            false,
+        )
+        .expect("gpu strict");
+        assert_eq!(gpu_strict.backend_used, WarpBackend::Cpu);
+    }
+
+    #[test]
+    fn cpu_simd_under_fast_matches_cpu_until_saturation_and_is_deterministic() {
+        let deltas: Vec<(usize, i64)> = (0..53).map(|idx| (idx % 4, idx as i64 * 7 - 90)).collect();
+        let cpu = run_warp_bench(
+            bench_input(&deltas),
+            WarpBackend::Cpu,
+            WarpPolicy::Fast,
+            1,
+            false,
+            false,
+        )
+        .expect("cpu");
+        let simd = run_warp_bench(
+            bench_input(&deltas),
+            WarpBackend::CpuSimd,
+            WarpPolicy::Fast,
+            1,
+            false,
+            false,
+        )
+        .expect("cpu simd");
+        assert_eq!(cpu.state_hashes, simd.state_hashes);
+
+        // 포화가 끼면 Fast는 lane 순서로 더하므로 스칼라와 다를 수 있지만, 매번 같은 값을 낸다.
+        let mut saturating = deltas.clone();
+        saturating.extend([(1, i64::MAX), (1, -3), (1, i64::MAX), (1, i64::MIN), (1, 9)]);
+        let runs: Vec<_> = [1, 4]
+            .into_iter()
+            .map(|threads| {
+                run_warp_bench(
+                    bench_input(&saturating),
+                    WarpBackend::CpuSimd,
+                    WarpPolicy::Fast,
+                    threads,
+                    false,
+                    false,
+                )
+                .expect("fast saturating")
+                .state_hashes
+            })
+            .collect();
+        assert_eq!(runs[0], runs[1]);
+    }
+
+    #[test]
+    fn simd_lane_sums_match_scalar_sums() {
+        let mut deltas: Vec<i64> = (0..29).map(|idx| idx * 1_000_003 - 9_000_000).collect();
+        assert_eq!(lane_sums(&deltas), lane_sums_scalar(&deltas));
+        assert!(lane_sums(&deltas).exact());
+        deltas.extend([i64::MIN, i64::MAX, -1, 1 << 40]);
+        assert_eq!(lane_sums(&deltas), lane_sums_scalar(&deltas));
+        assert!(!lane_sums(&deltas).exact());
+        assert_eq!(lane_sums(&[]), LaneSums::default());
+    }
+
+    #[test]
+    fn measure_reports_stage_breakdown_under_cpu() {
+        let deltas: Vec<(usize, i64)> = (0..64).map(|idx| (idx % 4, idx as i64)).collect();
+        let measured = run_warp_bench(
+            bench_input(&deltas),
+            WarpBackend::Cpu,
+            WarpPolicy::Strict,
+            1,
+            true,
+            false,
+        )
+        .expect("measured");
+        let breakdown = measured.breakdown.expect("breakdown");
+        assert!(breakdown.stage_sum_us() <= breakdown.total_us);
+        assert_eq!(measured.cpu_ms, (breakdown.total_us / 1000).max(1));
+
+        let plain = run_warp_bench(
+            bench_input(&deltas),
+            WarpBackend::Cpu,
+            WarpPolicy::Strict,
+            1,
+            false,
+            false,
+        )
+        .expect("plain");
+        assert!(plain.breakdown.is_none());
+    }
+
+    #[test]
+    fn gpu_without_device_falls_back_unless_required() {
+        let deltas: Vec<(usize, i64)> = (0..16).map(|idx| (idx % 4, idx as i64)).collect();
+        let cpu = run_warp_bench(
+            bench_input(&deltas),
+            WarpBackend::Cpu,
+            WarpPolicy::Fast,
+            1,
+            false,
+            false,
+        )
+        .expect("cpu");
+        let fallback = run_warp_bench_with_probe(
+            bench_input(&deltas),
+            WarpBackend::Gpu,
+            WarpPolicy::Fast,
+            8,
+            false,
+            false,
+            GpuProbe::Unavailable,
+        )
+        .expect("fallback");
+        assert_eq!(fallback.backend_used, WarpBackend::Cpu);
+        assert!(fallback.gpu_unavailable);
+        assert_eq!(fallback.backend_label(), "cpu (gpu unavailable)");
+        assert_eq!(fallback.gpu_ms, fallback.cpu_ms);
+        assert_eq!(fallback.state_hashes, cpu.state_hashes);
+
+        let err = run_warp_bench_with_probe(
+            bench_input(&deltas),
+            WarpBackend::Gpu,
+            WarpPolicy::Fast,
+            8,
+            false,
+            true,
+            GpuProbe::Unavailable,
+        )
+        .expect_err("require gpu");
+        assert!(err.starts_with("E_WARP_GPU_UNAVAILABLE "), "{err}");
+
+        let gpu = run_warp_bench_with_probe(
+            bench_input(&deltas),
+            WarpBackend::Gpu,
+            WarpPolicy::Fast,
+            8,
+            false,
+            true,
+            GpuProbe::Available,
+        )
+        .expect("gpu");
+        assert_eq!(gpu.backend_used, WarpBackend::Gpu);
+        assert_eq!(gpu.backend_label(), "gpu");
+    }
+
+    #[test]
+    fn default_probe_falls_back_on_a_machine_without_gpu() {
+        if probe_gpu() == GpuProbe::Available {
+            return;
+        }
+        let deltas: Vec<(usize, i64)> = (0..8).map(|idx| (idx % 4, idx as i64)).collect();
+        let fallback = run_warp_bench(
+            bench_input(&deltas),
+            WarpBackend::Gpu,
+            WarpPolicy::Fast,
+            8,
+            false,
+            false,
+        )
+        .expect("fallback");
+        assert_eq!(fallback.backend_label(), "cpu (gpu unavailable)");
+        let err = run_warp_bench(
+            bench_input(&deltas),
+            WarpBackend::Gpu,
+            WarpPolicy::Fast,
+            8,
+            false,
+            true,
+        )
+        .expect_err("require gpu");
+        assert!(err.starts_with("E_WARP_GPU_UNAVAILABLE "), "{err}");
+    }
+
+    #[test]
+    fn ragged_soa_reports_mismatched_field() {
+        let mut input = bench_input(&[(0, 1), (1, 2), (2, 3)]);
+        input.step_batch.deltas.pop();
+        assert_eq!(
+            input.step_batch.validate(),
+            Err(WarpError::RaggedSoA {
+                field: "deltas",
+                len: 2,
+                expected: 3
+            })
+        );
+        let err = run_warp_bench(input, WarpBackend::Cpu, WarpPolicy::Strict, 1, false, false)
+            .expect_err("ragged");
+        assert_eq!(err, "E_WARP_SOA_LENGTH field=deltas len=2 expected=3");
+    }
+}
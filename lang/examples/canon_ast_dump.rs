@@ -382,7 +382,12 @@ fn collect_calls_from_expr(expr: &Expr, out: &mut Vec<CallDet>) {
         }
         ExprKind::FieldAccess { target, .. } => collect_calls_from_expr(target, out),
         ExprKind::SeedLiteral { body, .. } => collect_calls_from_expr(body, out),
-        ExprKind::Infix { left, right, .. } => {
+        ExprKind::Infix { left, right, .. }
+        | ExprKind::Range {
+            start: left,
+            end: right,
+            ..
+        } => {
             collect_calls_from_expr(left, out);
             collect_calls_from_expr(right, out);
         }
@@ -456,6 +461,7 @@ fn expr_fingerprint(expr: &Expr) -> String {
         ExprKind::SeedLiteral { param, .. } => format!("seed_literal:{}", param),
         ExprKind::Call { func, .. } => format!("call:{}", func),
         ExprKind::Infix { op, .. } => format!("infix:{}", op),
+        ExprKind::Range { inclusive, .. } => format!("range:{}", inclusive),
         ExprKind::Suffix { at, .. } => format!("suffix:{:?}", at),
         ExprKind::Thunk(_) => "thunk".to_string(),
         ExprKind::Eval { mode, .. } => format!("eval:{:?}", mode),
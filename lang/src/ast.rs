@@ -3,6 +3,7 @@ use ddonirang_core::Fixed64;
 use std::collections::HashMap;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Span {
     pub start: usize,
     pub end: usize,
@@ -36,22 +37,29 @@ pub struct OriginMap {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum TopLevelItem {
     SeedDef(SeedDef),
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct SeedDef {
     pub id: NodeId,
     pub span: Span,
     pub canonical_name: String,
     pub seed_kind: SeedKind,
     pub params: Vec<ParamPin>,
+    /// `셈씨<T>`처럼 씨앗 종류 뒤에 적은 두루(generic) 타입 이름들. 핀 타입에서 `T`로 쓴다.
+    pub type_params: Vec<String>,
     pub body: Option<Body>,
     pub modifiers: Vec<Modifier>,
+    /// 씨앗 바로 위 `///` 설명글(줄은 `\n`으로 이음). 정본화 때 그대로 다시 쓴다.
+    pub doc: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum SeedKind {
     Imeumssi,
     Umjikssi,
@@ -66,6 +74,7 @@ pub enum SeedKind {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ParamPin {
     pub id: NodeId,
     pub span: Span,
@@ -77,6 +86,7 @@ pub struct ParamPin {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum TypeRef {
     Named(String),
     Applied { name: String, args: Vec<TypeRef> },
@@ -84,6 +94,7 @@ pub enum TypeRef {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Body {
     pub id: NodeId,
     pub span: Span,
@@ -91,6 +102,7 @@ pub struct Body {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum MetaBlockKind {
     Setting,
     Bogae,
@@ -106,6 +118,7 @@ pub enum MetaBlockKind {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Stmt {
     DeclBlock {
         id: NodeId,
@@ -286,13 +299,48 @@ pub enum Stmt {
     },
 }
 
+impl Stmt {
+    /// 문장 종류와 관계없이 원본 위치를 돌려준다.
+    pub fn span(&self) -> Span {
+        match self {
+            Stmt::DeclBlock { span, .. }
+            | Stmt::Mutate { span, .. }
+            | Stmt::Expr { span, .. }
+            | Stmt::Receive { span, .. }
+            | Stmt::Send { span, .. }
+            | Stmt::Show { span, .. }
+            | Stmt::Inspect { span, .. }
+            | Stmt::MetaBlock { span, .. }
+            | Stmt::Pragma { span, .. }
+            | Stmt::Return { span, .. }
+            | Stmt::If { span, .. }
+            | Stmt::Try { span, .. }
+            | Stmt::Choose { span, .. }
+            | Stmt::Repeat { span, .. }
+            | Stmt::While { span, .. }
+            | Stmt::ForEach { span, .. }
+            | Stmt::Quantifier { span, .. }
+            | Stmt::Break { span, .. }
+            | Stmt::ContinueLoop { span, .. }
+            | Stmt::Contract { span, .. }
+            | Stmt::Guard { span, .. }
+            | Stmt::BeatBlock { span, .. }
+            | Stmt::Hook { span, .. }
+            | Stmt::HookWhenBecomes { span, .. }
+            | Stmt::HookWhile { span, .. } => *span,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum DeclKind {
     Gureut,
     Butbak,
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct DeclItem {
     pub id: NodeId,
     pub span: Span,
@@ -303,6 +351,7 @@ pub struct DeclItem {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Expr {
     pub id: NodeId,
     pub span: Span,
@@ -310,6 +359,7 @@ pub struct Expr {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ExprKind {
     Literal(Literal),
     Var(String),
@@ -330,6 +380,13 @@ pub enum ExprKind {
         op: String,
         right: Box<Expr>,
     },
+    /// `시작..끝`(끝 제외) / `시작..=끝`(끝 포함). 거꾸로거나 빈 범위도 그대로 담고,
+    /// 정본화는 이 모양을 그대로 두고, 정규화기와 실행기가 `표준.범위` 호출로 내린다.
+    Range {
+        start: Box<Expr>,
+        end: Box<Expr>,
+        inclusive: bool,
+    },
     Suffix {
         value: Box<Expr>,
         at: AtSuffix,
@@ -365,6 +422,7 @@ pub enum ExprKind {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ArgBinding {
     pub id: NodeId,
     pub span: Span,
@@ -375,12 +433,14 @@ pub struct ArgBinding {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum AtSuffix {
     Unit(String),
     Asset(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum BindingReason {
     Dictionary,
     Positional,
@@ -390,6 +450,7 @@ pub enum BindingReason {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ThunkEvalMode {
     Value,
     Bool,
@@ -399,24 +460,28 @@ pub enum ThunkEvalMode {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ChooseBranch {
     pub condition: Expr,
     pub body: Body,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ContractKind {
     Pre,
     Post,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ContractMode {
     Abort,
     Alert,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum QuantifierKind {
     ForAll,
     Exists,
@@ -424,6 +489,7 @@ pub enum QuantifierKind {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum HookKind {
     Start,
     End,
@@ -432,6 +498,7 @@ pub enum HookKind {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Mood {
     Declarative,
     Imperative,
@@ -441,9 +508,10 @@ pub enum Mood {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Literal {
     Int(i64),
-    Fixed64(Fixed64),
+    Fixed64(#[cfg_attr(feature = "serde", serde(serialize_with = "serialize_fixed64"))] Fixed64),
     String(String),
     Bool(bool),
     Atom(String),
@@ -452,13 +520,24 @@ pub enum Literal {
     None,
 }
 
+/// `Fixed64`는 정본 문자열 표기(`Display`)로 직렬화한다.
+#[cfg(feature = "serde")]
+fn serialize_fixed64<S: serde::Serializer>(
+    value: &Fixed64,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct RegexLiteral {
     pub pattern: String,
     pub flags: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Template {
     pub raw: String,
     pub parts: Vec<TemplatePart>,
@@ -466,6 +545,7 @@ pub struct Template {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum FormulaDialect {
     Ascii,
     Ascii1,
@@ -474,6 +554,7 @@ pub enum FormulaDialect {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Formula {
     pub raw: String,
     pub dialect: FormulaDialect,
@@ -481,6 +562,7 @@ pub struct Formula {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct StateMachine {
     pub states: Vec<String>,
     pub initial: String,
@@ -489,12 +571,14 @@ pub struct StateMachine {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Assertion {
     pub body_source: String,
     pub canon: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct StateTransition {
     pub from: String,
     pub to: String,
@@ -503,18 +587,21 @@ pub struct StateTransition {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum TemplatePart {
     Text(String),
     Placeholder(TemplatePlaceholder),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct TemplatePlaceholder {
     pub path: Vec<String>,
     pub format: Option<TemplateFormat>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct TemplateFormat {
     pub raw: String,
     pub width: Option<usize>,
@@ -524,6 +611,7 @@ pub struct TemplateFormat {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Modifier {
     pub id: NodeId,
     pub span: Span,
@@ -533,4 +621,236 @@ impl Expr {
     pub fn new(id: NodeId, span: Span, kind: ExprKind) -> Self {
         Self { id, span, kind }
     }
+
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// `Range`를 정본 `표준.범위` 호출(`시작`/`끝`/`끝포함` 핀)로 내린다. 범위가 아니면 `None`.
+    /// 새로 만드는 마디의 id는 `next_id`에서 하나씩 받는다.
+    pub fn lower_range(&self, next_id: &mut impl FnMut() -> NodeId) -> Option<Expr> {
+        let ExprKind::Range {
+            start,
+            end,
+            inclusive,
+        } = &self.kind
+        else {
+            return None;
+        };
+        let pinned = |id: NodeId, expr: Expr, pin: &str| ArgBinding {
+            id,
+            span: expr.span,
+            expr,
+            josa: None,
+            resolved_pin: Some(pin.to_string()),
+            binding_reason: BindingReason::UserFixed,
+        };
+        let flag = Expr::new(
+            next_id(),
+            end.span,
+            ExprKind::Literal(Literal::Fixed64(Fixed64::from_i64(i64::from(*inclusive)))),
+        );
+        let args = vec![
+            pinned(next_id(), (**start).clone(), "시작"),
+            pinned(next_id(), (**end).clone(), "끝"),
+            pinned(next_id(), flag, "끝포함"),
+        ];
+        Some(Expr::new(
+            next_id(),
+            self.span,
+            ExprKind::Call {
+                args,
+                func: "표준.범위".to_string(),
+            },
+        ))
+    }
+}
+
+/// 읽기 전용 AST 순회. 필요한 `visit_*`만 덮어쓰면 되고, 자식으로 내려가는 일은
+/// `walk_*`가 맡는다. 부모를 먼저 방문한 뒤 원본 순서대로 자식을 방문한다.
+pub trait Visitor {
+    fn visit_seed(&mut self, _seed: &SeedDef) {}
+    fn visit_stmt(&mut self, _stmt: &Stmt) {}
+    fn visit_expr(&mut self, _expr: &Expr) {}
+}
+
+pub fn walk_program<V: Visitor + ?Sized>(visitor: &mut V, program: &CanonProgram) {
+    for item in &program.items {
+        let TopLevelItem::SeedDef(seed) = item;
+        walk_seed(visitor, seed);
+    }
+}
+
+pub fn walk_seed<V: Visitor + ?Sized>(visitor: &mut V, seed: &SeedDef) {
+    visitor.visit_seed(seed);
+    for param in &seed.params {
+        if let Some(default) = &param.default_value {
+            walk_expr(visitor, default);
+        }
+    }
+    if let Some(body) = &seed.body {
+        walk_body(visitor, body);
+    }
+}
+
+pub fn walk_body<V: Visitor + ?Sized>(visitor: &mut V, body: &Body) {
+    for stmt in &body.stmts {
+        walk_stmt(visitor, stmt);
+    }
+}
+
+pub fn walk_stmt<V: Visitor + ?Sized>(visitor: &mut V, stmt: &Stmt) {
+    visitor.visit_stmt(stmt);
+    match stmt {
+        Stmt::DeclBlock { items, .. } => {
+            for item in items {
+                if let Some(value) = &item.value {
+                    walk_expr(visitor, value);
+                }
+            }
+        }
+        Stmt::Mutate { target, value, .. } => {
+            walk_expr(visitor, target);
+            walk_expr(visitor, value);
+        }
+        Stmt::Expr { expr, .. }
+        | Stmt::Show { expr, .. }
+        | Stmt::Inspect { expr, .. }
+        | Stmt::Return { value: expr, .. } => walk_expr(visitor, expr),
+        Stmt::Receive {
+            condition, body, ..
+        } => {
+            if let Some(condition) = condition {
+                walk_expr(visitor, condition);
+            }
+            walk_body(visitor, body);
+        }
+        Stmt::Send {
+            sender,
+            payload,
+            receiver,
+            ..
+        } => {
+            if let Some(sender) = sender {
+                walk_expr(visitor, sender);
+            }
+            walk_expr(visitor, payload);
+            walk_expr(visitor, receiver);
+        }
+        Stmt::If {
+            condition,
+            then_body,
+            else_body,
+            ..
+        } => {
+            walk_expr(visitor, condition);
+            walk_body(visitor, then_body);
+            if let Some(else_body) = else_body {
+                walk_body(visitor, else_body);
+            }
+        }
+        Stmt::Try { action, body, .. } => {
+            walk_expr(visitor, action);
+            walk_body(visitor, body);
+        }
+        Stmt::Choose {
+            branches,
+            else_body,
+            ..
+        } => {
+            for branch in branches {
+                walk_expr(visitor, &branch.condition);
+                walk_body(visitor, &branch.body);
+            }
+            walk_body(visitor, else_body);
+        }
+        Stmt::While {
+            condition, body, ..
+        }
+        | Stmt::Guard {
+            condition, body, ..
+        }
+        | Stmt::HookWhenBecomes {
+            condition, body, ..
+        }
+        | Stmt::HookWhile {
+            condition, body, ..
+        } => {
+            walk_expr(visitor, condition);
+            walk_body(visitor, body);
+        }
+        Stmt::ForEach { iterable, body, .. } => {
+            walk_expr(visitor, iterable);
+            walk_body(visitor, body);
+        }
+        Stmt::Contract {
+            condition,
+            then_body,
+            else_body,
+            ..
+        } => {
+            walk_expr(visitor, condition);
+            if let Some(then_body) = then_body {
+                walk_body(visitor, then_body);
+            }
+            walk_body(visitor, else_body);
+        }
+        Stmt::Repeat { body, .. }
+        | Stmt::Quantifier { body, .. }
+        | Stmt::BeatBlock { body, .. }
+        | Stmt::Hook { body, .. } => walk_body(visitor, body),
+        Stmt::MetaBlock { .. }
+        | Stmt::Pragma { .. }
+        | Stmt::Break { .. }
+        | Stmt::ContinueLoop { .. } => {}
+    }
+}
+
+pub fn walk_expr<V: Visitor + ?Sized>(visitor: &mut V, expr: &Expr) {
+    visitor.visit_expr(expr);
+    match &expr.kind {
+        ExprKind::FieldAccess { target: inner, .. }
+        | ExprKind::SeedLiteral { body: inner, .. }
+        | ExprKind::Suffix { value: inner, .. }
+        | ExprKind::Eval { thunk: inner, .. }
+        | ExprKind::Nuance { expr: inner, .. } => walk_expr(visitor, inner),
+        ExprKind::Call { args, .. } => {
+            for arg in args {
+                walk_expr(visitor, &arg.expr);
+            }
+        }
+        ExprKind::Infix { left, right, .. }
+        | ExprKind::Range {
+            start: left,
+            end: right,
+            ..
+        } => {
+            walk_expr(visitor, left);
+            walk_expr(visitor, right);
+        }
+        ExprKind::Thunk(body) => walk_body(visitor, body),
+        ExprKind::Pipe { stages } => {
+            for stage in stages {
+                walk_expr(visitor, stage);
+            }
+        }
+        ExprKind::Pack { fields: entries }
+        | ExprKind::TemplateRender {
+            inject: entries, ..
+        }
+        | ExprKind::FormulaEval {
+            inject: entries, ..
+        } => {
+            for (_, value) in entries {
+                walk_expr(visitor, value);
+            }
+        }
+        ExprKind::Literal(_)
+        | ExprKind::Var(_)
+        | ExprKind::FlowValue
+        | ExprKind::Assertion(_)
+        | ExprKind::StateMachine(_)
+        | ExprKind::Formula(_)
+        | ExprKind::Template(_) => {}
+    }
 }
@@ -3,6 +3,7 @@ use crate::lexer::{Lexer, TokenKind};
 use crate::parser::ParseError;
 use crate::stdlib::minimal_stdlib_sigs;
 use crate::term_map;
+use ddonirang_core::Fixed64;
 use std::collections::{HashMap, HashSet};
 
 const CALL_TAIL_SHORT_FORMS: [&str; 4] = ["기", "고", "면", "면서"];
@@ -17,17 +18,36 @@ pub struct CanonicalizeReport {
     pub warnings: Vec<LintWarning>,
 }
 
+impl CanonicalizeReport {
+    /// `code` 경고를 오류로 올린다. 해당 경고가 있으면 첫 번째 것을 오류로 돌려준다.
+    pub fn promote(&self, code: &str) -> Result<(), ParseError> {
+        match self.warnings.iter().find(|warning| warning.code == code) {
+            Some(warning) => Err(ParseError {
+                span: warning.span,
+                message: format!("{}: {}", warning.code, warning.message),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
 pub fn canonicalize(program: &mut CanonProgram) -> Result<CanonicalizeReport, ParseError> {
     let mut warnings = Vec::new();
     let signatures = collect_seed_signatures(program);
     for item in &mut program.items {
         canonicalize_top_level_item(item, &signatures, &mut warnings)?;
     }
+    check_generic_calls(program)?;
+    check_negative_index_literals(program)?;
     let known_seeds = collect_known_seeds(program);
     let stdlib_names = collect_stdlib_names();
     lint_tailless_calls(program, &known_seeds, &stdlib_names, &mut warnings);
     lint_deprecated_block_header_colon(program, &mut warnings);
+    lint_compound_ident_spacing(program, &mut warnings);
     lint_redundant_top_level_chaebi_reassign(program, &mut warnings);
+    lint_unreachable_after_return(program, &mut warnings);
+    lint_empty_contract_else(program, &mut warnings);
+    lint_unknown_meta_keys(program, &mut warnings);
     Ok(CanonicalizeReport { warnings })
 }
 
@@ -71,6 +91,23 @@ fn lint_deprecated_block_header_colon(program: &CanonProgram, warnings: &mut Vec
     }
 }
 
+/// `나이 : 수`처럼 복합 식별자 안의 띄어쓰기가 정본과 다르면 경고한다. 찾는 규칙은 `term_map`에 있다.
+fn lint_compound_ident_spacing(program: &CanonProgram, warnings: &mut Vec<LintWarning>) {
+    for spacing in term_map::find_compound_spacing(&program.origin.source) {
+        warnings.push(LintWarning {
+            code: term_map::SPACING_LINT_CODE,
+            span: Span {
+                start: spacing.start,
+                end: spacing.end,
+            },
+            message: format!(
+                "복합 식별자 `{}`는 `{}`로 붙여 씁니다",
+                spacing.surface, spacing.canonical
+            ),
+        });
+    }
+}
+
 fn lint_redundant_top_level_chaebi_reassign(
     program: &CanonProgram,
     warnings: &mut Vec<LintWarning>,
@@ -112,6 +149,278 @@ fn lint_redundant_top_level_chaebi_reassign(
     }
 }
 
+fn lint_unreachable_after_return(program: &CanonProgram, warnings: &mut Vec<LintWarning>) {
+    for item in &program.items {
+        let TopLevelItem::SeedDef(seed) = item;
+        if let Some(body) = &seed.body {
+            lint_unreachable_body(body, warnings);
+        }
+    }
+}
+
+/// 블록이 반드시 `돌려줘`로 끝나면 true. 그 뒤 첫 문장에만 경고를 단다.
+fn lint_unreachable_body(body: &Body, warnings: &mut Vec<LintWarning>) -> bool {
+    let mut returned = false;
+    for stmt in &body.stmts {
+        if returned {
+            warnings.push(LintWarning {
+                code: "FLOW-LINT-UNREACHABLE",
+                span: stmt.span(),
+                message: "`돌려줘` 뒤의 문장은 실행되지 않습니다".to_string(),
+            });
+            return true;
+        }
+        returned = lint_unreachable_stmt(stmt, warnings);
+    }
+    returned
+}
+
+fn lint_unreachable_stmt(stmt: &Stmt, warnings: &mut Vec<LintWarning>) -> bool {
+    match stmt {
+        Stmt::Return { .. } => true,
+        Stmt::If {
+            then_body,
+            else_body,
+            ..
+        } => {
+            let then_returns = lint_unreachable_body(then_body, warnings);
+            match else_body {
+                Some(else_body) => lint_unreachable_body(else_body, warnings) && then_returns,
+                None => false,
+            }
+        }
+        Stmt::Choose {
+            branches,
+            else_body,
+            ..
+        } => {
+            let mut all_return = true;
+            for branch in branches {
+                all_return &= lint_unreachable_body(&branch.body, warnings);
+            }
+            lint_unreachable_body(else_body, warnings) && all_return
+        }
+        Stmt::Contract {
+            then_body,
+            else_body,
+            ..
+        } => {
+            if let Some(then_body) = then_body {
+                lint_unreachable_body(then_body, warnings);
+            }
+            lint_unreachable_body(else_body, warnings);
+            false
+        }
+        Stmt::Receive { body, .. }
+        | Stmt::Try { body, .. }
+        | Stmt::Repeat { body, .. }
+        | Stmt::While { body, .. }
+        | Stmt::ForEach { body, .. }
+        | Stmt::Quantifier { body, .. }
+        | Stmt::Guard { body, .. }
+        | Stmt::BeatBlock { body, .. }
+        | Stmt::Hook { body, .. }
+        | Stmt::HookWhenBecomes { body, .. }
+        | Stmt::HookWhile { body, .. } => {
+            lint_unreachable_body(body, warnings);
+            false
+        }
+        Stmt::DeclBlock { .. }
+        | Stmt::Mutate { .. }
+        | Stmt::Expr { .. }
+        | Stmt::Send { .. }
+        | Stmt::Show { .. }
+        | Stmt::Inspect { .. }
+        | Stmt::MetaBlock { .. }
+        | Stmt::Pragma { .. }
+        | Stmt::Break { .. }
+        | Stmt::ContinueLoop { .. } => false,
+    }
+}
+
+/// 알림 계약은 위반 뒤에도 계속 진행하므로, `아니면 { }`이 비어 있으면 위반이 그냥 지나간다.
+/// 물림 계약은 어차피 멈추므로 빈 `아니면`을 허용한다.
+fn lint_empty_contract_else(program: &CanonProgram, warnings: &mut Vec<LintWarning>) {
+    struct EmptyContractElse<'a> {
+        warnings: &'a mut Vec<LintWarning>,
+    }
+    impl Visitor for EmptyContractElse<'_> {
+        fn visit_stmt(&mut self, stmt: &Stmt) {
+            let Stmt::Contract {
+                mode, else_body, ..
+            } = stmt
+            else {
+                return;
+            };
+            if *mode == ContractMode::Alert && else_body.stmts.is_empty() {
+                self.warnings.push(LintWarning {
+                    code: "CONTRACT-LINT-EMPTY-ELSE",
+                    span: else_body.span,
+                    message: "계약의 `아니면` 갈래가 비어 있어 위반이 무시됩니다".to_string(),
+                });
+            }
+        }
+    }
+    walk_program(&mut EmptyContractElse { warnings }, program);
+}
+
+/// `설정 { 화멘: "기본". }`처럼 알 수 없는 키는 조용히 무시되므로 경고하고 가장 가까운 키를 제안한다.
+/// 그리기 문장이나 `문서 { .. }` 같은 안쪽 블록은 한 낱말 키가 아니어서 건너뛴다.
+fn lint_unknown_meta_keys(program: &CanonProgram, warnings: &mut Vec<LintWarning>) {
+    struct UnknownMetaKeys<'a> {
+        warnings: &'a mut Vec<LintWarning>,
+    }
+    impl Visitor for UnknownMetaKeys<'_> {
+        fn visit_stmt(&mut self, stmt: &Stmt) {
+            let Stmt::MetaBlock {
+                span,
+                kind,
+                entries,
+                ..
+            } = stmt
+            else {
+                return;
+            };
+            let Some(known) = term_map::known_meta_keys(kind) else {
+                return;
+            };
+            for entry in entries {
+                let Some((key, _)) = entry.split_once(':') else {
+                    continue;
+                };
+                let key = key.trim();
+                if key.is_empty()
+                    || !key.chars().all(|ch| ch == '_' || ch.is_alphanumeric())
+                    || term_map::is_known_meta_key(key, &known)
+                {
+                    continue;
+                }
+                let message = match term_map::closest_meta_key(key, &known) {
+                    Some(suggestion) => {
+                        format!("알 수 없는 메타 키 `{key}`입니다. `{suggestion}`을(를) 뜻했나요?")
+                    }
+                    None => format!("알 수 없는 메타 키 `{key}`입니다"),
+                };
+                self.warnings.push(LintWarning {
+                    code: term_map::META_UNKNOWN_KEY_LINT_CODE,
+                    span: *span,
+                    message,
+                });
+            }
+        }
+    }
+    walk_program(&mut UnknownMetaKeys { warnings }, program);
+}
+
+/// 두루 타입 씨앗(`셈씨<T>`) 호출에서 같은 두루 타입 핀에 들어온 인자들이 한 타입으로 묶이는지 본다.
+/// 인자 타입은 글자값과 이 씨앗의 핀/채비 선언으로만 알아내고, 모르는 인자는 건너뛴다.
+fn check_generic_calls(program: &CanonProgram) -> Result<(), ParseError> {
+    struct GenericCalls<'a> {
+        generics: HashMap<&'a str, &'a SeedDef>,
+        local_types: HashMap<String, String>,
+        error: Option<ParseError>,
+    }
+    impl Visitor for GenericCalls<'_> {
+        fn visit_seed(&mut self, seed: &SeedDef) {
+            self.local_types.clear();
+            for param in &seed.params {
+                if let TypeRef::Named(name) = &param.type_ref {
+                    self.local_types
+                        .insert(param.pin_name.clone(), name.clone());
+                }
+            }
+        }
+        fn visit_stmt(&mut self, stmt: &Stmt) {
+            let Stmt::DeclBlock { items, .. } = stmt else {
+                return;
+            };
+            for item in items {
+                if let TypeRef::Named(name) = &item.type_ref {
+                    self.local_types.insert(item.name.clone(), name.clone());
+                }
+            }
+        }
+        fn visit_expr(&mut self, expr: &Expr) {
+            if self.error.is_some() {
+                return;
+            }
+            let ExprKind::Call { args, func } = &expr.kind else {
+                return;
+            };
+            let Some(seed) = self.generics.get(func.as_str()) else {
+                return;
+            };
+            let mut bound: HashMap<&str, String> = HashMap::new();
+            for (idx, arg) in args.iter().enumerate() {
+                let param = match &arg.resolved_pin {
+                    Some(pin) => seed.params.iter().find(|param| &param.pin_name == pin),
+                    None => seed.params.get(idx),
+                };
+                let Some(TypeRef::Named(type_name)) = param.map(|param| &param.type_ref) else {
+                    continue;
+                };
+                if !seed.type_params.contains(type_name) {
+                    continue;
+                }
+                let Some(actual) = self.arg_type(&arg.expr) else {
+                    continue;
+                };
+                match bound.get(type_name.as_str()) {
+                    Some(first) if *first != actual => {
+                        self.error = Some(ParseError {
+                            span: arg.span,
+                            message: format!(
+                                "E_CALL_TYPE_PARAM: '{}'의 두루 타입 {}가 {}와 {}로 엇갈립니다",
+                                func, type_name, first, actual
+                            ),
+                        });
+                        return;
+                    }
+                    Some(_) => {}
+                    None => {
+                        bound.insert(type_name.as_str(), actual);
+                    }
+                }
+            }
+        }
+    }
+    impl GenericCalls<'_> {
+        fn arg_type(&self, expr: &Expr) -> Option<String> {
+            match &expr.kind {
+                ExprKind::Literal(Literal::Int(_) | Literal::Fixed64(_)) => Some("수".to_string()),
+                ExprKind::Literal(Literal::String(_)) => Some("글".to_string()),
+                ExprKind::Literal(Literal::Bool(_)) => Some("참거짓".to_string()),
+                ExprKind::Var(name) => self.local_types.get(name).cloned(),
+                _ => None,
+            }
+        }
+    }
+
+    let generics: HashMap<&str, &SeedDef> = program
+        .items
+        .iter()
+        .map(|item| {
+            let TopLevelItem::SeedDef(seed) = item;
+            seed
+        })
+        .filter(|seed| !seed.type_params.is_empty())
+        .map(|seed| (seed.canonical_name.as_str(), seed))
+        .collect();
+    if generics.is_empty() {
+        return Ok(());
+    }
+    let mut check = GenericCalls {
+        generics,
+        local_types: HashMap::new(),
+        error: None,
+    };
+    walk_program(&mut check, program);
+    match check.error {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
 fn is_redundant_top_level_chaebi_reassign_value(value: &Expr) -> bool {
     matches!(value.kind, ExprKind::Literal(_))
 }
@@ -318,6 +627,7 @@ fn canonicalize_expr(
         }
         ExprKind::Call { args, func } => {
             canonicalize_ident(func, expr.span, warnings)?;
+            check_call_arity(func, args.len(), expr.span, signatures)?;
             for arg in args {
                 canonicalize_expr(&mut arg.expr, signatures, warnings)?;
                 if let Some(pin) = &mut arg.resolved_pin {
@@ -327,7 +637,12 @@ fn canonicalize_expr(
                 lint_call_arg_josa_conflict(arg, func, signatures, warnings);
             }
         }
-        ExprKind::Infix { left, right, .. } => {
+        ExprKind::Infix { left, right, .. }
+        | ExprKind::Range {
+            start: left,
+            end: right,
+            ..
+        } => {
             canonicalize_expr(left, signatures, warnings)?;
             canonicalize_expr(right, signatures, warnings)?;
         }
@@ -375,6 +690,109 @@ fn canonicalize_expr(
     Ok(())
 }
 
+/// `보드[-1]`/`보드[-1] <- 3`처럼 차림 인덱스가 정적으로 음수인 글자값이면 막는다.
+/// 이름이나 식으로 된 인덱스는 실행 중 범위 검사에 맡긴다.
+fn check_negative_index_literals(program: &CanonProgram) -> Result<(), ParseError> {
+    struct NegativeIndex {
+        error: Option<ParseError>,
+    }
+    impl Visitor for NegativeIndex {
+        fn visit_expr(&mut self, expr: &Expr) {
+            if self.error.is_some() {
+                return;
+            }
+            let ExprKind::Call { args, func } = &expr.kind else {
+                return;
+            };
+            if func != "차림.값" && func != "차림.바꾼값" {
+                return;
+            }
+            let index = args
+                .iter()
+                .find(|arg| arg.resolved_pin.as_deref() == Some("i"))
+                .or_else(|| args.get(1));
+            let Some(index) = index else {
+                return;
+            };
+            if is_static_negative_literal(&index.expr) {
+                self.error = Some(ParseError {
+                    span: index.span,
+                    message: format!(
+                        "E_INDEX_NEGATIVE_LITERAL: '{}'의 인덱스가 음수 글자값입니다; 차림 인덱스는 0부터 셉니다",
+                        func
+                    ),
+                });
+            }
+        }
+    }
+    let mut check = NegativeIndex { error: None };
+    walk_program(&mut check, program);
+    match check.error {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+/// 음수 글자값이거나, 파서가 단항 `-`를 낮춘 `0 - 양수 글자값` 꼴이면 참이다.
+fn is_static_negative_literal(expr: &Expr) -> bool {
+    fn literal_sign(expr: &Expr) -> Option<std::cmp::Ordering> {
+        match &expr.kind {
+            ExprKind::Literal(Literal::Int(value)) => Some(value.cmp(&0)),
+            ExprKind::Literal(Literal::Fixed64(value)) => Some(value.cmp(&Fixed64::ZERO)),
+            _ => None,
+        }
+    }
+    match &expr.kind {
+        ExprKind::Infix { left, op, right } if op == "-" => {
+            literal_sign(left) == Some(std::cmp::Ordering::Equal)
+                && literal_sign(right) == Some(std::cmp::Ordering::Greater)
+        }
+        _ => literal_sign(expr) == Some(std::cmp::Ordering::Less),
+    }
+}
+
+/// 같은 파일에 정의된 씨앗 호출의 인자 수를 필수/선택/기본값 핀과 맞춰 본다.
+/// 다른 파일(가지)의 씨앗은 `signatures`에 없으므로 건너뛴다.
+fn check_call_arity(
+    func: &str,
+    actual: usize,
+    span: Span,
+    signatures: &HashMap<String, Vec<ParamPin>>,
+) -> Result<(), ParseError> {
+    let Some(params) = signatures.get(func) else {
+        return Ok(());
+    };
+    let required = params
+        .iter()
+        .filter(|param| param.default_value.is_none() && !param.optional)
+        .count();
+    if actual < required || actual > params.len() {
+        return Err(call_arity_error(func, required, params.len(), actual, span));
+    }
+    Ok(())
+}
+
+pub(crate) fn call_arity_error(
+    func: &str,
+    required: usize,
+    max: usize,
+    actual: usize,
+    span: Span,
+) -> ParseError {
+    let expected = if required == max {
+        max.to_string()
+    } else {
+        format!("{}~{}", required, max)
+    };
+    ParseError {
+        span,
+        message: format!(
+            "E_CALL_ARITY: '{}'는 인자 {}개를 받지만 {}개가 주어졌습니다",
+            func, expected, actual
+        ),
+    }
+}
+
 fn canonicalize_call_arg_josa(
     arg: &mut ArgBinding,
     func: &str,
@@ -721,7 +1139,12 @@ fn lint_tailless_expr(
         ExprKind::FieldAccess { target, .. } => {
             lint_tailless_expr(target, known_seeds, stdlib_names, warnings)
         }
-        ExprKind::Infix { left, right, .. } => {
+        ExprKind::Infix { left, right, .. }
+        | ExprKind::Range {
+            start: left,
+            end: right,
+            ..
+        } => {
             lint_tailless_expr(left, known_seeds, stdlib_names, warnings);
             lint_tailless_expr(right, known_seeds, stdlib_names, warnings);
         }
@@ -530,8 +530,11 @@ impl<'a> Lexer<'a> {
 
     fn read_number(&mut self) -> Result<Token, LexError> {
         let start = self.pos;
+        if self.peek_char() == Some('0') && matches!(self.peek_ahead(1), Some('x' | 'X')) {
+            return self.read_hex_number();
+        }
         while let Some(ch) = self.peek_char() {
-            if ch.is_ascii_digit() {
+            if ch.is_ascii_digit() || ch == '_' {
                 self.advance();
             } else if ch == '.'
                 && self
@@ -545,10 +548,12 @@ impl<'a> Lexer<'a> {
             }
         }
         let raw = &self.source[start..self.pos];
-        let kind = if raw.contains('.') {
-            TokenKind::Float(raw.to_string())
+        let digits = strip_digit_separators(raw, |ch| ch.is_ascii_digit())
+            .ok_or_else(|| LexError::new(start, "수 구분자 '_'는 숫자 사이에 하나씩만 씁니다"))?;
+        let kind = if digits.contains('.') {
+            TokenKind::Float(digits)
         } else {
-            TokenKind::Integer(raw.parse().unwrap_or(0))
+            TokenKind::Integer(digits.parse().unwrap_or(0))
         };
         Ok(Token {
             kind,
@@ -557,6 +562,27 @@ impl<'a> Lexer<'a> {
         })
     }
 
+    fn read_hex_number(&mut self) -> Result<Token, LexError> {
+        let start = self.pos;
+        self.advance();
+        self.advance();
+        while let Some(ch) = self.peek_char() {
+            if ch.is_ascii_hexdigit() || ch == '_' {
+                self.advance();
+            } else {
+                break;
+            }
+        }
+        let raw = &self.source[start..self.pos];
+        let value =
+            parse_int_surface(raw).ok_or_else(|| LexError::new(start, "잘못된 16진 수 표기"))?;
+        Ok(Token {
+            kind: TokenKind::Integer(value),
+            span: Span::new(start, self.pos),
+            raw: raw.to_string(),
+        })
+    }
+
     fn read_string(&mut self) -> Result<Token, LexError> {
         let start = self.pos;
         self.advance();
@@ -587,6 +613,7 @@ impl<'a> Lexer<'a> {
     }
 
     fn read_string_escape(&mut self, start: usize) -> Result<String, LexError> {
+        let backslash = self.pos - 1;
         if self.is_eof() {
             return Err(LexError::new(self.pos, "문자열 종료"));
         }
@@ -640,14 +667,47 @@ impl<'a> Lexer<'a> {
                 self.advance();
                 Ok("\\".to_string())
             }
+            Some('u') => {
+                self.advance();
+                self.read_unicode_escape(backslash)
+            }
             Some(other) => Err(LexError::new(
-                self.pos,
+                backslash,
                 &format!("알 수 없는 글 이스케이프: \\{}", other),
             )),
             None => Err(LexError::new(self.pos, "문자열 종료")),
         }
     }
 
+    /// `\u{AC00}` 꼴. 16진 숫자 1~6자리가 유효한 유니코드 스칼라여야 한다.
+    fn read_unicode_escape(&mut self, backslash: usize) -> Result<String, LexError> {
+        let invalid = || LexError::new(backslash, "잘못된 유니코드 이스케이프: \\u{16진수}");
+        if self.peek_char() != Some('{') {
+            return Err(invalid());
+        }
+        self.advance();
+        let mut digits = String::new();
+        while let Some(ch) = self.peek_char() {
+            if ch == '}' {
+                break;
+            }
+            if !ch.is_ascii_hexdigit() || digits.len() == 6 {
+                return Err(invalid());
+            }
+            digits.push(ch);
+            self.advance();
+        }
+        if self.peek_char() != Some('}') || digits.is_empty() {
+            return Err(invalid());
+        }
+        self.advance();
+        u32::from_str_radix(&digits, 16)
+            .ok()
+            .and_then(char::from_u32)
+            .map(String::from)
+            .ok_or_else(invalid)
+    }
+
     fn read_rich_markup_braced_body(
         &mut self,
         start: usize,
@@ -1165,6 +1225,100 @@ impl<'a> Lexer<'a> {
     }
 }
 
+/// `_`가 양옆 모두 숫자일 때만 지우고, 아니면 None.
+fn strip_digit_separators(body: &str, is_digit: fn(char) -> bool) -> Option<String> {
+    let chars: Vec<char> = body.chars().collect();
+    let mut out = String::with_capacity(body.len());
+    for (idx, ch) in chars.iter().enumerate() {
+        if *ch != '_' {
+            out.push(*ch);
+            continue;
+        }
+        let prev = idx.checked_sub(1).and_then(|i| chars.get(i));
+        let next = chars.get(idx + 1);
+        if !prev.is_some_and(|c| is_digit(*c)) || !next.is_some_and(|c| is_digit(*c)) {
+            return None;
+        }
+    }
+    Some(out)
+}
+
+/// 정수 표기(`1_000`, `0xFF`, 앞의 `-` 허용)를 값으로 읽는다. 소수는 None.
+pub(crate) fn parse_int_surface(text: &str) -> Option<i64> {
+    let (negative, body) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text),
+    };
+    let value = match body.strip_prefix("0x").or_else(|| body.strip_prefix("0X")) {
+        Some(hex) => {
+            let digits = strip_digit_separators(hex, |ch| ch.is_ascii_hexdigit())?;
+            if digits.is_empty() {
+                return None;
+            }
+            i64::from_str_radix(&digits, 16).ok()?
+        }
+        None => {
+            let digits = strip_digit_separators(body, |ch| ch.is_ascii_digit())?;
+            if digits.is_empty() || !digits.chars().all(|ch| ch.is_ascii_digit()) {
+                return None;
+            }
+            digits.parse().ok()?
+        }
+    };
+    Some(if negative { -value } else { value })
+}
+
+/// 글 값을 다시 `"..."` 안에 쓸 수 있게 최소한으로 이스케이프한다. 콘솔 표지(`\반전`, `\색{..}`)의
+/// 역빗금은 글 값에 그대로 남아 있으므로 다시 이스케이프하지 않는다.
+pub fn escape_string_literal(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for (idx, ch) in value.char_indices() {
+        match ch {
+            '\\' if starts_rich_markup(&value[idx + 1..]) => out.push('\\'),
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            ch if ch.is_control() => out.push_str(&format!("\\u{{{:X}}}", ch as u32)),
+            ch => out.push(ch),
+        }
+    }
+    out
+}
+
+fn starts_rich_markup(rest: &str) -> bool {
+    ["반전끝", "굵게끝", "반전", "굵게", "되돌림", "색{", "배경{"]
+        .iter()
+        .any(|surface| rest.starts_with(surface))
+}
+
+/// `offset`이 줄의 첫 낱말일 때, 바로 위에 빈 줄 없이 붙은 `///` 설명글 줄들을 모은다.
+/// 줄마다 `///` 뒤 빈칸 하나를 떼고 `\n`으로 잇는다. `////`로 시작하는 줄은 보통 주석이다.
+pub fn leading_doc_comment(source: &str, offset: usize) -> Option<String> {
+    let head = source.get(..offset)?;
+    let line_start = head.rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+    if !head[line_start..].trim().is_empty() {
+        return None;
+    }
+    let mut lines = Vec::new();
+    for line in head[..line_start].lines().rev() {
+        let line = line.trim();
+        let Some(text) = line.strip_prefix("///") else {
+            break;
+        };
+        if text.starts_with('/') {
+            break;
+        }
+        lines.push(text.strip_prefix(' ').unwrap_or(text));
+    }
+    if lines.is_empty() {
+        return None;
+    }
+    lines.reverse();
+    Some(lines.join("\n"))
+}
+
 fn is_josa_tail_char(ch: char) -> bool {
     ch.is_alphanumeric() || ch == '_' || ch == '-'
 }
@@ -1259,6 +1413,47 @@ mod tests {
         assert!(has_ascii_atom, "expected #ascii atom token");
     }
 
+    #[test]
+    fn hex_and_grouped_integers_share_literal_value() {
+        let mut lexer = Lexer::new("0xFF 1_000 1_000.5");
+        let tokens = lexer.tokenize().expect("tokenize");
+        assert!(matches!(tokens[0].kind, TokenKind::Integer(255)));
+        assert_eq!(tokens[0].raw, "0xFF");
+        assert!(matches!(tokens[1].kind, TokenKind::Integer(1000)));
+        assert!(matches!(tokens[2].kind, TokenKind::Float(ref text) if text == "1000.5"));
+    }
+
+    #[test]
+    fn string_escapes_decode_and_reescape_minimally() {
+        let mut lexer = Lexer::new(r#""a\nb" "\u{AC00}\t\"""#);
+        let tokens = lexer.tokenize().expect("tokenize");
+        let TokenKind::StringLit(newline) = &tokens[0].kind else {
+            panic!("string literal expected");
+        };
+        assert_eq!(newline.chars().count(), 3);
+        assert_eq!(newline, "a\nb");
+        assert!(matches!(&tokens[1].kind, TokenKind::StringLit(text) if text == "\u{ac00}\t\""));
+        assert_eq!(escape_string_literal("\u{ac00}\t\""), "\u{ac00}\\t\\\"");
+        assert_eq!(escape_string_literal("\\반전굵게\\"), "\\반전굵게\\\\");
+
+        let err = Lexer::new(r#"x <- "ab\q"."#)
+            .tokenize()
+            .expect_err("unknown escape");
+        assert_eq!(err.pos, 8);
+        let err = Lexer::new(r#""\u{110000}""#)
+            .tokenize()
+            .expect_err("out of range");
+        assert_eq!(err.pos, 1);
+    }
+
+    #[test]
+    fn malformed_numeric_separators_are_rejected() {
+        for source in ["0x", "1__0", "1_", "0x_FF"] {
+            let mut lexer = Lexer::new(source);
+            assert!(lexer.tokenize().is_err(), "{source} should be rejected");
+        }
+    }
+
     #[test]
     fn english_keyword_is_only_active_under_en_dialect() {
         let mut ko = Lexer::new("if 참.\n");
@@ -36,7 +36,7 @@ pub use frontdoor::{
     wrap_lang_parity_source,
 };
 pub use lexer::{LexError, Lexer, Token, TokenKind};
-pub use normalizer::{normalize, NormalizationLevel, Normalizer};
+pub use normalizer::{normalize, normalize_expr_pretty, NormalizationLevel, Normalizer};
 pub use parser::{ParseError, ParseMode, Parser};
 pub use runtime::{
     input_just_pressed, input_pressed, list_add, list_len, list_new, list_nth, list_remove,
@@ -82,6 +82,23 @@ pub fn parse_with_mode(
     parser.parse_program(source.to_string(), file_path.to_string())
 }
 
+/// 편리 함수: 셈씨 본문 끝의 마침표 없는 식을 `돌려줘`로 읽는 opt-in 파싱
+pub fn parse_with_implicit_return(
+    source: &str,
+    file_path: &str,
+) -> Result<CanonProgram, ParseError> {
+    let tokens = Lexer::new(source).tokenize().map_err(|e| ParseError {
+        span: crate::ast::Span {
+            start: e.pos,
+            end: e.pos + 1,
+        },
+        message: e.message,
+    })?;
+
+    let mut parser = Parser::new_with_mode(tokens, ParseMode::Strict).with_implicit_return(true);
+    parser.parse_program(source.to_string(), file_path.to_string())
+}
+
 /// 편리 함수: 소스 → 정본화
 pub fn parse_and_normalize(
     source: &str,
@@ -500,6 +517,120 @@ mod integration_tests {
         }
     }
 
+    #[test]
+    fn test_every_statement_reports_nonempty_span() {
+        fn walk(body: &Body, seen: &mut Vec<String>) {
+            for stmt in &body.stmts {
+                let span = stmt.span();
+                assert!(span.end > span.start, "empty span: {stmt:?}");
+                seen.push(
+                    format!("{stmt:?}")
+                        .split_whitespace()
+                        .next()
+                        .unwrap()
+                        .to_string(),
+                );
+                match stmt {
+                    Stmt::If {
+                        condition,
+                        then_body,
+                        else_body,
+                        ..
+                    } => {
+                        assert!(condition.span().end > condition.span().start);
+                        walk(then_body, seen);
+                        if let Some(else_body) = else_body {
+                            walk(else_body, seen);
+                        }
+                    }
+                    Stmt::Repeat { body, .. } | Stmt::While { body, .. } => walk(body, seen),
+                    Stmt::Mutate { target, value, .. } => {
+                        assert!(target.span().end > target.span().start);
+                        assert!(value.span().end > value.span().start);
+                    }
+                    Stmt::Return { value, .. } | Stmt::Expr { expr: value, .. } => {
+                        assert!(value.span().end > value.span().start);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let source = r#"
+(x:수) 판정:셈씨 = {
+    채비 { 점수:수 <- 0. }.
+    점수 <- x + 1.
+    점수 보여주기.
+    (x) 판정.
+    (x < 0) 일때 {
+        "음수" 돌려줘.
+    } 아니면 {
+        반복 { 멈추기. }.
+    }
+    { 점수 < 3 }인것 동안 {
+        점수 <- 점수 + 1.
+    }.
+    점수 돌려줘.
+}
+"#;
+        let program = parse(source, "test.ddoni").unwrap();
+        let TopLevelItem::SeedDef(seed) = &program.items[0];
+        let mut seen = Vec::new();
+        walk(seed.body.as_ref().unwrap(), &mut seen);
+        for kind in [
+            "DeclBlock",
+            "Mutate",
+            "Show",
+            "Expr",
+            "If",
+            "Return",
+            "Repeat",
+            "Break",
+            "While",
+        ] {
+            assert!(seen.iter().any(|seen| seen == kind), "{kind} missing: {seen:?}");
+        }
+    }
+
+    #[test]
+    fn test_visitor_counts_calls_in_nested_positions() {
+        struct CallCounter {
+            calls: Vec<String>,
+            seeds: usize,
+        }
+        impl Visitor for CallCounter {
+            fn visit_seed(&mut self, _seed: &SeedDef) {
+                self.seeds += 1;
+            }
+            fn visit_expr(&mut self, expr: &Expr) {
+                if let ExprKind::Call { func, .. } = &expr.kind {
+                    self.calls.push(func.clone());
+                }
+            }
+        }
+
+        let source = r#"
+(x:수) 두배:셈씨 = {
+    x * 2 돌려줘.
+}
+
+테스트:셈씨 = {
+    값 <- ((1) 두배) 두배.
+    (값 > 2) 일때 {
+        (값) 두배 보여주기.
+    }
+}
+"#;
+        let program = parse(source, "test.ddoni").unwrap();
+        let mut counter = CallCounter {
+            calls: Vec::new(),
+            seeds: 0,
+        };
+        walk_program(&mut counter, &program);
+        assert_eq!(counter.seeds, 2);
+        assert_eq!(counter.calls, vec!["두배", "두배", "두배"]);
+    }
+
     #[test]
     fn test_if_statement_parsing() {
         let source = r#"
@@ -693,6 +824,161 @@ mod integration_tests {
         assert!(normalized.contains("3:x 1:y 2:z 합"));
     }
 
+    #[test]
+    fn test_inserted_optional_pin_keeps_existing_injection_order() {
+        let before = r#"
+(x:수, a:수?=1, b:수?=2, c:수?=3) 합:셈씨 = {
+    x 돌려줘.
+}
+
+테스트:셈씨 = {
+    (5) 합.
+}
+"#;
+        let after = r#"
+(x:수, a:수?=1, w:수?=9, b:수?=2, c:수?=3) 합:셈씨 = {
+    x 돌려줘.
+}
+
+테스트:셈씨 = {
+    (5) 합.
+}
+"#;
+        let before = parse_and_normalize(before, "test.ddoni", NormalizationLevel::N1).unwrap();
+        let after = parse_and_normalize(after, "test.ddoni", NormalizationLevel::N1).unwrap();
+        assert!(before.contains("5:x 1:a 2:b 3:c 합"), "{before}");
+        assert!(after.contains("5:x 1:a 9:w 2:b 3:c 합"), "{after}");
+
+        let mut program = parse(
+            "(x:수, a:수?=1, b:수?=2) 합:셈씨 = {\n    x 돌려줘.\n}\n\n테스트:셈씨 = {\n    (5) 합.\n}\n",
+            "test.ddoni",
+        )
+        .unwrap();
+        for item in &mut program.items {
+            let TopLevelItem::SeedDef(seed) = item;
+            for stmt in seed.body.iter_mut().flat_map(|body| body.stmts.iter_mut()) {
+                if let Stmt::Expr { expr, .. } = stmt {
+                    if let ExprKind::Call { args, .. } = &mut expr.kind {
+                        args.reverse();
+                    }
+                }
+            }
+        }
+        let normalized = normalize(&program, NormalizationLevel::N1);
+        assert!(normalized.contains("5:x 1:a 2:b 합"), "{normalized}");
+    }
+
+    #[test]
+    fn test_seed_doc_comment_is_attached_and_survives_normalization() {
+        let source = r#"
+// 보통 주석은 붙지 않는다.
+
+/// 두 수를 더한다.
+///
+/// 결과는 수다.
+(a:수, b:수) 더하:셈씨 = {
+    (a + b) 돌려줘.
+}
+
+// 설명글 없음
+테스트:셈씨 = {
+    (1, 2) 더하.
+}
+"#;
+        let program = parse(source, "test.ddoni").unwrap();
+        let docs: Vec<Option<&str>> = program
+            .items
+            .iter()
+            .map(|item| {
+                let TopLevelItem::SeedDef(seed) = item;
+                seed.doc.as_deref()
+            })
+            .collect();
+        assert_eq!(docs, vec![Some("두 수를 더한다.\n\n결과는 수다."), None]);
+
+        let normalized = normalize(&program, NormalizationLevel::N1);
+        assert!(
+            normalized
+                .starts_with("/// 두 수를 더한다.\n///\n/// 결과는 수다.\n(a:수, b:수) 더하:셈씨"),
+            "{normalized}"
+        );
+        let again = parse_and_normalize(&normalized, "test.ddoni", NormalizationLevel::N1).unwrap();
+        assert_eq!(again, normalized);
+    }
+
+    #[test]
+    fn test_range_literal_parses_bounds_and_lowers_to_beomwi_call() {
+        let source = r#"
+테스트:셈씨 = {
+    목록 <- 1..=3.
+    거꾸로 <- 3..1.
+}
+"#;
+        let program = parse(source, "test.ddoni").unwrap();
+        let TopLevelItem::SeedDef(seed) = &program.items[0];
+        let body = seed.body.as_ref().expect("body");
+        let ranges: Vec<(String, String, bool)> = body
+            .stmts
+            .iter()
+            .map(|stmt| {
+                let Stmt::Mutate { value, .. } = stmt else {
+                    panic!("mutate expected: {stmt:?}");
+                };
+                let ExprKind::Range {
+                    start,
+                    end,
+                    inclusive,
+                } = &value.kind
+                else {
+                    panic!("range expected: {value:?}");
+                };
+                let bound = |expr: &Expr| match &expr.kind {
+                    ExprKind::Literal(Literal::Fixed64(v)) => v.to_string(),
+                    ExprKind::Literal(Literal::Int(v)) => v.to_string(),
+                    other => panic!("literal bound expected: {other:?}"),
+                };
+                (bound(start), bound(end), *inclusive)
+            })
+            .collect();
+        assert_eq!(
+            ranges,
+            vec![
+                ("1".to_string(), "3".to_string(), true),
+                ("3".to_string(), "1".to_string(), false),
+            ]
+        );
+
+        let Stmt::Mutate { value, .. } = &body.stmts[0] else {
+            unreachable!();
+        };
+        let mut nid = program.id;
+        let lowered = value
+            .lower_range(&mut || {
+                nid += 1;
+                nid
+            })
+            .expect("lowered");
+        let ExprKind::Call { args, .. } = &lowered.kind else {
+            panic!("call expected: {lowered:?}");
+        };
+        let mut ids: Vec<_> = args.iter().map(|arg| arg.id).collect();
+        ids.push(args[2].expr.id);
+        ids.push(lowered.id);
+        assert!(
+            ids.iter().all(|id| *id > program.id && *id != value.id),
+            "{ids:?}"
+        );
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), 5);
+
+        let normalized = normalize(&program, NormalizationLevel::N1);
+        assert!(normalized.contains("표준.범위"), "{normalized}");
+        let again = parse_and_normalize(&normalized, "test.ddoni", NormalizationLevel::N1).unwrap();
+        assert_eq!(again, normalized);
+        assert_eq!(normalize(&program, NormalizationLevel::N1), normalized);
+    }
+
     #[test]
     fn test_josa_binding_orders_args() {
         let source = r#"
@@ -804,6 +1090,131 @@ mod integration_tests {
         assert!(normalized.contains("10:x 1:y 더하기"));
     }
 
+    #[test]
+    fn test_call_arity_over_argumented_call_errors() {
+        let source = r#"
+(x:수, y:수) 더하:셈씨 = {
+    x + y 돌려줘.
+}
+
+테스트:셈씨 = {
+    (1, 2, 3) 더하기.
+}
+"#;
+        let err = parse_and_normalize(source, "test.ddoni", NormalizationLevel::N1).unwrap_err();
+        assert_eq!(err.code(), "E_CALL_ARITY", "{}", err.message);
+        assert!(err.message.starts_with("E_CALL_ARITY:"), "{}", err.message);
+        assert!(
+            err.message.contains("인자 2개를 받지만 3개가"),
+            "{}",
+            err.message
+        );
+    }
+
+    #[test]
+    fn test_call_arity_accepts_required_and_defaulted_pins() {
+        let source = r#"
+(x:수, y:수 = 1) 더하:셈씨 = {
+    x + y 돌려줘.
+}
+
+테스트:셈씨 = {
+    (10) 더하기.
+    (10, 2) 더하기.
+}
+"#;
+        let mut program = parse(source, "test.ddoni").unwrap();
+        canonicalize(&mut program).unwrap();
+    }
+
+    #[test]
+    fn test_canonicalize_rejects_call_with_missing_args() {
+        let source = r#"
+(x:수, y:수 = 1) 더하:셈씨 = {
+    x + y 돌려줘.
+}
+
+테스트:셈씨 = {
+    (10, 2) 더하기.
+}
+"#;
+        let mut program = parse(source, "test.ddoni").unwrap();
+        for item in &mut program.items {
+            let TopLevelItem::SeedDef(seed) = item;
+            if seed.canonical_name != "테스트" {
+                continue;
+            }
+            let body = seed.body.as_mut().expect("테스트 body");
+            let Stmt::Expr { expr, .. } = &mut body.stmts[0] else {
+                panic!("expr stmt expected");
+            };
+            let ExprKind::Call { args, .. } = &mut expr.kind else {
+                panic!("call expr expected");
+            };
+            args.clear();
+        }
+        let err = canonicalize(&mut program).err().expect("arity error");
+        assert_eq!(err.code(), "E_CALL_ARITY", "{}", err.message);
+        assert!(err.message.starts_with("E_CALL_ARITY:"), "{}", err.message);
+        assert!(
+            err.message.contains("인자 1~2개를 받지만 0개가"),
+            "{}",
+            err.message
+        );
+    }
+
+    #[test]
+    fn test_generic_seed_parses_type_params_and_binds_call() {
+        let source = r#"
+(x:T) 그대로:셈씨<T> = {
+    x 돌려줘.
+}
+
+테스트:셈씨 = {
+    (10) 그대로.
+}
+"#;
+        let mut program = parse(source, "test.ddoni").unwrap();
+        let seed = program
+            .items
+            .iter()
+            .map(|item| match item {
+                TopLevelItem::SeedDef(seed) => seed,
+            })
+            .find(|seed| seed.canonical_name == "그대로")
+            .expect("그대로 seed");
+        assert_eq!(seed.type_params, vec!["T".to_string()]);
+        assert!(matches!(&seed.params[0].type_ref, TypeRef::Named(name) if name == "T"));
+        canonicalize(&mut program).unwrap();
+        let normalized = normalize(&program, NormalizationLevel::N1);
+        assert!(
+            normalized.contains("(x:T) 그대로:셈씨<T> = "),
+            "{normalized}"
+        );
+    }
+
+    #[test]
+    fn test_generic_seed_rejects_disagreeing_type_args() {
+        let source = r#"
+(왼:T, 오른:T) 첫째:셈씨<T> = {
+    왼 돌려줘.
+}
+
+테스트:셈씨 = {
+    (1, "하나") 첫째.
+}
+"#;
+        let mut program = parse(source, "test.ddoni").unwrap();
+        let err = canonicalize(&mut program).err().expect("type param error");
+        assert_eq!(err.code(), "E_CALL_TYPE_PARAM", "{}", err.message);
+        assert!(
+            err.message.starts_with("E_CALL_TYPE_PARAM:"),
+            "{}",
+            err.message
+        );
+        assert!(err.message.contains("수와 글"), "{}", err.message);
+    }
+
     #[test]
     fn test_canonicalize_normalizes_alias_josa_to_primary_form() {
         let source = r#"
@@ -1120,6 +1531,42 @@ mod integration_tests {
         assert!(normalized.contains("보드 <- 보드:대상 2:i 3:값 차림.바꾼값."));
     }
 
+    #[test]
+    fn test_negative_literal_index_rejected_at_canonicalization() {
+        let source = r#"
+테스트:셈씨 = {
+    보드[-1] <- 3.
+}
+"#;
+        let mut program = parse(source, "test.ddoni").unwrap();
+        let err = canonicalize(&mut program).err().expect("negative index");
+        assert_eq!(err.code(), "E_INDEX_NEGATIVE_LITERAL");
+        assert!(
+            err.message.starts_with("E_INDEX_NEGATIVE_LITERAL:"),
+            "{}",
+            err.message
+        );
+
+        let source = r#"
+테스트:셈씨 = {
+    값 <- 보드[-2].
+}
+"#;
+        let mut program = parse(source, "test.ddoni").unwrap();
+        let err = canonicalize(&mut program).err().expect("negative read");
+        assert_eq!(err.code(), "E_INDEX_NEGATIVE_LITERAL");
+
+        let source = r#"
+테스트:셈씨 = {
+    i <- 1.
+    보드[i] <- 3.
+    보드[0] <- 4.
+}
+"#;
+        let mut program = parse(source, "test.ddoni").unwrap();
+        assert!(canonicalize(&mut program).is_ok());
+    }
+
     #[test]
     fn test_right_assign_normalizes_to_left_assign() {
         let source = r#"
@@ -1189,6 +1636,42 @@ mod integration_tests {
         assert!(matches!(arg.binding_reason, BindingReason::Dictionary));
     }
 
+    #[test]
+    fn test_bare_tilde_forces_positional_binding() {
+        let source = r#"
+(거리:수~에서) 이동:셈씨 = {
+    거리 돌려줘.
+}
+
+테스트:셈씨 = {
+    (1~) 이동.
+}
+"#;
+        let args = first_call_args(source);
+        assert_eq!(args.len(), 1);
+        assert_eq!(args[0].josa, None);
+        assert_eq!(args[0].resolved_pin.as_deref(), Some("거리"));
+        assert!(matches!(args[0].binding_reason, BindingReason::Positional));
+
+        let source = r#"
+(출발:수~에서, 거리:수~로) 이동:셈씨 = {
+    거리 돌려줘.
+}
+
+테스트:셈씨 = {
+    (5~, 1~에서) 이동.
+}
+"#;
+        let args = first_call_args(source);
+        assert_eq!(args.len(), 2);
+        assert_eq!(args[0].josa.as_deref(), Some("에서"));
+        assert_eq!(args[0].resolved_pin.as_deref(), Some("출발"));
+        assert!(matches!(args[0].binding_reason, BindingReason::Dictionary));
+        assert_eq!(args[1].josa, None);
+        assert_eq!(args[1].resolved_pin.as_deref(), Some("거리"));
+        assert!(matches!(args[1].binding_reason, BindingReason::Positional));
+    }
+
     #[test]
     fn test_suffix_chain_asset_pin_josa() {
         let source = r#"
@@ -1438,6 +1921,139 @@ mod integration_tests {
         assert!(matches!(body.stmts[1], Stmt::Contract { .. }));
     }
 
+    #[test]
+    fn test_value_choose_without_else_is_non_exhaustive() {
+        let source = r#"
+Test:셈씨 = {
+    고르기:
+      { 1 }인것: { 2. }
+}
+"#;
+        let err = parse(source, "test.ddoni").unwrap_err();
+        assert_eq!(err.code(), "E_CHOOSE_NON_EXHAUSTIVE", "{}", err.message);
+        assert!(
+            err.message.starts_with("E_CHOOSE_NON_EXHAUSTIVE"),
+            "{}",
+            err.message
+        );
+    }
+
+    #[test]
+    fn test_statement_choose_may_omit_else() {
+        let source = r#"
+Test:움직씨 = {
+    채비 { 점수:수 <- 0. }.
+    고르기:
+      { 1 }인것: { 점수 <- 2. }
+}
+"#;
+        let program = parse(source, "test.ddoni").unwrap();
+        let TopLevelItem::SeedDef(seed) = &program.items[0];
+        let body = seed.body.as_ref().unwrap();
+        let Stmt::Choose {
+            branches,
+            else_body,
+            ..
+        } = &body.stmts[1]
+        else {
+            panic!("expected choose");
+        };
+        assert_eq!(branches.len(), 1);
+        assert!(else_body.stmts.is_empty());
+    }
+
+    #[test]
+    fn test_compound_ident_spacing_warns_with_canonical_form() {
+        let source = r#"
+Test:셈씨 = {
+    채비 {
+        나이 : 수 <- 10.
+        키:수 <- 3.
+    }.
+}
+"#;
+        let mut program = parse(source, "test.ddoni").unwrap();
+        let report = canonicalize(&mut program).unwrap();
+        let warnings: Vec<_> = report
+            .warnings
+            .iter()
+            .filter(|w| w.code == "STYLE-LINT-SPACING")
+            .collect();
+        assert_eq!(warnings.len(), 1);
+        let surface = &source[warnings[0].span.start..warnings[0].span.end];
+        assert_eq!(surface, "나이 : 수");
+        assert_eq!(
+            term_map::canonical_compound_spacing(surface).as_deref(),
+            Some("나이:수")
+        );
+    }
+
+    #[test]
+    fn test_empty_contract_else_warns_and_can_be_promoted() {
+        let empty = r#"
+Test:셈씨 = {
+    { 1 }인것 바탕으로(알림) 아니면 { }
+}
+"#;
+        let mut program = parse(empty, "test.ddoni").unwrap();
+        let report = canonicalize(&mut program).unwrap();
+        let warning = report
+            .warnings
+            .iter()
+            .find(|w| w.code == "CONTRACT-LINT-EMPTY-ELSE")
+            .expect("empty else warning");
+        assert_eq!(&empty[warning.span.start..warning.span.end], "{ }");
+        let err = report.promote("CONTRACT-LINT-EMPTY-ELSE").unwrap_err();
+        assert!(err.message.starts_with("CONTRACT-LINT-EMPTY-ELSE"));
+
+        let filled = r#"
+Test:셈씨 = {
+    { 1 }인것 바탕으로(알림) 아니면 { 2. }
+    { 1 }인것 바탕으로(물림) 아니면 { }
+}
+"#;
+        let mut program = parse(filled, "test.ddoni").unwrap();
+        let report = canonicalize(&mut program).unwrap();
+        assert!(!report
+            .warnings
+            .iter()
+            .any(|w| w.code == "CONTRACT-LINT-EMPTY-ELSE"));
+        assert!(report.promote("CONTRACT-LINT-EMPTY-ELSE").is_ok());
+    }
+
+    #[test]
+    fn test_unknown_meta_key_warns_with_closest_suggestion() {
+        let typo = r#"
+Test:셈씨 = {
+    설정 { 화멘: "기본". }.
+    1 돌려줘.
+}
+"#;
+        let mut program = parse(typo, "test.ddoni").unwrap();
+        let report = canonicalize(&mut program).unwrap();
+        let warnings: Vec<_> = report
+            .warnings
+            .iter()
+            .filter(|w| w.code == "META-LINT-UNKNOWN-KEY")
+            .collect();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("`화멘`"));
+        assert!(warnings[0].message.contains("`화면`"));
+
+        let known = r#"
+Test:셈씨 = {
+    설정 { 화면: "기본". 마디수: 3. 제목: "공". Default_Series: "높이". }.
+    1 돌려줘.
+}
+"#;
+        let mut program = parse(known, "test.ddoni").unwrap();
+        let report = canonicalize(&mut program).unwrap();
+        assert!(!report
+            .warnings
+            .iter()
+            .any(|w| w.code == "META-LINT-UNKNOWN-KEY"));
+    }
+
     #[test]
     fn test_contract_alert_mode_normalizes() {
         let source = r#"
@@ -2023,6 +2639,27 @@ Test:셈씨 = {
             .any(|warning| warning.code == "W_CHAEBI_REDUNDANT_TOP_REASSIGN"));
     }
 
+    #[test]
+    fn test_statement_after_return_is_unreachable() {
+        let source = r#"
+테스트:셈씨 = {
+    1 돌려줘.
+    값 <- 2.
+    값 <- 3.
+}
+"#;
+        let mut program = parse(source, "test.ddoni").expect("parse");
+        let report = canonicalize(&mut program).expect("canonicalize");
+        let unreachable = report
+            .warnings
+            .iter()
+            .filter(|warning| warning.code == "FLOW-LINT-UNREACHABLE")
+            .collect::<Vec<_>>();
+        assert_eq!(unreachable.len(), 1);
+        let at = &source[unreachable[0].span.start..unreachable[0].span.end];
+        assert!(at.starts_with("값 <- 2"), "span text: {at}");
+    }
+
     #[test]
     fn test_top_level_chaebi_derived_reassign_does_not_warn() {
         let source = r#"
@@ -8,6 +8,8 @@
 // - N3: 완전 정본 (빌드/증명)
 
 use crate::ast::*;
+use crate::lexer::{escape_string_literal, parse_int_surface};
+use ddonirang_core::Fixed64;
 use std::collections::HashMap;
 
 /// 정규화 레벨
@@ -29,6 +31,12 @@ pub struct Normalizer {
     indent: usize,
     output: String,
     call_signatures: HashMap<String, Vec<ParamPin>>,
+    /// 정수 리터럴의 원래 표기(`0xFF`, `1_000`)를 되살리는 데 쓴다.
+    source: String,
+    /// 있으면 이 폭을 넘는 위치 인자 호출을 여러 줄로 감싼다.
+    max_width: Option<usize>,
+    /// 범위를 내리며 만드는 마디에 줄 id. 파서가 마지막으로 준 프로그램 id 뒤부터 센다.
+    lowered_nid: NodeId,
 }
 
 impl Normalizer {
@@ -38,12 +46,24 @@ impl Normalizer {
             indent: 0,
             output: String::new(),
             call_signatures: HashMap::new(),
+            source: String::new(),
+            max_width: None,
+            lowered_nid: 0,
         }
     }
 
+    /// 위치 인자 호출이 `max_width` 글자를 넘으면 인자를 한 줄에 하나씩, 여는 괄호 뒤에 맞춰 쓴다.
+    /// 줄바꿈은 빈칸과 같게 읽히므로 감싼 결과도 같은 AST로 다시 읽힌다.
+    pub fn with_max_width(mut self, max_width: usize) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
     /// 프로그램 정본화
     pub fn normalize_program(&mut self, program: &CanonProgram) -> String {
         self.call_signatures = collect_call_signatures(program);
+        self.source = program.origin.source.clone();
+        self.lowered_nid = program.id;
         for item in &program.items {
             self.normalize_top_level_item(item);
             self.write("\n\n");
@@ -61,6 +81,19 @@ impl Normalizer {
     /// 씨앗 정의 정본화
     /// 표준 형식: (params) name:kind = { body }
     fn normalize_seed_def(&mut self, seed: &SeedDef) {
+        // 설명글
+        if let Some(doc) = &seed.doc {
+            for line in doc.split('\n') {
+                if line.is_empty() {
+                    self.write("///\n");
+                } else {
+                    self.write("/// ");
+                    self.write(line);
+                    self.write("\n");
+                }
+            }
+        }
+
         // 매개변수
         if !seed.params.is_empty() {
             self.write("(");
@@ -81,6 +114,11 @@ impl Normalizer {
 
         // 씨앗 종류
         self.normalize_seed_kind(&seed.seed_kind);
+        if !seed.type_params.is_empty() {
+            self.write("<");
+            self.write(&seed.type_params.join(", "));
+            self.write(">");
+        }
 
         // 등호 (N1: 앞뒤 공백 있음)
         self.write(" = ");
@@ -484,6 +522,18 @@ impl Normalizer {
 
     fn normalize_expr(&mut self, expr: &Expr) {
         match &expr.kind {
+            ExprKind::Literal(Literal::Int(n)) => {
+                let text = self
+                    .int_literal_surface(expr.span, |value| value == *n)
+                    .unwrap_or_else(|| n.to_string());
+                self.write(&text);
+            }
+            ExprKind::Literal(Literal::Fixed64(f)) => {
+                let text = self
+                    .int_literal_surface(expr.span, |value| Fixed64::from_i64(value) == *f)
+                    .unwrap_or_else(|| f.to_string());
+                self.write(&text);
+            }
             ExprKind::Literal(lit) => self.normalize_literal(lit),
             ExprKind::Var(name) => self.write(name),
             ExprKind::FieldAccess { target, field } => {
@@ -513,6 +563,17 @@ impl Normalizer {
                 }
                 self.normalize_positional_call(args, func);
             }
+            ExprKind::Range { .. } => {
+                let mut nid = self.lowered_nid;
+                let lowered = expr.lower_range(&mut || {
+                    nid += 1;
+                    nid
+                });
+                self.lowered_nid = nid;
+                if let Some(lowered) = lowered {
+                    self.normalize_expr(&lowered);
+                }
+            }
             ExprKind::Infix { left, op, right } => {
                 self.normalize_expr(left);
                 self.write(" ");
@@ -612,6 +673,11 @@ impl Normalizer {
     }
 
     fn normalize_positional_call(&mut self, args: &[ArgBinding], func: &str) {
+        let separator = if self.positional_call_overflows(args, func) {
+            format!(",\n{}", " ".repeat(self.current_column() + 1))
+        } else {
+            ", ".to_string()
+        };
         self.write("(");
         let mut first = true;
         for arg in args.iter() {
@@ -619,7 +685,7 @@ impl Normalizer {
                 continue;
             }
             if !first {
-                self.write(", ");
+                self.write(&separator);
             }
             first = false;
             self.normalize_call_arg_fallback(arg, func);
@@ -628,6 +694,29 @@ impl Normalizer {
         self.write(func);
     }
 
+    fn positional_call_overflows(&self, args: &[ArgBinding], func: &str) -> bool {
+        let Some(max_width) = self.max_width else {
+            return false;
+        };
+        let shown = args
+            .iter()
+            .filter(|arg| !matches!(arg.binding_reason, BindingReason::FlowInjected))
+            .count();
+        if shown < 2 {
+            return false;
+        }
+        let mut flat = Normalizer::new(self._level);
+        flat.call_signatures = self.call_signatures.clone();
+        flat.source = self.source.clone();
+        flat.normalize_positional_call(args, func);
+        self.current_column() + flat.output.chars().count() > max_width
+    }
+
+    fn current_column(&self) -> usize {
+        let line_start = self.output.rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+        self.output[line_start..].chars().count()
+    }
+
     fn normalize_call_arg_fallback(&mut self, arg: &ArgBinding, func: &str) {
         if let Some(text) = self.render_bound_arg_text(arg, func) {
             self.write(&text);
@@ -640,9 +729,22 @@ impl Normalizer {
         }
     }
 
+    /// 핀이 붙은 호출 인자는 씨 서명에 선언된 매개변수 순서대로 쓴다.
+    /// 기본값/없음으로 채워진 인자도 같은 규칙을 따르므로, 서명 중간에 새 선택 핀이
+    /// 끼어들어도 기존 인자끼리의 순서는 바뀌지 않는다(골든 정본 안정성 계약).
+    /// 서명에 없는 핀은 원래 순서를 유지한 채 뒤에 둔다.
     fn render_bound_call_args(&self, args: &[ArgBinding], func: &str) -> Vec<String> {
+        let mut ordered: Vec<&ArgBinding> = args.iter().collect();
+        if let Some(params) = self.call_signatures.get(func) {
+            ordered.sort_by_key(|arg| {
+                arg.resolved_pin
+                    .as_deref()
+                    .and_then(|pin| params.iter().position(|param| param.pin_name == pin))
+                    .unwrap_or(usize::MAX)
+            });
+        }
         let mut rendered = Vec::new();
-        for arg in args {
+        for arg in ordered {
             if matches!(arg.binding_reason, BindingReason::FlowInjected) {
                 continue;
             }
@@ -721,10 +823,19 @@ impl Normalizer {
         }
         let mut normalizer = Normalizer::new(self._level);
         normalizer.call_signatures = self.call_signatures.clone();
+        normalizer.source = self.source.clone();
         normalizer.normalize_expr(expr);
         Some(normalizer.output)
     }
 
+    /// 원문이 16진이나 `_` 묶음 표기였고 값이 같으면 그 표기를 돌려준다.
+    fn int_literal_surface(&self, span: Span, matches: impl Fn(i64) -> bool) -> Option<String> {
+        let surface = self.source.get(span.start..span.end)?.trim();
+        let styled = surface.contains("0x") || surface.contains("0X") || surface.contains('_');
+        let value = parse_int_surface(surface)?;
+        (styled && matches(value)).then(|| surface.to_string())
+    }
+
     fn normalize_literal(&mut self, lit: &Literal) {
         match lit {
             Literal::Int(n) => self.write(&n.to_string()),
@@ -749,7 +860,7 @@ impl Normalizer {
             }
             Literal::String(s) => {
                 self.write("\"");
-                self.write(s);
+                self.write(&escape_string_literal(s));
                 self.write("\"");
             }
             Literal::Resource(path) => {
@@ -939,6 +1050,13 @@ pub fn normalize(program: &CanonProgram, level: NormalizationLevel) -> String {
     normalizer.normalize_program(program)
 }
 
+/// 식 하나를 N1로 쓰되, `max_width`를 넘는 위치 인자 호출은 여러 줄로 감싼다.
+pub fn normalize_expr_pretty(expr: &Expr, max_width: usize) -> String {
+    let mut normalizer = Normalizer::new(NormalizationLevel::N1).with_max_width(max_width);
+    normalizer.normalize_expr(expr);
+    normalizer.output
+}
+
 fn collect_call_signatures(program: &CanonProgram) -> HashMap<String, Vec<ParamPin>> {
     let mut out = HashMap::new();
     for item in &program.items {
@@ -1005,6 +1123,71 @@ mod tests {
         assert_eq!(normalized.trim(), expected);
     }
 
+    #[test]
+    fn test_hex_and_grouped_literals_keep_their_form() {
+        let source = "(x:수)가림:셈씨={(x+0xFF)+1_000돌려줘.}";
+        let normalized = parse_and_normalize(source, NormalizationLevel::N1);
+
+        assert!(normalized.contains("0xFF"), "{normalized}");
+        assert!(normalized.contains("1_000"), "{normalized}");
+    }
+
+    #[test]
+    fn test_semssi_trailing_expr_normalizes_to_explicit_return() {
+        let source = "(x:수, y:수) 더하:셈씨 = { x + y }";
+        let program = crate::parse_with_implicit_return(source, "test.ddoni").unwrap();
+        let implicit = normalize(&program, NormalizationLevel::N1);
+        let explicit = parse_and_normalize(
+            "(x:수, y:수) 더하:셈씨 = { x + y 돌려줘. }",
+            NormalizationLevel::N1,
+        );
+
+        assert_eq!(implicit, explicit);
+    }
+
+    #[test]
+    fn test_umjikssi_body_is_excluded_from_implicit_return() {
+        let unterminated = "(x:수, y:수) 더하:움직씨 = { x + y }";
+        assert!(crate::parse_with_implicit_return(unterminated, "test.ddoni").is_err());
+
+        let source = "(x:수, y:수) 더하:움직씨 = { x + y. }";
+        let program = crate::parse_with_implicit_return(source, "test.ddoni").unwrap();
+        let normalized = normalize(&program, NormalizationLevel::N1);
+        assert!(!normalized.contains("돌려줘"), "{normalized}");
+        assert_eq!(
+            normalized,
+            parse_and_normalize(source, NormalizationLevel::N1)
+        );
+    }
+
+    fn first_mutate_value(source: &str) -> Expr {
+        let tokens = Lexer::new(source).tokenize().unwrap();
+        let program = Parser::new(tokens)
+            .parse_program(source.to_string(), "test.ddoni".to_string())
+            .unwrap();
+        let TopLevelItem::SeedDef(seed) = &program.items[0];
+        match &seed.body.as_ref().unwrap().stmts[0] {
+            Stmt::Mutate { value, .. } => value.clone(),
+            other => panic!("mutate expected, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_pretty_expr_wraps_long_call_args_and_reparses() {
+        let source = "Test:셈씨 = {\n    결과 <- (100, 200, 300, 400, 500, 600) 더해.\n}";
+        let expr = first_mutate_value(source);
+        let flat = normalize_expr_pretty(&expr, 80);
+        assert_eq!(flat, "(100, 200, 300, 400, 500, 600) 더해");
+
+        let wrapped = normalize_expr_pretty(&expr, 16);
+        assert_eq!(wrapped, "(100,\n 200,\n 300,\n 400,\n 500,\n 600) 더해");
+        assert!(wrapped.lines().all(|line| line.chars().count() <= 16));
+
+        let reparsed = first_mutate_value(&format!("Test:셈씨 = {{\n    결과 <- {wrapped}.\n}}"));
+        assert_eq!(normalize_expr_pretty(&reparsed, 80), flat);
+        assert_eq!(normalize_expr_pretty(&reparsed, 16), wrapped);
+    }
+
     #[test]
     fn test_roundtrip() {
         let original = "(x:수) 증가:셈씨 = { x + 1 돌려줘. }";
@@ -1,6 +1,7 @@
 // lang/src/parser.rs
 use crate::ast::*;
-use crate::lexer::{Lexer, Token, TokenKind};
+use crate::canonicalizer::call_arity_error;
+use crate::lexer::{leading_doc_comment, Lexer, Token, TokenKind};
 use crate::normalizer::{NormalizationLevel, Normalizer};
 use crate::stdlib::minimal_stdlib_sigs;
 use crate::term_map;
@@ -17,6 +18,8 @@ pub struct Parser {
     chaebi_forbidden_depth: usize,
     declared_scopes: Vec<HashSet<String>>,
     seed_kind_stack: Vec<SeedKind>,
+    /// 셈씨 본문 끝의 마침표 없는 식을 `돌려줘`로 읽을지(opt-in).
+    implicit_return: bool,
 }
 struct ArgSuffix {
     josa: Option<String>,
@@ -46,8 +49,14 @@ impl Parser {
             chaebi_forbidden_depth: 0,
             declared_scopes: vec![HashSet::new()],
             seed_kind_stack: Vec::new(),
+            implicit_return: false,
         }
     }
+    /// 셈씨 본문이 `{ x + y }`처럼 마침표 없는 식으로 끝나면 `돌려줘`로 내려 읽는다.
+    pub fn with_implicit_return(mut self, enabled: bool) -> Self {
+        self.implicit_return = enabled;
+        self
+    }
     fn next_id(&mut self) -> NodeId {
         let id = self.nid;
         self.nid += 1;
@@ -105,7 +114,10 @@ impl Parser {
                 top_level_decl.push(stmt);
                 continue;
             }
-            items.push(self.parse_top_level_item()?);
+            let mut item = self.parse_top_level_item()?;
+            let TopLevelItem::SeedDef(seed) = &mut item;
+            seed.doc = leading_doc_comment(&source, seed.span.start);
+            items.push(item);
         }
         if !top_level_decl.is_empty() {
             self.inject_top_level_decl_blocks(&mut items, top_level_decl)?;
@@ -175,6 +187,7 @@ impl Parser {
         self.validate_seed_name_tail(&name, self.previous_span())?;
         self.expect(&TokenKind::Colon, ":")?;
         let kind = self.parse_seed_kind()?;
+        let type_params = self.parse_seed_type_params()?;
         self.expect(&TokenKind::Equals, "=")?;
         self.enter_scope();
         for param in &params {
@@ -182,8 +195,8 @@ impl Parser {
         }
         self.seed_kind_stack.push(kind.clone());
         let body_result = if self.check(&TokenKind::LBrace) {
-            let body = self.parse_body();
-            match body {
+            let implicit_return = self.implicit_return && matches!(kind, SeedKind::Semssi);
+            match self.parse_body(implicit_return) {
                 Ok(body) => Ok(body),
                 Err(err) => {
                     self.seed_kind_stack.pop();
@@ -214,10 +227,40 @@ impl Parser {
             canonical_name: name,
             seed_kind: kind,
             params,
+            type_params,
             body: Some(body),
             modifiers: Vec::new(),
+            doc: None,
         })
     }
+    /// 씨앗 종류 뒤의 `<T, U>` 두루 타입 목록. 없으면 빈 목록이다.
+    fn parse_seed_type_params(&mut self) -> Result<Vec<String>, ParseError> {
+        if !self.check(&TokenKind::Lt) {
+            return Ok(Vec::new());
+        }
+        self.advance();
+        let mut names: Vec<String> = Vec::new();
+        while !self.check(&TokenKind::Gt) {
+            let token = self.expect_ident("두루 타입 이름")?;
+            if token.raw == "_" || names.contains(&token.raw) {
+                return Err(ParseError {
+                    span: self.to_ast_span(token.span),
+                    message: format!("두루 타입 이름 '{}'를 쓸 수 없습니다", token.raw),
+                });
+            }
+            names.push(token.raw);
+            if self.check(&TokenKind::Comma) {
+                self.advance();
+            } else {
+                break;
+            }
+        }
+        self.expect(&TokenKind::Gt, ">")?;
+        if names.is_empty() {
+            return Err(self.error("두루 타입 이름"));
+        }
+        Ok(names)
+    }
     fn parse_params(&mut self) -> Result<Vec<ParamPin>, ParseError> {
         self.expect(&TokenKind::LParen, "(")?;
         let mut ps = Vec::new();
@@ -331,13 +374,35 @@ impl Parser {
             _ => Err(self.error("씨앗 종류")),
         }
     }
-    fn parse_body(&mut self) -> Result<Body, ParseError> {
+    /// `implicit_return`이면(셈씨 본문) 마지막 문장이 마침표 없는 식일 때 그 식을 `돌려줘`로 내린다.
+    /// `x + y.`처럼 마침표로 끝나면 그대로 식 문장이다.
+    fn parse_body(&mut self, implicit_return: bool) -> Result<Body, ParseError> {
         let s = self.current_span();
         self.expect(&TokenKind::LBrace, "{")?;
         self.enter_scope();
         let mut stmts = Vec::new();
         while !self.check(&TokenKind::RBrace) {
-            stmts.push(self.parse_stmt(false)?);
+            let stmt = self.parse_stmt(implicit_return)?;
+            let unterminated = implicit_return
+                && self.check(&TokenKind::RBrace)
+                && !matches!(
+                    self.tokens[self.pos - 1].kind,
+                    TokenKind::Dot | TokenKind::Question | TokenKind::Bang
+                );
+            stmts.push(match stmt {
+                Stmt::Expr {
+                    id,
+                    span,
+                    mood,
+                    expr,
+                } if unterminated => Stmt::Return {
+                    id,
+                    span,
+                    mood,
+                    value: expr,
+                },
+                other => other,
+            });
         }
         self.expect(&TokenKind::RBrace, "}")?;
         self.exit_scope();
@@ -375,7 +440,7 @@ impl Parser {
 
     fn parse_body_with_chaebi_restriction(&mut self) -> Result<Body, ParseError> {
         self.chaebi_forbidden_depth += 1;
-        let body = self.parse_body();
+        let body = self.parse_body(false);
         self.chaebi_forbidden_depth = self.chaebi_forbidden_depth.saturating_sub(1);
         body
     }
@@ -474,7 +539,7 @@ impl Parser {
 
         if self.check(&TokenKind::KwNeuljikeobogo) {
             self.advance();
-            let body = self.parse_body()?;
+            let body = self.parse_body(false)?;
             self.validate_guard_body(&body, s.merge(&self.previous_span()))?;
             let mood = self.consume_optional_terminator()?;
             return Ok(Stmt::Guard {
@@ -489,7 +554,7 @@ impl Parser {
         if self.check(&TokenKind::KwHaebogo) {
             self.advance();
             self.expect(&TokenKind::Colon, ":")?;
-            let body = self.parse_body()?;
+            let body = self.parse_body(false)?;
             let mood = self.consume_optional_terminator()?;
             return Ok(Stmt::Try {
                 id: self.next_id(),
@@ -512,13 +577,13 @@ impl Parser {
             self.ensure_eval_condition(&e, "계약 조건")?;
             let else_body = if self.check(&TokenKind::KwAniramyeon) {
                 self.advance();
-                self.parse_body()?
+                self.parse_body(false)?
             } else {
                 return Err(self.error("아니면"));
             };
             let then_body = if self.check(&TokenKind::KwMajeumyeon) {
                 self.advance();
-                Some(self.parse_body()?)
+                Some(self.parse_body(false)?)
             } else {
                 None
             };
@@ -716,7 +781,7 @@ impl Parser {
             });
         }
         self.advance();
-        let body = self.parse_body()?;
+        let body = self.parse_body(false)?;
         let mood = self.consume_optional_terminator()?;
         Ok(Some(Stmt::Receive {
             id: self.next_id(),
@@ -897,11 +962,11 @@ impl Parser {
         start: Span,
         condition: Expr,
     ) -> Result<Stmt, ParseError> {
-        let then_body = self.parse_body()?;
+        let then_body = self.parse_body(false)?;
         let else_body = if let Some(pos) = self.find_if_else_lead_pos() {
             self.pos = pos;
             self.advance();
-            Some(self.parse_body()?)
+            Some(self.parse_body(false)?)
         } else {
             None
         };
@@ -1406,6 +1471,15 @@ impl Parser {
         )
     }
 
+    /// 갈래 본문이 호출이 아닌 맨 식으로 끝나면 값을 내는 갈래로 본다.
+    /// 호출로 끝나는 갈래는 일을 시키는 문장으로 취급한다.
+    fn is_value_arm(body: &Body) -> bool {
+        matches!(
+            body.stmts.last(),
+            Some(Stmt::Expr { expr, .. }) if !matches!(expr.kind, ExprKind::Call { .. })
+        )
+    }
+
     fn parse_choose_stmt(&mut self) -> Result<Stmt, ParseError> {
         let s = self.current_span();
         self.expect(&TokenKind::KwGoreugi, "고르기")?;
@@ -1416,7 +1490,7 @@ impl Parser {
             if self.check(&TokenKind::KwAniramyeon) {
                 self.advance();
                 self.expect(&TokenKind::Colon, ":")?;
-                else_body = Some(self.parse_body()?);
+                else_body = Some(self.parse_body(false)?);
                 break;
             }
             if self.check(&TokenKind::RBrace) || self.check(&TokenKind::Eof) {
@@ -1425,14 +1499,31 @@ impl Parser {
             let cond = self.parse_expr()?;
             self.ensure_eval_condition(&cond, "고르기 조건")?;
             self.expect(&TokenKind::Colon, ":")?;
-            let body = self.parse_body()?;
+            let body = self.parse_body(false)?;
             branches.push(ChooseBranch {
                 condition: cond,
                 body,
             });
         }
-        let Some(else_body) = else_body else {
-            return Err(self.error("고르기에는 아니면 절이 필요합니다"));
+        let yields_value = branches
+            .iter()
+            .any(|branch| Self::is_value_arm(&branch.body));
+        let else_body = match else_body {
+            Some(body) => body,
+            None if branches.is_empty() => {
+                return Err(self.error("고르기에는 아니면 절이 필요합니다"));
+            }
+            None if yields_value => {
+                return Err(self.error(
+                    "E_CHOOSE_NON_EXHAUSTIVE: 값을 내는 고르기에는 아니면 절이 필요합니다",
+                ));
+            }
+            // 문장 고르기는 아니면을 생략할 수 있다. 빈 아니면으로 채워 둔다.
+            None => Body {
+                id: self.next_id(),
+                span: Span::new(self.previous_span().end, self.previous_span().end),
+                stmts: Vec::new(),
+            },
         };
         let mood = self.consume_optional_terminator()?;
         Ok(Stmt::Choose {
@@ -1886,31 +1977,15 @@ impl Parser {
         if !matches!(self.current().kind, TokenKind::DotDot | TokenKind::DotDotEq) {
             return Ok(left);
         }
-        let op = self.advance();
-        let inclusive = matches!(op.kind, TokenKind::DotDotEq);
+        let inclusive = matches!(self.advance().kind, TokenKind::DotDotEq);
         let right = self.parse_addition()?;
-        let span = left.span.merge(&right.span);
-        let mut arg_start = self.new_arg_binding(left);
-        arg_start.resolved_pin = Some("시작".to_string());
-        arg_start.binding_reason = BindingReason::UserFixed;
-        let mut arg_end = self.new_arg_binding(right);
-        arg_end.resolved_pin = Some("끝".to_string());
-        arg_end.binding_reason = BindingReason::UserFixed;
-        let flag_value = if inclusive { 1 } else { 0 };
-        let flag_expr = Expr::new(
-            self.next_id(),
-            self.to_ast_span(op.span),
-            ExprKind::Literal(Literal::Fixed64(Fixed64::from_i64(flag_value))),
-        );
-        let mut arg_flag = self.new_arg_binding(flag_expr);
-        arg_flag.resolved_pin = Some("끝포함".to_string());
-        arg_flag.binding_reason = BindingReason::UserFixed;
         Ok(Expr::new(
             self.next_id(),
-            span,
-            ExprKind::Call {
-                args: vec![arg_start, arg_end, arg_flag],
-                func: "표준.범위".to_string(),
+            left.span.merge(&right.span),
+            ExprKind::Range {
+                start: Box::new(left),
+                end: Box::new(right),
+                inclusive,
             },
         ))
     }
@@ -2642,7 +2717,7 @@ impl Parser {
             let TopLevelItem::SeedDef(seed) = &mut items[idx];
             if let Some(body) = seed.body.as_mut() {
                 if let Some(first) = decls.first() {
-                    let first_span = Self::stmt_span(first);
+                    let first_span = first.span();
                     body.span = body.span.merge(&first_span);
                 }
                 let mut new_stmts = Vec::with_capacity(decls.len() + body.stmts.len());
@@ -2652,7 +2727,7 @@ impl Parser {
             } else {
                 let span = decls
                     .first()
-                    .map(|stmt| Self::stmt_span(stmt))
+                    .map(Stmt::span)
                     .unwrap_or_else(|| Span::new(0, 0));
                 seed.body = Some(Body {
                     id: self.next_id(),
@@ -2665,7 +2740,7 @@ impl Parser {
 
         let span = decls
             .first()
-            .map(|stmt| Self::stmt_span(stmt))
+            .map(Stmt::span)
             .unwrap_or_else(|| Span::new(0, 0));
         let body = Body {
             id: self.next_id(),
@@ -2678,43 +2753,15 @@ impl Parser {
             canonical_name: "매마디".to_string(),
             seed_kind: SeedKind::Umjikssi,
             params: Vec::new(),
+            type_params: Vec::new(),
             body: Some(body),
             modifiers: Vec::new(),
+            doc: None,
         };
         items.push(TopLevelItem::SeedDef(seed));
         Ok(())
     }
 
-    fn stmt_span(stmt: &Stmt) -> Span {
-        match stmt {
-            Stmt::DeclBlock { span, .. }
-            | Stmt::Mutate { span, .. }
-            | Stmt::Expr { span, .. }
-            | Stmt::Receive { span, .. }
-            | Stmt::Send { span, .. }
-            | Stmt::Show { span, .. }
-            | Stmt::Inspect { span, .. }
-            | Stmt::Return { span, .. }
-            | Stmt::If { span, .. }
-            | Stmt::Try { span, .. }
-            | Stmt::Choose { span, .. }
-            | Stmt::Repeat { span, .. }
-            | Stmt::While { span, .. }
-            | Stmt::ForEach { span, .. }
-            | Stmt::Quantifier { span, .. }
-            | Stmt::Break { span, .. }
-            | Stmt::ContinueLoop { span, .. }
-            | Stmt::Contract { span, .. }
-            | Stmt::Guard { span, .. }
-            | Stmt::BeatBlock { span, .. }
-            | Stmt::Hook { span, .. }
-            | Stmt::HookWhenBecomes { span, .. }
-            | Stmt::HookWhile { span, .. }
-            | Stmt::MetaBlock { span, .. }
-            | Stmt::Pragma { span, .. } => *span,
-        }
-    }
-
     fn consume_compound_update(&mut self) -> Option<&'static str> {
         match self.current().kind {
             TokenKind::PlusArrow => {
@@ -3628,9 +3675,12 @@ impl Parser {
             ExprKind::FieldAccess { target, .. } => self.expr_has_mutation(target),
             ExprKind::SeedLiteral { body, .. } => self.expr_has_mutation(body),
             ExprKind::Call { args, .. } => args.iter().any(|arg| self.expr_has_mutation(&arg.expr)),
-            ExprKind::Infix { left, right, .. } => {
-                self.expr_has_mutation(left) || self.expr_has_mutation(right)
-            }
+            ExprKind::Infix { left, right, .. }
+            | ExprKind::Range {
+                start: left,
+                end: right,
+                ..
+            } => self.expr_has_mutation(left) || self.expr_has_mutation(right),
             ExprKind::Suffix { value, .. } => self.expr_has_mutation(value),
             ExprKind::Pipe { stages } => stages.iter().any(|stage| self.expr_has_mutation(stage)),
             ExprKind::Pack { fields } => {
@@ -3670,9 +3720,12 @@ impl Parser {
             ExprKind::FieldAccess { target, .. } => self.expr_has_eval_do(target),
             ExprKind::SeedLiteral { body, .. } => self.expr_has_eval_do(body),
             ExprKind::Call { args, .. } => args.iter().any(|arg| self.expr_has_eval_do(&arg.expr)),
-            ExprKind::Infix { left, right, .. } => {
-                self.expr_has_eval_do(left) || self.expr_has_eval_do(right)
-            }
+            ExprKind::Infix { left, right, .. }
+            | ExprKind::Range {
+                start: left,
+                end: right,
+                ..
+            } => self.expr_has_eval_do(left) || self.expr_has_eval_do(right),
             ExprKind::Suffix { value, .. } => self.expr_has_eval_do(value),
             ExprKind::Pipe { stages } => stages.iter().any(|stage| self.expr_has_eval_do(stage)),
             ExprKind::Pack { fields } => fields.iter().any(|(_, expr)| self.expr_has_eval_do(expr)),
@@ -3702,9 +3755,12 @@ impl Parser {
                 }
                 args.iter().any(|arg| self.expr_has_random(&arg.expr))
             }
-            ExprKind::Infix { left, right, .. } => {
-                self.expr_has_random(left) || self.expr_has_random(right)
-            }
+            ExprKind::Infix { left, right, .. }
+            | ExprKind::Range {
+                start: left,
+                end: right,
+                ..
+            } => self.expr_has_random(left) || self.expr_has_random(right),
             ExprKind::Suffix { value, .. } => self.expr_has_random(value),
             ExprKind::Pipe { stages } => stages.iter().any(|stage| self.expr_has_random(stage)),
             ExprKind::Pack { fields } => fields.iter().any(|(_, expr)| self.expr_has_random(expr)),
@@ -3729,9 +3785,12 @@ impl Parser {
             ExprKind::FieldAccess { target, .. } => self.expr_has_show(target),
             ExprKind::SeedLiteral { body, .. } => self.expr_has_show(body),
             ExprKind::Call { args, .. } => args.iter().any(|arg| self.expr_has_show(&arg.expr)),
-            ExprKind::Infix { left, right, .. } => {
-                self.expr_has_show(left) || self.expr_has_show(right)
-            }
+            ExprKind::Infix { left, right, .. }
+            | ExprKind::Range {
+                start: left,
+                end: right,
+                ..
+            } => self.expr_has_show(left) || self.expr_has_show(right),
             ExprKind::Suffix { value, .. } => self.expr_has_show(value),
             ExprKind::Pipe { stages } => stages.iter().any(|stage| self.expr_has_show(stage)),
             ExprKind::Pack { fields } => fields.iter().any(|(_, expr)| self.expr_has_show(expr)),
@@ -3860,11 +3919,10 @@ impl Parser {
         }
         if self.check(&TokenKind::Tilde) {
             self.advance();
+            // 조사 없는 맨 `~`는 "조사 없음, 자리로만 묶기"를 뜻한다.
             if let TokenKind::Josa(value) = &self.current().kind {
                 josa = Some(value.clone());
                 self.advance();
-            } else {
-                return Err(self.error("~ 뒤 조사"));
             }
         } else if let TokenKind::Josa(value) = &self.current().kind {
             josa = Some(value.clone());
@@ -5063,6 +5121,11 @@ impl Parser {
                 self.infer_expr_dim(body)?;
                 Ok(DimState::Unknown)
             }
+            ExprKind::Range { start, end, .. } => {
+                self.infer_expr_dim(start)?;
+                self.infer_expr_dim(end)?;
+                Ok(DimState::Unknown)
+            }
             ExprKind::Nuance { expr, .. } => self.infer_expr_dim(expr),
             ExprKind::Suffix { value, at } => match at {
                 AtSuffix::Unit(unit) => {
@@ -5314,7 +5377,12 @@ impl Parser {
             ExprKind::FieldAccess { target, .. } => {
                 self.apply_defaults_in_expr(target, signatures, known_seeds)?;
             }
-            ExprKind::Infix { left, right, .. } => {
+            ExprKind::Infix { left, right, .. }
+            | ExprKind::Range {
+                start: left,
+                end: right,
+                ..
+            } => {
                 self.apply_defaults_in_expr(left, signatures, known_seeds)?;
                 self.apply_defaults_in_expr(right, signatures, known_seeds)?;
             }
@@ -5906,11 +5974,19 @@ impl Parser {
             });
         }
 
-        if pos_iter.next().is_some() {
-            return Err(ParseError {
+        let extra = pos_iter.count();
+        if extra > 0 {
+            let required = params
+                .iter()
+                .filter(|param| param.default_value.is_none() && !param.optional)
+                .count();
+            return Err(call_arity_error(
+                func,
+                required,
+                params.len(),
+                params.len() + extra,
                 span,
-                message: "인자 수가 너무 많습니다".to_string(),
-            });
+            ));
         }
 
         *args = bound.into_iter().flatten().collect();
@@ -6188,6 +6264,18 @@ impl ParseError {
         if self.message.starts_with("E_CALL_TAIL_AMBIGUOUS:") {
             return "E_CALL_TAIL_AMBIGUOUS";
         }
+        if self.message.starts_with("E_CHOOSE_NON_EXHAUSTIVE:") {
+            return "E_CHOOSE_NON_EXHAUSTIVE";
+        }
+        if self.message.starts_with("E_CALL_ARITY:") {
+            return "E_CALL_ARITY";
+        }
+        if self.message.starts_with("E_CALL_TYPE_PARAM:") {
+            return "E_CALL_TYPE_PARAM";
+        }
+        if self.message.starts_with("E_INDEX_NEGATIVE_LITERAL:") {
+            return "E_INDEX_NEGATIVE_LITERAL";
+        }
         if self.message.contains("조사 '")
             && self.message.contains("모호합니다")
             && self.message.contains("값:핀")
@@ -342,6 +342,11 @@ pub fn list_function_sigs() -> Vec<FunctionSig> {
             params: &["차림", "기준"],
             ret: "차림<T>",
         },
+        FunctionSig {
+            name: "목록.정렬",
+            params: &["차림", "비교?"],
+            ret: "차림<T>",
+        },
         FunctionSig {
             name: "거르기",
             params: &["차림", "조건"],
@@ -402,6 +407,26 @@ pub fn container_function_sigs() -> Vec<FunctionSig> {
             params: &["짝맞춤", "열쇠", "값"],
             ret: "짝맞춤<K,V>",
         },
+        FunctionSig {
+            name: "짝.넣어",
+            params: &["짝맞춤", "열쇠", "값"],
+            ret: "짝맞춤<K,V>",
+        },
+        FunctionSig {
+            name: "짝.값",
+            params: &["짝맞춤", "열쇠"],
+            ret: "값?",
+        },
+        FunctionSig {
+            name: "짝.있나",
+            params: &["짝맞춤", "열쇠"],
+            ret: "참거짓",
+        },
+        FunctionSig {
+            name: "짝.빼",
+            params: &["짝맞춤", "열쇠"],
+            ret: "짝맞춤<K,V>",
+        },
     ]
 }
 
@@ -567,6 +592,16 @@ pub fn input_function_sigs() -> Vec<FunctionSig> {
             params: &["키"],
             ret: "참거짓",
         },
+        FunctionSig {
+            name: "환경",
+            params: &["키"],
+            ret: "글?",
+        },
+        FunctionSig {
+            name: "나중에",
+            params: &["마디수", "할일"],
+            ret: "없음",
+        },
         FunctionSig {
             name: "입력사상.만들기",
             params: &["묶음?"],
@@ -1542,6 +1577,7 @@ mod tests {
         assert!(sigs.iter().any(|s| s.name == "이력.잘라보기"));
         assert!(sigs.iter().any(|s| s.name == "첫번째"));
         assert!(sigs.iter().any(|s| s.name == "정렬"));
+        assert!(sigs.iter().any(|s| s.name == "목록.정렬"));
         assert!(sigs.iter().any(|s| s.name == "포함하나"));
         assert!(sigs.iter().any(|s| s.name == "숫자로"));
         assert!(sigs.iter().any(|s| s.name == "지니"));
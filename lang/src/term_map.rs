@@ -1,3 +1,6 @@
+use crate::ast::MetaBlockKind;
+use crate::lexer::{Lexer, TokenKind};
+
 #[derive(Clone, Copy)]
 pub struct TermEntry {
     pub code: &'static str,
@@ -132,3 +135,296 @@ pub fn is_josa_only(term: &str) -> bool {
 pub fn is_reserved_word(term: &str) -> bool {
     RESERVED_WORDS.iter().any(|entry| *entry == term)
 }
+
+/// 복합 식별자(`이름:갈래`)에서 두 조각을 잇는 구분자. 정본은 구분자 양옆을 붙여 쓴다.
+pub const COMPOUND_SEPARATOR: char = ':';
+
+pub const SPACING_LINT_CODE: &str = "STYLE-LINT-SPACING";
+
+/// `surface`가 띄어쓰기만 어긋난 복합 식별자(`나이 : 수`, `나이: 수`)면 정본 꼴(`나이:수`)을 돌려준다.
+/// 이미 정본이거나 복합 식별자가 아니면 `None`이다.
+pub fn canonical_compound_spacing(surface: &str) -> Option<String> {
+    let (head, tail) = surface.split_once(COMPOUND_SEPARATOR)?;
+    let (head, tail) = (head.trim(), tail.trim());
+    if !is_compound_part(head) || !is_compound_part(tail) {
+        return None;
+    }
+    let canonical = format!("{head}{COMPOUND_SEPARATOR}{tail}");
+    if canonical == surface {
+        None
+    } else {
+        Some(canonical)
+    }
+}
+
+/// 띄어쓰기가 어긋난 복합 식별자 한 곳. `start..end`는 소스 안의 바이트 범위다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompoundSpacing {
+    pub start: usize,
+    pub end: usize,
+    pub surface: String,
+    pub canonical: String,
+}
+
+/// 소스에서 띄어쓰기가 어긋난 복합 식별자를 모두 찾는다. 린트와 fix-it이 같은 규칙을 쓴다.
+/// 뒤에 `<-`, `=`, `,`, `)`가 오는 선언/핀만 보고, `슬기훅정책: 실행.` 같은 설정 항목은 건드리지 않는다.
+pub fn find_compound_spacing(source: &str) -> Vec<CompoundSpacing> {
+    let Ok(tokens) = Lexer::new(source).tokenize() else {
+        return Vec::new();
+    };
+    let mut found = Vec::new();
+    for window in tokens.windows(4) {
+        let [head, sep, tail, next] = window else {
+            continue;
+        };
+        if !matches!(sep.kind, TokenKind::Colon)
+            || !matches!(head.kind, TokenKind::Ident(_) | TokenKind::Josa(_))
+            || !matches!(tail.kind, TokenKind::Ident(_) | TokenKind::Josa(_))
+            || !matches!(
+                next.kind,
+                TokenKind::Arrow | TokenKind::Equals | TokenKind::Comma | TokenKind::RParen
+            )
+        {
+            continue;
+        }
+        let Some(surface) = source.get(head.span.start..tail.span.end) else {
+            continue;
+        };
+        if surface.contains('\n') {
+            continue;
+        }
+        let Some(canonical) = canonical_compound_spacing(surface) else {
+            continue;
+        };
+        found.push(CompoundSpacing {
+            start: head.span.start,
+            end: tail.span.end,
+            surface: surface.to_string(),
+            canonical,
+        });
+    }
+    found
+}
+
+fn is_compound_part(part: &str) -> bool {
+    !part.is_empty()
+        && part
+            .chars()
+            .all(|ch| ch == '_' || ch == '\'' || ch.is_alphanumeric())
+}
+
+pub const META_UNKNOWN_KEY_LINT_CODE: &str = "META-LINT-UNKNOWN-KEY";
+
+/// 셈그림 `guide_meta`의 정본 키와, `seamgrim_ui_mvp/tools/README.md`에 적힌 별칭만 받는다.
+/// 화면용 별칭을 모두 받으면 오타도 별칭으로 통과하므로 문서에 없는 별칭은 넣지 않는다.
+const GUIDE_NAME_KEYS: (&str, &[&str]) =
+    ("name", &["이름", "title", "제목", "가이드이름", "ガイド名"]);
+const GUIDE_DESC_KEYS: (&str, &[&str]) = (
+    "desc",
+    &["설명", "풀이", "해설", "description", "summary", "解説"],
+);
+const GUIDE_OBSERVATION_KEYS: (&str, &[&str]) = (
+    "default_observation",
+    &["기본관찰", "기본관측", "default-series"],
+);
+const GUIDE_OBSERVATION_X_KEYS: (&str, &[&str]) = (
+    "default_observation_x",
+    &["기본관찰x", "기본관측x", "x-axis"],
+);
+
+/// `설정`에만 있는 화면 설정 키.
+const SETTING_META_KEYS: [&str; 3] = ["화면", "마디수", "문서"];
+
+fn guide_keys(groups: &[(&'static str, &'static [&'static str])]) -> Vec<&'static str> {
+    groups
+        .iter()
+        .flat_map(|(canon, aliases)| std::iter::once(*canon).chain(aliases.iter().copied()))
+        .collect()
+}
+
+/// 메타 블록 종류마다 알려진 `키: 값` 키 목록. 그리기 문장만 담는 블록(보임/보개마당/짜임)은 `None`이다.
+pub fn known_meta_keys(kind: &MetaBlockKind) -> Option<Vec<&'static str>> {
+    match kind {
+        MetaBlockKind::Setting => {
+            let mut keys = SETTING_META_KEYS.to_vec();
+            keys.extend(guide_keys(&[
+                GUIDE_NAME_KEYS,
+                GUIDE_DESC_KEYS,
+                GUIDE_OBSERVATION_KEYS,
+                GUIDE_OBSERVATION_X_KEYS,
+            ]));
+            Some(keys)
+        }
+        MetaBlockKind::Bogae | MetaBlockKind::Seulgi => Some(guide_keys(&[
+            GUIDE_OBSERVATION_KEYS,
+            GUIDE_OBSERVATION_X_KEYS,
+        ])),
+        MetaBlockKind::Boim | MetaBlockKind::BogeaMadang | MetaBlockKind::Jjaim => None,
+    }
+}
+
+/// 셈그림 `normalizeGuideMetaKey`와 같이 앞뒤 공백을 떼고 소문자로 바꾼 뒤 공백/`_`/`-`를 지운다.
+pub fn normalize_meta_key(key: &str) -> String {
+    key.trim()
+        .to_lowercase()
+        .chars()
+        .filter(|ch| !ch.is_whitespace() && *ch != '_' && *ch != '-')
+        .collect()
+}
+
+/// `key`가 알려진 키 가운데 하나와 정규화 후 같은지. `Default-X`, `y_axis`, `YAxis`가 모두 맞는다.
+pub fn is_known_meta_key(key: &str, known: &[&'static str]) -> bool {
+    let key = normalize_meta_key(key);
+    known
+        .iter()
+        .any(|candidate| normalize_meta_key(candidate) == key)
+}
+
+/// 알려진 키 가운데 `key`와 편집 거리가 가장 가까운 것. 거리는 정규화한 키끼리 재고,
+/// 정규화한 글자 수의 절반보다 멀면 제안하지 않는다.
+pub fn closest_meta_key(key: &str, known: &[&'static str]) -> Option<&'static str> {
+    let key = normalize_meta_key(key);
+    let limit = (key.chars().count() / 2).max(1);
+    known
+        .iter()
+        .map(|candidate| {
+            let distance = edit_distance(&key, &normalize_meta_key(candidate));
+            (distance, *candidate)
+        })
+        .filter(|(distance, _)| *distance <= limit)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut row = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitute = prev[j] + usize::from(ca != *cb);
+            row[j + 1] = substitute.min(prev[j + 1] + 1).min(row[j] + 1);
+        }
+        prev = row;
+    }
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    use super::{
+        canonical_compound_spacing, closest_meta_key, is_known_meta_key, known_meta_keys,
+        normalize_meta_key, GUIDE_DESC_KEYS, GUIDE_NAME_KEYS, GUIDE_OBSERVATION_KEYS,
+        GUIDE_OBSERVATION_X_KEYS,
+    };
+    use crate::ast::MetaBlockKind;
+
+    #[test]
+    fn compound_spacing_joins_separator() {
+        for surface in ["나이 : 수", "나이: 수", "나이 :수", "나이\t:  수"] {
+            assert_eq!(
+                canonical_compound_spacing(surface).as_deref(),
+                Some("나이:수"),
+                "{surface}"
+            );
+        }
+        assert_eq!(canonical_compound_spacing("나이:수"), None);
+        assert_eq!(canonical_compound_spacing("나이 수"), None);
+        assert_eq!(canonical_compound_spacing("채비 : {"), None);
+        assert_eq!(canonical_compound_spacing("a : b : c"), None);
+    }
+
+    #[test]
+    fn documented_meta_aliases_are_known_after_normalization() {
+        let setting = known_meta_keys(&MetaBlockKind::Setting).unwrap();
+        for key in [
+            "제목",
+            "Title",
+            "가이드이름",
+            "summary",
+            "해설",
+            "Default_Series",
+            "default-observation",
+            "기본관측",
+            "X_Axis",
+            "기본관찰x",
+            "화면",
+        ] {
+            assert!(is_known_meta_key(key, &setting), "{key}");
+        }
+        // 문서에 없는 화면용 별칭은 받지 않는다.
+        for key in ["표제", "series", "obs", "y_axis", "default_x", "화멘"] {
+            assert!(!is_known_meta_key(key, &setting), "{key}");
+        }
+        let bogae = known_meta_keys(&MetaBlockKind::Bogae).unwrap();
+        assert!(is_known_meta_key("default-observation-x", &bogae));
+        assert!(!is_known_meta_key("제목", &bogae));
+    }
+
+    #[test]
+    fn closest_meta_key_measures_distance_after_normalization() {
+        let setting = known_meta_keys(&MetaBlockKind::Setting).unwrap();
+        assert_eq!(
+            closest_meta_key("DEFAULT-SERIS", &setting),
+            Some("default-series")
+        );
+        assert_eq!(closest_meta_key("Titel", &setting), Some("title"));
+        assert_eq!(closest_meta_key("화멘", &setting), Some("화면"));
+    }
+
+    /// 셈그림 `guide_meta.js`의 별칭표를 읽어 정본 키마다 별칭 묶음을 돌려준다.
+    fn guide_meta_js_groups() -> BTreeMap<String, BTreeSet<String>> {
+        let source = include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../solutions/seamgrim_ui_mvp/ui/components/guide_meta.js"
+        ));
+        let start = source
+            .find("export const GUIDE_META_ALIASES")
+            .expect("GUIDE_META_ALIASES");
+        let mut groups = BTreeMap::new();
+        let mut current: Option<String> = None;
+        for line in source[start..].lines().skip(1) {
+            let line = line.trim();
+            if line == "});" {
+                break;
+            }
+            if let Some(canon) = line.strip_suffix(": Object.freeze([") {
+                current = Some(canon.to_string());
+                groups.insert(canon.to_string(), BTreeSet::new());
+            } else if let Some(alias) = line.strip_prefix('"').and_then(|l| l.strip_suffix("\",")) {
+                let canon = current.as_ref().expect("alias outside a group");
+                groups
+                    .get_mut(canon)
+                    .expect("group")
+                    .insert(normalize_meta_key(alias));
+            }
+        }
+        groups
+    }
+
+    #[test]
+    fn guide_meta_table_matches_seamgrim_guide_meta_js() {
+        let js = guide_meta_js_groups();
+        let rust = [
+            GUIDE_NAME_KEYS,
+            GUIDE_DESC_KEYS,
+            GUIDE_OBSERVATION_KEYS,
+            GUIDE_OBSERVATION_X_KEYS,
+        ];
+        let rust_canons: BTreeSet<String> =
+            rust.iter().map(|(canon, _)| canon.to_string()).collect();
+        let js_canons: BTreeSet<String> = js.keys().cloned().collect();
+        assert_eq!(rust_canons, js_canons);
+        for (canon, aliases) in rust {
+            let js_aliases = &js[canon];
+            for key in std::iter::once(canon).chain(aliases.iter().copied()) {
+                assert!(
+                    js_aliases.contains(&normalize_meta_key(key)),
+                    "{key} is not a guide_meta.js alias of {canon}"
+                );
+            }
+        }
+    }
+}
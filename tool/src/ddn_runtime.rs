@@ -1699,7 +1699,6 @@ pub fn set_default_age_target(age: AgeTarget) {
 
 #[derive(Clone)]
 pub struct DdnProgram {
-    #[allow(dead_code)]
     program: CanonProgram,
     functions: HashMap<String, SeedDef>,
     top_level_decl_names: HashSet<String>,
@@ -2506,9 +2505,12 @@ fn expr_regex_feature(expr: &Expr) -> Option<&'static str> {
             }
             None
         }
-        ExprKind::Infix { left, right, .. } => {
-            expr_regex_feature(left).or_else(|| expr_regex_feature(right))
-        }
+        ExprKind::Infix { left, right, .. }
+        | ExprKind::Range {
+            start: left,
+            end: right,
+            ..
+        } => expr_regex_feature(left).or_else(|| expr_regex_feature(right)),
         ExprKind::Suffix { value, .. } => expr_regex_feature(value),
         ExprKind::Thunk(body) => body_regex_feature(body),
         ExprKind::Eval { thunk, .. } => expr_regex_feature(thunk),
@@ -2562,9 +2564,12 @@ fn expr_assertion_feature(expr: &Expr) -> Option<&'static str> {
             }
             None
         }
-        ExprKind::Infix { left, right, .. } => {
-            expr_assertion_feature(left).or_else(|| expr_assertion_feature(right))
-        }
+        ExprKind::Infix { left, right, .. }
+        | ExprKind::Range {
+            start: left,
+            end: right,
+            ..
+        } => expr_assertion_feature(left).or_else(|| expr_assertion_feature(right)),
         ExprKind::Suffix { value, .. } => expr_assertion_feature(value),
         ExprKind::Thunk(body) => body_assertion_feature(body),
         ExprKind::Eval { thunk, .. } => expr_assertion_feature(thunk),
@@ -2618,9 +2623,12 @@ fn expr_state_machine_feature(expr: &Expr) -> Option<&'static str> {
             }
             None
         }
-        ExprKind::Infix { left, right, .. } => {
-            expr_state_machine_feature(left).or_else(|| expr_state_machine_feature(right))
-        }
+        ExprKind::Infix { left, right, .. }
+        | ExprKind::Range {
+            start: left,
+            end: right,
+            ..
+        } => expr_state_machine_feature(left).or_else(|| expr_state_machine_feature(right)),
         ExprKind::Suffix { value, .. } => expr_state_machine_feature(value),
         ExprKind::Thunk(body) => body_state_machine_feature(body),
         ExprKind::Eval { thunk, .. } => expr_state_machine_feature(thunk),
@@ -2834,6 +2842,7 @@ struct EvalContext<'a> {
     guard_rejected: bool,
     aborted: bool,
     current_seed_name: Option<String>,
+    seed_call_depth: u32,
     rng_state: u64,
     flow_stack: Vec<Option<Value>>,
     tick_id: u64,
@@ -2965,6 +2974,7 @@ impl<'a> EvalContext<'a> {
             guard_rejected: false,
             aborted: false,
             current_seed_name: None,
+            seed_call_depth: 0,
             rng_state: rng_seed,
             flow_stack: Vec::new(),
             tick_id,
@@ -3001,6 +3011,7 @@ impl<'a> EvalContext<'a> {
     fn eval_seed(&mut self, seed: &SeedDef, args: Vec<Value>) -> Result<Value, EvalError> {
         let prev_seed = self.current_seed_name.clone();
         self.current_seed_name = Some(seed.canonical_name.clone());
+        self.seed_call_depth += 1;
         self.enter_const_scope();
         let result = (|| {
             if self.aborted {
@@ -3045,6 +3056,7 @@ impl<'a> EvalContext<'a> {
             }
         })();
         self.exit_const_scope();
+        self.seed_call_depth -= 1;
         self.current_seed_name = prev_seed;
         result
     }
@@ -3928,6 +3940,17 @@ impl<'a> EvalContext<'a> {
                 body: (*body.clone()),
                 captured: locals.clone(),
             })),
+            ExprKind::Range { .. } => {
+                let mut nid = self.program.program.id;
+                let lowered = expr.lower_range(&mut || {
+                    nid += 1;
+                    nid
+                });
+                match lowered {
+                    Some(lowered) => self.eval_expr(locals, &lowered),
+                    None => Err("범위 식을 내리지 못했습니다".to_string().into()),
+                }
+            }
             ExprKind::Infix { left, op, right } => {
                 let l = self.eval_expr(locals, left)?;
                 let r = self.eval_expr(locals, right)?;
@@ -4101,6 +4124,7 @@ impl<'a> EvalContext<'a> {
         };
         let prev_seed = self.current_seed_name.clone();
         self.current_seed_name = Some(receiver_name.to_string());
+        self.seed_call_depth += 1;
         let result = (|| {
             for rank in 0..4 {
                 for stmt in &body.stmts {
@@ -4137,6 +4161,7 @@ impl<'a> EvalContext<'a> {
             }
             Ok(())
         })();
+        self.seed_call_depth -= 1;
         self.current_seed_name = prev_seed;
         result
     }
@@ -7532,12 +7557,15 @@ impl<'a> EvalContext<'a> {
         let location = match kind {
             ArithmeticFaultKind::DimensionMismatch { .. } => "ddn:unit_mismatch",
             ArithmeticFaultKind::DivByZero => "ddn:div0",
+            ArithmeticFaultKind::Overflow => "ddn:overflow",
         };
         let ctx = FaultContext {
             tick_id: self.tick_id,
             location,
             source_span,
             expr,
+            seed: self.current_seed_name.clone(),
+            call_depth: self.seed_call_depth,
         };
         self.patch_ops.push(PatchOp::EmitSignal {
             signal: Signal::ArithmeticFault { ctx, kind },
@@ -8442,6 +8470,7 @@ impl<'a> EvalContext<'a> {
             guard_rejected: self.guard_rejected,
             aborted: false,
             current_seed_name: self.current_seed_name.clone(),
+            seed_call_depth: self.seed_call_depth,
             rng_state: self.rng_state,
             flow_stack: self.flow_stack.clone(),
             tick_id: self.tick_id,
@@ -8488,6 +8517,7 @@ impl<'a> EvalContext<'a> {
             guard_rejected: self.guard_rejected,
             aborted: false,
             current_seed_name: self.current_seed_name.clone(),
+            seed_call_depth: self.seed_call_depth,
             rng_state: self.rng_state,
             flow_stack: self.flow_stack.clone(),
             tick_id: self.tick_id,
@@ -8591,6 +8621,7 @@ impl<'a> EvalContext<'a> {
             guard_rejected: false,
             aborted: false,
             current_seed_name: self.current_seed_name.clone(),
+            seed_call_depth: self.seed_call_depth,
             rng_state: self.rng_state,
             flow_stack: self.flow_stack.clone(),
             tick_id: self.tick_id,
@@ -16874,6 +16905,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn div_zero_fault_inside_seed_reports_enclosing_seed() {
+        let script = r#"
+(왼:수, 오른:수) 더하:셈씨 = {
+    몫 <- 왼 / 오른.
+    왼 + 오른 돌려줘.
+}
+
+매틱:움직씨 = {
+    합 <- (1, 0) 더하.
+}
+"#;
+        let program = DdnProgram::from_source(script, "seed_fault_ctx.ddn").expect("parse");
+        let mut runner = DdnRunner::new(program, "매틱");
+        let world = NuriWorld::new();
+        let mut defaults: HashMap<String, RuntimeValue> = HashMap::new();
+        defaults.insert("합".to_string(), RuntimeValue::Fixed64(Fixed64::ZERO));
+        defaults.insert("몫".to_string(), RuntimeValue::Fixed64(Fixed64::ZERO));
+        let output = runner
+            .run_update(&world, &empty_input(), &defaults)
+            .expect("run update");
+
+        let faults: Vec<&FaultContext> = output
+            .patch
+            .ops
+            .iter()
+            .filter_map(|op| match op {
+                PatchOp::EmitSignal {
+                    signal:
+                        Signal::ArithmeticFault {
+                            ctx,
+                            kind: ArithmeticFaultKind::DivByZero,
+                        },
+                    ..
+                } => Some(ctx),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(faults.len(), 1);
+        assert_eq!(faults[0].seed.as_deref(), Some("더하"));
+        assert_eq!(faults[0].call_depth, 2);
+        assert_eq!(
+            faults[0].seed_context_label().as_deref(),
+            Some("seed:더하 depth:2")
+        );
+        assert_eq!(extract_fixed(&output.resources, "합"), Fixed64::from_i64(1));
+    }
+
     fn contract_diag_events(output: &DdnRunOutput) -> Vec<&DiagEvent> {
         output
             .patch
@@ -1,5 +1,8 @@
 use blake3::hash;
-use ddonirang_core::{set_unit_registry_symbols, unit_spec_from_symbol, ResourceHandle};
+use ddonirang_core::{
+    set_unit_registry_currency_formats, set_unit_registry_symbols, unit_spec_from_symbol,
+    validate_currency_formats, CurrencyFormat, ResourceHandle,
+};
 use serde::Deserialize;
 use std::collections::{HashMap, HashSet};
 use std::fs;
@@ -40,6 +43,8 @@ struct AssetEntryRaw {
 struct UnitsRegistry {
     version: Option<String>,
     units: Vec<String>,
+    #[serde(default)]
+    currency_formats: Vec<CurrencyFormat>,
 }
 
 pub fn ensure_gate0_registries() -> Result<(), String> {
@@ -124,7 +129,9 @@ fn load_asset_registry(manifest_path: &Path, strict_hash: bool) -> Result<AssetR
         last_path = Some(normalized.clone());
         let handle = parse_handle(&entry.handle)?;
         let expected =
-            ddonirang_core::asset_handle_from_bundle_path(&manifest.bundle_id, &normalized).raw();
+            ddonirang_core::asset_handle_from_bundle_path(&manifest.bundle_id, &normalized)
+                .map_err(|err| format!("ASSET_MANIFEST_INVALID: {}", err.code()))?
+                .raw();
         if handle != expected {
             return Err(format!(
                 "ASSET_MANIFEST_INVALID: handle mismatch for {}",
@@ -172,7 +179,21 @@ fn ensure_units_registry_default() -> Result<(), String> {
             return Err(format!("UNIT_UNKNOWN: {}", unit));
         }
     }
+    let mut format_units = HashSet::new();
+    for format in &registry.currency_formats {
+        if !format_units.insert(format.unit.clone()) {
+            return Err(format!(
+                "UNIT_REGISTRY_INVALID: duplicate currency format {}",
+                format.unit
+            ));
+        }
+        if !symbols.contains(&format.unit) {
+            return Err(format!("UNIT_UNKNOWN: {}", format.unit));
+        }
+    }
+    validate_currency_formats(&registry.currency_formats)?;
     set_unit_registry_symbols(symbols)?;
+    set_unit_registry_currency_formats(registry.currency_formats)?;
     Ok(())
 }
 
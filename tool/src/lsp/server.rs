@@ -259,7 +259,12 @@ impl LspServer {
                 }
             }
             ExprKind::FieldAccess { target, .. } => self.collect_from_expr(target, visualizations),
-            ExprKind::Infix { left, right, .. } => {
+            ExprKind::Infix { left, right, .. }
+            | ExprKind::Range {
+                start: left,
+                end: right,
+                ..
+            } => {
                 self.collect_from_expr(left, visualizations);
                 self.collect_from_expr(right, visualizations);
             }
@@ -669,7 +674,12 @@ impl LspServer {
             ExprKind::FieldAccess { target, .. } => {
                 self.check_call_tail_missing_expr(target, known_seeds, diagnostics);
             }
-            ExprKind::Infix { left, right, .. } => {
+            ExprKind::Infix { left, right, .. }
+            | ExprKind::Range {
+                start: left,
+                end: right,
+                ..
+            } => {
                 self.check_call_tail_missing_expr(left, known_seeds, diagnostics);
                 self.check_call_tail_missing_expr(right, known_seeds, diagnostics);
             }
@@ -8,11 +8,24 @@ use zip::ZipArchive;
 const SSOT_INDEX_PREFIX: &str = "SSOT_INDEX_";
 const SSOT_BUNDLE_PREFIX: &str = "SSOT_bundle_";
 const AI_PROMPT_TEMPLATE: &str = include_str!("../assets/ai_prompt_template_v20.0.3.txt");
+/// `--budget-tokens`로 줄일 때 먼저 버리는 순서. 목록에 없는 파일은 이보다 먼저 버린다.
+/// 템플릿(작업 지시)과 컨텍스트 머리는 버리지 않는다.
+const BUDGET_DROP_ORDER: &[&str] = &[
+    "GATE0_IMPLEMENTATION_CHECKLIST",
+    "SSOT_DEMOS",
+    "SSOT_PLATFORM",
+    "SSOT_DECISIONS",
+    "SSOT_TERMS",
+    "SSOT_LANG",
+    "SSOT_INDEX",
+    "SSOT_ALL",
+];
 
 pub struct AiPromptArgs {
     pub profile: String,
     pub out_path: Option<PathBuf>,
     pub bundle_path: Option<PathBuf>,
+    pub budget_tokens: Option<usize>,
 }
 
 struct PromptFile {
@@ -25,13 +38,44 @@ enum BundleSource {
     Zip(PathBuf),
 }
 
+struct PromptContext<'a> {
+    version: &'a str,
+    profile: &'a str,
+    bundle_kind: &'a str,
+    bundle_hash: &'a str,
+    file_names: &'a [String],
+}
+
+struct BudgetReport {
+    budget: usize,
+    estimated: usize,
+    dropped: Vec<(String, usize)>,
+}
+
 struct SsotSelection {
     version: String,
     file_names: Vec<String>,
 }
 
 pub fn run_ai_prompt(args: AiPromptArgs) -> Result<(), String> {
-    let output = build_ai_prompt(&args)?;
+    let (output, report) = build_ai_prompt(&args)?;
+    if let Some(report) = &report {
+        for (name, tokens) in &report.dropped {
+            eprintln!("ai_prompt_dropped={name} tokens={tokens}");
+        }
+        eprintln!(
+            "ai_prompt_budget={} estimated_tokens={} dropped={}",
+            report.budget,
+            report.estimated,
+            report.dropped.len()
+        );
+        if report.estimated > report.budget {
+            eprintln!(
+                "W_AI_PROMPT_BUDGET 버릴 수 있는 구역을 모두 버려도 예산을 넘습니다: {} > {}",
+                report.estimated, report.budget
+            );
+        }
+    }
     if let Some(out_path) = &args.out_path {
         if let Some(parent) = out_path.parent() {
             if !parent.as_os_str().is_empty() {
@@ -49,30 +93,105 @@ pub fn run_ai_prompt(args: AiPromptArgs) -> Result<(), String> {
     Ok(())
 }
 
-fn build_ai_prompt(args: &AiPromptArgs) -> Result<Vec<u8>, String> {
+fn build_ai_prompt(args: &AiPromptArgs) -> Result<(Vec<u8>, Option<BudgetReport>), String> {
     let profile = args.profile.to_ascii_lowercase();
     let (bundle_kind, source) = resolve_bundle_source(args.bundle_path.as_deref())?;
     let selection = select_ssot(&source, &profile)?;
     let files = load_profile_files(&source, &selection.file_names)?;
     let bundle_hash = compute_bundle_hash(&files);
+    let context = PromptContext {
+        version: &selection.version,
+        profile: &profile,
+        bundle_kind: &bundle_kind,
+        bundle_hash: &bundle_hash,
+        file_names: &selection.file_names,
+    };
+    match args.budget_tokens {
+        Some(budget) => {
+            let (out, report) = assemble_prompt_within_budget(&context, &files, budget)?;
+            Ok((out, Some(report)))
+        }
+        None => Ok((assemble_prompt(&context, &files, &[])?, None)),
+    }
+}
 
+fn assemble_prompt(
+    context: &PromptContext,
+    files: &[PromptFile],
+    dropped: &[String],
+) -> Result<Vec<u8>, String> {
     let mut out = Vec::new();
     append_template(&mut out)?;
     out.push(b'\n');
     write_context_header(
         &mut out,
-        &selection.version,
-        &profile,
-        &bundle_kind,
-        &bundle_hash,
-        &selection.file_names,
+        context.version,
+        context.profile,
+        context.bundle_kind,
+        context.bundle_hash,
+        context.file_names,
     );
-    for file in &files {
-        append_file_block(&mut out, file);
+    if !dropped.is_empty() {
+        push_line(&mut out, "DROPPED_FOR_BUDGET =");
+        for name in dropped {
+            push_line(&mut out, &format!("- {name}"));
+        }
+    }
+    for file in files {
+        if !dropped.contains(&file.name) {
+            append_file_block(&mut out, file);
+        }
     }
     Ok(out)
 }
 
+/// 추정 토큰이 예산 안에 들 때까지 `BUDGET_DROP_ORDER` 순서로 파일 구역을 하나씩 버린다.
+fn assemble_prompt_within_budget(
+    context: &PromptContext,
+    files: &[PromptFile],
+    budget: usize,
+) -> Result<(Vec<u8>, BudgetReport), String> {
+    let mut order: Vec<&PromptFile> = files.iter().collect();
+    order.sort_by_key(|file| budget_drop_rank(&file.name));
+    let mut dropped = Vec::new();
+    let mut report_dropped = Vec::new();
+    let mut out = assemble_prompt(context, files, &dropped)?;
+    for file in order {
+        if estimate_tokens(&out) <= budget {
+            break;
+        }
+        dropped.push(file.name.clone());
+        report_dropped.push((file.name.clone(), estimate_tokens(&file.bytes)));
+        out = assemble_prompt(context, files, &dropped)?;
+    }
+    let estimated = estimate_tokens(&out);
+    Ok((
+        out,
+        BudgetReport {
+            budget,
+            estimated,
+            dropped: report_dropped,
+        },
+    ))
+}
+
+fn budget_drop_rank(name: &str) -> usize {
+    let base = name.rsplit_once("_v").map(|(base, _)| base).unwrap_or(name);
+    BUDGET_DROP_ORDER
+        .iter()
+        .position(|entry| *entry == base)
+        .map(|pos| pos + 1)
+        .unwrap_or(0)
+}
+
+/// 결정적 토큰 추정: ASCII는 4글자당 1토큰(올림), 그 밖의 글자는 글자마다 1토큰.
+fn estimate_tokens(bytes: &[u8]) -> usize {
+    let text = String::from_utf8_lossy(bytes);
+    let ascii = text.chars().filter(|ch| ch.is_ascii()).count();
+    let other = text.chars().count() - ascii;
+    ascii.div_ceil(4) + other
+}
+
 fn resolve_bundle_source(bundle_path: Option<&Path>) -> Result<(String, BundleSource), String> {
     if let Some(path) = bundle_path {
         if path.is_dir() {
@@ -498,4 +617,50 @@ mod tests {
             "sha256:628d75b0daab4ea5006e09b27937d73c7a6d2ad4d10a5c4cc3482ad1c8dc4c5a"
         );
     }
+
+    #[test]
+    fn tiny_budget_drops_low_priority_sections_but_keeps_template() {
+        let file_names = vec![
+            "SSOT_INDEX_v1.md".to_string(),
+            "SSOT_LANG_v1.md".to_string(),
+            "SSOT_DEMOS_v1.md".to_string(),
+        ];
+        let files = file_names
+            .iter()
+            .map(|name| PromptFile {
+                name: name.clone(),
+                bytes: format!("{}\n", "x".repeat(400)).into_bytes(),
+            })
+            .collect::<Vec<_>>();
+        let context = PromptContext {
+            version: "v1",
+            profile: "lean",
+            bundle_kind: "dir",
+            bundle_hash: "sha256:0",
+            file_names: &file_names,
+        };
+        let full = assemble_prompt(&context, &files, &[]).expect("full");
+        let budget = estimate_tokens(&full) - 150;
+        let (out, report) =
+            assemble_prompt_within_budget(&context, &files, budget).expect("budget");
+        assert_eq!(
+            report
+                .dropped
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["SSOT_DEMOS_v1.md", "SSOT_LANG_v1.md"]
+        );
+        assert!(report.estimated <= budget);
+        let text = String::from_utf8(out).expect("utf8");
+        assert!(text.starts_with(AI_PROMPT_TEMPLATE));
+        assert!(text.contains("===== BEGIN SSOT_INDEX_v1.md ====="));
+        assert!(!text.contains("===== BEGIN SSOT_DEMOS_v1.md ====="));
+
+        let (out, report) = assemble_prompt_within_budget(&context, &files, 1).expect("tiny");
+        assert_eq!(report.dropped.len(), 3);
+        assert!(String::from_utf8(out)
+            .expect("utf8")
+            .starts_with(AI_PROMPT_TEMPLATE));
+    }
 }
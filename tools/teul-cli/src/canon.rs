@@ -586,16 +586,18 @@ impl<'a> Lexer<'a> {
         buf
     }
 
+    /// 원래 표기(`0xFF`, `1_000`)를 그대로 돌려준다. 정본 출력에서 16진/자리 묶음이 유지된다.
     fn lex_number(&mut self) -> Result<String, CanonError> {
         let mut buf = String::new();
-        while let Some(ch) = self.peek() {
-            if ch.is_ascii_digit() {
-                buf.push(ch);
-                self.bump();
-            } else {
-                break;
-            }
+        if self.peek() == Some('0') && matches!(self.peek_next(), Some('x' | 'X')) {
+            buf.push('0');
+            self.bump();
+            buf.extend(self.peek());
+            self.bump();
+            self.lex_digit_group(&mut buf, |ch| ch.is_ascii_hexdigit())?;
+            return Ok(buf);
         }
+        self.lex_digit_group(&mut buf, |ch| ch.is_ascii_digit())?;
         if self.peek() == Some('.')
             && self
                 .peek_next()
@@ -604,22 +606,38 @@ impl<'a> Lexer<'a> {
         {
             buf.push('.');
             self.bump();
-            while let Some(ch) = self.peek() {
-                if ch.is_ascii_digit() {
-                    buf.push(ch);
-                    self.bump();
-                } else {
-                    break;
-                }
+            self.lex_digit_group(&mut buf, |ch| ch.is_ascii_digit())?;
+        }
+        Ok(buf)
+    }
+
+    fn lex_digit_group(
+        &mut self,
+        buf: &mut String,
+        is_digit: fn(char) -> bool,
+    ) -> Result<(), CanonError> {
+        let mut last_was_digit = false;
+        while let Some(ch) = self.peek() {
+            if ch == '_' && !(last_was_digit && self.peek_next().is_some_and(is_digit)) {
+                return Err(CanonError::new(
+                    "E_CANON_BAD_NUMBER",
+                    "숫자 파싱 실패: `_`는 숫자 사이에 하나씩만 씁니다.",
+                ));
             }
+            if !is_digit(ch) && ch != '_' {
+                break;
+            }
+            last_was_digit = ch != '_';
+            buf.push(ch);
+            self.bump();
         }
-        if buf.is_empty() {
+        if !last_was_digit {
             return Err(CanonError::new(
                 "E_CANON_BAD_NUMBER",
                 "숫자 파싱 실패: 빈 숫자입니다.",
             ));
         }
-        Ok(buf)
+        Ok(())
     }
 
     fn lex_atom(&mut self) -> Result<String, CanonError> {
@@ -31,10 +31,12 @@ pub fn run_manifest(root: &Path, out: Option<&Path>) -> Result<(), String> {
             .unwrap_or_default()
             .to_string();
         let rel_path = normalize_rel_path(root, &path);
+        let image = probe_image(&path, &bytes);
         entries.push(AssetEntry {
             id,
             name,
             path: rel_path,
+            image,
         });
     }
 
@@ -51,6 +53,84 @@ struct AssetEntry {
     id: String,
     name: String,
     path: String,
+    image: Option<ImageInfo>,
+}
+
+/// 그림 자산의 형식과 크기. 그림 확장자인데 머리를 읽지 못하면 `format`이 "unknown"이고 크기는 없다.
+#[derive(Debug, PartialEq, Eq)]
+struct ImageInfo {
+    format: &'static str,
+    size: Option<(u32, u32)>,
+}
+
+const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg"];
+
+/// 파일 머리로 png/jpg를 알아본다. 그림이 아닌 파일은 `None`이다.
+fn probe_image(path: &Path, bytes: &[u8]) -> Option<ImageInfo> {
+    if let Some(size) = png_size(bytes) {
+        return Some(ImageInfo {
+            format: "png",
+            size: Some(size),
+        });
+    }
+    if let Some(size) = jpeg_size(bytes) {
+        return Some(ImageInfo {
+            format: "jpg",
+            size: Some(size),
+        });
+    }
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_ascii_lowercase())?;
+    if IMAGE_EXTENSIONS.contains(&extension.as_str()) {
+        return Some(ImageInfo {
+            format: "unknown",
+            size: None,
+        });
+    }
+    None
+}
+
+fn png_size(bytes: &[u8]) -> Option<(u32, u32)> {
+    const SIGNATURE: &[u8; 8] = b"\x89PNG\r\n\x1a\n";
+    if bytes.len() < 24 || &bytes[..8] != SIGNATURE || &bytes[12..16] != b"IHDR" {
+        return None;
+    }
+    let width = u32::from_be_bytes(bytes[16..20].try_into().ok()?);
+    let height = u32::from_be_bytes(bytes[20..24].try_into().ok()?);
+    Some((width, height))
+}
+
+fn jpeg_size(bytes: &[u8]) -> Option<(u32, u32)> {
+    if bytes.len() < 4 || bytes[0] != 0xFF || bytes[1] != 0xD8 {
+        return None;
+    }
+    let mut idx = 2usize;
+    while idx + 4 <= bytes.len() {
+        if bytes[idx] != 0xFF {
+            return None;
+        }
+        let marker = bytes[idx + 1];
+        if marker == 0xFF {
+            idx += 1;
+            continue;
+        }
+        if marker == 0xD9 || marker == 0xDA {
+            return None;
+        }
+        let len = u16::from_be_bytes([bytes[idx + 2], bytes[idx + 3]]) as usize;
+        // SOF0..SOF15 중 DHT(C4)/JPG(C8)/DAC(CC)를 뺀 것이 프레임 머리다.
+        let is_sof = (0xC0..=0xCF).contains(&marker) && !matches!(marker, 0xC4 | 0xC8 | 0xCC);
+        if is_sof {
+            let frame = bytes.get(idx + 4..idx + 9)?;
+            let height = u16::from_be_bytes([frame[1], frame[2]]) as u32;
+            let width = u16::from_be_bytes([frame[3], frame[4]]) as u32;
+            return Some((width, height));
+        }
+        idx += 2 + len;
+    }
+    None
 }
 
 fn collect_files(root: &Path, out: &mut Vec<PathBuf>) -> Result<(), String> {
@@ -85,10 +165,28 @@ fn build_manifest_text(entries: &[AssetEntry]) -> String {
             "      \"path\": \"{}\",\n",
             escape_json(&entry.path)
         ));
-        out.push_str(&format!(
-            "      \"name\": \"{}\"\n",
-            escape_json(&entry.name)
-        ));
+        match &entry.image {
+            None => out.push_str(&format!(
+                "      \"name\": \"{}\"\n",
+                escape_json(&entry.name)
+            )),
+            Some(image) => {
+                out.push_str(&format!(
+                    "      \"name\": \"{}\",\n",
+                    escape_json(&entry.name)
+                ));
+                match image.size {
+                    Some((width, height)) => {
+                        out.push_str(&format!("      \"format\": \"{}\",\n", image.format));
+                        out.push_str(&format!("      \"width\": {},\n", width));
+                        out.push_str(&format!("      \"height\": {}\n", height));
+                    }
+                    None => {
+                        out.push_str(&format!("      \"format\": \"{}\"\n", image.format));
+                    }
+                }
+            }
+        }
         out.push_str("    }");
         if idx + 1 != entries.len() {
             out.push(',');
@@ -114,3 +212,47 @@ fn escape_json(text: &str) -> String {
     }
     out
 }
+
+#[cfg(test)]
+mod tests {
+    use super::run_manifest;
+    use crate::core::bogae_raster::{encode_png, RasterImage};
+    use serde_json::Value;
+    use std::fs;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    #[test]
+    fn manifest_records_png_dimensions_and_unknown_images() {
+        let nonce = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        let root = std::env::temp_dir().join(format!("teul_cli_asset_manifest_{nonce}"));
+        let assets = root.join("assets");
+        fs::create_dir_all(&assets).expect("mkdir");
+        fs::write(assets.join("hero.png"), encode_png(&RasterImage::new(3, 2))).expect("png");
+        fs::write(assets.join("broken.jpg"), b"not a jpeg").expect("jpg");
+        fs::write(assets.join("notes.txt"), b"hello").expect("txt");
+        let out = root.join("manifest.detjson");
+
+        run_manifest(&assets, Some(&out)).expect("manifest");
+
+        let manifest: Value =
+            serde_json::from_str(&fs::read_to_string(&out).expect("read")).expect("json");
+        let assets = manifest["assets"].as_array().expect("assets");
+        let find = |path: &str| {
+            assets
+                .iter()
+                .find(|entry| entry["path"] == path)
+                .expect("entry")
+        };
+        let hero = find("hero.png");
+        assert_eq!(hero["format"], "png");
+        assert_eq!(hero["width"], 3);
+        assert_eq!(hero["height"], 2);
+        assert_eq!(find("broken.jpg")["format"], "unknown");
+        assert!(find("broken.jpg").get("width").is_none());
+        assert!(find("notes.txt").get("format").is_none());
+        let _ = fs::remove_dir_all(&root);
+    }
+}
@@ -8,7 +8,8 @@ use crate::core::bdl_packet::{
 };
 
 pub fn wrap_packet(input: &Path, out: &Path) -> Result<(), String> {
-    let payload = fs::read(input).map_err(|e| format_error(input, BdlPacketError::Truncated, e))?;
+    let payload =
+        fs::read(input).map_err(|e| format_error(input, BdlPacketError::ReadFailed, e))?;
     if payload.len() < 4 || &payload[0..4] != b"BDL1" {
         return Err(format!(
             "{} {}:1:1 입력이 BDL1 detbin이 아닙니다.",
@@ -27,7 +28,8 @@ pub fn wrap_packet(input: &Path, out: &Path) -> Result<(), String> {
 }
 
 pub fn unwrap_packet(input: &Path, out: &Path) -> Result<(), String> {
-    let bytes = fs::read(input).map_err(|e| format_error(input, BdlPacketError::Truncated, e))?;
+    let bytes = fs::read(input).map_err(|e| format_error(input, BdlPacketError::ReadFailed, e))?;
+    // 잘리거나 바뀐 packet은 E_BDL_CORRUPT(offset 포함)로 멈추고 out은 쓰지 않는다.
     let (payload, info) = decode_bdl1_packet(&bytes).map_err(|err| format_error(input, err, ""))?;
     if let Some(parent) = out.parent() {
         fs::create_dir_all(parent).map_err(|e| e.to_string())?;
@@ -50,3 +52,41 @@ fn format_error(path: &Path, err: BdlPacketError, suffix: impl std::fmt::Display
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{unwrap_packet, wrap_packet};
+    use std::fs;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    #[test]
+    fn unwrap_rejects_packet_truncated_by_one_byte() {
+        let nonce = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        let root = std::env::temp_dir().join(format!("teul_cli_bdl_packet_{nonce}"));
+        fs::create_dir_all(&root).expect("mkdir");
+        let detbin = root.join("input.bdl1.detbin");
+        fs::write(&detbin, b"BDL1\x01\x02\x03\x04payload").expect("detbin");
+        let packet = root.join("input.packet");
+        wrap_packet(&detbin, &packet).expect("wrap");
+
+        let roundtrip = root.join("roundtrip.bdl1.detbin");
+        unwrap_packet(&packet, &roundtrip).expect("unwrap");
+        assert_eq!(
+            fs::read(&roundtrip).expect("roundtrip"),
+            fs::read(&detbin).expect("detbin")
+        );
+
+        let mut bytes = fs::read(&packet).expect("packet");
+        bytes.pop();
+        fs::write(&packet, &bytes).expect("truncate");
+        let out = root.join("truncated.bdl1.detbin");
+        let err = unwrap_packet(&packet, &out).expect_err("truncated");
+        assert!(err.starts_with("E_BDL_CORRUPT"), "{err}");
+        assert!(err.contains("offset=48 needed=15 available=14"), "{err}");
+        assert!(!out.exists());
+        let _ = fs::remove_dir_all(&root);
+    }
+}
@@ -8,6 +8,7 @@ use clap::ValueEnum;
 pub enum BogaeMode {
     Web,
     Console,
+    Png,
 }
 
 #[derive(Clone, Copy, Debug, ValueEnum)]
@@ -97,3 +98,12 @@ pub fn resolve_bogae_out_dir(bogae_out: Option<&Path>) -> PathBuf {
         None => default_bogae_out_dir(),
     }
 }
+
+/// PNG 출력 경로. `--bogae-out`이 디렉터리면 그 안의 `frame.png`를 쓴다.
+pub fn resolve_bogae_png_path(bogae_out: Option<&Path>) -> PathBuf {
+    match bogae_out {
+        Some(path) if !is_bogae_out_dir(path) => path.to_path_buf(),
+        Some(path) => path.join("frame.png"),
+        None => default_bogae_out_dir().join("frame.png"),
+    }
+}
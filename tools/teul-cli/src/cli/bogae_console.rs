@@ -1,8 +1,23 @@
 use std::io::{self, Write};
 
-use crate::core::bogae::{BogaeCmd, BogaeDrawListV1};
+use crate::core::bogae::{BogaeCmd, BogaeDrawListV1, Rgba};
 use clap::ValueEnum;
 
+/// 흑백 팔레트에서 밝기 순으로 쓰는 글자.
+const MONOCHROME_RAMP: [char; 9] = ['.', ':', '-', '=', '+', '*', '#', '%', '@'];
+
+/// Okabe–Ito 8색. 적록 색각 이상에서도 서로 구별되는 색만 쓴다.
+const DEUTERANOPIA_PALETTE: [(u8, u8, u8); 8] = [
+    (0, 0, 0),
+    (230, 159, 0),
+    (86, 180, 233),
+    (0, 158, 115),
+    (240, 228, 66),
+    (0, 114, 178),
+    (213, 94, 0),
+    (204, 121, 167),
+];
+
 #[derive(Clone, Copy, Debug, ValueEnum)]
 pub enum ConsoleCellAspect {
     Auto,
@@ -12,6 +27,17 @@ pub enum ConsoleCellAspect {
     TwoToOne,
 }
 
+/// 콘솔 보개의 색 표시 방식. 그리기 결과(글자 격자)만 바꾸고 상태는 건드리지 않는다.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ConsolePalette {
+    /// 색 없이 도형 글자만 낸다(기존 출력).
+    Default,
+    /// 도형 색을 Okabe–Ito 팔레트의 가장 가까운 색으로 바꿔 ANSI 글자색으로 낸다.
+    Deuteranopia,
+    /// 도형 색의 밝기에 따라 글자를 골라 색 없이도 구별되게 한다.
+    Monochrome,
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct ConsoleRenderConfig {
     pub max_cols: usize,
@@ -22,6 +48,7 @@ pub struct ConsoleRenderConfig {
     pub panel_cols: usize,
     pub panel_fill: char,
     pub panel_border: char,
+    pub palette: ConsolePalette,
 }
 
 impl ConsoleRenderConfig {
@@ -35,6 +62,7 @@ impl ConsoleRenderConfig {
             panel_cols: 0,
             panel_fill: '.',
             panel_border: '|',
+            palette: ConsolePalette::Default,
         }
     }
 
@@ -91,140 +119,247 @@ pub fn render_drawlist_ascii(drawlist: &BogaeDrawListV1, config: ConsoleRenderCo
     let (cols, rows, scale_x, scale_y) = compute_grid(drawlist, config);
     let mut grid = vec![vec![' '; cols]; rows];
     prefill_panel(&mut grid, config);
+    let mut colors = vec![vec![None; cols]; rows];
     for cmd in &drawlist.cmds {
-        match cmd {
-            BogaeCmd::Clear { .. } => {
-                for row in &mut grid {
-                    for cell in row.iter_mut() {
-                        *cell = ' ';
+        draw_cmd(&mut grid, cmd, scale_x, scale_y, config);
+        if !matches!(config.palette, ConsolePalette::Default) {
+            record_cmd_colors(&mut colors, cmd, scale_x, scale_y, config);
+        }
+    }
+    draw_panel_border(&mut grid, config);
+    let border_bottom = draw_playfield_border(&mut grid, config, drawlist, scale_x, scale_y);
+    match config.palette {
+        ConsolePalette::Default => grid_to_string(&grid, border_bottom),
+        ConsolePalette::Monochrome => {
+            for (row, color_row) in grid.iter_mut().zip(&colors) {
+                for (cell, color) in row.iter_mut().zip(color_row) {
+                    if let Some(color) = color {
+                        *cell = monochrome_glyph(*color);
                     }
                 }
-                prefill_panel(&mut grid, config);
             }
-            BogaeCmd::RectFill { x, y, w, h, .. } => {
-                let x = snap_i32(*x);
-                let y = snap_i32(*y);
-                let w = snap_i32(*w);
-                let h = snap_i32(*h);
-                fill_rect(&mut grid, x, y, w, h, scale_x, scale_y, '#');
-            }
-            BogaeCmd::RectStroke {
-                x,
-                y,
-                w,
-                h,
-                thickness,
-                ..
-            } => {
-                let x = snap_i32(*x);
-                let y = snap_i32(*y);
-                let w = snap_i32(*w);
-                let h = snap_i32(*h);
-                let thickness = snap_i32(*thickness);
-                stroke_rect(&mut grid, x, y, w, h, thickness, scale_x, scale_y, '*');
+            grid_to_string(&grid, border_bottom)
+        }
+        ConsolePalette::Deuteranopia => colored_grid_to_string(&grid, &colors, border_bottom),
+    }
+}
+
+/// 이 명령이 칠한 칸에 명령의 색을 적는다. 글자와 스프라이트는 글자 자체가 뜻을
+/// 가지므로 색을 지워 원래 글자로 남긴다.
+fn record_cmd_colors(
+    colors: &mut [Vec<Option<Rgba>>],
+    cmd: &BogaeCmd,
+    scale_x: i32,
+    scale_y: i32,
+    config: ConsoleRenderConfig,
+) {
+    let color = match cmd {
+        BogaeCmd::Clear { .. } => {
+            for row in colors.iter_mut() {
+                row.fill(None);
             }
-            BogaeCmd::Line { x1, y1, x2, y2, .. } => {
-                let x1 = snap_i32(*x1);
-                let y1 = snap_i32(*y1);
-                let x2 = snap_i32(*x2);
-                let y2 = snap_i32(*y2);
-                draw_line(&mut grid, x1, y1, x2, y2, scale_x, scale_y, '*');
+            return;
+        }
+        BogaeCmd::Text { .. } | BogaeCmd::Sprite { .. } => None,
+        BogaeCmd::RectFill { color, .. }
+        | BogaeCmd::RectStroke { color, .. }
+        | BogaeCmd::Line { color, .. }
+        | BogaeCmd::CircleFill { color, .. }
+        | BogaeCmd::CircleStroke { color, .. }
+        | BogaeCmd::ArcStroke { color, .. }
+        | BogaeCmd::CurveCubicStroke { color, .. } => Some(*color),
+    };
+    let cols = colors.first().map_or(0, |row| row.len());
+    let mut mask = vec![vec!['\0'; cols]; colors.len()];
+    draw_cmd(&mut mask, cmd, scale_x, scale_y, config);
+    for (row, mask_row) in colors.iter_mut().zip(&mask) {
+        for (cell, mark) in row.iter_mut().zip(mask_row) {
+            if *mark != '\0' {
+                *cell = color;
             }
-            BogaeCmd::Text { x, y, text, .. } => {
-                let x = snap_i32(*x);
-                let y = snap_i32(*y);
-                draw_text(&mut grid, x, y, scale_x, scale_y, text, config);
+        }
+    }
+}
+
+fn luma(color: Rgba) -> u32 {
+    (299 * color.r as u32 + 587 * color.g as u32 + 114 * color.b as u32) / 1000
+}
+
+fn monochrome_glyph(color: Rgba) -> char {
+    MONOCHROME_RAMP[luma(color) as usize * MONOCHROME_RAMP.len() / 256]
+}
+
+fn deuteranopia_color(color: Rgba) -> Rgba {
+    let dist = |&(r, g, b): &(u8, u8, u8)| {
+        let dr = r as i32 - color.r as i32;
+        let dg = g as i32 - color.g as i32;
+        let db = b as i32 - color.b as i32;
+        dr * dr + dg * dg + db * db
+    };
+    let (r, g, b) = DEUTERANOPIA_PALETTE
+        .into_iter()
+        .min_by_key(dist)
+        .unwrap_or(DEUTERANOPIA_PALETTE[0]);
+    Rgba { r, g, b, a: 255 }
+}
+
+fn colored_grid_to_string(
+    grid: &[Vec<char>],
+    colors: &[Vec<Option<Rgba>>],
+    max_row: Option<usize>,
+) -> String {
+    let mut out = String::new();
+    let last_row = max_row.unwrap_or_else(|| grid.len().saturating_sub(1));
+    for (row_idx, (row, color_row)) in grid.iter().zip(colors).take(last_row + 1).enumerate() {
+        let mut current: Option<Rgba> = None;
+        for (ch, color) in row.iter().zip(color_row) {
+            let color = color.map(deuteranopia_color);
+            if color != current {
+                match color {
+                    Some(c) => out.push_str(&format!("\u{1b}[38;2;{};{};{}m", c.r, c.g, c.b)),
+                    None => out.push_str("\u{1b}[0m"),
+                }
+                current = color;
             }
-            BogaeCmd::Sprite {
-                x,
-                y,
-                w,
-                h,
-                tint,
-                asset,
-                ..
-            } => {
-                if tint.a == 0 {
-                    continue;
+            out.push(*ch);
+        }
+        if current.is_some() {
+            out.push_str("\u{1b}[0m");
+        }
+        if row_idx < last_row {
+            out.push('\n');
+        }
+    }
+    out
+}
+
+fn draw_cmd(
+    grid: &mut [Vec<char>],
+    cmd: &BogaeCmd,
+    scale_x: i32,
+    scale_y: i32,
+    config: ConsoleRenderConfig,
+) {
+    match cmd {
+        BogaeCmd::Clear { .. } => {
+            for row in grid.iter_mut() {
+                for cell in row.iter_mut() {
+                    *cell = ' ';
                 }
-                let x = snap_i32(*x);
-                let y = snap_i32(*y);
-                let w = snap_i32(*w);
-                let h = snap_i32(*h);
-                let ch = sprite_glyph(&asset.uri);
-                fill_rect(&mut grid, x, y, w, h, scale_x, scale_y, ch);
             }
-            BogaeCmd::CircleFill { cx, cy, r, .. } => {
-                let cx = snap_i32(*cx);
-                let cy = snap_i32(*cy);
-                let r = snap_i32(*r).abs();
-                fill_rect(
-                    &mut grid,
-                    cx - r,
-                    cy - r,
-                    r * 2,
-                    r * 2,
-                    scale_x,
-                    scale_y,
-                    'o',
-                );
+            prefill_panel(grid, config);
+        }
+        BogaeCmd::RectFill { x, y, w, h, .. } => {
+            let x = snap_i32(*x);
+            let y = snap_i32(*y);
+            let w = snap_i32(*w);
+            let h = snap_i32(*h);
+            fill_rect(grid, x, y, w, h, scale_x, scale_y, '#');
+        }
+        BogaeCmd::RectStroke {
+            x,
+            y,
+            w,
+            h,
+            thickness,
+            ..
+        } => {
+            let x = snap_i32(*x);
+            let y = snap_i32(*y);
+            let w = snap_i32(*w);
+            let h = snap_i32(*h);
+            let thickness = snap_i32(*thickness);
+            stroke_rect(grid, x, y, w, h, thickness, scale_x, scale_y, '*');
+        }
+        BogaeCmd::Line { x1, y1, x2, y2, .. } => {
+            let x1 = snap_i32(*x1);
+            let y1 = snap_i32(*y1);
+            let x2 = snap_i32(*x2);
+            let y2 = snap_i32(*y2);
+            draw_line(grid, x1, y1, x2, y2, scale_x, scale_y, '*');
+        }
+        BogaeCmd::Text { x, y, text, .. } => {
+            let x = snap_i32(*x);
+            let y = snap_i32(*y);
+            draw_text(grid, x, y, scale_x, scale_y, text, config);
+        }
+        BogaeCmd::Sprite {
+            x,
+            y,
+            w,
+            h,
+            tint,
+            asset,
+            ..
+        } => {
+            if tint.a == 0 {
+                return;
             }
-            BogaeCmd::CircleStroke {
-                cx,
-                cy,
-                r,
+            let x = snap_i32(*x);
+            let y = snap_i32(*y);
+            let w = snap_i32(*w);
+            let h = snap_i32(*h);
+            let ch = sprite_glyph(&asset.uri);
+            fill_rect(grid, x, y, w, h, scale_x, scale_y, ch);
+        }
+        BogaeCmd::CircleFill { cx, cy, r, .. } => {
+            let cx = snap_i32(*cx);
+            let cy = snap_i32(*cy);
+            let r = snap_i32(*r).abs();
+            fill_rect(grid, cx - r, cy - r, r * 2, r * 2, scale_x, scale_y, 'o');
+        }
+        BogaeCmd::CircleStroke {
+            cx,
+            cy,
+            r,
+            thickness,
+            ..
+        } => {
+            let cx = snap_i32(*cx);
+            let cy = snap_i32(*cy);
+            let r = snap_i32(*r).abs();
+            let thickness = snap_i32(*thickness);
+            stroke_rect(
+                grid,
+                cx - r,
+                cy - r,
+                r * 2,
+                r * 2,
                 thickness,
-                ..
-            } => {
-                let cx = snap_i32(*cx);
-                let cy = snap_i32(*cy);
-                let r = snap_i32(*r).abs();
-                let thickness = snap_i32(*thickness);
-                stroke_rect(
-                    &mut grid,
-                    cx - r,
-                    cy - r,
-                    r * 2,
-                    r * 2,
-                    thickness,
-                    scale_x,
-                    scale_y,
-                    'o',
-                );
-            }
-            BogaeCmd::ArcStroke {
-                cx,
-                cy,
-                r,
-                start_turn,
-                sweep_turn,
-                ..
-            } => {
-                let cx = snap_i32(*cx) as f64;
-                let cy = snap_i32(*cy) as f64;
-                let r = snap_i32(*r).abs() as f64;
-                let start = (*start_turn as f64) * std::f64::consts::PI * 2.0;
-                let end = start + (*sweep_turn as f64) * std::f64::consts::PI * 2.0;
-                let x1 = (cx + r * start.cos()).round() as i32;
-                let y1 = (cy + r * start.sin()).round() as i32;
-                let x2 = (cx + r * end.cos()).round() as i32;
-                let y2 = (cy + r * end.sin()).round() as i32;
-                draw_line(&mut grid, x1, y1, x2, y2, scale_x, scale_y, '*');
-            }
-            BogaeCmd::CurveCubicStroke {
-                p0x, p0y, p3x, p3y, ..
-            } => {
-                let p0x = snap_i32(*p0x);
-                let p0y = snap_i32(*p0y);
-                let p3x = snap_i32(*p3x);
-                let p3y = snap_i32(*p3y);
-                draw_line(&mut grid, p0x, p0y, p3x, p3y, scale_x, scale_y, '*');
-            }
+                scale_x,
+                scale_y,
+                'o',
+            );
+        }
+        BogaeCmd::ArcStroke {
+            cx,
+            cy,
+            r,
+            start_turn,
+            sweep_turn,
+            ..
+        } => {
+            let cx = snap_i32(*cx) as f64;
+            let cy = snap_i32(*cy) as f64;
+            let r = snap_i32(*r).abs() as f64;
+            let start = (*start_turn as f64) * std::f64::consts::PI * 2.0;
+            let end = start + (*sweep_turn as f64) * std::f64::consts::PI * 2.0;
+            let x1 = (cx + r * start.cos()).round() as i32;
+            let y1 = (cy + r * start.sin()).round() as i32;
+            let x2 = (cx + r * end.cos()).round() as i32;
+            let y2 = (cy + r * end.sin()).round() as i32;
+            draw_line(grid, x1, y1, x2, y2, scale_x, scale_y, '*');
+        }
+        BogaeCmd::CurveCubicStroke {
+            p0x, p0y, p3x, p3y, ..
+        } => {
+            let p0x = snap_i32(*p0x);
+            let p0y = snap_i32(*p0y);
+            let p3x = snap_i32(*p3x);
+            let p3y = snap_i32(*p3y);
+            draw_line(grid, p0x, p0y, p3x, p3y, scale_x, scale_y, '*');
         }
     }
-    draw_panel_border(&mut grid, config);
-    let border_bottom = draw_playfield_border(&mut grid, config, drawlist, scale_x, scale_y);
-    grid_to_string(&grid, border_bottom)
 }
 
 fn sprite_glyph(uri: &str) -> char {
@@ -835,3 +970,78 @@ fn snap_i32(value: f32) -> i32 {
         (value - 0.5).ceil() as i32
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{render_drawlist_ascii, ConsolePalette, ConsoleRenderConfig};
+    use crate::core::bogae::{BogaeCmd, BogaeDrawListV1, Rgba};
+
+    fn rect(x: f32, r: u8, g: u8, b: u8) -> BogaeCmd {
+        BogaeCmd::RectFill {
+            x,
+            y: 0.0,
+            w: 2.0,
+            h: 2.0,
+            color: Rgba { r, g, b, a: 255 },
+            aa: false,
+        }
+    }
+
+    fn sample() -> BogaeDrawListV1 {
+        BogaeDrawListV1 {
+            width_px: 8,
+            height_px: 2,
+            cmds: vec![
+                BogaeCmd::Clear {
+                    color: Rgba {
+                        r: 0,
+                        g: 0,
+                        b: 0,
+                        a: 255,
+                    },
+                    aa: false,
+                },
+                rect(0.0, 255, 0, 0),
+                rect(3.0, 0, 255, 0),
+                rect(6.0, 0, 0, 255),
+            ],
+        }
+    }
+
+    #[test]
+    fn monochrome_palette_maps_colors_to_distinct_gray_glyphs() {
+        let default = render_drawlist_ascii(&sample(), ConsoleRenderConfig::default());
+        assert_eq!(default, "## ## ##\n## ## ##");
+
+        let mut config = ConsoleRenderConfig::default();
+        config.palette = ConsolePalette::Monochrome;
+        let mono = render_drawlist_ascii(&sample(), config);
+        assert_eq!(mono, "-- ** ::\n-- ** ::");
+        let glyphs = mono.lines().next().unwrap().chars().collect::<Vec<_>>();
+        assert_ne!(glyphs[0], glyphs[3]);
+        assert_ne!(glyphs[3], glyphs[6]);
+        assert_ne!(glyphs[0], glyphs[6]);
+    }
+
+    #[test]
+    fn deuteranopia_palette_colors_cells_without_changing_glyphs() {
+        let mut config = ConsoleRenderConfig::default();
+        config.palette = ConsolePalette::Deuteranopia;
+        let out = render_drawlist_ascii(&sample(), config);
+        let first = out.lines().next().unwrap();
+        assert!(first.starts_with("\u{1b}[38;2;213;94;0m##\u{1b}[0m "));
+        assert!(first.contains("\u{1b}[38;2;0;158;115m##"));
+        let plain = out
+            .split('\u{1b}')
+            .enumerate()
+            .map(|(idx, part)| {
+                if idx == 0 {
+                    part
+                } else {
+                    &part[part.find('m').unwrap() + 1..]
+                }
+            })
+            .collect::<String>();
+        assert_eq!(plain, "## ## ##\n## ## ##");
+    }
+}
@@ -0,0 +1,65 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::cli::bogae_web::is_relative_asset;
+use crate::core::bogae::BogaeDrawListV1;
+use crate::core::bogae_raster::{decode_png, encode_png, rasterize_drawlist, RasterImage};
+
+/// 드로우리스트를 PNG 한 장으로 그려 `path`에 쓴다.
+pub fn write_drawlist_png(
+    path: &Path,
+    drawlist: &BogaeDrawListV1,
+    skin: Option<&Path>,
+) -> Result<(), String> {
+    let sprites = load_skin_sprites(skin)?;
+    let image = rasterize_drawlist(drawlist, &sprites);
+    if let Some(parent) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        fs::create_dir_all(parent).map_err(|e| format!("E_BOGAE_PNG_WRITE {}", e))?;
+    }
+    fs::write(path, encode_png(&image))
+        .map_err(|e| format!("E_BOGAE_PNG_WRITE {} {}", path.display(), e))
+}
+
+/// 웹 보개와 같은 규칙(`frames[0]`, 없으면 `asset_uri`)으로 심볼 그림을 읽는다.
+/// 상대 경로의 PNG만 읽고, 못 읽은 심볼은 건너뛰어 tint 사각형으로 그려지게 둔다.
+fn load_skin_sprites(skin: Option<&Path>) -> Result<BTreeMap<String, RasterImage>, String> {
+    let mut sprites = BTreeMap::new();
+    let Some(skin_path) = skin else {
+        return Ok(sprites);
+    };
+    let text = fs::read_to_string(skin_path)
+        .map_err(|e| format!("E_BOGAE_SKIN {} {}", skin_path.display(), e))?;
+    let json: Value = serde_json::from_str(&text)
+        .map_err(|e| format!("E_BOGAE_SKIN {} {}", skin_path.display(), e))?;
+    let Some(symbols) = json.get("symbols").and_then(|value| value.as_array()) else {
+        return Ok(sprites);
+    };
+    let base = skin_path.parent().unwrap_or_else(|| Path::new("."));
+    for sym in symbols {
+        let Some(key) = sym.get("key").and_then(|value| value.as_str()) else {
+            continue;
+        };
+        let Some(web) = sym.get("web") else {
+            continue;
+        };
+        let uri = web
+            .get("frames")
+            .and_then(|value| value.as_array())
+            .and_then(|frames| frames.first())
+            .and_then(|value| value.as_str())
+            .or_else(|| web.get("asset_uri").and_then(|value| value.as_str()));
+        let Some(uri) = uri.filter(|uri| is_relative_asset(uri)) else {
+            continue;
+        };
+        let Ok(bytes) = fs::read(base.join(uri)) else {
+            continue;
+        };
+        if let Ok(image) = decode_png(&bytes) {
+            sprites.insert(key.to_string(), image);
+        }
+    }
+    Ok(sprites)
+}
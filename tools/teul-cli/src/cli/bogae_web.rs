@@ -742,7 +742,7 @@ fn copy_asset_if_relative(base: &Path, out_dir: &Path, uri: &str) {
     let _ = fs::copy(src, dest);
 }
 
-fn is_relative_asset(uri: &str) -> bool {
+pub(crate) fn is_relative_asset(uri: &str) -> bool {
     if uri.starts_with('/') || uri.starts_with('\\') {
         return false;
     }
@@ -0,0 +1,443 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde_json::{json, Value};
+
+use crate::canon;
+use crate::cli::check::{self, CheckArgs, CheckFormat};
+use crate::cli::frontdoor_parse::{parse_program_for_runtime, FrontdoorParseFailure};
+use crate::cli::run::RunError;
+use crate::lang::ast::{ModuleImportItem, Program, Stmt};
+
+/// `.ddnc` 빌드 산출물 스키마. 본문은 정본화된 입구 파일과 가지 의존성의 정본을 담는다.
+pub const BUILD_ARTIFACT_SCHEMA: &str = "ddn.build.artifact.v1";
+pub const BUILD_ARTIFACT_EXT: &str = "ddnc";
+
+#[derive(Debug)]
+pub struct BuildArtifact {
+    pub entry: String,
+    pub source: String,
+    pub gaji: Vec<BuildGajiPackage>,
+    pub manifest_hash: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct BuildGajiPackage {
+    pub id: String,
+    pub version: String,
+    pub hash: String,
+    pub files: Vec<(String, String)>,
+}
+
+/// 검사를 통과한 입구 파일을 정본화해 `.ddnc` 한 파일로 묶는다.
+/// `out`이 없으면 입구 파일 옆에 확장자만 바꿔 쓴다.
+pub fn run(file: &Path, out: Option<&Path>) -> Result<(), String> {
+    check::run(
+        file,
+        CheckArgs {
+            emit_schema: true,
+            format: CheckFormat::Human,
+            out: None,
+            entry_seed: None,
+        },
+    )?;
+    let artifact = build_artifact(file)?;
+    let out = out
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| file.with_extension(BUILD_ARTIFACT_EXT));
+    if let Some(parent) = out.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        fs::create_dir_all(parent).map_err(|e| format!("E_BUILD_WRITE {}", e))?;
+    }
+    let text = serde_json::to_string_pretty(&artifact_json(&artifact))
+        .map_err(|e| format!("E_BUILD_WRITE {}", e))?;
+    fs::write(&out, text + "\n").map_err(|e| format!("E_BUILD_WRITE {} {}", out.display(), e))?;
+    println!("build_artifact_written={}", out.display());
+    println!("build_manifest_hash={}", artifact.manifest_hash);
+    Ok(())
+}
+
+pub fn build_artifact(file: &Path) -> Result<BuildArtifact, String> {
+    let source =
+        fs::read_to_string(file).map_err(|e| format!("E_BUILD_READ {} {}", file.display(), e))?;
+    let source = canonical_ddn(&source, file)?;
+    let label = file.display().to_string();
+    let (program, _) = parse_program_for_runtime(&source).map_err(|err| match err {
+        FrontdoorParseFailure::Guard(message) => message,
+        FrontdoorParseFailure::Lex(err) => RunError::Lex(err).format(&label),
+        FrontdoorParseFailure::Parse(err) => RunError::Parse(err).format(&label),
+    })?;
+    // 입구가 쓰는 꾸러미만 묶는다. 맞지 않는 쓰임은 실행 때와 같이 빌드에서 거절한다.
+    let locked = if uses_gaji(&program) {
+        project_gaji(file)?
+    } else {
+        Vec::new()
+    };
+    let mut gaji: Vec<BuildGajiPackage> = Vec::new();
+    for (_, pkg) in resolve_gaji_imports(&program, &locked)? {
+        if !gaji
+            .iter()
+            .any(|seen| seen.id == pkg.id && seen.version == pkg.version)
+        {
+            gaji.push(pkg.clone());
+        }
+    }
+    gaji.sort_by(|a, b| a.id.cmp(&b.id).then_with(|| a.version.cmp(&b.version)));
+    let entry = file
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let manifest_hash = manifest_hash(&entry, &source, &gaji);
+    Ok(BuildArtifact {
+        entry,
+        source,
+        gaji,
+        manifest_hash,
+    })
+}
+
+/// 산출물을 읽고 manifest 해시를 다시 계산해 맞는지 확인한다.
+pub fn load_artifact(path: &Path) -> Result<BuildArtifact, String> {
+    let text = fs::read_to_string(path)
+        .map_err(|e| format!("E_BUILD_ARTIFACT_READ {} {}", path.display(), e))?;
+    let json: Value = serde_json::from_str(&text)
+        .map_err(|e| format!("E_BUILD_ARTIFACT_PARSE {} {}", path.display(), e))?;
+    let schema = json.get("schema").and_then(Value::as_str).unwrap_or("");
+    if schema != BUILD_ARTIFACT_SCHEMA {
+        return Err(format!(
+            "E_BUILD_ARTIFACT_SCHEMA schema={} (need {})",
+            schema, BUILD_ARTIFACT_SCHEMA
+        ));
+    }
+    let text_field = |value: &Value, key: &str| -> Result<String, String> {
+        value
+            .get(key)
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .ok_or_else(|| format!("E_BUILD_ARTIFACT_PARSE {} 누락", key))
+    };
+    let mut gaji = Vec::new();
+    for pkg in json
+        .get("gaji")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        let mut files = Vec::new();
+        for file in pkg
+            .get("files")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            files.push((text_field(file, "path")?, text_field(file, "ddn")?));
+        }
+        gaji.push(BuildGajiPackage {
+            id: text_field(pkg, "id")?,
+            version: text_field(pkg, "version")?,
+            hash: text_field(pkg, "hash")?,
+            files,
+        });
+    }
+    let artifact = BuildArtifact {
+        entry: text_field(&json, "entry")?,
+        source: text_field(&json, "source")?,
+        gaji,
+        manifest_hash: text_field(&json, "manifest_hash")?,
+    };
+    let expected = manifest_hash(&artifact.entry, &artifact.source, &artifact.gaji);
+    if artifact.manifest_hash != expected {
+        return Err(format!(
+            "E_BUILD_ARTIFACT_HASH {} manifest_hash={} computed={}",
+            path.display(),
+            artifact.manifest_hash,
+            expected
+        ));
+    }
+    Ok(artifact)
+}
+
+/// 입구 파일 위쪽의 `ddn.lock`이 가리키는 가지를 실행용으로 읽는다. 잠금이 없으면 빈 목록이다.
+pub fn project_gaji(file: &Path) -> Result<Vec<BuildGajiPackage>, String> {
+    match find_lock(file) {
+        Some(lock_path) => collect_gaji(&lock_path),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// 실행할 때 `쓰임` 별명 아래에 심을 가지 꾸러미. 파일마다 파싱한 프로그램을 담는다.
+pub struct GajiModule {
+    pub alias: String,
+    pub programs: Vec<Program>,
+}
+
+/// 입구 프로그램의 가지 쓰임(`나눔/`·`내/`·`벌림/`)을 꾸러미에 맞춘다.
+/// 맞는 꾸러미가 없는 쓰임은 `E_IMPORT_UNRESOLVED`로 거절한다.
+pub fn resolve_gaji_imports<'a>(
+    program: &Program,
+    gaji: &'a [BuildGajiPackage],
+) -> Result<Vec<(String, &'a BuildGajiPackage)>, String> {
+    let mut resolved = Vec::new();
+    for item in program_imports(program).filter(|item| is_gaji_import(&item.path)) {
+        let pkg = gaji
+            .iter()
+            .find(|pkg| gaji_matches_import(pkg, &item.path))
+            .ok_or_else(|| {
+                format!(
+                    "E_IMPORT_UNRESOLVED {}: \"{}\"에 맞는 가지 꾸러미가 ddn.lock에 없습니다.",
+                    item.alias, item.path
+                )
+            })?;
+        resolved.push((item.alias.clone(), pkg));
+    }
+    Ok(resolved)
+}
+
+/// 쓰임을 꾸러미에 맞추고 꾸러미 파일을 파싱한다. run·build·watch가 같은 맞춤을 쓴다.
+pub fn load_gaji_modules(
+    program: &Program,
+    gaji: &[BuildGajiPackage],
+) -> Result<Vec<GajiModule>, String> {
+    let mut modules = Vec::new();
+    for (alias, pkg) in resolve_gaji_imports(program, gaji)? {
+        let mut programs = Vec::new();
+        for (path, ddn) in &pkg.files {
+            let label = format!("{}/{}", pkg.id, path);
+            let (program, _) = parse_program_for_runtime(ddn).map_err(|err| match err {
+                FrontdoorParseFailure::Guard(message) => message,
+                FrontdoorParseFailure::Lex(err) => RunError::Lex(err).format(&label),
+                FrontdoorParseFailure::Parse(err) => RunError::Parse(err).format(&label),
+            })?;
+            programs.push(program);
+        }
+        modules.push(GajiModule { alias, programs });
+    }
+    Ok(modules)
+}
+
+/// 입구 프로그램이 가지 쓰임을 하나라도 적었는지 본다.
+pub fn uses_gaji(program: &Program) -> bool {
+    program_imports(program).any(|item| is_gaji_import(&item.path))
+}
+
+fn program_imports(program: &Program) -> impl Iterator<Item = &ModuleImportItem> {
+    program
+        .stmts
+        .iter()
+        .filter_map(|stmt| match stmt {
+            Stmt::ImportBlock { items, .. } => Some(items),
+            _ => None,
+        })
+        .flatten()
+}
+
+/// 가지에서 찾아야 하는 쓰임 경로(`나눔/`·`내/`·`벌림/`)인지 본다.
+fn is_gaji_import(import: &str) -> bool {
+    import
+        .split_once('/')
+        .is_some_and(|(scope, _)| matches!(scope, "나눔" | "내" | "벌림"))
+}
+
+/// 쓰임 경로의 마지막 이름이 꾸러미 id 끝과 같고, 판이 적혔으면 판도 같아야 한다.
+fn gaji_matches_import(pkg: &BuildGajiPackage, import: &str) -> bool {
+    if !is_gaji_import(import) {
+        return false;
+    }
+    let (package, version) = match import.rsplit_once('@') {
+        Some((left, right)) => (left, Some(right)),
+        None => (import, None),
+    };
+    let name = package.rsplit('/').next().unwrap_or(package);
+    let pkg_name = pkg.id.rsplit('/').next().unwrap_or(&pkg.id);
+    let name_matches = pkg.id == package || pkg_name == name;
+    name_matches && version.is_none_or(|version| version == pkg.version)
+}
+
+pub fn is_artifact_path(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext == BUILD_ARTIFACT_EXT)
+}
+
+fn canonical_ddn(source: &str, file: &Path) -> Result<String, String> {
+    canon::canonicalize(source, false)
+        .map(|out| out.ddn)
+        .map_err(|err| format!("E_BUILD_CANON {} {}", file.display(), err))
+}
+
+/// 입구 파일에서 위로 올라가며 처음 만나는 `ddn.lock`을 프로젝트 잠금으로 쓴다.
+fn find_lock(file: &Path) -> Option<PathBuf> {
+    file.parent()?
+        .ancestors()
+        .map(|dir| dir.join("ddn.lock"))
+        .find(|path| path.is_file())
+}
+
+/// `ddn.lock` 꾸러미 하나와 그 `.ddn` 파일의 디스크 경로.
+struct LockedGaji {
+    id: String,
+    version: String,
+    hash: String,
+    files: Vec<(String, PathBuf)>,
+}
+
+fn read_lock(lock_path: &Path) -> Result<Vec<LockedGaji>, String> {
+    let text = fs::read_to_string(lock_path)
+        .map_err(|e| format!("E_BUILD_LOCK {} {}", lock_path.display(), e))?;
+    let lock: Value = serde_json::from_str(&text)
+        .map_err(|e| format!("E_BUILD_LOCK {} {}", lock_path.display(), e))?;
+    let gaji_root = lock_path
+        .parent()
+        .unwrap_or_else(|| Path::new("."))
+        .join("gaji");
+    let mut packages = Vec::new();
+    for pkg in lock
+        .get("packages")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        let field = |key: &str| {
+            pkg.get(key)
+                .and_then(Value::as_str)
+                .unwrap_or("")
+                .to_string()
+        };
+        let pkg_dir = gaji_root.join(field("path"));
+        let mut files = Vec::new();
+        for file in pkg
+            .get("files")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            let Some(rel) = file.get("path").and_then(Value::as_str) else {
+                continue;
+            };
+            if !rel.ends_with(".ddn") {
+                continue;
+            }
+            files.push((rel.to_string(), pkg_dir.join(rel)));
+        }
+        files.sort();
+        packages.push(LockedGaji {
+            id: field("id"),
+            version: field("version"),
+            hash: field("hash"),
+            files,
+        });
+    }
+    Ok(packages)
+}
+
+fn collect_gaji(lock_path: &Path) -> Result<Vec<BuildGajiPackage>, String> {
+    let mut packages = Vec::new();
+    for locked in read_lock(lock_path)? {
+        let mut files = Vec::new();
+        for (rel, path) in locked.files {
+            let source = fs::read_to_string(&path)
+                .map_err(|e| format!("E_BUILD_GAJI_READ {} {}", path.display(), e))?;
+            files.push((rel, canonical_ddn(&source, &path)?));
+        }
+        packages.push(BuildGajiPackage {
+            id: locked.id,
+            version: locked.version,
+            hash: locked.hash,
+            files,
+        });
+    }
+    packages.sort_by(|a, b| a.id.cmp(&b.id).then_with(|| a.version.cmp(&b.version)));
+    Ok(packages)
+}
+
+/// `--watch`가 지켜볼 가지 파일: 입구 위쪽 `ddn.lock`과 거기 적힌 꾸러미의 `.ddn` 파일들.
+/// 실행과 같은 잠금을 읽으므로 실행이 읽는 파일만 지켜본다.
+pub fn project_gaji_paths(file: &Path) -> Vec<PathBuf> {
+    let Some(lock_path) = find_lock(file) else {
+        return Vec::new();
+    };
+    let mut paths = vec![lock_path.clone()];
+    for locked in read_lock(&lock_path).unwrap_or_default() {
+        paths.extend(locked.files.into_iter().map(|(_, path)| path));
+    }
+    paths
+}
+
+fn manifest_hash(entry: &str, source: &str, gaji: &[BuildGajiPackage]) -> String {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(BUILD_ARTIFACT_SCHEMA.as_bytes());
+    hasher.update(&[0]);
+    hasher.update(entry.as_bytes());
+    hasher.update(&[0]);
+    hasher.update(source.as_bytes());
+    hasher.update(&[0]);
+    for pkg in gaji {
+        for part in [&pkg.id, &pkg.version, &pkg.hash] {
+            hasher.update(part.as_bytes());
+            hasher.update(&[0]);
+        }
+        for (path, ddn) in &pkg.files {
+            hasher.update(path.as_bytes());
+            hasher.update(&[0]);
+            hasher.update(ddn.as_bytes());
+            hasher.update(&[0]);
+        }
+    }
+    format!("blake3:{}", hasher.finalize().to_hex())
+}
+
+fn artifact_json(artifact: &BuildArtifact) -> Value {
+    let gaji: Vec<Value> = artifact
+        .gaji
+        .iter()
+        .map(|pkg| {
+            let files: Vec<Value> = pkg
+                .files
+                .iter()
+                .map(|(path, ddn)| json!({ "path": path, "ddn": ddn }))
+                .collect();
+            json!({
+                "id": pkg.id,
+                "version": pkg.version,
+                "hash": pkg.hash,
+                "files": files,
+            })
+        })
+        .collect();
+    json!({
+        "schema": BUILD_ARTIFACT_SCHEMA,
+        "entry": artifact.entry,
+        "source": artifact.source,
+        "gaji": gaji,
+        "manifest_hash": artifact.manifest_hash,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    #[test]
+    fn tampered_artifact_is_rejected_on_load() {
+        let nonce = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("teul_build_artifact_{nonce}"));
+        fs::create_dir_all(&dir).expect("mkdir");
+        let input = dir.join("main.ddn");
+        fs::write(&input, "x <- 1 + 2.\nx 보여주기.\n").expect("write");
+        let artifact = build_artifact(&input).expect("build");
+        let out = dir.join("main.ddnc");
+        let mut json = artifact_json(&artifact);
+        fs::write(&out, json.to_string()).expect("write artifact");
+        let loaded = load_artifact(&out).expect("load");
+        assert_eq!(loaded.source, artifact.source);
+        assert_eq!(loaded.manifest_hash, artifact.manifest_hash);
+
+        json["source"] = Value::String("x <- 5.\n".to_string());
+        fs::write(&out, json.to_string()).expect("write tampered");
+        let err = load_artifact(&out).expect_err("tampered");
+        assert!(err.starts_with("E_BUILD_ARTIFACT_HASH"), "{err}");
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -52,6 +52,7 @@ pub enum EmitKind {
     MaegimControlJson,
     FixitsJson,
     Both,
+    JsonAst,
 }
 
 #[derive(Clone, Copy, Debug, ValueEnum)]
@@ -68,6 +69,7 @@ pub struct CanonArgs {
     pub diag_jsonl: Option<PathBuf>,
     pub meta_out: Option<PathBuf>,
     pub check: bool,
+    pub diff: bool,
 }
 
 pub fn run(path: &Path, args: CanonArgs) -> Result<(), String> {
@@ -82,10 +84,27 @@ pub fn run(path: &Path, args: CanonArgs) -> Result<(), String> {
         );
         return Err(err.to_string());
     }
+    if args.diff && !matches!(args.emit, EmitKind::Ddn) {
+        return Err("E_CLI_CANON --diff는 --emit ddn에서만 쓸 수 있습니다".to_string());
+    }
     let fixits_json = build_fixits_json(&source, path);
     let bridge = matches!(args.bridge, Some(BridgeKind::Age0Step01));
     let legacy_block_header_colon_count = detect_legacy_block_header_colon_count(&source);
 
+    if matches!(args.emit, EmitKind::JsonAst) {
+        let json_ast = match build_json_ast(&source, path) {
+            Ok(text) => text,
+            Err(err) => {
+                maybe_write_fixits(&fixits_json, &args.fixits_json)?;
+                maybe_write_diag(&args.diag_jsonl, &diag_error_line(&err))?;
+                return Err(err.to_string());
+            }
+        };
+        maybe_write_fixits(&fixits_json, &args.fixits_json)?;
+        maybe_write_diag(&args.diag_jsonl, &diag_ok_line())?;
+        return write_emit_text(&args, &json_ast);
+    }
+
     if matches!(args.emit, EmitKind::Ddn) {
         let (ddn, meta, mut warnings) = match canonicalize_ddn_strict(&source, path, bridge) {
             Ok(output) => output,
@@ -101,6 +120,9 @@ pub fn run(path: &Path, args: CanonArgs) -> Result<(), String> {
             }
         };
         append_block_header_warning_if_needed(&mut warnings, legacy_block_header_colon_count);
+        if args.diff {
+            return print_canon_diff(path, &source, &ddn);
+        }
         ensure_no_inplace_fixits(path, &args, &fixits_json)?;
         if args.check && !is_canon_match(&source, &ddn) {
             let err = CanonError::new(
@@ -211,6 +233,24 @@ pub fn run(path: &Path, args: CanonArgs) -> Result<(), String> {
     }
 }
 
+/// 정본화된 `CanonProgram`(씨앗·핀·몸통·span)을 `ddn.ast.v1` JSON으로 직렬화한다.
+fn build_json_ast(source: &str, path: &Path) -> Result<String, CanonError> {
+    let input = prepare_frontdoor_canon_input(source);
+    let file_label = path.to_string_lossy().replace('\\', "/");
+    let mut program = ddonirang_lang::parse(&input.prepared, &file_label)
+        .map_err(|err| CanonError::new(err.code(), err.message))?;
+    ddonirang_lang::canonicalize(&mut program)
+        .map_err(|err| CanonError::new(err.code(), err.message))?;
+    let doc = json!({
+        "schema": "ddn.ast.v1",
+        "file": file_label,
+        "items": program.items,
+    });
+    let text = serde_json::to_string_pretty(&doc)
+        .map_err(|e| CanonError::new("E_CLI_CANON", format!("AST 직렬화 실패: {}", e)))?;
+    Ok(format!("{}\n", text))
+}
+
 fn canonicalize_ddn_strict(
     source: &str,
     _path: &Path,
@@ -322,9 +362,18 @@ fn ensure_no_inplace_fixits(
     Ok(())
 }
 
+/// 띄어쓰기 fix-it은 정본 출력이 이미 반영하므로 자동 반영을 막지 않는다.
 fn has_fixits(fixits_json: &str) -> bool {
     let trimmed = fixits_json.trim();
-    !(trimmed.is_empty() || trimmed == "[]")
+    if trimmed.is_empty() || trimmed == "[]" {
+        return false;
+    }
+    match serde_json::from_str::<Vec<serde_json::Value>>(trimmed) {
+        Ok(entries) => entries
+            .iter()
+            .any(|entry| entry["code"] != ddonirang_lang::term_map::SPACING_LINT_CODE),
+        Err(_) => true,
+    }
 }
 
 fn is_same_path(left: &Path, right: &Path) -> bool {
@@ -334,6 +383,18 @@ fn is_same_path(left: &Path, right: &Path) -> bool {
     }
 }
 
+fn write_emit_text(args: &CanonArgs, text: &str) -> Result<(), String> {
+    if let Some(out_path) = args.out_dir.as_ref() {
+        if let Some(parent) = out_path.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("E_CLI_WRITE {}", e))?;
+        }
+        fs::write(out_path, text).map_err(|e| format!("E_CLI_WRITE {}", e))?;
+    } else {
+        print!("{}", text);
+    }
+    Ok(())
+}
+
 fn write_emit(
     path: &Path,
     args: &CanonArgs,
@@ -422,6 +483,7 @@ fn write_emit(
             fs::write(fixits_path, fixits_json).map_err(|e| format!("E_CLI_WRITE {}", e))?;
             Ok(())
         }
+        EmitKind::JsonAst => Err("E_CLI_CANON json-ast는 AST 경로에서만 출력합니다".to_string()),
     }
 }
 
@@ -470,6 +532,155 @@ fn is_canon_match(source: &str, canon: &str) -> bool {
     normalized.trim_end() == source.trim_end()
 }
 
+/// `--diff`: 원본과 정본 출력의 unified diff를 찍는다. 차이가 있으면 실패로 돌려준다.
+fn print_canon_diff(path: &Path, source: &str, canon: &str) -> Result<(), String> {
+    if is_canon_match(source, canon) {
+        return Ok(());
+    }
+    let label = path.display().to_string().replace('\\', "/");
+    print!("{}", unified_diff(source, canon, &label));
+    Err(CanonError::new(
+        "E_CANON_DIFF",
+        format!("정본 차이 있음: {}", path.display()),
+    )
+    .to_string())
+}
+
+const DIFF_CONTEXT: usize = 3;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum DiffOp {
+    Keep,
+    Del,
+    Add,
+}
+
+fn unified_diff(old: &str, new: &str, label: &str) -> String {
+    let a = old.trim_end().lines().collect::<Vec<_>>();
+    let b = new.trim_end().lines().collect::<Vec<_>>();
+    // (op, a 줄 번호, b 줄 번호)
+    let ops = diff_lines(&a, &b);
+    if ops.iter().all(|(op, _, _)| *op == DiffOp::Keep) {
+        return String::new();
+    }
+    let mut out = format!("--- {label}\n+++ {label}\n");
+    let mut idx = 0;
+    while idx < ops.len() {
+        if ops[idx].0 == DiffOp::Keep {
+            idx += 1;
+            continue;
+        }
+        let start = idx.saturating_sub(DIFF_CONTEXT);
+        // 변경 사이의 같은 줄이 DIFF_CONTEXT*2 줄 이하면 같은 덩어리로 묶는다.
+        let mut last_change = idx;
+        let mut cursor = idx + 1;
+        while cursor < ops.len() && cursor - last_change <= DIFF_CONTEXT * 2 + 1 {
+            if ops[cursor].0 != DiffOp::Keep {
+                last_change = cursor;
+            }
+            cursor += 1;
+        }
+        let end = (last_change + 1 + DIFF_CONTEXT).min(ops.len());
+        let hunk = &ops[start..end];
+        let old_start = hunk.iter().map(|(_, ai, _)| *ai).next().unwrap_or(0);
+        let new_start = hunk.iter().map(|(_, _, bi)| *bi).next().unwrap_or(0);
+        let old_len = hunk.iter().filter(|(op, _, _)| *op != DiffOp::Add).count();
+        let new_len = hunk.iter().filter(|(op, _, _)| *op != DiffOp::Del).count();
+        out.push_str(&format!(
+            "@@ -{},{} +{},{} @@\n",
+            hunk_start(old_start, old_len),
+            old_len,
+            hunk_start(new_start, new_len),
+            new_len
+        ));
+        for (op, ai, bi) in hunk {
+            match op {
+                DiffOp::Keep => out.push_str(&format!(" {}\n", a[*ai])),
+                DiffOp::Del => out.push_str(&format!("-{}\n", a[*ai])),
+                DiffOp::Add => out.push_str(&format!("+{}\n", b[*bi])),
+            }
+        }
+        idx = end;
+    }
+    out
+}
+
+fn hunk_start(zero_based: usize, len: usize) -> usize {
+    if len == 0 {
+        zero_based
+    } else {
+        zero_based + 1
+    }
+}
+
+/// 줄 단위 Myers 차이로 편집 순서를 만든다.
+///
+/// 편집 거리 D에 대해 O((N+M)·D) 시간, O(D²) 기억만 쓴다.
+fn diff_lines(a: &[&str], b: &[&str]) -> Vec<(DiffOp, usize, usize)> {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let max = n + m;
+    let offset = max + 1;
+    let mut v = vec![0isize; (2 * max + 3) as usize];
+    // trace[d]는 d번째 단계 직전의 v[-d..=d]다.
+    let mut trace: Vec<Vec<isize>> = Vec::new();
+    'search: for d in 0..=max {
+        trace.push(v[(offset - d) as usize..=(offset + d) as usize].to_vec());
+        let mut k = -d;
+        while k <= d {
+            let at = |k: isize| v[(offset + k) as usize];
+            let mut x = if k == -d || (k != d && at(k - 1) < at(k + 1)) {
+                at(k + 1)
+            } else {
+                at(k - 1) + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[(offset + k) as usize] = x;
+            if x >= n && y >= m {
+                break 'search;
+            }
+            k += 2;
+        }
+    }
+    let mut ops = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for (d, row) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let at = |k: isize| row[(k + d) as usize];
+        let k = x - y;
+        let prev_k = if k == -d || (k != d && at(k - 1) < at(k + 1)) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let (prev_x, prev_y) = if d == 0 {
+            (0, 0)
+        } else {
+            let prev_x = at(prev_k);
+            (prev_x, prev_x - prev_k)
+        };
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            ops.push((DiffOp::Keep, x as usize, y as usize));
+        }
+        if d > 0 {
+            if x == prev_x {
+                ops.push((DiffOp::Add, x as usize, (y - 1) as usize));
+            } else {
+                ops.push((DiffOp::Del, (x - 1) as usize, y as usize));
+            }
+        }
+        x = prev_x;
+        y = prev_y;
+    }
+    ops.reverse();
+    ops
+}
+
 fn diag_ok_line() -> String {
     format!(
         "{{\"kind\":\"canon\",\"level\":\"info\",\"code\":\"OK\",\"message\":\"{}\"}}",
@@ -524,6 +735,7 @@ fn build_fixits_json(source: &str, path: &Path) -> String {
     let mut entries: Vec<FixitEntry> = Vec::new();
 
     collect_legacy_term_fixits(&tokens, &file_label, &mut entries);
+    collect_spacing_fixits(source, &file_label, &mut entries);
     collect_surface_fixits(source, &file_label, &mut entries);
     let mut parsed_fixit = false;
     if let Err(err) = Parser::parse_with_default_root(tokens, "살림") {
@@ -615,6 +827,38 @@ fn collect_legacy_term_fixits(
     }
 }
 
+/// `나이 : 수`처럼 띄어쓰기가 어긋난 복합 식별자를 정본 꼴로 바꾸는 fix-it. 찾는 규칙은 `term_map`이 정한다.
+fn collect_spacing_fixits(source: &str, file_label: &str, entries: &mut Vec<FixitEntry>) {
+    for spacing in ddonirang_lang::term_map::find_compound_spacing(source) {
+        let (start_line, start_col) = byte_line_col(source, spacing.start);
+        let (end_line, end_col) = byte_line_col(source, spacing.end);
+        entries.push(FixitEntry {
+            file: file_label.to_string(),
+            start_line,
+            start_col,
+            end_line,
+            end_col: end_col.saturating_sub(1),
+            code: ddonirang_lang::term_map::SPACING_LINT_CODE.to_string(),
+            message: format!(
+                "복합 식별자 `{}`는 `{}`로 붙여 씁니다",
+                spacing.surface, spacing.canonical
+            ),
+            suggestion_kind: "replace",
+            old: Some(spacing.surface),
+            new: Some(spacing.canonical),
+            note: None,
+        });
+    }
+}
+
+/// 바이트 위치를 1부터 세는 (줄, 글자 칸)으로 바꾼다.
+fn byte_line_col(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset.min(source.len())];
+    let line_start = before.rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+    let line = before.matches('\n').count() + 1;
+    (line, before[line_start..].chars().count() + 1)
+}
+
 fn collect_surface_fixits(source: &str, file_label: &str, entries: &mut Vec<FixitEntry>) {
     const DEPRECATED_HEADER_KEYWORDS: &[&str] = &[
         "채비", "설정", "보개", "모양", "슬기", "반복", "동안", "대해",
@@ -1008,6 +1252,7 @@ mod tests {
             diag_jsonl: None,
             meta_out: None,
             check: false,
+            diff: false,
         };
         run(&src, args).expect("run canon");
         let text = fs::read_to_string(&out).expect("read out");
@@ -1026,6 +1271,7 @@ mod tests {
             diag_jsonl: None,
             meta_out: None,
             check: false,
+            diff: false,
         };
         let err = run(&src, args).expect_err("canon must fail");
         let _ = fs::remove_file(src);
@@ -1043,6 +1289,7 @@ mod tests {
             diag_jsonl: None,
             meta_out: None,
             check: false,
+            diff: false,
         };
         run(&src, args).expect("run canon");
         let text = fs::read_to_string(&out).unwrap_or_default();
@@ -1051,6 +1298,27 @@ mod tests {
         text
     }
 
+    #[test]
+    fn spacing_fixit_rewrites_misspaced_compound_ident() {
+        let source =
+            "실행정책 {\n  슬기훅정책: 실행.\n}.\n채비 {\n  나이 : 수 <- 10.\n  키:수 <- 3.\n}.\n";
+        let text = build_fixits_json(source, Path::new("spacing.ddn"));
+        let entries: Vec<serde_json::Value> = serde_json::from_str(&text).expect("fixits json");
+        let spacing: Vec<_> = entries
+            .iter()
+            .filter(|entry| entry["code"] == "STYLE-LINT-SPACING")
+            .collect();
+        assert_eq!(spacing.len(), 1, "{text}");
+        let entry = spacing[0];
+        assert_eq!(entry["span"]["start_line"], 5);
+        assert_eq!(entry["span"]["start_col"], 3);
+        assert_eq!(entry["span"]["end_col"], 8);
+        assert_eq!(entry["suggestion"]["kind"], "replace");
+        assert_eq!(entry["suggestion"]["old"], "나이 : 수");
+        assert_eq!(entry["suggestion"]["new"], "나이:수");
+        assert!(!has_fixits(&text));
+    }
+
     #[test]
     fn lifecycle_fixit_candidate_uses_next_available_suffix() {
         let source = r#"
@@ -1169,6 +1437,7 @@ mod tests {
             EmitKind::MaegimControlJson,
             EmitKind::FixitsJson,
             EmitKind::Both,
+            EmitKind::JsonAst,
         ];
         for emit in emits {
             let err = run_expect_error(source, emit, "legacy_header_all_emit");
@@ -1179,10 +1448,88 @@ mod tests {
         }
     }
 
+    #[test]
+    fn canon_json_ast_emits_seed_params_and_statements() {
+        let source = "(x:수, y:수) 더하:셈씨 = {\n    x + y 돌려줘.\n}\n";
+        let text = run_emit_and_read(source, EmitKind::JsonAst, "json_ast_emit");
+        let doc: serde_json::Value = serde_json::from_str(&text).expect("json-ast parse");
+        assert_eq!(doc["schema"], "ddn.ast.v1");
+        let seed = &doc["items"][0]["SeedDef"];
+        assert_eq!(seed["canonical_name"], "더하");
+        assert_eq!(seed["params"].as_array().map(Vec::len), Some(2));
+        let stmts = seed["body"]["stmts"].as_array().expect("stmts");
+        assert!(!stmts.is_empty(), "text={text}");
+        assert!(seed["span"]["end"].as_u64().unwrap_or(0) > 0, "text={text}");
+    }
+
     #[test]
     fn canon_accepts_boim_surface_for_ddn_emit() {
         let source = "보임 { x: 1. }.";
         let text = run_emit_and_read(source, EmitKind::Ddn, "boim_ddn_emit");
         assert!(text.contains("보임"), "text={text:?}");
     }
+
+    fn run_diff(source: &str, name: &str) -> Result<(), String> {
+        let src = write_temp_ddn(source, name);
+        let args = CanonArgs {
+            emit: EmitKind::Ddn,
+            out_dir: None,
+            bridge: None,
+            fixits_json: None,
+            diag_jsonl: None,
+            meta_out: None,
+            check: false,
+            diff: true,
+        };
+        let result = run(&src, args);
+        let _ = fs::remove_file(src);
+        result
+    }
+
+    #[test]
+    fn canon_diff_reports_changes_and_fails() {
+        let source = "채비 {\n  x:수    <- 1.\n}.\n(매마디)마다 {\n  x <- x+1.\n}.\n";
+        let err = run_diff(source, "diff_needs_canon").expect_err("diff must fail");
+        assert!(err.contains("E_CANON_DIFF"), "err={err}");
+
+        let (ddn, _, _) =
+            canonicalize_ddn_strict(source, Path::new("diff.ddn"), false).expect("canon");
+        let diff = unified_diff(source, &ddn, "diff.ddn");
+        assert!(diff.starts_with("--- diff.ddn\n+++ diff.ddn\n@@ -1,6 +1,6 @@\n"));
+        assert!(
+            diff.contains("\n-  x:수    <- 1.\n+  x:수 <- 1.\n"),
+            "diff={diff}"
+        );
+        assert!(
+            diff.contains("\n-  x <- x+1.\n+  x <- x + 1.\n"),
+            "diff={diff}"
+        );
+    }
+
+    #[test]
+    fn canon_unified_diff_keeps_three_context_lines_per_hunk() {
+        let old = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\nk\nl\nm\nn\no\np\n";
+        let new = "a\nB\nc\nd\ne\nf\ng\nh\ni\nj\nk\nl\nm\nn\nO\np\n";
+        assert_eq!(
+            unified_diff(old, new, "x.ddn"),
+            "--- x.ddn\n+++ x.ddn\n\
+             @@ -1,5 +1,5 @@\n a\n-b\n+B\n c\n d\n e\n\
+             @@ -12,5 +12,5 @@\n l\n m\n n\n-o\n+O\n p\n"
+        );
+        let merged = "a\nB\nc\nd\ne\nf\ng\nh\nI\nj\nk\nl\nm\nn\no\np\n";
+        assert_eq!(
+            unified_diff(old, merged, "x.ddn"),
+            "--- x.ddn\n+++ x.ddn\n\
+             @@ -1,12 +1,12 @@\n a\n-b\n+B\n c\n d\n e\n f\n g\n h\n-i\n+I\n j\n k\n l\n"
+        );
+    }
+
+    #[test]
+    fn canon_diff_is_silent_for_canonical_source() {
+        let source = "채비 {\n  x:수 <- 1.\n}.\n(매마디)마다 {\n  x <- x + 1.\n}.\n";
+        run_diff(source, "diff_already_canon").expect("canonical source has no diff");
+        let (ddn, _, _) =
+            canonicalize_ddn_strict(source, Path::new("diff.ddn"), false).expect("canon");
+        assert_eq!(unified_diff(source, &ddn, "diff.ddn"), "");
+    }
 }
@@ -1,4 +1,4 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::{
@@ -8,7 +8,9 @@ use std::{
 
 use crate::cli::frontdoor_parse::{parse_program_for_runtime, FrontdoorParseFailure};
 use crate::cli::run::RunError;
-use crate::lang::ast::{Expr, Literal, Stmt};
+use crate::cli::scan::call_graph_for_program;
+use crate::lang::ast::{Expr, Literal, Program, Stmt};
+use crate::lang::span::Span;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum TypeKind {
@@ -40,18 +42,51 @@ struct SchemaEntry {
     type_name: String,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CheckFormat {
+    Human,
+    Sarif,
+}
+
 pub struct CheckArgs {
     pub emit_schema: bool,
+    pub format: CheckFormat,
+    pub out: Option<PathBuf>,
+    /// 부르는 곳이 없어도 되는 진입 씨앗 이름. 없으면 `시작`.
+    pub entry_seed: Option<String>,
+}
+
+/// 진입 씨앗 기본 이름.
+const DEFAULT_ENTRY_SEED: &str = "시작";
+/// 씨앗 설명글(`///`)에 이 줄이 있으면 DEAD-SEED를 내지 않는다.
+const DEAD_SEED_ALLOW_MARKER: &str = "허용: DEAD-SEED";
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+struct SarifResult {
+    rule_id: String,
+    level: &'static str,
+    message: String,
+    region: Option<(usize, usize, usize, usize)>,
 }
 
 pub fn run(file: &Path, args: CheckArgs) -> Result<(), String> {
+    if args.format == CheckFormat::Sarif {
+        return run_sarif(file, &args);
+    }
     let source = fs::read_to_string(file).map_err(|e| e.to_string())?;
-    let (program, _) = parse_program_for_runtime(&source).map_err(|err| match err {
+    let (program, prepared) = parse_program_for_runtime(&source).map_err(|err| match err {
         FrontdoorParseFailure::Guard(e) => e,
         FrontdoorParseFailure::Lex(e) => RunError::Lex(e).format(&file.display().to_string()),
         FrontdoorParseFailure::Parse(e) => RunError::Parse(e).format(&file.display().to_string()),
     })?;
+    for dead in dead_seeds(&program, &prepared, args.entry_seed.as_deref()) {
+        eprintln!("warning: {}", dead.message());
+    }
+    check_symbols(file, &program, args.emit_schema)
+}
 
+fn check_symbols(file: &Path, program: &Program, emit_schema: bool) -> Result<(), String> {
     let mut symbols: BTreeMap<String, TypeKind> = BTreeMap::new();
 
     for stmt in &program.stmts {
@@ -75,7 +110,7 @@ pub fn run(file: &Path, args: CheckArgs) -> Result<(), String> {
         symbols.insert(name, value_type);
     }
 
-    if args.emit_schema {
+    if emit_schema {
         let entries = symbols
             .iter()
             .filter_map(|(name, kind)| {
@@ -91,6 +126,335 @@ pub fn run(file: &Path, args: CheckArgs) -> Result<(), String> {
     Ok(())
 }
 
+/// 진단을 SARIF 2.1.0으로 낸다. 오류가 하나라도 있으면 문서를 쓴 뒤 실패로 돌려준다.
+fn run_sarif(file: &Path, args: &CheckArgs) -> Result<(), String> {
+    let source = fs::read_to_string(file).map_err(|e| e.to_string())?;
+    let file_label = file.display().to_string();
+    let mut results = collect_lang_diagnostics(&source, &file_label);
+    let runtime_check = match parse_program_for_runtime(&source) {
+        Ok((program, prepared)) => {
+            let dead = dead_seeds(&program, &prepared, args.entry_seed.as_deref());
+            let checked = check_symbols(file, &program, args.emit_schema);
+            results.extend(dead.iter().map(DeadSeed::sarif_result));
+            checked
+        }
+        Err(FrontdoorParseFailure::Guard(e)) => Err(e),
+        Err(FrontdoorParseFailure::Lex(e)) => Err(RunError::Lex(e).format(&file_label)),
+        Err(FrontdoorParseFailure::Parse(e)) => Err(RunError::Parse(e).format(&file_label)),
+    };
+    push_check_failure(&mut results, || runtime_check);
+    let text = build_sarif(&file_label.replace('\\', "/"), &results);
+    match &args.out {
+        Some(path) => {
+            if let Some(parent) = path.parent() {
+                if !parent.as_os_str().is_empty() {
+                    fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+                }
+            }
+            fs::write(path, &text).map_err(|e| e.to_string())?;
+        }
+        None => println!("{}", text),
+    }
+    let errors = results.iter().filter(|r| r.level == "error").count();
+    if errors > 0 {
+        return Err(format!("E_CHECK_SARIF_ERRORS count={}", errors));
+    }
+    Ok(())
+}
+
+/// 언어 진단에 오류가 없을 때만 런타임 검사를 돌려 실패를 더한다. 경고만 있으면 검사는 그대로 돈다.
+fn push_check_failure(results: &mut Vec<SarifResult>, check: impl FnOnce() -> Result<(), String>) {
+    if results.iter().any(|r| r.level == "error") {
+        return;
+    }
+    if let Err(err) = check() {
+        let rule_id = err
+            .split_whitespace()
+            .next()
+            .unwrap_or("E_CHECK")
+            .to_string();
+        results.push(SarifResult {
+            rule_id,
+            level: "error",
+            message: err,
+            region: None,
+        });
+    }
+}
+
+fn collect_lang_diagnostics(source: &str, file_label: &str) -> Vec<SarifResult> {
+    let mut program = match ddonirang_lang::parse_with_mode(
+        source,
+        file_label,
+        ddonirang_lang::ParseMode::Strict,
+    ) {
+        Ok(program) => program,
+        Err(err) => return vec![parse_error_result(source, &err)],
+    };
+    match ddonirang_lang::canonicalize(&mut program) {
+        Ok(report) => report
+            .warnings
+            .iter()
+            .map(|warning| {
+                let rule_id = lint_rule_prefix(&warning.message)
+                    .unwrap_or(warning.code)
+                    .to_string();
+                SarifResult {
+                    level: if warning.code.starts_with("E_") {
+                        "error"
+                    } else {
+                        "warning"
+                    },
+                    rule_id,
+                    message: warning.message.clone(),
+                    region: Some(span_region(source, warning.span.start, warning.span.end)),
+                }
+            })
+            .collect(),
+        Err(err) => vec![parse_error_result(source, &err)],
+    }
+}
+
+fn parse_error_result(source: &str, err: &ddonirang_lang::ParseError) -> SarifResult {
+    SarifResult {
+        rule_id: lint_rule_prefix(&err.message)
+            .unwrap_or(err.code())
+            .to_string(),
+        level: "error",
+        message: err.message.clone(),
+        region: Some(span_region(source, err.span.start, err.span.end)),
+    }
+}
+
+struct DeadSeed {
+    name: String,
+    span: Span,
+}
+
+impl DeadSeed {
+    fn message(&self) -> String {
+        format!(
+            "DEAD-SEED: 씨앗 '{}'을(를) 부르는 곳이 없습니다 ({}:{})",
+            self.name, self.span.start_line, self.span.start_col
+        )
+    }
+
+    fn sarif_result(&self) -> SarifResult {
+        SarifResult {
+            rule_id: "DEAD-SEED".to_string(),
+            level: "warning",
+            message: self.message(),
+            region: Some((
+                self.span.start_line,
+                self.span.start_col,
+                self.span.end_line,
+                self.span.end_col,
+            )),
+        }
+    }
+}
+
+/// 파일 안 어느 호출도 가리키지 않는 최상위 씨앗을 모은다. 자기 자신을 부르는 것은 치지 않고,
+/// 진입 씨앗과 설명글에 `허용: DEAD-SEED`를 적은 씨앗은 뺀다.
+fn dead_seeds(program: &Program, prepared: &str, entry_seed: Option<&str>) -> Vec<DeadSeed> {
+    let entry_seed = entry_seed.unwrap_or(DEFAULT_ENTRY_SEED);
+    let graph = call_graph_for_program(program);
+    let mut signal_refs = BTreeSet::new();
+    collect_signal_refs(&program.stmts, &mut signal_refs);
+    let mut out = Vec::new();
+    for stmt in &program.stmts {
+        let Stmt::SeedDef { name, span, .. } = stmt else {
+            continue;
+        };
+        if name == entry_seed
+            || signal_refs.contains(name)
+            || graph
+                .edges
+                .iter()
+                .any(|(from, to)| to == name && from != name)
+        {
+            continue;
+        }
+        let allowed = line_start_offset(prepared, span.start_line)
+            .and_then(|offset| ddonirang_lang::lexer::leading_doc_comment(prepared, offset))
+            .is_some_and(|doc| {
+                doc.lines()
+                    .any(|line| line.trim() == DEAD_SEED_ALLOW_MARKER)
+            });
+        if !allowed {
+            out.push(DeadSeed {
+                name: name.clone(),
+                span: *span,
+            });
+        }
+    }
+    out
+}
+
+/// `~~>`로 신호를 받는 씨와 `받으면`이 기다리는 알림 갈래를 모은다. 부름은 아니어도 쓰이는 씨다.
+fn collect_signal_refs(stmts: &[Stmt], out: &mut BTreeSet<String>) {
+    for stmt in stmts {
+        match stmt {
+            Stmt::Send {
+                receiver: Expr::Path(path),
+                ..
+            } => {
+                if let Some(name) = path.segments.last() {
+                    out.insert(name.clone());
+                }
+            }
+            Stmt::Receive { kind, body, .. } => {
+                if let Some(kind) = kind {
+                    out.insert(kind.clone());
+                }
+                collect_signal_refs(body, out);
+            }
+            Stmt::If {
+                then_body,
+                else_body,
+                ..
+            } => {
+                collect_signal_refs(then_body, out);
+                if let Some(else_body) = else_body {
+                    collect_signal_refs(else_body, out);
+                }
+            }
+            Stmt::Choose {
+                branches,
+                else_body,
+                ..
+            } => {
+                for branch in branches {
+                    collect_signal_refs(&branch.body, out);
+                }
+                if let Some(else_body) = else_body {
+                    collect_signal_refs(else_body, out);
+                }
+            }
+            Stmt::Contract {
+                then_body,
+                else_body,
+                ..
+            } => {
+                if let Some(then_body) = then_body {
+                    collect_signal_refs(then_body, out);
+                }
+                collect_signal_refs(else_body, out);
+            }
+            Stmt::SeedDef { body, .. }
+            | Stmt::HookWhenBecomes { body, .. }
+            | Stmt::HookWhile { body, .. }
+            | Stmt::While { body, .. }
+            | Stmt::ForEach { body, .. }
+            | Stmt::Hook { body, .. }
+            | Stmt::OpenBlock { body, .. }
+            | Stmt::BeatBlock { body, .. }
+            | Stmt::LifecycleBlock { body, .. }
+            | Stmt::Repeat { body, .. }
+            | Stmt::Quantifier { body, .. } => collect_signal_refs(body, out),
+            _ => {}
+        }
+    }
+}
+
+fn line_start_offset(source: &str, line: usize) -> Option<usize> {
+    if line <= 1 {
+        return Some(0);
+    }
+    source
+        .match_indices('\n')
+        .nth(line - 2)
+        .map(|(idx, _)| idx + 1)
+}
+
+/// `NAME-LINT-01: ...`처럼 메시지 앞에 붙은 규칙 id를 꺼낸다.
+fn lint_rule_prefix(message: &str) -> Option<&str> {
+    let (head, _) = message.split_once(':')?;
+    let valid = !head.is_empty()
+        && head.contains('-')
+        && head
+            .chars()
+            .all(|ch| ch.is_ascii_uppercase() || ch.is_ascii_digit() || ch == '-');
+    valid.then_some(head)
+}
+
+/// 바이트 구간 → SARIF region (1부터, 열은 utf16 단위).
+fn span_region(source: &str, start: usize, end: usize) -> (usize, usize, usize, usize) {
+    let (start_line, start_col) = line_col(source, start);
+    let (end_line, end_col) = line_col(source, end.max(start));
+    (start_line, start_col, end_line, end_col)
+}
+
+fn line_col(source: &str, byte_pos: usize) -> (usize, usize) {
+    let target = byte_pos.min(source.len());
+    let mut line = 1usize;
+    let mut col = 1usize;
+    for (idx, ch) in source.char_indices() {
+        if idx >= target {
+            break;
+        }
+        if ch == '\n' {
+            line += 1;
+            col = 1;
+        } else {
+            col += ch.len_utf16();
+        }
+    }
+    (line, col)
+}
+
+fn build_sarif(file_uri: &str, results: &[SarifResult]) -> String {
+    let mut rule_ids = results
+        .iter()
+        .map(|r| r.rule_id.clone())
+        .collect::<Vec<_>>();
+    rule_ids.sort();
+    rule_ids.dedup();
+    let rules = rule_ids
+        .iter()
+        .map(|id| serde_json::json!({ "id": id }))
+        .collect::<Vec<_>>();
+    let results = results
+        .iter()
+        .map(|result| {
+            let mut location = serde_json::json!({
+                "physicalLocation": {
+                    "artifactLocation": { "uri": file_uri },
+                }
+            });
+            if let Some((start_line, start_col, end_line, end_col)) = result.region {
+                location["physicalLocation"]["region"] = serde_json::json!({
+                    "startLine": start_line,
+                    "startColumn": start_col,
+                    "endLine": end_line,
+                    "endColumn": end_col,
+                });
+            }
+            serde_json::json!({
+                "ruleId": result.rule_id,
+                "level": result.level,
+                "message": { "text": result.message },
+                "locations": [location],
+            })
+        })
+        .collect::<Vec<_>>();
+    let doc = serde_json::json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "teul-cli",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules,
+                }
+            },
+            "results": results,
+        }],
+    });
+    serde_json::to_string_pretty(&doc).unwrap_or_default()
+}
+
 fn expr_type(expr: &Expr, symbols: &BTreeMap<String, TypeKind>) -> TypeKind {
     match expr {
         Expr::Literal(lit, _) => match lit {
@@ -194,9 +558,33 @@ fn escape_json(input: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::schema_path_for;
+    use super::{collect_lang_diagnostics, dead_seeds, push_check_failure, schema_path_for};
+    use crate::cli::frontdoor_parse::parse_program_for_runtime;
     use std::path::Path;
 
+    #[test]
+    fn dead_seed_flags_uncalled_helper_only() {
+        let source = "(x:수) 쓰는셈:셈씨 = {\n  x 돌려줘.\n}\n\n(x:수) 버린셈:셈씨 = {\n  (x)버린셈 돌려줘.\n}\n\n/// 나중에 쓴다.\n/// 허용: DEAD-SEED\n(x:수) 남긴셈:셈씨 = {\n  x 돌려줘.\n}\n\n차림:셈씨 = {\n  (1)쓰는셈 돌려줘.\n}\n";
+        let (program, prepared) = parse_program_for_runtime(source).expect("parse");
+        let dead = dead_seeds(&program, &prepared, Some("차림"));
+        let names: Vec<&str> = dead.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, vec!["버린셈"]);
+        assert!(dead[0].message().starts_with("DEAD-SEED:"));
+        assert_eq!(dead[0].span.start_line, 5);
+    }
+
+    #[test]
+    fn dead_seed_counts_signal_targets_and_receive_hooks() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(
+            "../../pack/lang_consistency_v1/c16_receive_hooks_non_consuming_order_run/input.ddn",
+        );
+        let source = std::fs::read_to_string(path).expect("read pack input");
+        let (program, prepared) = parse_program_for_runtime(&source).expect("parse");
+        let dead = dead_seeds(&program, &prepared, None);
+        let names: Vec<&str> = dead.iter().map(|d| d.name.as_str()).collect();
+        assert!(names.is_empty(), "{names:?}");
+    }
+
     #[test]
     fn schema_path_is_redirected_to_build_cache_zone() {
         let source = Path::new("solutions/seamgrim_ui_mvp/lessons/foo/lesson.ddn");
@@ -206,4 +594,58 @@ mod tests {
         assert!(out_text.ends_with(".ddn.schema.json"));
         assert!(!out_text.contains("/lessons/foo/ddn.schema.json"));
     }
+
+    #[test]
+    fn sarif_reports_reserved_word_rule_and_region() {
+        let source = "\n마디:셈씨 = {\n    1 돌려줘.\n}\n";
+        let results = collect_lang_diagnostics(source, "reserved.ddn");
+        assert_eq!(results.len(), 1);
+        let text = super::build_sarif("reserved.ddn", &results);
+        let doc: serde_json::Value = serde_json::from_str(&text).expect("sarif json");
+        assert_eq!(doc["version"], "2.1.0");
+        let result = &doc["runs"][0]["results"][0];
+        assert_eq!(result["ruleId"], "NAME-LINT-01");
+        assert_eq!(result["level"], "error");
+        let region = &result["locations"][0]["physicalLocation"]["region"];
+        assert_eq!(region["startLine"], 2);
+        assert_eq!(region["startColumn"], 1);
+        assert_eq!(region["endColumn"], 3);
+        assert_eq!(
+            doc["runs"][0]["tool"]["driver"]["rules"][0]["id"],
+            "NAME-LINT-01"
+        );
+    }
+
+    #[test]
+    fn sarif_keeps_type_error_next_to_lint_warning() {
+        let mut results = collect_lang_diagnostics("채비: {\n  x:수 <- 1.\n}\n", "mixed.ddn");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].level, "warning");
+        push_check_failure(&mut results, || {
+            Err("E_CHECK_TYPE_MISMATCH x 수 -> 글".to_string())
+        });
+        let text = super::build_sarif("mixed.ddn", &results);
+        let doc: serde_json::Value = serde_json::from_str(&text).expect("sarif json");
+        let found = doc["runs"][0]["results"]
+            .as_array()
+            .expect("results")
+            .iter()
+            .map(|r| (r["ruleId"].as_str().unwrap(), r["level"].as_str().unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            found,
+            vec![
+                ("W_BLOCK_HEADER_COLON_DEPRECATED", "warning"),
+                ("E_CHECK_TYPE_MISMATCH", "error"),
+            ]
+        );
+    }
+
+    #[test]
+    fn sarif_skips_type_check_after_lang_error() {
+        let mut results =
+            collect_lang_diagnostics("\n마디:셈씨 = {\n    1 돌려줘.\n}\n", "reserved.ddn");
+        push_check_failure(&mut results, || panic!("type check must not run"));
+        assert_eq!(results.len(), 1);
+    }
 }
@@ -1,12 +1,19 @@
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
 use std::path::Path;
 
 use crate::core::geoul::{audit_hash, decode_input_snapshot, GeoulBundleReader};
+use crate::core::state::Key;
+use crate::core::value::Value as StateValue;
+use crate::core::State;
 use ddonirang_core::nurigym::spec::{ActionSpec, ObservationSpec};
+use ddonirang_core::seulgi::intent::intent_kind;
 use hex::encode as hex_encode;
 use serde_json::{Map, Value};
 
 use super::detjson::{sha256_hex, write_text};
+use super::geoul::replay_geoul_states;
+use super::intent::parse_intent_jsonl;
 
 pub fn run_export(
     geoul_dir: &Path,
@@ -17,6 +24,7 @@ pub fn run_export(
     match format {
         "nurigym_v0" => run_export_v0(geoul_dir, out_dir, env_id),
         "nurigym_v1" => run_export_v1(geoul_dir, out_dir, env_id),
+        "csv" => run_export_csv(geoul_dir, out_dir, env_id),
         other => Err(format!("E_DATASET_FORMAT 지원하지 않는 format: {}", other)),
     }
 }
@@ -188,6 +196,163 @@ fn run_export_v1(geoul_dir: &Path, out_dir: &Path, env_id: &str) -> Result<(), S
     Ok(())
 }
 
+/// 한 마디·한 에이전트가 한 행이다. 열은 `madi,agent_id,state_hash`, 관측 칸
+/// (`ObservationSpec::slot_names`), `action,reward,done` 순으로 고정한다.
+/// 마디마다 geoul을 다시 돌린 상태가 그 걸음의 기록이다. 관측 칸은 `관측` 차림을
+/// 칸 순서대로 채우고(모자라면 비우고, 넘치면 오류), 보상은 `보상` 수를 쓴다(없으면 0).
+/// 에이전트와 행동은 geoul의 `intent.jsonl`(target_madi 기준)에서 읽고,
+/// 없으면 에이전트 0 하나에 행동 `none`으로 둔다.
+fn run_export_csv(geoul_dir: &Path, out_dir: &Path, env_id: &str) -> Result<(), String> {
+    fs::create_dir_all(out_dir).map_err(|e| e.to_string())?;
+
+    let (obs_spec, action_spec) = canonical_specs_for_env(env_id);
+    write_text(&out_dir.join("obs_spec.detjson"), &obs_spec.to_detjson())?;
+    write_text(
+        &out_dir.join("action_spec.detjson"),
+        &action_spec.to_detjson(),
+    )?;
+
+    let mut reader = GeoulBundleReader::open(geoul_dir)?;
+    let frame_count = reader.frame_count();
+    if frame_count == 0 {
+        return Err("E_DATASET_EMPTY geoul 로그에 프레임이 없습니다".to_string());
+    }
+    let mut state_hashes = Vec::new();
+    for idx in 0..frame_count {
+        let frame = reader.read_frame(idx)?;
+        state_hashes.push(format!("blake3:{}", hex_encode(frame.header.state_hash)));
+    }
+
+    let slot_count = obs_spec.slot_count as usize;
+    let mut steps = Vec::with_capacity(frame_count as usize);
+    replay_geoul_states(geoul_dir, frame_count - 1, None, |madi, state| {
+        steps.push(csv_step_record(madi, state, slot_count));
+    })?;
+    let steps = steps.into_iter().collect::<Result<Vec<_>, _>>()?;
+
+    let mut actions: BTreeMap<(u64, u64), &'static str> = BTreeMap::new();
+    let mut agents = BTreeSet::new();
+    let intent_path = geoul_dir.join("intent.jsonl");
+    if intent_path.exists() {
+        let mut records = parse_intent_jsonl(&intent_path, None, None)?;
+        records.sort_by_key(|record| (record.target_madi, record.agent_id, record.recv_seq));
+        for record in &records {
+            agents.insert(record.agent_id);
+            actions
+                .entry((record.target_madi, record.agent_id))
+                .or_insert(intent_kind(&record.intent));
+        }
+    }
+    if agents.is_empty() {
+        agents.insert(0);
+    }
+
+    let mut header = vec![
+        "madi".to_string(),
+        "agent_id".to_string(),
+        "state_hash".to_string(),
+    ];
+    header.extend(obs_spec.slot_names());
+    header.extend(["action", "reward", "done"].map(String::from));
+    let mut text = csv_row(&header);
+    for (idx, state_hash) in state_hashes.iter().enumerate() {
+        let madi = idx as u64;
+        let done = idx + 1 == state_hashes.len();
+        for agent_id in &agents {
+            let step = steps.get(idx);
+            let mut row = vec![madi.to_string(), agent_id.to_string(), state_hash.clone()];
+            row.extend(
+                step.map(|step| step.observation.clone())
+                    .unwrap_or_default(),
+            );
+            row.resize(3 + slot_count, String::new());
+            let action = actions.get(&(madi, *agent_id)).copied().unwrap_or("none");
+            let reward = step.map_or_else(|| "0".to_string(), |step| step.reward.clone());
+            row.extend([action.to_string(), reward, done.to_string()]);
+            text.push_str(&csv_row(&row));
+        }
+    }
+
+    write_text(&out_dir.join("dataset.csv"), &text)?;
+    let dataset_hash = format!("sha256:{}", sha256_hex(text.as_bytes()));
+    write_text(
+        &out_dir.join("dataset_hash.txt"),
+        &format!("{}\n", dataset_hash),
+    )?;
+    println!("dataset_hash={}", dataset_hash);
+    Ok(())
+}
+
+/// csv 한 마디의 관측 칸 값과 보상.
+struct CsvStep {
+    observation: Vec<String>,
+    reward: String,
+}
+
+fn csv_step_record(madi: u64, state: &State, slot_count: usize) -> Result<CsvStep, String> {
+    let observation = match state.get(&Key::new("관측".to_string())) {
+        None => Vec::new(),
+        Some(StateValue::List(list)) => list
+            .items
+            .iter()
+            .map(|item| match item {
+                StateValue::Num(quantity) => Ok(quantity.display()),
+                other => Err(format!(
+                    "E_DATASET_OBS 마디 {} 관측 칸은 수여야 합니다: {}",
+                    madi,
+                    other.display()
+                )),
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        Some(other) => {
+            return Err(format!(
+                "E_DATASET_OBS 마디 {} 관측은 차림이어야 합니다: {}",
+                madi,
+                other.display()
+            ))
+        }
+    };
+    if observation.len() > slot_count {
+        return Err(format!(
+            "E_DATASET_OBS 마디 {} 관측 {}칸이 obs_spec slot_count {}보다 많습니다",
+            madi,
+            observation.len(),
+            slot_count
+        ));
+    }
+    let reward = match state.get(&Key::new("보상".to_string())) {
+        None => "0".to_string(),
+        Some(StateValue::Num(quantity)) => quantity.display(),
+        Some(other) => {
+            return Err(format!(
+                "E_DATASET_REWARD 마디 {} 보상은 수여야 합니다: {}",
+                madi,
+                other.display()
+            ))
+        }
+    };
+    Ok(CsvStep {
+        observation,
+        reward,
+    })
+}
+
+fn csv_row(fields: &[String]) -> String {
+    let mut line = fields
+        .iter()
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.clone()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    line.push('\n');
+    line
+}
+
 fn build_episode_header(
     env_id: &str,
     episode_id: u64,
@@ -244,7 +409,7 @@ fn build_dataset_header_v1(
         "actions".to_string(),
         Value::Array(
             action_spec
-                .actions
+                .action_names()
                 .iter()
                 .cloned()
                 .map(Value::String)
@@ -580,27 +745,97 @@ fn canonical_specs_for_env(env_id: &str) -> (ObservationSpec, ActionSpec) {
     match env_id {
         "nurigym.cartpole1d" => (
             ObservationSpec { slot_count: 4 },
-            ActionSpec {
-                actions: vec!["left".to_string(), "right".to_string()],
-            },
+            ActionSpec::discrete(vec!["left".to_string(), "right".to_string()]),
         ),
         "nurigym.pendulum1d" => (
             ObservationSpec { slot_count: 2 },
-            ActionSpec {
-                actions: vec!["left".to_string(), "right".to_string()],
-            },
+            ActionSpec::discrete(vec!["left".to_string(), "right".to_string()]),
         ),
         "nurigym.gridmaze2d" => (
             ObservationSpec { slot_count: 2 },
-            ActionSpec {
-                actions: vec![
-                    "left".to_string(),
-                    "right".to_string(),
-                    "down".to_string(),
-                    "up".to_string(),
-                ],
-            },
+            ActionSpec::discrete(vec![
+                "left".to_string(),
+                "right".to_string(),
+                "down".to_string(),
+                "up".to_string(),
+            ]),
         ),
         _ => (ObservationSpec::default_k64(), ActionSpec::empty()),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{canonical_specs_for_env, run_export};
+    use crate::cli::geoul::write_test_geoul;
+    use std::fs;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    #[test]
+    fn export_csv_has_spec_header_and_row_per_tick_and_agent() {
+        let nonce = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        let root = std::env::temp_dir().join(format!("teul_cli_dataset_csv_{nonce}"));
+        let geoul = root.join("geoul");
+        let source = "채비 {\n  위치:수 <- 0.\n  속도:수 <- 2.\n}.\n(매마디)마다 {\n  위치 <- 위치 + 속도.\n  관측 <- [위치, 속도].\n  보상 <- 위치 - 1.\n}.\n";
+        write_test_geoul(&geoul, source, 3);
+        fs::write(
+            geoul.join("intent.jsonl"),
+            concat!(
+                "{\"accepted_madi\":1,\"agent_id\":1,\"recv_seq\":1,\"kind\":\"Attack\",\"target_id\":9}\n",
+                "{\"accepted_madi\":0,\"agent_id\":2,\"recv_seq\":1,\"kind\":\"Say\",\"text\":\"a\"}\n",
+            ),
+        )
+        .expect("write intents");
+
+        let out = root.join("out");
+        run_export(&geoul, "csv", &out, "nurigym.cartpole1d").expect("export csv");
+        let text = fs::read_to_string(out.join("dataset.csv")).expect("csv");
+        let lines = text.lines().collect::<Vec<_>>();
+
+        let (obs_spec, _) = canonical_specs_for_env("nurigym.cartpole1d");
+        let expected_header = ["madi", "agent_id", "state_hash"]
+            .map(String::from)
+            .into_iter()
+            .chain(obs_spec.slot_names())
+            .chain(["action", "reward", "done"].map(String::from))
+            .collect::<Vec<_>>()
+            .join(",");
+        assert_eq!(lines[0], expected_header);
+        assert_eq!(lines.len() - 1, 3 * 2);
+
+        let cells = |line: &str| line.split(',').map(String::from).collect::<Vec<_>>();
+        let first = cells(lines[1]);
+        assert_eq!((first[0].as_str(), first[1].as_str()), ("0", "1"));
+        // 관측 칸은 obs_spec 순서대로 `관측` 차림을 채우고 남는 칸은 비운다.
+        assert_eq!(first[3..7], ["2", "2", "", ""].map(String::from));
+        assert_eq!(first[first.len() - 3], "none");
+        // 보상은 그 마디 상태의 `보상`이다.
+        assert_eq!(first[first.len() - 2], "1");
+        assert_eq!(cells(lines[5])[3..4], ["6".to_string()]);
+        assert_eq!(cells(lines[5])[8], "5");
+        assert_eq!(cells(lines[2])[1..2], ["2".to_string()]);
+        assert!(lines[2].contains(",Say,"));
+        assert!(lines[3].contains(",Attack,"));
+        assert!(lines[6].ends_with(",true"));
+        let _ = fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn export_csv_rejects_observation_wider_than_spec() {
+        let nonce = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        let root = std::env::temp_dir().join(format!("teul_cli_dataset_csv_wide_{nonce}"));
+        let geoul = root.join("geoul");
+        let source = "(매마디)마다 {\n  관측 <- [1, 2, 3].\n}.\n";
+        write_test_geoul(&geoul, source, 1);
+        let err = run_export(&geoul, "csv", &root.join("out"), "nurigym.pendulum1d")
+            .expect_err("3 slots > 2");
+        let _ = fs::remove_dir_all(root);
+        assert!(err.starts_with("E_DATASET_OBS"), "{err}");
+    }
+}
@@ -47,13 +47,8 @@ pub fn run_case(options: DotbogiCaseOptions<'_>) -> Result<(), String> {
     if !view_meta.is_object() {
         return Err("E_DOTBOGI_CASE_VIEW_META dotbogi.view_meta는 object여야 합니다".to_string());
     }
-    let events = dotbogi
-        .get("events")
-        .cloned()
-        .unwrap_or(JsonValue::Array(Vec::new()));
-    if !events.is_array() {
-        return Err("E_DOTBOGI_CASE_EVENTS dotbogi.events는 list여야 합니다".to_string());
-    }
+    let batches = read_event_batches(dotbogi)?;
+    let events = JsonValue::Array(batches.iter().flatten().cloned().collect());
 
     if is_forbidden_state_write(dotbogi.get("state_write")) {
         return Err("E_DOTBOGI_STATE_WRITE_FORBIDDEN dotbogi state write is forbidden".to_string());
@@ -117,8 +112,28 @@ pub fn run_case(options: DotbogiCaseOptions<'_>) -> Result<(), String> {
             .get("state")
             .cloned()
             .ok_or_else(|| "E_DOTBOGI_CASE_STATE input.state가 필요합니다".to_string())?;
+        if !state.is_object() {
+            return Err("E_DOTBOGI_CASE_STATE input.state는 object여야 합니다".to_string());
+        }
+        let rule_map = parse_event_rules(roundtrip)?;
         let mut state_mut = state;
-        apply_roundtrip(roundtrip, &events, &mut state_mut)?;
+        let mut batch_reports = Vec::with_capacity(batches.len());
+        let mut event_offset = 0;
+        for (batch_idx, batch) in batches.iter().enumerate() {
+            apply_events(&rule_map, batch, event_offset, &mut state_mut)?;
+            event_offset += batch.len();
+            batch_reports.push(JsonValue::Object(Map::from_iter(vec![
+                ("batch".to_string(), JsonValue::Number(batch_idx.into())),
+                ("after_state".to_string(), state_mut.clone()),
+                (
+                    "after_state_hash".to_string(),
+                    JsonValue::String(hash_sha256(&state_mut)?),
+                ),
+            ])));
+        }
+        if dotbogi.contains_key("event_batches") {
+            report_map.insert("batches".to_string(), JsonValue::Array(batch_reports));
+        }
         let after_state_hash = hash_sha256(&state_mut)?;
         report_map.insert("after_state".to_string(), state_mut.clone());
         report_map.insert(
@@ -195,14 +210,41 @@ fn canonicalize_json(value: &JsonValue) -> JsonValue {
     }
 }
 
-fn apply_roundtrip(
-    roundtrip: &Map<String, JsonValue>,
-    events: &JsonValue,
-    state: &mut JsonValue,
-) -> Result<(), String> {
-    if !state.is_object() {
-        return Err("E_DOTBOGI_CASE_STATE input.state는 object여야 합니다".to_string());
+/// `dotbogi.events`(한 묶음) 또는 `dotbogi.event_batches`(차례로 적용할 여러 묶음)를 읽는다.
+fn read_event_batches(dotbogi: &Map<String, JsonValue>) -> Result<Vec<Vec<JsonValue>>, String> {
+    match (dotbogi.get("events"), dotbogi.get("event_batches")) {
+        (Some(_), Some(_)) => Err(
+            "E_DOTBOGI_CASE_EVENTS dotbogi.events와 dotbogi.event_batches는 함께 쓸 수 없습니다"
+                .to_string(),
+        ),
+        (None, Some(batches)) => {
+            let batches = batches.as_array().ok_or_else(|| {
+                "E_DOTBOGI_CASE_EVENT_BATCHES dotbogi.event_batches는 list여야 합니다".to_string()
+            })?;
+            batches
+                .iter()
+                .enumerate()
+                .map(|(idx, batch)| {
+                    batch.as_array().cloned().ok_or_else(|| {
+                        format!(
+                            "E_DOTBOGI_CASE_EVENT_BATCHES dotbogi.event_batches[{}]는 list여야 합니다",
+                            idx
+                        )
+                    })
+                })
+                .collect()
+        }
+        (events, None) => match events {
+            None => Ok(vec![Vec::new()]),
+            Some(JsonValue::Array(items)) => Ok(vec![items.clone()]),
+            Some(_) => Err("E_DOTBOGI_CASE_EVENTS dotbogi.events는 list여야 합니다".to_string()),
+        },
     }
+}
+
+fn parse_event_rules(
+    roundtrip: &Map<String, JsonValue>,
+) -> Result<std::collections::BTreeMap<String, Vec<JsonValue>>, String> {
     let rules = roundtrip
         .get("event_rules")
         .and_then(|v| v.as_array())
@@ -241,8 +283,18 @@ fn apply_roundtrip(
             .to_vec();
         rule_map.insert(event_type, ops);
     }
+    Ok(rule_map)
+}
 
-    for (event_idx, event) in events.as_array().into_iter().flatten().enumerate() {
+/// 사건들을 차례로 state에 적용한다. `event_offset`은 오류에 찍을 전체 사건 번호의 시작이다.
+fn apply_events(
+    rule_map: &std::collections::BTreeMap<String, Vec<JsonValue>>,
+    events: &[JsonValue],
+    event_offset: usize,
+    state: &mut JsonValue,
+) -> Result<(), String> {
+    for (idx, event) in events.iter().enumerate() {
+        let event_idx = event_offset + idx;
         let Some(event_obj) = event.as_object() else {
             continue;
         };
@@ -492,6 +544,70 @@ mod tests {
         assert_eq!(after_state_doc, after_state);
     }
 
+    #[test]
+    fn run_case_applies_event_batches_in_order() {
+        let dir = temp_dir("batches");
+        let input_path = dir.join("case.detjson");
+        let report_path = dir.join("report.detjson");
+        let doc = serde_json::json!({
+            "schema": "ddn.dotbogi.case.v1",
+            "input": {
+                "schema": "dotbogi.input.v1",
+                "state": { "player": { "hp": 10 } }
+            },
+            "dotbogi": {
+                "view_meta": {},
+                "event_batches": [
+                    [{ "type": "방패획득" }],
+                    [{ "type": "방패강화" }, { "type": "피격" }]
+                ]
+            },
+            "roundtrip": {
+                "event_rules": [
+                    {
+                        "event_type": "방패획득",
+                        "ops": [{ "op": "set", "path": "player.shield", "value": 3 }]
+                    },
+                    {
+                        "event_type": "방패강화",
+                        "ops": [{ "op": "add", "path": "player.shield", "value": 2 }]
+                    },
+                    {
+                        "event_type": "피격",
+                        "ops": [{ "op": "add", "path": "player.hp", "value": -4 }]
+                    }
+                ]
+            }
+        });
+        write_json(&input_path, &doc);
+
+        run_case(DotbogiCaseOptions {
+            input: &input_path,
+            out: None,
+            after_state_out: None,
+            report_out: Some(&report_path),
+        })
+        .expect("run_case");
+
+        let report: JsonValue =
+            serde_json::from_str(&fs::read_to_string(&report_path).expect("read report"))
+                .expect("parse report");
+        let batches = report["batches"].as_array().expect("batches");
+        assert_eq!(batches.len(), 2);
+        assert_eq!(
+            batches[0]["after_state"],
+            serde_json::json!({ "player": { "hp": 10, "shield": 3 } })
+        );
+        // 두 번째 묶음의 방패강화는 첫 묶음이 만든 shield 위에 더해진다.
+        let final_state = serde_json::json!({ "player": { "hp": 6, "shield": 5 } });
+        assert_eq!(batches[1]["after_state"], final_state);
+        assert_eq!(report["after_state"], final_state);
+        assert_eq!(
+            report["output"]["events"].as_array().expect("events").len(),
+            3
+        );
+    }
+
     #[test]
     fn run_case_rejects_state_write() {
         let dir = temp_dir("write_forbidden");
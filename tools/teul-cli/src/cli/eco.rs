@@ -91,7 +91,78 @@ struct ShockSpec {
     scope: ShockScope,
 }
 
+struct LoadedRunner {
+    spec: MacroMicroRunnerInput,
+    shock: Option<ShockSpec>,
+    macro_source: String,
+    micro_source: String,
+}
+
+struct DiagnosticOutcome {
+    divergence_tick: Option<u64>,
+    entry: JsonValue,
+}
+
 pub fn run_macro_micro(input: &Path, out: Option<&Path>) -> Result<(), String> {
+    let runner = load_macro_micro(input)?;
+    let spec = &runner.spec;
+    let shock_spec = runner.shock.as_ref();
+    let shock_tick = shock_spec.map(|shock| shock.at_tick);
+    let results = evaluate_macro_micro(&runner, shock_spec)?
+        .into_iter()
+        .map(|outcome| outcome.entry)
+        .collect::<Vec<_>>();
+
+    let mut report = BTreeMap::new();
+    report.insert(
+        "schema".to_string(),
+        JsonValue::String("ddn.runner_report.v0".to_string()),
+    );
+    report.insert(
+        "seed".to_string(),
+        JsonValue::Number(serde_json::Number::from(spec.seed)),
+    );
+    report.insert(
+        "ticks".to_string(),
+        JsonValue::Number(serde_json::Number::from(spec.ticks)),
+    );
+    report.insert(
+        "shock_tick".to_string(),
+        match shock_tick {
+            Some(value) => JsonValue::Number(serde_json::Number::from(value)),
+            None => JsonValue::Null,
+        },
+    );
+    if let Some(shock) = shock_spec {
+        report.insert(
+            "shock_target".to_string(),
+            JsonValue::String(shock.target.clone()),
+        );
+        report.insert(
+            "shock_delta".to_string(),
+            JsonValue::String(shock.delta.format()),
+        );
+        report.insert(
+            "shock_scope".to_string(),
+            JsonValue::String(shock.scope.label().to_string()),
+        );
+        if let Some(kind) = shock.kind.as_ref() {
+            report.insert("shock_type".to_string(), JsonValue::String(kind.clone()));
+        }
+    }
+    report.insert("results".to_string(), JsonValue::Array(results));
+    let report_json = JsonValue::Object(report.into_iter().collect::<Map<String, JsonValue>>());
+    let report_text = serde_json::to_string(&report_json)
+        .map_err(|e| format!("E_ECO_RUNNER_REPORT_JSON {}", e))?;
+
+    let out_path = resolve_report_path(input, spec.report_path.as_deref(), out);
+    ensure_parent_dir(&out_path, "E_ECO_RUNNER_DIR")?;
+    write_text(&out_path, &(report_text + "\n"))?;
+    println!("eco_runner_report={}", out_path.display());
+    Ok(())
+}
+
+fn load_macro_micro(input: &Path) -> Result<LoadedRunner, String> {
     let text = fs::read_to_string(input)
         .map_err(|e| format!("E_ECO_RUNNER_READ {} {}", input.display(), e))?;
     let spec: MacroMicroRunnerInput =
@@ -107,7 +178,7 @@ pub fn run_macro_micro(input: &Path, out: Option<&Path>) -> Result<(), String> {
     if spec.diagnostics.is_empty() {
         return Err("E_ECO_RUNNER_DIAG diagnostics는 최소 1개 이상이어야 합니다".to_string());
     }
-    let shock_spec = parse_shock(spec.shock.as_ref(), spec.ticks)?;
+    let shock = parse_shock(spec.shock.as_ref(), spec.ticks)?;
 
     let base_dir = input.parent().unwrap_or_else(|| Path::new("."));
     let macro_path = resolve_model_path(base_dir, &spec.models.macro_model);
@@ -117,15 +188,24 @@ pub fn run_macro_micro(input: &Path, out: Option<&Path>) -> Result<(), String> {
     let micro_source = fs::read_to_string(&micro_path)
         .map_err(|e| format!("E_ECO_RUNNER_MODEL_READ {} {}", micro_path.display(), e))?;
 
-    let macro_shock = shock_spec
-        .as_ref()
-        .filter(|shock| shock.scope.applies_macro());
-    let micro_shock = shock_spec
-        .as_ref()
-        .filter(|shock| shock.scope.applies_micro());
-    let macro_states = run_model_series(&macro_source, spec.seed, spec.ticks, macro_shock)?;
-    let micro_states = run_model_series(&micro_source, spec.seed, spec.ticks, micro_shock)?;
-    let shock_tick = shock_spec.as_ref().map(|shock| shock.at_tick);
+    Ok(LoadedRunner {
+        spec,
+        shock,
+        macro_source,
+        micro_source,
+    })
+}
+
+fn evaluate_macro_micro(
+    runner: &LoadedRunner,
+    shock_spec: Option<&ShockSpec>,
+) -> Result<Vec<DiagnosticOutcome>, String> {
+    let spec = &runner.spec;
+    let macro_shock = shock_spec.filter(|shock| shock.scope.applies_macro());
+    let micro_shock = shock_spec.filter(|shock| shock.scope.applies_micro());
+    let macro_states = run_model_series(&runner.macro_source, spec.seed, spec.ticks, macro_shock)?;
+    let micro_states = run_model_series(&runner.micro_source, spec.seed, spec.ticks, micro_shock)?;
+    let shock_tick = shock_spec.map(|shock| shock.at_tick);
 
     let mut results = Vec::with_capacity(spec.diagnostics.len());
     for diagnostic in &spec.diagnostics {
@@ -205,13 +285,90 @@ pub fn run_macro_micro(input: &Path, out: Option<&Path>) -> Result<(), String> {
                 entry.insert("divergence_tick".to_string(), JsonValue::Null);
             }
         }
-        results.push(JsonValue::Object(entry));
+        results.push(DiagnosticOutcome {
+            divergence_tick,
+            entry: JsonValue::Object(entry),
+        });
     }
+    Ok(results)
+}
 
+/// `macro-micro` 입력을 그대로 쓰되, 충격 대상 `param`의 변화량을 `from..=to`까지
+/// `step`씩 바꿔 가며 돌린다. 충격 시점·범위는 입력의 shock을 따르고, 없으면 1마디·양쪽이다.
+pub fn run_macro_micro_sweep(
+    input: &Path,
+    param: &str,
+    from: &str,
+    to: &str,
+    step: &str,
+    out: Option<&Path>,
+) -> Result<(), String> {
+    let param = param.trim();
+    if param.is_empty() {
+        return Err("E_ECO_SWEEP_PARAM param이 필요합니다".to_string());
+    }
+    let values = sweep_values(from, to, step)?;
+    let runner = load_macro_micro(input)?;
+    let base_shock = runner.shock.clone().unwrap_or(ShockSpec {
+        kind: None,
+        target: String::new(),
+        delta: Fixed64::zero(),
+        at_tick: 1,
+        scope: ShockScope::Both,
+    });
+
+    let mut rows = Vec::with_capacity(values.len());
+    for value in values {
+        let shock = ShockSpec {
+            target: param.to_string(),
+            delta: value,
+            ..base_shock.clone()
+        };
+        let outcomes = evaluate_macro_micro(&runner, Some(&shock))?;
+        let divergence_tick = outcomes
+            .iter()
+            .filter_map(|outcome| outcome.divergence_tick)
+            .min();
+        let outcome = if divergence_tick.is_some() {
+            "발산"
+        } else {
+            "수렴"
+        };
+        println!(
+            "{}={} divergence_tick={} outcome={}",
+            param,
+            value.format(),
+            divergence_tick
+                .map(|tick| tick.to_string())
+                .unwrap_or_else(|| "-".to_string()),
+            outcome
+        );
+
+        let mut row = Map::new();
+        row.insert("value".to_string(), JsonValue::String(value.format()));
+        row.insert(
+            "divergence_tick".to_string(),
+            match divergence_tick {
+                Some(tick) => JsonValue::Number(tick.into()),
+                None => JsonValue::Null,
+            },
+        );
+        row.insert(
+            "outcome".to_string(),
+            JsonValue::String(outcome.to_string()),
+        );
+        row.insert(
+            "results".to_string(),
+            JsonValue::Array(outcomes.into_iter().map(|outcome| outcome.entry).collect()),
+        );
+        rows.push(JsonValue::Object(row));
+    }
+
+    let spec = &runner.spec;
     let mut report = BTreeMap::new();
     report.insert(
         "schema".to_string(),
-        JsonValue::String("ddn.runner_report.v0".to_string()),
+        JsonValue::String("ddn.runner_sweep_report.v0".to_string()),
     );
     report.insert(
         "seed".to_string(),
@@ -223,40 +380,66 @@ pub fn run_macro_micro(input: &Path, out: Option<&Path>) -> Result<(), String> {
     );
     report.insert(
         "shock_tick".to_string(),
-        match shock_tick {
-            Some(value) => JsonValue::Number(serde_json::Number::from(value)),
-            None => JsonValue::Null,
-        },
+        JsonValue::Number(serde_json::Number::from(base_shock.at_tick)),
     );
-    if let Some(shock) = shock_spec.as_ref() {
-        report.insert(
-            "shock_target".to_string(),
-            JsonValue::String(shock.target.clone()),
-        );
-        report.insert(
-            "shock_delta".to_string(),
-            JsonValue::String(shock.delta.format()),
-        );
-        report.insert(
-            "shock_scope".to_string(),
-            JsonValue::String(shock.scope.label().to_string()),
-        );
-        if let Some(kind) = shock.kind.as_ref() {
-            report.insert("shock_type".to_string(), JsonValue::String(kind.clone()));
-        }
-    }
-    report.insert("results".to_string(), JsonValue::Array(results));
-    let report_json = JsonValue::Object(report.into_iter().collect::<Map<String, JsonValue>>());
-    let report_text = serde_json::to_string(&report_json)
-        .map_err(|e| format!("E_ECO_RUNNER_REPORT_JSON {}", e))?;
-
-    let out_path = resolve_report_path(input, spec.report_path.as_deref(), out);
-    ensure_parent_dir(&out_path, "E_ECO_RUNNER_DIR")?;
+    report.insert(
+        "shock_scope".to_string(),
+        JsonValue::String(base_shock.scope.label().to_string()),
+    );
+    report.insert("param".to_string(), JsonValue::String(param.to_string()));
+    report.insert(
+        "from".to_string(),
+        JsonValue::String(from.trim().to_string()),
+    );
+    report.insert("to".to_string(), JsonValue::String(to.trim().to_string()));
+    report.insert(
+        "step".to_string(),
+        JsonValue::String(step.trim().to_string()),
+    );
+    report.insert("rows".to_string(), JsonValue::Array(rows));
+    let report_text = serde_json::to_string(&JsonValue::Object(
+        report.into_iter().collect::<Map<String, JsonValue>>(),
+    ))
+    .map_err(|e| format!("E_ECO_SWEEP_REPORT_JSON {}", e))?;
+
+    let out_path = resolve_sweep_report_path(out);
+    ensure_parent_dir(&out_path, "E_ECO_SWEEP_DIR")?;
     write_text(&out_path, &(report_text + "\n"))?;
-    println!("eco_runner_report={}", out_path.display());
+    println!("eco_sweep_report={}", out_path.display());
     Ok(())
 }
 
+fn sweep_values(from: &str, to: &str, step: &str) -> Result<Vec<Fixed64>, String> {
+    let parse = |name: &str, text: &str| {
+        Fixed64::parse_literal(text.trim())
+            .ok_or_else(|| format!("E_ECO_SWEEP_RANGE {} 파싱 실패: {}", name, text))
+    };
+    let from = parse("from", from)?;
+    let to = parse("to", to)?;
+    let step = parse("step", step)?;
+    if step <= Fixed64::zero() {
+        return Err("E_ECO_SWEEP_RANGE step은 0보다 커야 합니다".to_string());
+    }
+    if from > to {
+        return Err(format!(
+            "E_ECO_SWEEP_RANGE from({})이 to({})보다 큽니다",
+            from.format(),
+            to.format()
+        ));
+    }
+    let mut values = Vec::new();
+    let mut value = from;
+    while value <= to {
+        values.push(value);
+        let next = value.saturating_add(step);
+        if next <= value {
+            break;
+        }
+        value = next;
+    }
+    Ok(values)
+}
+
 pub fn run_network_flow(
     input: &Path,
     ticks: u64,
@@ -270,15 +453,32 @@ pub fn run_network_flow(
     let source = fs::read_to_string(input)
         .map_err(|e| format!("E_ECO_NETWORK_FLOW_READ {} {}", input.display(), e))?;
     let state = run_model_snapshot(&source, seed, ticks, None)?;
-    let lhs = resolve_network_income(&state)?;
-    let rhs = resolve_network_spending(&state)?;
-    let delta = fixed64_abs(lhs.saturating_sub(rhs));
-    let converged = delta.raw() <= threshold.raw();
+    let sectors = resolve_network_sectors(&state);
+    let aggregate = match (
+        resolve_network_income(&state),
+        resolve_network_spending(&state),
+    ) {
+        (Ok(lhs), Ok(rhs)) => Some((lhs, rhs)),
+        (Err(err), _) | (_, Err(err)) if sectors.is_empty() => return Err(err),
+        _ => None,
+    };
+
+    let mut identities = Vec::with_capacity(sectors.len() + 1);
+    if let Some((lhs, rhs)) = aggregate {
+        identities.push(SectorIdentity::new(NETWORK_AGGREGATE_SECTOR, lhs, rhs));
+    }
+    for (sector, (lhs, rhs)) in &sectors {
+        identities.push(SectorIdentity::new(sector, *lhs, *rhs));
+    }
+    let failed = identities
+        .iter()
+        .find(|identity| fixed64_abs(identity.residual).raw() > threshold.raw());
+    let converged = failed.is_none();
 
     let mut report = BTreeMap::new();
     report.insert(
         "schema".to_string(),
-        JsonValue::String("ddn.eco.network_flow_report.v0".to_string()),
+        JsonValue::String("ddn.eco.network_flow_report.v1".to_string()),
     );
     report.insert(
         "seed".to_string(),
@@ -288,9 +488,16 @@ pub fn run_network_flow(
         "ticks".to_string(),
         JsonValue::Number(serde_json::Number::from(ticks)),
     );
-    report.insert("lhs".to_string(), JsonValue::String(lhs.format()));
-    report.insert("rhs".to_string(), JsonValue::String(rhs.format()));
-    report.insert("delta".to_string(), JsonValue::String(delta.format()));
+    if let Some((lhs, rhs)) = aggregate {
+        let delta = fixed64_abs(lhs.saturating_sub(rhs));
+        report.insert("lhs".to_string(), JsonValue::String(lhs.format()));
+        report.insert("rhs".to_string(), JsonValue::String(rhs.format()));
+        report.insert("delta".to_string(), JsonValue::String(delta.format()));
+    }
+    report.insert(
+        "identities".to_string(),
+        JsonValue::Array(identities.iter().map(SectorIdentity::to_json).collect()),
+    );
     report.insert(
         "threshold".to_string(),
         JsonValue::String(threshold.format()),
@@ -303,11 +510,19 @@ pub fn run_network_flow(
             "발산".to_string()
         }),
     );
-    if !converged {
+    if let Some(identity) = failed {
         report.insert(
             "error_code".to_string(),
             JsonValue::String("E_SFC_IDENTITY_VIOLATION".to_string()),
         );
+        report.insert(
+            "failed_sector".to_string(),
+            JsonValue::String(identity.sector.clone()),
+        );
+        report.insert(
+            "residual".to_string(),
+            JsonValue::String(identity.residual.format()),
+        );
     }
     let out_path = resolve_network_flow_report_path(out);
     ensure_parent_dir(&out_path, "E_ECO_NETWORK_FLOW_DIR")?;
@@ -640,6 +855,63 @@ fn fixed_from_state_key(state: &State, key: &str) -> Option<Fixed64> {
     }
 }
 
+/// `총수입`/`총지출`(또는 임금·이전소득/소비·세금)로 세우는 경제 전체 항등식의 이름.
+const NETWORK_AGGREGATE_SECTOR: &str = "전체";
+const NETWORK_SECTOR_INCOME_SUFFIX: &str = "_총수입";
+const NETWORK_SECTOR_SPENDING_SUFFIX: &str = "_총지출";
+
+/// 부문 하나의 예산 항등식(수입 = 지출). `residual`은 부호 있는 `수입 - 지출`이다.
+struct SectorIdentity {
+    sector: String,
+    lhs: Fixed64,
+    rhs: Fixed64,
+    residual: Fixed64,
+}
+
+impl SectorIdentity {
+    fn new(sector: &str, lhs: Fixed64, rhs: Fixed64) -> Self {
+        Self {
+            sector: sector.to_string(),
+            lhs,
+            rhs,
+            residual: lhs.saturating_sub(rhs),
+        }
+    }
+
+    fn to_json(&self) -> JsonValue {
+        let mut entry = Map::new();
+        entry.insert("sector".to_string(), JsonValue::String(self.sector.clone()));
+        entry.insert("lhs".to_string(), JsonValue::String(self.lhs.format()));
+        entry.insert("rhs".to_string(), JsonValue::String(self.rhs.format()));
+        entry.insert(
+            "residual".to_string(),
+            JsonValue::String(self.residual.format()),
+        );
+        JsonValue::Object(entry)
+    }
+}
+
+/// `<부문>_총수입`과 `<부문>_총지출`이 둘 다 있는 부문을 이름순으로 모은다.
+fn resolve_network_sectors(state: &State) -> BTreeMap<String, (Fixed64, Fixed64)> {
+    let mut sectors = BTreeMap::new();
+    for key in state.resources.keys() {
+        let Some(sector) = key.as_str().strip_suffix(NETWORK_SECTOR_INCOME_SUFFIX) else {
+            continue;
+        };
+        if sector.is_empty() {
+            continue;
+        }
+        let spending_key = format!("{sector}{NETWORK_SECTOR_SPENDING_SUFFIX}");
+        if let (Some(lhs), Some(rhs)) = (
+            fixed_from_state_key(state, key.as_str()),
+            fixed_from_state_key(state, &spending_key),
+        ) {
+            sectors.insert(sector.to_string(), (lhs, rhs));
+        }
+    }
+    sectors
+}
+
 fn resolve_network_income(state: &State) -> Result<Fixed64, String> {
     if let Some(value) = fixed_from_state_key(state, "총수입") {
         return Ok(value);
@@ -816,6 +1088,15 @@ fn resolve_report_path(input: &Path, report_path: Option<&str>, out: Option<&Pat
         .join("macro_micro_runner.report.detjson")
 }
 
+fn resolve_sweep_report_path(out: Option<&Path>) -> PathBuf {
+    if let Some(path) = out {
+        return path.to_path_buf();
+    }
+    paths::build_dir()
+        .join("eco")
+        .join("macro_micro_sweep.report.detjson")
+}
+
 fn resolve_network_flow_report_path(out: Option<&Path>) -> PathBuf {
     if let Some(path) = out {
         return path.to_path_buf();
@@ -927,6 +1208,90 @@ mod tests {
         );
     }
 
+    #[test]
+    fn macro_micro_sweep_emits_one_row_per_step() {
+        let dir = temp_dir("sweep");
+        let macro_model = dir.join("macro.ddn");
+        let micro_model = dir.join("micro.ddn");
+        fs::write(
+            &macro_model,
+            "(시작)할때 { 세율 <- 0. }.\n(매마디)마다 { 균형가격 <- (100 + 세율 * 50). }.\n",
+        )
+        .expect("write macro");
+        fs::write(
+            &micro_model,
+            "(시작)할때 { 세율 <- 0. }.\n(매마디)마다 { 평균가격 <- (100 + 세율 * 200). }.\n",
+        )
+        .expect("write micro");
+        let input = dir.join("runner.json");
+        let out = dir.join("sweep.report.detjson");
+        let spec = serde_json::json!({
+            "schema": "ddn.macro_micro_runner.v0",
+            "seed": 42,
+            "ticks": 4,
+            "shock": {
+                "type": "세율_인상",
+                "target": "세율",
+                "delta": 0,
+                "at_tick": 3,
+                "scope": "양쪽"
+            },
+            "models": {
+                "거시": macro_model.to_string_lossy(),
+                "미시": micro_model.to_string_lossy()
+            },
+            "diagnostics": [
+                {
+                    "name": "거시↔미시 균형가격",
+                    "lhs": "거시.균형가격",
+                    "rhs": "미시.평균가격",
+                    "threshold": 10.0
+                }
+            ]
+        });
+        fs::write(&input, serde_json::to_string(&spec).expect("spec")).expect("write input");
+
+        run_macro_micro_sweep(&input, "세율", "0", "0.2", "0.05", Some(&out)).expect("sweep 1");
+        let first = fs::read_to_string(&out).expect("read 1");
+        run_macro_micro_sweep(&input, "세율", "0", "0.2", "0.05", Some(&out)).expect("sweep 2");
+        let second = fs::read_to_string(&out).expect("read 2");
+        assert_eq!(first, second, "sweep report must be deterministic");
+
+        let doc: JsonValue = serde_json::from_str(&first).expect("report json");
+        assert_eq!(
+            doc.get("schema").and_then(|v| v.as_str()),
+            Some("ddn.runner_sweep_report.v0")
+        );
+        assert_eq!(doc.get("param").and_then(|v| v.as_str()), Some("세율"));
+        let rows = doc.get("rows").and_then(|v| v.as_array()).expect("rows");
+        assert_eq!(rows.len(), 5);
+        let table = rows
+            .iter()
+            .map(|row| {
+                (
+                    row.get("divergence_tick").and_then(|v| v.as_u64()),
+                    row.get("outcome").and_then(|v| v.as_str()).unwrap_or(""),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            table,
+            vec![
+                (None, "수렴"),
+                (None, "수렴"),
+                (Some(3), "발산"),
+                (Some(3), "발산"),
+                (Some(3), "발산"),
+            ]
+        );
+    }
+
+    #[test]
+    fn macro_micro_sweep_rejects_non_positive_step() {
+        let err = sweep_values("0", "1", "0").expect_err("must fail");
+        assert!(err.contains("E_ECO_SWEEP_RANGE"));
+    }
+
     #[test]
     fn macro_micro_runner_rejects_invalid_shock_input() {
         let shock = RunnerShock {
@@ -970,13 +1335,54 @@ mod tests {
             serde_json::from_str(&fs::read_to_string(&out).expect("read report")).expect("json");
         assert_eq!(
             report.get("schema").and_then(|v| v.as_str()),
-            Some("ddn.eco.network_flow_report.v0")
+            Some("ddn.eco.network_flow_report.v1")
         );
         assert_eq!(report.get("result").and_then(|v| v.as_str()), Some("발산"));
         assert_eq!(
             report.get("error_code").and_then(|v| v.as_str()),
             Some("E_SFC_IDENTITY_VIOLATION")
         );
+        assert_eq!(
+            report.get("failed_sector").and_then(|v| v.as_str()),
+            Some("전체")
+        );
+    }
+
+    #[test]
+    fn network_flow_report_names_unbalanced_sector_and_residual() {
+        let dir = temp_dir("network_sector_violation");
+        let input = dir.join("model.ddn");
+        let out = dir.join("report.detjson");
+        fs::write(
+            &input,
+            "(매마디)마다 {\n  가계_총수입 <- 100.\n  가계_총지출 <- 100.\n  기업_총수입 <- 80.\n  기업_총지출 <- 92.5.\n}.\n",
+        )
+        .expect("write model");
+        let threshold = Fixed64::parse_literal("0.01").expect("threshold");
+        let err = run_network_flow(&input, 1, 0, threshold, Some(&out)).expect_err("must diverge");
+        assert_eq!(err, "E_SFC_IDENTITY_VIOLATION");
+        let report: JsonValue =
+            serde_json::from_str(&fs::read_to_string(&out).expect("read report")).expect("json");
+        assert_eq!(
+            report.get("failed_sector").and_then(|v| v.as_str()),
+            Some("기업")
+        );
+        let residual = report
+            .get("residual")
+            .and_then(|v| v.as_str())
+            .and_then(Fixed64::parse_literal)
+            .expect("residual");
+        assert_eq!(residual, Fixed64::parse_literal("-12.5").expect("expected"));
+        let identities = report
+            .get("identities")
+            .and_then(|v| v.as_array())
+            .expect("identities");
+        let sectors = identities
+            .iter()
+            .map(|row| row.get("sector").and_then(|v| v.as_str()).unwrap_or(""))
+            .collect::<Vec<_>>();
+        assert_eq!(sectors, vec!["가계", "기업"]);
+        assert!(report.get("lhs").is_none());
     }
 
     #[test]
@@ -6,14 +6,20 @@ use serde::Deserialize;
 use serde_json::{Map, Value as JsonValue};
 
 use super::detjson::{sha256_hex, write_text};
+use super::geoul::{parse_query_key, replay_geoul_states};
 use super::paths;
+use crate::core::fixed64::Fixed64;
+use crate::core::geoul::GeoulBundleReader;
+use crate::core::value::Value;
 
 #[derive(Deserialize)]
 struct EvalConfig {
     schema: Option<String>,
     suite_id: String,
-    model_path: String,
+    model_path: Option<String>,
     artifact_path: Option<String>,
+    geouls: Option<String>,
+    metric: Option<String>,
 }
 
 #[derive(Deserialize)]
@@ -28,6 +34,13 @@ struct MlpModel {
 
 const CARTPOLE_SUITE_ID: &str = "cartpole1d_v1";
 const CARTPOLE_THRESHOLD: u64 = 195;
+const GEOUL_METRIC_SUITE_ID: &str = "geoul_metric_v1";
+
+struct GeoulMetricRow {
+    geoul: String,
+    frame_count: u64,
+    value: Fixed64,
+}
 
 pub fn run_eval(config_path: &Path, out_dir: Option<&Path>) -> Result<(), String> {
     let text = fs::read_to_string(config_path)
@@ -46,11 +59,18 @@ pub fn run_eval(config_path: &Path, out_dir: Option<&Path>) -> Result<(), String
         }
     }
 
+    if config.suite_id == GEOUL_METRIC_SUITE_ID {
+        return run_eval_geoul_batch(config_path, &config, &config_file, &config_hash, out_dir);
+    }
     if config.suite_id != CARTPOLE_SUITE_ID {
         return Err(format!("E_EVAL_SUITE {}", config.suite_id));
     }
 
-    let model_path = resolve_path(config_path, &config.model_path);
+    let model_path = config
+        .model_path
+        .as_deref()
+        .ok_or_else(|| "E_EVAL_CONFIG_PARSE model_path가 필요합니다".to_string())?;
+    let model_path = resolve_path(config_path, model_path);
     let model_bytes = fs::read(&model_path)
         .map_err(|e| format!("E_EVAL_MODEL_READ {} {}", model_path.display(), e))?;
     let model_file = model_path
@@ -202,6 +222,235 @@ pub fn run_eval(config_path: &Path, out_dir: Option<&Path>) -> Result<(), String
     Ok(())
 }
 
+/// 여러 geoul의 마지막 마디에서 `metric` 키 값을 읽어 파일별 행과 평균·표준편차·최소·최대를 낸다.
+/// 결과가 실행 환경에 따라 달라지지 않도록 geoul은 경로 순으로 정렬해 처리한다.
+fn run_eval_geoul_batch(
+    config_path: &Path,
+    config: &EvalConfig,
+    config_file: &str,
+    config_hash: &str,
+    out_dir: Option<&Path>,
+) -> Result<(), String> {
+    let pattern = config
+        .geouls
+        .as_deref()
+        .ok_or_else(|| "E_EVAL_CONFIG_PARSE geouls가 필요합니다".to_string())?;
+    let metric = config
+        .metric
+        .as_deref()
+        .ok_or_else(|| "E_EVAL_CONFIG_PARSE metric이 필요합니다".to_string())?;
+    let metric_key = parse_query_key(metric)?;
+
+    let geoul_dirs = collect_geoul_dirs(&resolve_path(config_path, pattern))?;
+    let mut rows = Vec::with_capacity(geoul_dirs.len());
+    for dir in &geoul_dirs {
+        let frame_count = GeoulBundleReader::open(dir)?.frame_count();
+        if frame_count == 0 {
+            return Err(format!("E_EVAL_GEOUL_EMPTY {}", dir.display()));
+        }
+        let mut last = None;
+        replay_geoul_states(dir, frame_count - 1, None, |_, state| {
+            last = Some(state.get(&metric_key).cloned());
+        })?;
+        let value = match last.flatten() {
+            Some(Value::Num(quantity)) => quantity.raw,
+            Some(_) => {
+                return Err(format!(
+                    "E_EVAL_METRIC_TYPE {} {}는 수가 아닙니다",
+                    dir.display(),
+                    metric
+                ))
+            }
+            None => {
+                return Err(format!(
+                    "E_EVAL_METRIC_MISSING {} {}",
+                    dir.display(),
+                    metric
+                ))
+            }
+        };
+        let geoul = dir
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("geoul")
+            .to_string();
+        rows.push(GeoulMetricRow {
+            geoul,
+            frame_count,
+            value,
+        });
+    }
+
+    let report_text = build_geoul_batch_report(config_file, config_hash, metric, &rows);
+    let out_dir = resolve_out_dir(out_dir);
+    fs::create_dir_all(&out_dir).map_err(|e| e.to_string())?;
+    write_text(&out_dir.join("eval_batch_report.detjson"), &report_text)?;
+    println!("{}", report_text);
+    Ok(())
+}
+
+/// `path`가 geoul 묶음이면 그것 하나, 디렉터리면 그 아래 geoul 묶음들,
+/// 마지막 조각에 `*`/`?`가 있으면 이름이 맞는 geoul 묶음들을 경로 순으로 돌려준다.
+fn collect_geoul_dirs(path: &Path) -> Result<Vec<PathBuf>, String> {
+    let is_geoul = |dir: &Path| dir.join("audit.ddni").is_file();
+    let name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("");
+    let (base, name_pattern) = if name.contains(['*', '?']) {
+        let parent = path.parent().unwrap_or_else(|| Path::new("."));
+        (parent.to_path_buf(), Some(name.to_string()))
+    } else if is_geoul(path) {
+        return Ok(vec![path.to_path_buf()]);
+    } else {
+        (path.to_path_buf(), None)
+    };
+
+    let entries =
+        fs::read_dir(&base).map_err(|e| format!("E_EVAL_GEOUL_READ {} {}", base.display(), e))?;
+    let mut dirs = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("E_EVAL_GEOUL_READ {} {}", base.display(), e))?;
+        let child = entry.path();
+        if !is_geoul(&child) {
+            continue;
+        }
+        if let Some(pattern) = name_pattern.as_deref() {
+            let child_name = entry.file_name().to_string_lossy().into_owned();
+            if !wildcard_match(pattern, &child_name) {
+                continue;
+            }
+        }
+        dirs.push(child);
+    }
+    if dirs.is_empty() {
+        return Err(format!("E_EVAL_GEOUL_EMPTY {}", path.display()));
+    }
+    dirs.sort();
+    Ok(dirs)
+}
+
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = star {
+            p = star_p + 1;
+            t = star_t + 1;
+            star = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|ch| *ch == '*')
+}
+
+fn build_geoul_batch_report(
+    config_file: &str,
+    config_hash: &str,
+    metric: &str,
+    rows: &[GeoulMetricRow],
+) -> String {
+    let count = Fixed64::from_int(rows.len() as i64);
+    let sum = rows
+        .iter()
+        .fold(Fixed64::zero(), |acc, row| acc.saturating_add(row.value));
+    let mean = sum.checked_div(count).unwrap_or(Fixed64::zero());
+    let variance = rows
+        .iter()
+        .fold(Fixed64::zero(), |acc, row| {
+            let diff = row.value.saturating_sub(mean);
+            acc.saturating_add(diff.saturating_mul(diff))
+        })
+        .checked_div(count)
+        .unwrap_or(Fixed64::zero());
+    let std = variance.sqrt().unwrap_or(Fixed64::zero());
+    let min = rows
+        .iter()
+        .map(|row| row.value)
+        .min()
+        .unwrap_or(Fixed64::zero());
+    let max = rows
+        .iter()
+        .map(|row| row.value)
+        .max()
+        .unwrap_or(Fixed64::zero());
+
+    let mut source_provenance = Map::new();
+    source_provenance.insert(
+        "schema".to_string(),
+        JsonValue::String("seulgi.source_provenance.v1".to_string()),
+    );
+    source_provenance.insert(
+        "source_kind".to_string(),
+        JsonValue::String("eval_config.v1".to_string()),
+    );
+    source_provenance.insert(
+        "config_file".to_string(),
+        JsonValue::String(config_file.to_string()),
+    );
+    source_provenance.insert(
+        "config_hash".to_string(),
+        JsonValue::String(config_hash.to_string()),
+    );
+
+    let mut aggregate = Map::new();
+    aggregate.insert("count".to_string(), JsonValue::Number(rows.len().into()));
+    aggregate.insert("mean".to_string(), JsonValue::String(mean.format()));
+    aggregate.insert("std".to_string(), JsonValue::String(std.format()));
+    aggregate.insert("min".to_string(), JsonValue::String(min.format()));
+    aggregate.insert("max".to_string(), JsonValue::String(max.format()));
+
+    let mut map = Map::new();
+    map.insert(
+        "schema".to_string(),
+        JsonValue::String("seulgi.eval_batch_report.v1".to_string()),
+    );
+    map.insert(
+        "source_hash".to_string(),
+        JsonValue::String(config_hash.to_string()),
+    );
+    map.insert(
+        "source_provenance".to_string(),
+        JsonValue::Object(source_provenance),
+    );
+    map.insert(
+        "suite_id".to_string(),
+        JsonValue::String(GEOUL_METRIC_SUITE_ID.to_string()),
+    );
+    map.insert("metric".to_string(), JsonValue::String(metric.to_string()));
+    map.insert(
+        "rows".to_string(),
+        JsonValue::Array(
+            rows.iter()
+                .map(|row| {
+                    let mut item = Map::new();
+                    item.insert("geoul".to_string(), JsonValue::String(row.geoul.clone()));
+                    item.insert(
+                        "frame_count".to_string(),
+                        JsonValue::Number(row.frame_count.into()),
+                    );
+                    item.insert("value".to_string(), JsonValue::String(row.value.format()));
+                    JsonValue::Object(item)
+                })
+                .collect(),
+        ),
+    );
+    map.insert("aggregate".to_string(), JsonValue::Object(aggregate));
+    let base_text = JsonValue::Object(map.clone()).to_string();
+    let report_hash = format!("sha256:{}", sha256_hex(base_text.as_bytes()));
+    map.insert("report_hash".to_string(), JsonValue::String(report_hash));
+    JsonValue::Object(map).to_string()
+}
+
 fn resolve_out_dir(out_dir: Option<&Path>) -> PathBuf {
     match out_dir {
         Some(path) => path.to_path_buf(),
@@ -338,3 +587,66 @@ fn hash_to_u64(input: &str) -> u64 {
     let digest = sha256_hex(input.as_bytes());
     u64::from_str_radix(&digest[..16], 16).unwrap_or(0)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::geoul::write_test_geoul;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let stamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("ddn_eval_{}_{}", name, stamp));
+        fs::create_dir_all(&dir).expect("mkdir");
+        dir
+    }
+
+    #[test]
+    fn geoul_batch_mean_matches_per_file_average() {
+        let root = temp_dir("batch");
+        for (name, start) in [("run_c", 10), ("run_a", 2), ("run_b", 3)] {
+            let source = format!(
+                "채비 {{\n  점수:수 <- {}.\n}}.\n(매마디)마다 {{\n  점수 <- 점수 + 1.\n}}.\n",
+                start
+            );
+            write_test_geoul(&root.join(name), &source, 3);
+        }
+        fs::create_dir_all(root.join("notes")).expect("mkdir notes");
+        let config_path = root.join("eval_config.json");
+        fs::write(
+            &config_path,
+            "{\"schema\":\"seulgi.eval_config.v1\",\"suite_id\":\"geoul_metric_v1\",\"geouls\":\"run_*\",\"metric\":\"점수\"}",
+        )
+        .expect("write config");
+        let out_dir = root.join("out");
+
+        run_eval(&config_path, Some(&out_dir)).expect("batch eval");
+
+        let text = fs::read_to_string(out_dir.join("eval_batch_report.detjson")).expect("report");
+        let report: JsonValue = serde_json::from_str(&text).expect("json");
+        let rows = report["rows"].as_array().expect("rows");
+        let names: Vec<&str> = rows
+            .iter()
+            .map(|row| row["geoul"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, vec!["run_a", "run_b", "run_c"]);
+        let values: Vec<f64> = rows
+            .iter()
+            .map(|row| row["value"].as_str().unwrap().parse().unwrap())
+            .collect();
+        let manual_mean = values.iter().sum::<f64>() / values.len() as f64;
+        let mean: f64 = report["aggregate"]["mean"]
+            .as_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert_eq!(mean, manual_mean);
+        assert_eq!(report["aggregate"]["count"], 3);
+        assert_eq!(report["aggregate"]["min"], values[0].to_string());
+        assert_eq!(report["aggregate"]["max"], values[2].to_string());
+        let _ = fs::remove_dir_all(&root);
+    }
+}
@@ -1,13 +1,19 @@
 use serde_json::{json, Value as JsonValue};
 use sha2::{Digest, Sha256};
+use std::collections::BTreeSet;
 use std::fs;
-use std::io::{BufRead, BufReader, Write};
+use std::io::{BufRead, BufReader, Read, Write};
 use std::net::{TcpListener, TcpStream, UdpSocket};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
 
 use super::detjson::{sha256_hex, write_text};
+use super::gateway_metrics::{GatewayMetrics, MetricsServer};
+use super::gateway_ws;
 use crate::core::hash::SSOT_VERSION;
+use ddonirang_core::seulgi::latency::LatencySchedule;
 
 pub struct LoadSimOptions {
     pub clients: u64,
@@ -17,6 +23,10 @@ pub struct LoadSimOptions {
     pub tick_hz: u64,
     pub threads: usize,
     pub out: Option<PathBuf>,
+    /// 의도가 도착하기까지 걸리는 마디 수.
+    pub latency_madi: Option<u64>,
+    /// 의도를 잃을 확률(0~100). `seed`로 결정적으로 굴린다.
+    pub loss_pct: Option<u64>,
 }
 
 pub struct ServeOptions {
@@ -32,6 +42,10 @@ pub struct ServeOptions {
     pub listen_timeout_ms: Option<u64>,
     pub send_path: Option<PathBuf>,
     pub send_format: InputFormat,
+    /// 운영 지표(Prometheus text)를 낼 주소. 보고서와 결정성에는 영향이 없다.
+    pub metrics_listen: Option<String>,
+    /// 켜지면 새 연결을 받지 않고 쌓인 입력만 처리한 뒤 보고서를 쓴다.
+    pub shutdown: Arc<AtomicBool>,
 }
 
 #[derive(Clone, Debug)]
@@ -55,6 +69,7 @@ pub enum InputFormat {
 pub enum ListenProtocol {
     Tcp,
     Udp,
+    Ws,
 }
 
 pub fn run_serve(opts: ServeOptions) -> Result<(), String> {
@@ -80,8 +95,62 @@ pub fn run_serve(opts: ServeOptions) -> Result<(), String> {
     if opts.send_path.is_some() && opts.listen_max_events.is_none() {
         return Err("E_GATEWAY_SEND_REQUIRES_MAX listen_max_events가 필요합니다.".to_string());
     }
+    let _sigint = match opts.listen_addr {
+        Some(_) => Some(SigintShutdown::register(&opts.shutdown)?),
+        None => None,
+    };
+    let metrics = Arc::new(GatewayMetrics::default());
+    let metrics_server = match opts.metrics_listen.as_deref() {
+        Some(addr) => {
+            let server = MetricsServer::start(addr, Arc::clone(&metrics))?;
+            println!("gateway_metrics_listen={}", addr);
+            Some(server)
+        }
+        None => None,
+    };
+    let result = write_serve_report(&opts, &metrics);
+    if let Some(server) = metrics_server {
+        server.stop();
+    }
+    result
+}
+
+/// listen 동안만 SIGINT를 종료 깃발로 돌린다.
+///
+/// 첫 Ctrl-C는 쌓인 입력을 마저 처리하게 하고, 두 번째 Ctrl-C는 프로세스를 바로 끝낸다.
+/// 떨어질 때 등록을 풀어 다음 호출과 기본 SIGINT 동작에 남지 않는다.
+struct SigintShutdown {
+    ids: Vec<signal_hook::SigId>,
+}
+
+impl SigintShutdown {
+    fn register(shutdown: &Arc<AtomicBool>) -> Result<Self, String> {
+        use signal_hook::consts::SIGINT;
+        let mut guard = Self { ids: Vec::new() };
+        // 깃발이 이미 켜진 뒤의 SIGINT는 바로 끝낸다. 깃발을 켜는 등록보다 먼저 둔다.
+        guard.ids.push(
+            signal_hook::flag::register_conditional_shutdown(SIGINT, 1, Arc::clone(shutdown))
+                .map_err(|e| format!("E_GATEWAY_SIGNAL {}", e))?,
+        );
+        guard.ids.push(
+            signal_hook::flag::register(SIGINT, Arc::clone(shutdown))
+                .map_err(|e| format!("E_GATEWAY_SIGNAL {}", e))?,
+        );
+        Ok(guard)
+    }
+}
+
+impl Drop for SigintShutdown {
+    fn drop(&mut self) {
+        for id in self.ids.drain(..) {
+            signal_hook::low_level::unregister(id);
+        }
+    }
+}
+
+fn write_serve_report(opts: &ServeOptions, metrics: &GatewayMetrics) -> Result<(), String> {
     if opts.input.is_some() || opts.listen_addr.is_some() {
-        let report = build_serve_report(&opts)?;
+        let report = build_serve_report(opts, metrics)?;
         let text = serde_json::to_string_pretty(&report)
             .map_err(|e| format!("E_GATEWAY_REPORT_JSON {}", e))?
             + "\n";
@@ -116,8 +185,14 @@ pub fn run_load_sim(opts: LoadSimOptions) -> Result<(), String> {
         .clients
         .checked_mul(opts.tick_hz)
         .ok_or_else(|| "E_GATEWAY_OVERFLOW throughput overflow".to_string())?;
+    if opts.loss_pct.is_some_and(|pct| pct > 100) {
+        return Err("E_GATEWAY_INVALID_PARAMS loss_pct는 0~100이어야 합니다.".to_string());
+    }
 
-    let mut hashers = vec![Sha256::new(); opts.realms as usize];
+    let mut baseline = vec![Sha256::new(); opts.realms as usize];
+    let mut delivered = Vec::new();
+    let mut lost = 0u64;
+    let mut in_flight = 0u64;
     for tick in 0..opts.ticks {
         for client in 0..opts.clients {
             let sender = format!("c{:0width$}", client, width = width as usize);
@@ -125,13 +200,37 @@ pub fn run_load_sim(opts: LoadSimOptions) -> Result<(), String> {
             let realm_id = (client % opts.realms) as usize;
             let payload = mix_payload(opts.seed, client, tick);
             let line = format!("sender={sender}|seq={seq}|realm={realm_id}|payload={payload}\n");
-            hashers[realm_id].update(line.as_bytes());
+            baseline[realm_id].update(line.as_bytes());
+            if opts
+                .loss_pct
+                .is_some_and(|pct| loss_roll(opts.seed, client, tick) < pct)
+            {
+                lost += 1;
+                continue;
+            }
+            let schedule = LatencySchedule::new(opts.latency_madi.unwrap_or(0), tick);
+            // 마지막 마디까지 도착하지 못한 의도는 세계에 반영되지 않는다.
+            if schedule.target_madi >= opts.ticks {
+                in_flight += 1;
+                continue;
+            }
+            delivered.push((schedule.target_madi, realm_id, line));
         }
     }
+    // 같은 마디에 도착한 의도는 보낸 순서를 지킨다.
+    delivered.sort_by_key(|(target_madi, _, _)| *target_madi);
+    let mut hashers = vec![Sha256::new(); opts.realms as usize];
+    for (_, realm_id, line) in &delivered {
+        hashers[*realm_id].update(line.as_bytes());
+    }
 
     let mut final_state_hashes = Vec::new();
-    for (realm_id, hasher) in hashers.into_iter().enumerate() {
+    let mut diverged_realms = Vec::new();
+    for (realm_id, (hasher, baseline)) in hashers.into_iter().zip(baseline).enumerate() {
         let digest = hasher.finalize();
+        if digest != baseline.finalize() {
+            diverged_realms.push(realm_id as u64);
+        }
         final_state_hashes.push(json!({
             "realm_id": realm_id,
             "state_hash": format!("sha256:{}", hex::encode(digest)),
@@ -139,7 +238,7 @@ pub fn run_load_sim(opts: LoadSimOptions) -> Result<(), String> {
     }
     let (source_hash, source_provenance) = build_load_source_provenance(&opts)?;
 
-    let report = json!({
+    let mut report = json!({
         "schema": "gateway.load_report.v1",
         "source_hash": source_hash,
         "source_provenance": source_provenance,
@@ -155,6 +254,17 @@ pub fn run_load_sim(opts: LoadSimOptions) -> Result<(), String> {
         "tick_hz": opts.tick_hz,
         "final_state_hashes": final_state_hashes,
     });
+    if opts.latency_madi.is_some() || opts.loss_pct.is_some() {
+        report["network"] = json!({
+            "latency_madi": opts.latency_madi.unwrap_or(0),
+            "loss_pct": opts.loss_pct.unwrap_or(0),
+            "intents_delivered": delivered.len() as u64,
+            "intents_dropped": lost + in_flight,
+            "intents_lost": lost,
+            "intents_in_flight": in_flight,
+            "diverged_realms": diverged_realms,
+        });
+    }
 
     let text = serde_json::to_string_pretty(&report)
         .map_err(|e| format!("E_GATEWAY_REPORT_JSON {}", e))?
@@ -195,6 +305,11 @@ fn mix_payload(seed: u64, client: u64, tick: u64) -> u64 {
     x
 }
 
+/// 손실 판정용 0~99 값. 페이로드와 겹치지 않도록 seed를 비틀어 쓴다.
+fn loss_roll(seed: u64, client: u64, tick: u64) -> u64 {
+    mix_payload(seed ^ 0x6c6f_7373_5f70_6374, client, tick) % 100
+}
+
 fn read_gateway_events(path: &Path, format: InputFormat) -> Result<Vec<GatewayNetEvent>, String> {
     match format {
         InputFormat::DetJson => read_detjson_events(path),
@@ -209,35 +324,62 @@ fn read_auto_events(path: &Path) -> Result<Vec<GatewayNetEvent>, String> {
     if ext.eq_ignore_ascii_case("jsonl") {
         return read_jsonl_events(path);
     }
+    let value = read_json_value(path)?;
+    if is_sam_input(&value) {
+        return sam_input_events_from_value(&value);
+    }
+    detjson_events_from_value(&value)
+}
+
+fn read_json_value(path: &Path) -> Result<JsonValue, String> {
     let text = fs::read_to_string(path).map_err(|e| format!("E_GATEWAY_INPUT_READ {}", e))?;
-    let value: JsonValue =
-        serde_json::from_str(&text).map_err(|e| format!("E_GATEWAY_INPUT_PARSE {e}"))?;
-    if let Some(schema) = value.get("schema").and_then(|v| v.as_str()) {
-        if schema == "sam.input.v0" {
-            return read_sam_input_events(path);
-        }
+    serde_json::from_str(&text).map_err(|e| format!("E_GATEWAY_INPUT_PARSE {e}"))
+}
+
+fn is_sam_input(value: &JsonValue) -> bool {
+    value.get("schema").and_then(|v| v.as_str()) == Some("sam.input.v0")
+}
+
+/// WebSocket 메시지 하나를 입력 형식에 맞춰 사건 목록으로 푼다.
+/// `Auto`는 JSON 하나면 sam/detjson/단일 사건 순으로, 아니면 jsonl로 본다.
+fn parse_message_events(text: &str, format: InputFormat) -> Result<Vec<GatewayNetEvent>, String> {
+    let parse_value = || -> Result<JsonValue, String> {
+        serde_json::from_str(text).map_err(|e| format!("E_GATEWAY_INPUT_PARSE {e}"))
+    };
+    match format {
+        InputFormat::DetJson => detjson_events_from_value(&parse_value()?),
+        InputFormat::Jsonl => jsonl_events_from_text(text),
+        InputFormat::Sam => sam_input_events_from_value(&parse_value()?),
+        InputFormat::Auto => match serde_json::from_str::<JsonValue>(text) {
+            Ok(value) if is_sam_input(&value) => sam_input_events_from_value(&value),
+            Ok(value) if value.get("net_events").is_some() => detjson_events_from_value(&value),
+            Ok(value) => Ok(vec![parse_event_from_value(&value)?]),
+            Err(_) => jsonl_events_from_text(text),
+        },
     }
-    read_detjson_events(path)
 }
 
 fn read_detjson_events(path: &Path) -> Result<Vec<GatewayNetEvent>, String> {
-    let text = fs::read_to_string(path).map_err(|e| format!("E_GATEWAY_INPUT_READ {}", e))?;
-    let value: JsonValue =
-        serde_json::from_str(&text).map_err(|e| format!("E_GATEWAY_INPUT_PARSE {e}"))?;
+    detjson_events_from_value(&read_json_value(path)?)
+}
+
+fn detjson_events_from_value(value: &JsonValue) -> Result<Vec<GatewayNetEvent>, String> {
     if let Some(schema) = value.get("schema").and_then(|v| v.as_str()) {
         if schema != "ddn.input_snapshot.v1" && schema != "sam.input.v0" {
             return Err(format!("E_GATEWAY_INPUT_SCHEMA {}", schema));
         }
     }
-    extract_net_events_from_value(&value)
+    extract_net_events_from_value(value)
 }
 
 fn read_jsonl_events(path: &Path) -> Result<Vec<GatewayNetEvent>, String> {
-    let file = fs::File::open(path).map_err(|e| format!("E_GATEWAY_INPUT_READ {}", e))?;
-    let reader = BufReader::new(file);
+    let text = fs::read_to_string(path).map_err(|e| format!("E_GATEWAY_INPUT_READ {}", e))?;
+    jsonl_events_from_text(&text)
+}
+
+fn jsonl_events_from_text(text: &str) -> Result<Vec<GatewayNetEvent>, String> {
     let mut events = Vec::new();
-    for (idx, line) in reader.lines().enumerate() {
-        let line = line.map_err(|e| format!("E_GATEWAY_INPUT_READ {}", e))?;
+    for (idx, line) in text.lines().enumerate() {
         let trimmed = line.trim();
         if trimmed.is_empty() {
             continue;
@@ -255,9 +397,10 @@ fn read_jsonl_events(path: &Path) -> Result<Vec<GatewayNetEvent>, String> {
 }
 
 fn read_sam_input_events(path: &Path) -> Result<Vec<GatewayNetEvent>, String> {
-    let text = fs::read_to_string(path).map_err(|e| format!("E_GATEWAY_INPUT_READ {}", e))?;
-    let value: JsonValue =
-        serde_json::from_str(&text).map_err(|e| format!("E_GATEWAY_INPUT_PARSE {e}"))?;
+    sam_input_events_from_value(&read_json_value(path)?)
+}
+
+fn sam_input_events_from_value(value: &JsonValue) -> Result<Vec<GatewayNetEvent>, String> {
     if let Some(schema) = value.get("schema").and_then(|v| v.as_str()) {
         if schema != "sam.input.v0" {
             return Err(format!("E_GATEWAY_INPUT_SCHEMA {}", schema));
@@ -334,7 +477,7 @@ fn parse_event_from_value(value: &JsonValue) -> Result<GatewayNetEvent, String>
     })
 }
 
-fn build_serve_report(opts: &ServeOptions) -> Result<JsonValue, String> {
+fn build_serve_report(opts: &ServeOptions, metrics: &GatewayMetrics) -> Result<JsonValue, String> {
     let world_hash = sha256_file(&opts.world)?;
     let input_hash = if let Some(input) = opts.input.as_deref() {
         Some(sha256_file(input)?)
@@ -352,13 +495,7 @@ fn build_serve_report(opts: &ServeOptions) -> Result<JsonValue, String> {
         } else {
             None
         };
-        read_events_from_socket(
-            addr,
-            opts.listen_proto,
-            opts.listen_max_events,
-            opts.listen_timeout_ms,
-            send_events,
-        )?
+        read_events_from_socket(addr, opts, send_events, metrics)?
     } else if let Some(input) = opts.input.as_deref() {
         read_gateway_events(input, opts.input_format)?
     } else {
@@ -366,6 +503,7 @@ fn build_serve_report(opts: &ServeOptions) -> Result<JsonValue, String> {
     };
     let total = events.len() as u64;
     let (ordered, dropped) = order_and_dedupe_events(events);
+    metrics.set_queue_depth(0);
     let realm_count = resolve_realm_count(&ordered, opts.realms)?;
     let final_state_hashes = compute_realm_hashes(&ordered, realm_count);
     let (source_hash, source_provenance) = build_serve_source_provenance(
@@ -398,6 +536,7 @@ fn build_serve_report(opts: &ServeOptions) -> Result<JsonValue, String> {
             match opts.listen_proto {
                 ListenProtocol::Tcp => "tcp",
                 ListenProtocol::Udp => "udp",
+                ListenProtocol::Ws => "ws",
             }
             .to_string(),
         );
@@ -413,22 +552,50 @@ fn build_serve_report(opts: &ServeOptions) -> Result<JsonValue, String> {
 
 fn read_events_from_socket(
     addr: &str,
-    proto: ListenProtocol,
-    max_events: Option<u64>,
-    timeout_ms: Option<u64>,
+    opts: &ServeOptions,
     send_events: Option<Vec<GatewayNetEvent>>,
+    metrics: &GatewayMetrics,
 ) -> Result<Vec<GatewayNetEvent>, String> {
-    match proto {
-        ListenProtocol::Tcp => read_events_from_tcp(addr, max_events, timeout_ms, send_events),
-        ListenProtocol::Udp => read_events_from_udp(addr, max_events, timeout_ms, send_events),
+    let max_events = opts.listen_max_events;
+    let timeout_ms = opts.listen_timeout_ms;
+    let shutdown = opts.shutdown.as_ref();
+    match opts.listen_proto {
+        ListenProtocol::Tcp => {
+            read_events_from_tcp(addr, max_events, timeout_ms, send_events, shutdown, metrics)
+        }
+        ListenProtocol::Udp => {
+            read_events_from_udp(addr, max_events, timeout_ms, send_events, shutdown, metrics)
+        }
+        ListenProtocol::Ws => read_events_from_ws(
+            addr,
+            max_events,
+            timeout_ms,
+            send_events,
+            opts.input_format,
+            shutdown,
+            metrics,
+        ),
     }
 }
 
+/// 종료 신호를 확인하는 주기. 읽기 제한 시간은 이 주기를 쌓아 센다.
+const LISTEN_POLL: Duration = Duration::from_millis(20);
+
+fn is_poll_timeout(err: &std::io::Error) -> bool {
+    err.kind() == std::io::ErrorKind::WouldBlock || err.kind() == std::io::ErrorKind::TimedOut
+}
+
+fn idle_expired(idle: Duration, timeout_ms: Option<u64>) -> bool {
+    timeout_ms.is_some_and(|ms| idle >= Duration::from_millis(ms))
+}
+
 fn read_events_from_tcp(
     addr: &str,
     max_events: Option<u64>,
     timeout_ms: Option<u64>,
     send_events: Option<Vec<GatewayNetEvent>>,
+    shutdown: &AtomicBool,
+    metrics: &GatewayMetrics,
 ) -> Result<Vec<GatewayNetEvent>, String> {
     let listener = TcpListener::bind(addr).map_err(|e| format!("E_GATEWAY_LISTEN {}", e))?;
     let local_addr = listener
@@ -443,64 +610,268 @@ fn read_events_from_tcp(
     } else {
         None
     };
-    let (stream, _) = listener
-        .accept()
-        .map_err(|e| format!("E_GATEWAY_ACCEPT {}", e))?;
-    if let Some(ms) = timeout_ms {
-        stream
-            .set_read_timeout(Some(Duration::from_millis(ms)))
-            .map_err(|e| format!("E_GATEWAY_TIMEOUT {}", e))?;
-    }
-    let events = read_events_from_stream(stream, max_events)?;
+    let events = match accept_until_shutdown(listener, shutdown, metrics)? {
+        Some(stream) => {
+            metrics.set_clients(1);
+            let events = read_events_from_stream(stream, max_events, timeout_ms, shutdown, metrics);
+            metrics.set_clients(0);
+            events?
+        }
+        None => Vec::new(),
+    };
     if let Some(handle) = sender_handle {
         let _ = handle.join();
     }
     Ok(events)
 }
 
+/// 연결 하나를 기다린다. 종료 신호가 먼저 오면 새 연결은 받지 않는다.
+fn accept_until_shutdown(
+    listener: TcpListener,
+    shutdown: &AtomicBool,
+    metrics: &GatewayMetrics,
+) -> Result<Option<TcpStream>, String> {
+    listener
+        .set_nonblocking(true)
+        .map_err(|e| format!("E_GATEWAY_LISTEN {}", e))?;
+    loop {
+        metrics.tick();
+        match listener.accept() {
+            Ok((stream, _)) => {
+                stream
+                    .set_nonblocking(false)
+                    .and_then(|_| stream.set_read_timeout(Some(LISTEN_POLL)))
+                    .map_err(|e| format!("E_GATEWAY_TIMEOUT {}", e))?;
+                return Ok(Some(stream));
+            }
+            Err(err) if is_poll_timeout(&err) => {
+                if shutdown.load(Ordering::SeqCst) {
+                    return Ok(None);
+                }
+                std::thread::sleep(LISTEN_POLL);
+            }
+            Err(err) => return Err(format!("E_GATEWAY_ACCEPT {}", err)),
+        }
+    }
+}
+
 fn read_events_from_stream(
-    mut stream: TcpStream,
+    stream: TcpStream,
     max_events: Option<u64>,
+    timeout_ms: Option<u64>,
+    shutdown: &AtomicBool,
+    metrics: &GatewayMetrics,
 ) -> Result<Vec<GatewayNetEvent>, String> {
     let mut events = Vec::new();
-    let mut reader = BufReader::new(&mut stream);
+    let mut reader = BufReader::new(&stream);
     let mut line = String::new();
+    let mut idle = Duration::ZERO;
+    let mut draining = false;
     loop {
-        line.clear();
+        metrics.tick();
+        metrics.set_queue_depth(events.len());
         match reader.read_line(&mut line) {
             Ok(0) => break,
             Ok(_) => {
+                idle = Duration::ZERO;
                 let trimmed = line.trim();
                 if trimmed.is_empty() {
+                    line.clear();
                     continue;
                 }
                 let value: JsonValue = serde_json::from_str(trimmed)
                     .map_err(|e| format!("E_GATEWAY_INPUT_PARSE {e}"))?;
                 events.push(parse_event_from_value(&value)?);
+                line.clear();
                 if let Some(limit) = max_events {
                     if events.len() as u64 >= limit {
                         break;
                     }
                 }
             }
-            Err(err) => {
-                if err.kind() == std::io::ErrorKind::WouldBlock
-                    || err.kind() == std::io::ErrorKind::TimedOut
-                {
+            Err(err) if is_poll_timeout(&err) => {
+                // 읽다 만 줄은 `line`에 남겨 두고 다음 읽기에서 잇는다.
+                if draining {
+                    break;
+                }
+                if shutdown.load(Ordering::SeqCst) {
+                    // 이미 도착해 쌓인 입력만 마저 읽고 끝낸다.
+                    draining = true;
+                    stream
+                        .set_nonblocking(true)
+                        .map_err(|e| format!("E_GATEWAY_INPUT_READ {}", e))?;
+                    continue;
+                }
+                idle += LISTEN_POLL;
+                if idle_expired(idle, timeout_ms) {
+                    break;
+                }
+            }
+            Err(err) => return Err(format!("E_GATEWAY_INPUT_READ {}", err)),
+        }
+    }
+    Ok(events)
+}
+
+fn read_events_from_ws(
+    addr: &str,
+    max_events: Option<u64>,
+    timeout_ms: Option<u64>,
+    send_events: Option<Vec<GatewayNetEvent>>,
+    input_format: InputFormat,
+    shutdown: &AtomicBool,
+    metrics: &GatewayMetrics,
+) -> Result<Vec<GatewayNetEvent>, String> {
+    let listener = TcpListener::bind(addr).map_err(|e| format!("E_GATEWAY_LISTEN {}", e))?;
+    let local_addr = listener
+        .local_addr()
+        .map_err(|e| format!("E_GATEWAY_LISTEN {}", e))?;
+    let sender_handle = send_events.map(|events| {
+        std::thread::spawn(move || {
+            if let Ok(mut stream) = TcpStream::connect(local_addr) {
+                let _ = send_events_over_ws(&mut stream, &events);
+            }
+        })
+    });
+    let events = match accept_until_shutdown(listener, shutdown, metrics)? {
+        Some(stream) => {
+            metrics.set_clients(1);
+            let events = read_events_from_ws_stream(
+                stream,
+                max_events,
+                timeout_ms,
+                input_format,
+                shutdown,
+                metrics,
+            );
+            metrics.set_clients(0);
+            events?
+        }
+        None => Vec::new(),
+    };
+    if let Some(handle) = sender_handle {
+        let _ = handle.join();
+    }
+    Ok(events)
+}
+
+/// 핸드셰이크 뒤 text/binary 메시지마다 입력 형식을 적용한다.
+/// 조각난 메시지는 FIN까지 모으고, close를 받으면 응답하고 끝낸다.
+/// 프레임이나 모은 메시지가 상한을 넘으면 1009로 닫는다.
+fn read_events_from_ws_stream(
+    mut stream: TcpStream,
+    max_events: Option<u64>,
+    timeout_ms: Option<u64>,
+    input_format: InputFormat,
+    shutdown: &AtomicBool,
+    metrics: &GatewayMetrics,
+) -> Result<Vec<GatewayNetEvent>, String> {
+    let mut events = Vec::new();
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+    let mut message = Vec::new();
+    let mut handshaken = false;
+    let mut idle = Duration::ZERO;
+    let mut draining = false;
+    'read: loop {
+        metrics.tick();
+        metrics.set_queue_depth(events.len());
+        if !handshaken {
+            if let Some(response) = gateway_ws::take_handshake(&mut buf)? {
+                stream
+                    .write_all(response.as_bytes())
+                    .map_err(|e| format!("E_GATEWAY_WS_HANDSHAKE {}", e))?;
+                handshaken = true;
+            }
+        }
+        if handshaken {
+            while let Some(frame) = close_if_too_big(&mut stream, gateway_ws::take_frame(&mut buf))?
+            {
+                match frame.opcode {
+                    gateway_ws::OPCODE_TEXT
+                    | gateway_ws::OPCODE_BINARY
+                    | gateway_ws::OPCODE_CONTINUATION => {
+                        close_if_too_big(
+                            &mut stream,
+                            gateway_ws::append_message(&mut message, &frame.payload),
+                        )?;
+                        if !frame.fin {
+                            continue;
+                        }
+                        let text = String::from_utf8(std::mem::take(&mut message))
+                            .map_err(|e| format!("E_GATEWAY_WS_UTF8 {}", e))?;
+                        if text.trim().is_empty() {
+                            continue;
+                        }
+                        events.extend(parse_message_events(&text, input_format)?);
+                        if let Some(limit) = max_events {
+                            if events.len() as u64 >= limit {
+                                events.truncate(limit as usize);
+                                break 'read;
+                            }
+                        }
+                    }
+                    gateway_ws::OPCODE_PING => {
+                        let pong =
+                            gateway_ws::encode_frame(gateway_ws::OPCODE_PONG, &frame.payload, None);
+                        stream
+                            .write_all(&pong)
+                            .map_err(|e| format!("E_GATEWAY_WS_WRITE {}", e))?;
+                    }
+                    gateway_ws::OPCODE_CLOSE => {
+                        let close = gateway_ws::encode_frame(gateway_ws::OPCODE_CLOSE, &[], None);
+                        let _ = stream.write_all(&close);
+                        break 'read;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        match stream.read(&mut chunk) {
+            Ok(0) => break,
+            Ok(size) => {
+                idle = Duration::ZERO;
+                buf.extend_from_slice(&chunk[..size]);
+            }
+            Err(err) if is_poll_timeout(&err) => {
+                if draining {
+                    break;
+                }
+                if shutdown.load(Ordering::SeqCst) {
+                    draining = true;
+                    stream
+                        .set_nonblocking(true)
+                        .map_err(|e| format!("E_GATEWAY_INPUT_READ {}", e))?;
+                    continue;
+                }
+                idle += LISTEN_POLL;
+                if idle_expired(idle, timeout_ms) {
                     break;
                 }
-                return Err(format!("E_GATEWAY_INPUT_READ {}", err));
             }
+            Err(err) => return Err(format!("E_GATEWAY_INPUT_READ {}", err)),
         }
     }
     Ok(events)
 }
 
+/// 크기 상한을 넘은 연결은 1009로 닫고 오류를 그대로 올린다.
+fn close_if_too_big<T>(stream: &mut TcpStream, result: Result<T, String>) -> Result<T, String> {
+    if let Err(err) = &result {
+        if err.starts_with(gateway_ws::E_TOO_BIG) {
+            let _ = stream.write_all(&gateway_ws::encode_close(gateway_ws::CLOSE_TOO_BIG));
+        }
+    }
+    result
+}
+
 fn read_events_from_udp(
     addr: &str,
     max_events: Option<u64>,
     timeout_ms: Option<u64>,
     send_events: Option<Vec<GatewayNetEvent>>,
+    shutdown: &AtomicBool,
+    metrics: &GatewayMetrics,
 ) -> Result<Vec<GatewayNetEvent>, String> {
     let socket = UdpSocket::bind(addr).map_err(|e| format!("E_GATEWAY_LISTEN {}", e))?;
     let local_addr = socket
@@ -515,17 +886,23 @@ fn read_events_from_udp(
     } else {
         None
     };
-    if let Some(ms) = timeout_ms {
-        socket
-            .set_read_timeout(Some(Duration::from_millis(ms)))
-            .map_err(|e| format!("E_GATEWAY_TIMEOUT {}", e))?;
-    }
+    socket
+        .set_read_timeout(Some(LISTEN_POLL))
+        .map_err(|e| format!("E_GATEWAY_TIMEOUT {}", e))?;
     let mut events = Vec::new();
     let mut buf = [0u8; 4096];
     let mut done = false;
+    let mut idle = Duration::ZERO;
+    let mut draining = false;
+    let mut peers = BTreeSet::new();
     loop {
+        metrics.tick();
+        metrics.set_queue_depth(events.len());
         match socket.recv_from(&mut buf) {
-            Ok((size, _)) => {
+            Ok((size, peer)) => {
+                idle = Duration::ZERO;
+                peers.insert(peer);
+                metrics.set_clients(peers.len() as u64);
                 let text = String::from_utf8_lossy(&buf[..size]);
                 for line in text.lines() {
                     let trimmed = line.trim();
@@ -546,14 +923,23 @@ fn read_events_from_udp(
                     break;
                 }
             }
-            Err(err) => {
-                if err.kind() == std::io::ErrorKind::WouldBlock
-                    || err.kind() == std::io::ErrorKind::TimedOut
-                {
+            Err(err) if is_poll_timeout(&err) => {
+                if draining {
+                    break;
+                }
+                if shutdown.load(Ordering::SeqCst) {
+                    draining = true;
+                    socket
+                        .set_nonblocking(true)
+                        .map_err(|e| format!("E_GATEWAY_INPUT_READ {}", e))?;
+                    continue;
+                }
+                idle += LISTEN_POLL;
+                if idle_expired(idle, timeout_ms) {
                     break;
                 }
-                return Err(format!("E_GATEWAY_INPUT_READ {}", err));
             }
+            Err(err) => return Err(format!("E_GATEWAY_INPUT_READ {}", err)),
         }
     }
     if let Some(handle) = sender_handle {
@@ -572,6 +958,46 @@ fn send_events_over_tcp(stream: &mut TcpStream, events: &[GatewayNetEvent]) -> R
     Ok(())
 }
 
+/// 고정 키와 마스크를 써서 보내는 쪽도 결정적으로 만든다.
+fn send_events_over_ws(stream: &mut TcpStream, events: &[GatewayNetEvent]) -> Result<(), String> {
+    const CLIENT_KEY: &str = "dGhlIHNhbXBsZSBub25jZQ==";
+    const CLIENT_MASK: [u8; 4] = [0x37, 0xfa, 0x21, 0x3d];
+    let request = format!(
+        "GET / HTTP/1.1\r\nHost: gateway\r\nUpgrade: websocket\r\nConnection: Upgrade\r\n\
+         Sec-WebSocket-Key: {CLIENT_KEY}\r\nSec-WebSocket-Version: 13\r\n\r\n"
+    );
+    stream
+        .write_all(request.as_bytes())
+        .map_err(|e| format!("E_GATEWAY_SEND {}", e))?;
+    let mut response = Vec::new();
+    let mut byte = [0u8; 1];
+    while gateway_ws::find_header_end(&response).is_none() {
+        match stream.read(&mut byte) {
+            Ok(0) => return Err("E_GATEWAY_SEND 핸드셰이크 응답 없음".to_string()),
+            Ok(_) => response.push(byte[0]),
+            Err(err) => return Err(format!("E_GATEWAY_SEND {}", err)),
+        }
+    }
+    if !response.starts_with(b"HTTP/1.1 101") {
+        return Err("E_GATEWAY_SEND 핸드셰이크 거부".to_string());
+    }
+    for event in events {
+        let line = serialize_event_line(event)?;
+        let frame = gateway_ws::encode_frame(
+            gateway_ws::OPCODE_TEXT,
+            line.trim_end().as_bytes(),
+            Some(CLIENT_MASK),
+        );
+        stream
+            .write_all(&frame)
+            .map_err(|e| format!("E_GATEWAY_SEND {}", e))?;
+    }
+    let close = gateway_ws::encode_frame(gateway_ws::OPCODE_CLOSE, &[], Some(CLIENT_MASK));
+    stream
+        .write_all(&close)
+        .map_err(|e| format!("E_GATEWAY_SEND {}", e))
+}
+
 fn send_events_over_udp(
     socket: &UdpSocket,
     target: std::net::SocketAddr,
@@ -753,6 +1179,7 @@ fn build_serve_source_provenance(
                 match opts.listen_proto {
                     ListenProtocol::Tcp => "tcp",
                     ListenProtocol::Udp => "udp",
+                    ListenProtocol::Ws => "ws",
                 }
                 .to_string(),
             ),
@@ -800,7 +1227,297 @@ fn build_load_source_provenance(opts: &LoadSimOptions) -> Result<(String, JsonVa
         "threads".to_string(),
         JsonValue::Number((opts.threads as u64).into()),
     );
+    if let Some(latency_madi) = opts.latency_madi {
+        provenance.insert(
+            "latency_madi".to_string(),
+            JsonValue::Number(latency_madi.into()),
+        );
+    }
+    if let Some(loss_pct) = opts.loss_pct {
+        provenance.insert("loss_pct".to_string(), JsonValue::Number(loss_pct.into()));
+    }
     let provenance_doc = JsonValue::Object(provenance);
     let source_hash = build_source_hash(&provenance_doc)?;
     Ok((source_hash, provenance_doc))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        gateway_ws, run_load_sim, run_serve, sha256_hex, InputFormat, ListenProtocol,
+        LoadSimOptions, ServeOptions,
+    };
+    use std::io::{Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::path::PathBuf;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let nonce = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("teul_cli_gateway_{name}_{nonce}"));
+        std::fs::create_dir_all(&dir).expect("mkdir");
+        dir
+    }
+
+    fn listen_options(
+        dir: &std::path::Path,
+        proto: ListenProtocol,
+        max_events: Option<u64>,
+        shutdown: Arc<AtomicBool>,
+    ) -> ServeOptions {
+        let world = dir.join("world.ddn");
+        std::fs::write(&world, "살림.점수 <- 0.\n").expect("write world");
+        let addr = TcpListener::bind("127.0.0.1:0")
+            .and_then(|probe| probe.local_addr())
+            .expect("free port")
+            .to_string();
+        ServeOptions {
+            world,
+            threads: 1,
+            input: None,
+            out: Some(dir.join("report.json")),
+            realms: None,
+            input_format: InputFormat::Auto,
+            listen_addr: Some(addr),
+            listen_proto: proto,
+            listen_max_events: max_events,
+            listen_timeout_ms: None,
+            send_path: None,
+            send_format: InputFormat::Auto,
+            metrics_listen: None,
+            shutdown,
+        }
+    }
+
+    fn connect(addr: &str) -> TcpStream {
+        (0..200)
+            .find_map(|_| {
+                TcpStream::connect(addr).ok().or_else(|| {
+                    std::thread::sleep(Duration::from_millis(10));
+                    None
+                })
+            })
+            .expect("connect")
+    }
+
+    fn read_report(dir: &std::path::Path) -> serde_json::Value {
+        let text = std::fs::read_to_string(dir.join("report.json")).expect("report");
+        serde_json::from_str(&text).expect("json")
+    }
+
+    #[test]
+    fn serve_drains_queued_events_on_shutdown() {
+        let dir = temp_dir("shutdown");
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let options = listen_options(&dir, ListenProtocol::Tcp, None, Arc::clone(&shutdown));
+        let addr = options.listen_addr.clone().expect("addr");
+        let server = std::thread::spawn(move || run_serve(options));
+
+        let mut stream = connect(&addr);
+        for line in [
+            "{\"sender\":\"a\",\"seq\":1,\"payload\":1}\n",
+            "{\"sender\":\"b\",\"seq\":1,\"payload\":2}\n",
+        ] {
+            stream.write_all(line.as_bytes()).expect("send");
+        }
+        stream.flush().expect("flush");
+        std::thread::sleep(Duration::from_millis(100));
+        // 연결을 열어 둔 채 종료 신호를 보낸다.
+        shutdown.store(true, Ordering::SeqCst);
+        server.join().expect("join").expect("serve");
+
+        let report = read_report(&dir);
+        assert_eq!(report["events_total"], 2);
+        assert_eq!(report["events_ordered"], 2);
+        drop(stream);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    fn scrape_metric(addr: &str, name: &str) -> u64 {
+        let mut stream = connect(addr);
+        stream
+            .write_all(b"GET /metrics HTTP/1.1\r\nHost: test\r\n\r\n")
+            .expect("scrape");
+        let mut response = String::new();
+        stream.read_to_string(&mut response).expect("metrics");
+        assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+        response
+            .lines()
+            .find_map(|line| line.strip_prefix(&format!("{name} ")))
+            .and_then(|value| value.parse().ok())
+            .unwrap_or_else(|| panic!("metric {name} missing: {response}"))
+    }
+
+    #[test]
+    fn serve_metrics_endpoint_reports_advancing_tick() {
+        let dir = temp_dir("metrics");
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let mut options = listen_options(&dir, ListenProtocol::Tcp, None, Arc::clone(&shutdown));
+        let metrics_addr = TcpListener::bind("127.0.0.1:0")
+            .and_then(|probe| probe.local_addr())
+            .expect("free port")
+            .to_string();
+        options.metrics_listen = Some(metrics_addr.clone());
+        let addr = options.listen_addr.clone().expect("addr");
+        let server = std::thread::spawn(move || run_serve(options));
+
+        let mut stream = connect(&addr);
+        for line in [
+            "{\"sender\":\"a\",\"seq\":1,\"payload\":1}\n",
+            "{\"sender\":\"b\",\"seq\":1,\"payload\":2}\n",
+        ] {
+            stream.write_all(line.as_bytes()).expect("send");
+        }
+        stream.flush().expect("flush");
+        std::thread::sleep(Duration::from_millis(100));
+        let first = scrape_metric(&metrics_addr, "ddn_gateway_tick");
+        std::thread::sleep(Duration::from_millis(100));
+        let second = scrape_metric(&metrics_addr, "ddn_gateway_tick");
+        assert!(second > first, "tick must advance: {first} -> {second}");
+        assert_eq!(
+            scrape_metric(&metrics_addr, "ddn_gateway_connected_clients"),
+            1
+        );
+        assert_eq!(
+            scrape_metric(&metrics_addr, "ddn_gateway_intent_queue_depth"),
+            2
+        );
+
+        shutdown.store(true, Ordering::SeqCst);
+        server.join().expect("join").expect("serve");
+        // 지표는 보고서에 들어가지 않는다.
+        let report = read_report(&dir);
+        assert_eq!(report["events_total"], 2);
+        assert!(report.get("metrics_listen").is_none());
+        drop(stream);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn serve_ws_reads_intent_frame_after_handshake() {
+        let dir = temp_dir("ws");
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let options = listen_options(&dir, ListenProtocol::Ws, Some(1), shutdown);
+        let addr = options.listen_addr.clone().expect("addr");
+        let server = std::thread::spawn(move || run_serve(options));
+
+        let mut stream = connect(&addr);
+        stream
+            .write_all(
+                b"GET / HTTP/1.1\r\nHost: test\r\nUpgrade: websocket\r\n\
+                  Connection: Upgrade\r\nSec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\n\
+                  Sec-WebSocket-Version: 13\r\n\r\n",
+            )
+            .expect("handshake");
+        let mut response = Vec::new();
+        let mut byte = [0u8; 1];
+        while gateway_ws::find_header_end(&response).is_none() {
+            stream.read_exact(&mut byte).expect("response");
+            response.push(byte[0]);
+        }
+        let response = String::from_utf8(response).expect("utf8");
+        assert!(response.starts_with("HTTP/1.1 101"));
+        assert!(response.contains("Sec-WebSocket-Accept: s3pPLMBiTxaQ9kYGzzhZRbK+xOo="));
+
+        let intent = br#"{"sender":"web","seq":7,"payload":{"key":"Space"}}"#;
+        let frame = gateway_ws::encode_frame(gateway_ws::OPCODE_TEXT, intent, Some([9, 8, 7, 6]));
+        stream.write_all(&frame).expect("frame");
+        server.join().expect("join").expect("serve");
+
+        let report = read_report(&dir);
+        assert_eq!(report["events_total"], 1);
+        assert_eq!(report["listen_proto"], "ws");
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn serve_ws_closes_oversized_frame_with_1009() {
+        let dir = temp_dir("ws_too_big");
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let options = listen_options(&dir, ListenProtocol::Ws, Some(1), shutdown);
+        let addr = options.listen_addr.clone().expect("addr");
+        let server = std::thread::spawn(move || run_serve(options));
+
+        let mut stream = connect(&addr);
+        stream
+            .write_all(
+                b"GET / HTTP/1.1\r\nHost: test\r\nUpgrade: websocket\r\n\
+                  Connection: Upgrade\r\nSec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\n\
+                  Sec-WebSocket-Version: 13\r\n\r\n",
+            )
+            .expect("handshake");
+        let mut response = Vec::new();
+        let mut byte = [0u8; 1];
+        while gateway_ws::find_header_end(&response).is_none() {
+            stream.read_exact(&mut byte).expect("response");
+            response.push(byte[0]);
+        }
+
+        let mut header = vec![0x80 | gateway_ws::OPCODE_TEXT, 0x80 | 127];
+        header.extend_from_slice(&(gateway_ws::MAX_FRAME_BYTES as u64 + 1).to_be_bytes());
+        stream.write_all(&header).expect("header");
+        let mut close = [0u8; 4];
+        stream.read_exact(&mut close).expect("close frame");
+        assert_eq!(close, [0x88, 0x02, 0x03, 0xF1]);
+
+        let err = server.join().expect("join").expect_err("too big");
+        assert!(err.starts_with(gateway_ws::E_TOO_BIG), "{err}");
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    fn load_sim_report(
+        name: &str,
+        latency_madi: Option<u64>,
+        loss_pct: Option<u64>,
+    ) -> serde_json::Value {
+        let dir = temp_dir(name);
+        run_load_sim(LoadSimOptions {
+            clients: 4,
+            ticks: 5,
+            seed: 1,
+            realms: 2,
+            tick_hz: 60,
+            threads: 1,
+            out: Some(dir.join("report.json")),
+            latency_madi,
+            loss_pct,
+        })
+        .expect("load-sim");
+        let report = read_report(&dir);
+        let _ = std::fs::remove_dir_all(&dir);
+        report
+    }
+
+    #[test]
+    fn load_sim_full_loss_drops_every_intent() {
+        let report = load_sim_report("loss_all", None, Some(100));
+        let network = &report["network"];
+        assert_eq!(network["intents_dropped"], report["events_total"]);
+        assert_eq!(network["intents_delivered"], 0);
+        assert_eq!(network["diverged_realms"], serde_json::json!([0, 1]));
+        // 아무 클라이언트도 없었던 것처럼 빈 입력의 해시가 나와야 한다.
+        let empty = format!("sha256:{}", sha256_hex(b""));
+        for entry in report["final_state_hashes"].as_array().expect("hashes") {
+            assert_eq!(entry["state_hash"], empty.as_str());
+        }
+    }
+
+    #[test]
+    fn load_sim_latency_leaves_tail_in_flight() {
+        let plain = load_sim_report("plain", None, None);
+        assert!(plain.get("network").is_none());
+        let report = load_sim_report("latency", Some(2), Some(0));
+        let network = &report["network"];
+        assert_eq!(network["intents_in_flight"], 8);
+        assert_eq!(network["intents_delivered"], 12);
+        assert_eq!(network["diverged_realms"], serde_json::json!([0, 1]));
+        let no_loss = load_sim_report("no_loss", Some(0), Some(0));
+        assert_eq!(no_loss["network"]["diverged_realms"], serde_json::json!([]));
+        assert_eq!(no_loss["final_state_hashes"], plain["final_state_hashes"]);
+    }
+}
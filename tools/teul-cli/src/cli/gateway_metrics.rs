@@ -0,0 +1,147 @@
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use super::gateway_ws::find_header_end;
+
+/// 수집 고리가 새 요청을 확인하는 주기.
+const METRICS_POLL: Duration = Duration::from_millis(20);
+/// 요청 머리를 이만큼 넘게 보내면 더 읽지 않고 응답한다.
+const METRICS_REQUEST_LIMIT: usize = 8192;
+
+/// `gateway serve` 운영 지표. 보고서와 상태 해시에는 들어가지 않는 읽기 전용 값이다.
+/// 마디(tick)는 listen 고리가 한 바퀴 돈 횟수다.
+#[derive(Debug)]
+pub(crate) struct GatewayMetrics {
+    started: Instant,
+    tick: AtomicU64,
+    clients: AtomicU64,
+    queue_depth: AtomicU64,
+}
+
+impl Default for GatewayMetrics {
+    fn default() -> Self {
+        Self {
+            started: Instant::now(),
+            tick: AtomicU64::new(0),
+            clients: AtomicU64::new(0),
+            queue_depth: AtomicU64::new(0),
+        }
+    }
+}
+
+impl GatewayMetrics {
+    pub(crate) fn tick(&self) {
+        self.tick.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn set_clients(&self, clients: u64) {
+        self.clients.store(clients, Ordering::Relaxed);
+    }
+
+    pub(crate) fn set_queue_depth(&self, depth: usize) {
+        self.queue_depth.store(depth as u64, Ordering::Relaxed);
+    }
+
+    /// Prometheus text 형식(0.0.4)으로 지표를 쓴다.
+    pub(crate) fn render(&self) -> String {
+        let tick = self.tick.load(Ordering::Relaxed);
+        let elapsed = self.started.elapsed().as_secs_f64();
+        let ticks_per_sec = if elapsed > 0.0 {
+            tick as f64 / elapsed
+        } else {
+            0.0
+        };
+        let mut out = String::new();
+        for (name, kind, help, value) in [
+            (
+                "ddn_gateway_tick",
+                "counter",
+                "listen 고리가 돈 마디 수",
+                tick.to_string(),
+            ),
+            (
+                "ddn_gateway_ticks_per_second",
+                "gauge",
+                "시작 뒤 평균 초당 마디 수",
+                format!("{:.3}", ticks_per_sec),
+            ),
+            (
+                "ddn_gateway_connected_clients",
+                "gauge",
+                "지금 붙어 있는 클라이언트 수",
+                self.clients.load(Ordering::Relaxed).to_string(),
+            ),
+            (
+                "ddn_gateway_intent_queue_depth",
+                "gauge",
+                "받았지만 아직 정렬하지 않은 의도 수",
+                self.queue_depth.load(Ordering::Relaxed).to_string(),
+            ),
+        ] {
+            out.push_str(&format!("# HELP {name} {help}\n"));
+            out.push_str(&format!("# TYPE {name} {kind}\n"));
+            out.push_str(&format!("{name} {value}\n"));
+        }
+        out
+    }
+}
+
+/// 지표 수집 고리. `stop`을 부르면 고리를 끝내고 스레드를 거둔다.
+pub(crate) struct MetricsServer {
+    stop: Arc<AtomicBool>,
+    handle: JoinHandle<()>,
+}
+
+impl MetricsServer {
+    pub(crate) fn start(addr: &str, metrics: Arc<GatewayMetrics>) -> Result<Self, String> {
+        let listener =
+            TcpListener::bind(addr).map_err(|e| format!("E_GATEWAY_METRICS_LISTEN {}", e))?;
+        listener
+            .set_nonblocking(true)
+            .map_err(|e| format!("E_GATEWAY_METRICS_LISTEN {}", e))?;
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_flag = Arc::clone(&stop);
+        let handle = std::thread::spawn(move || {
+            while !stop_flag.load(Ordering::SeqCst) {
+                match listener.accept() {
+                    Ok((stream, _)) => {
+                        let _ = respond(stream, &metrics);
+                    }
+                    Err(_) => std::thread::sleep(METRICS_POLL),
+                }
+            }
+        });
+        Ok(Self { stop, handle })
+    }
+
+    pub(crate) fn stop(self) {
+        self.stop.store(true, Ordering::SeqCst);
+        let _ = self.handle.join();
+    }
+}
+
+fn respond(mut stream: TcpStream, metrics: &GatewayMetrics) -> std::io::Result<()> {
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(Duration::from_millis(500)))?;
+    let mut request = Vec::new();
+    let mut chunk = [0u8; 1024];
+    while find_header_end(&request).is_none() && request.len() < METRICS_REQUEST_LIMIT {
+        let size = stream.read(&mut chunk)?;
+        if size == 0 {
+            break;
+        }
+        request.extend_from_slice(&chunk[..size]);
+    }
+    let body = metrics.render();
+    let response = format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes())?;
+    stream.flush()
+}
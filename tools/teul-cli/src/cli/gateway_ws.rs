@@ -0,0 +1,221 @@
+use base64::Engine;
+use sha1::{Digest, Sha1};
+
+/// RFC 6455 핸드셰이크에서 키 뒤에 붙이는 고정 GUID.
+const WS_GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+
+pub(crate) const OPCODE_CONTINUATION: u8 = 0x0;
+pub(crate) const OPCODE_TEXT: u8 = 0x1;
+pub(crate) const OPCODE_BINARY: u8 = 0x2;
+pub(crate) const OPCODE_CLOSE: u8 = 0x8;
+pub(crate) const OPCODE_PING: u8 = 0x9;
+pub(crate) const OPCODE_PONG: u8 = 0xA;
+
+/// 프레임 하나의 payload 상한.
+pub(crate) const MAX_FRAME_BYTES: usize = 64 * 1024;
+/// 조각을 모은 메시지 하나의 상한.
+pub(crate) const MAX_MESSAGE_BYTES: usize = 1024 * 1024;
+/// RFC 6455 7.4.1 "Message Too Big" 닫기 상태.
+pub(crate) const CLOSE_TOO_BIG: u16 = 1009;
+/// 크기 상한을 넘었을 때의 오류 머리. 받는 쪽은 이것을 보고 1009로 닫는다.
+pub(crate) const E_TOO_BIG: &str = "E_GATEWAY_WS_TOO_BIG";
+
+pub(crate) struct WsFrame {
+    pub fin: bool,
+    pub opcode: u8,
+    pub payload: Vec<u8>,
+}
+
+pub(crate) fn accept_key(key: &str) -> String {
+    let mut hasher = Sha1::new();
+    hasher.update(key.trim().as_bytes());
+    hasher.update(WS_GUID.as_bytes());
+    base64::engine::general_purpose::STANDARD.encode(hasher.finalize())
+}
+
+/// 버퍼에 요청 머리가 다 모였으면 떼어 내고 101 응답을 돌려준다.
+pub(crate) fn take_handshake(buf: &mut Vec<u8>) -> Result<Option<String>, String> {
+    let Some(end) = find_header_end(buf) else {
+        return Ok(None);
+    };
+    let head: Vec<u8> = buf.drain(..end + 4).collect();
+    let head = String::from_utf8_lossy(&head);
+    let mut lines = head.split("\r\n");
+    let request_line = lines.next().unwrap_or("");
+    if !request_line.starts_with("GET ") {
+        return Err(format!("E_GATEWAY_WS_HANDSHAKE {}", request_line));
+    }
+    let key = lines
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("sec-websocket-key"))
+        .map(|(_, value)| value.trim().to_string())
+        .ok_or_else(|| "E_GATEWAY_WS_HANDSHAKE Sec-WebSocket-Key 없음".to_string())?;
+    Ok(Some(format!(
+        "HTTP/1.1 101 Switching Protocols\r\n\
+         Upgrade: websocket\r\n\
+         Connection: Upgrade\r\n\
+         Sec-WebSocket-Accept: {}\r\n\r\n",
+        accept_key(&key)
+    )))
+}
+
+pub(crate) fn find_header_end(buf: &[u8]) -> Option<usize> {
+    buf.windows(4).position(|window| window == b"\r\n\r\n")
+}
+
+/// 버퍼에 프레임 하나가 온전히 모였으면 떼어 내 마스크를 풀어 돌려준다.
+pub(crate) fn take_frame(buf: &mut Vec<u8>) -> Result<Option<WsFrame>, String> {
+    if buf.len() < 2 {
+        return Ok(None);
+    }
+    let fin = buf[0] & 0x80 != 0;
+    let opcode = buf[0] & 0x0f;
+    if buf[1] & 0x80 == 0 {
+        return Err("E_GATEWAY_WS_FRAME 클라이언트 프레임은 마스크가 필요합니다".to_string());
+    }
+    let (len, mut pos) = match buf[1] & 0x7f {
+        126 => {
+            if buf.len() < 4 {
+                return Ok(None);
+            }
+            (u16::from_be_bytes([buf[2], buf[3]]) as u64, 4)
+        }
+        127 => {
+            if buf.len() < 10 {
+                return Ok(None);
+            }
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(&buf[2..10]);
+            (u64::from_be_bytes(bytes), 10)
+        }
+        len => (len as u64, 2),
+    };
+    // payload가 다 오기 전에 머리만 보고 거른다.
+    if len > MAX_FRAME_BYTES as u64 {
+        return Err(format!("{E_TOO_BIG} 프레임 {len}바이트 > {MAX_FRAME_BYTES}"));
+    }
+    let len = len as usize;
+    if buf.len() < pos + 4 {
+        return Ok(None);
+    }
+    let mut mask = [0u8; 4];
+    mask.copy_from_slice(&buf[pos..pos + 4]);
+    pos += 4;
+    let Some(total) = pos.checked_add(len) else {
+        return Err("E_GATEWAY_WS_FRAME 길이 초과".to_string());
+    };
+    if buf.len() < total {
+        return Ok(None);
+    }
+    let payload = buf[pos..total]
+        .iter()
+        .enumerate()
+        .map(|(idx, byte)| byte ^ mask[idx % 4])
+        .collect();
+    buf.drain(..total);
+    Ok(Some(WsFrame {
+        fin,
+        opcode,
+        payload,
+    }))
+}
+
+/// 조각난 메시지에 payload를 붙인다. 모인 길이가 상한을 넘으면 거부한다.
+pub(crate) fn append_message(message: &mut Vec<u8>, payload: &[u8]) -> Result<(), String> {
+    let total = message.len() + payload.len();
+    if total > MAX_MESSAGE_BYTES {
+        return Err(format!(
+            "{E_TOO_BIG} 메시지 {total}바이트 > {MAX_MESSAGE_BYTES}"
+        ));
+    }
+    message.extend_from_slice(payload);
+    Ok(())
+}
+
+/// 상태 코드를 담은 서버 close 프레임.
+pub(crate) fn encode_close(status: u16) -> Vec<u8> {
+    encode_frame(OPCODE_CLOSE, &status.to_be_bytes(), None)
+}
+
+/// 서버 프레임은 `mask` 없이, 클라이언트 프레임은 `mask`를 씌워 만든다.
+pub(crate) fn encode_frame(opcode: u8, payload: &[u8], mask: Option<[u8; 4]>) -> Vec<u8> {
+    let mut out = vec![0x80 | opcode];
+    let mask_bit = if mask.is_some() { 0x80 } else { 0 };
+    if payload.len() < 126 {
+        out.push(mask_bit | payload.len() as u8);
+    } else if payload.len() <= u16::MAX as usize {
+        out.push(mask_bit | 126);
+        out.extend_from_slice(&(payload.len() as u16).to_be_bytes());
+    } else {
+        out.push(mask_bit | 127);
+        out.extend_from_slice(&(payload.len() as u64).to_be_bytes());
+    }
+    match mask {
+        Some(mask) => {
+            out.extend_from_slice(&mask);
+            out.extend(
+                payload
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, byte)| byte ^ mask[idx % 4]),
+            );
+        }
+        None => out.extend_from_slice(payload),
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        accept_key, append_message, encode_close, encode_frame, take_frame, CLOSE_TOO_BIG,
+        E_TOO_BIG, MAX_FRAME_BYTES, MAX_MESSAGE_BYTES, OPCODE_CONTINUATION, OPCODE_TEXT,
+    };
+
+    #[test]
+    fn accept_key_matches_rfc_example() {
+        assert_eq!(
+            accept_key("dGhlIHNhbXBsZSBub25jZQ=="),
+            "s3pPLMBiTxaQ9kYGzzhZRbK+xOo="
+        );
+    }
+
+    #[test]
+    fn masked_frame_round_trips_in_pieces() {
+        let payload = vec![b'x'; 300];
+        let bytes = encode_frame(OPCODE_TEXT, &payload, Some([1, 2, 3, 4]));
+        let mut buf = bytes[..5].to_vec();
+        assert!(take_frame(&mut buf).expect("partial").is_none());
+        buf.extend_from_slice(&bytes[5..]);
+        let frame = take_frame(&mut buf).expect("frame").expect("complete");
+        assert!(frame.fin);
+        assert_eq!(frame.opcode, OPCODE_TEXT);
+        assert_eq!(frame.payload, payload);
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn oversized_frame_is_rejected_from_its_header() {
+        let mut buf = vec![0x80 | OPCODE_TEXT, 0x80 | 127];
+        buf.extend_from_slice(&((MAX_FRAME_BYTES as u64) + 1).to_be_bytes());
+        let err = take_frame(&mut buf).err().expect("too big");
+        assert!(err.starts_with(E_TOO_BIG), "{err}");
+
+        let payload = vec![b'x'; MAX_FRAME_BYTES];
+        let mut buf = encode_frame(OPCODE_CONTINUATION, &payload, Some([1, 2, 3, 4]));
+        assert!(take_frame(&mut buf).expect("at limit").is_some());
+    }
+
+    #[test]
+    fn message_over_total_limit_is_rejected() {
+        let chunk = vec![b'x'; MAX_FRAME_BYTES];
+        let mut message = Vec::new();
+        for _ in 0..MAX_MESSAGE_BYTES / MAX_FRAME_BYTES {
+            append_message(&mut message, &chunk).expect("within limit");
+        }
+        let err = append_message(&mut message, b"x").expect_err("too big");
+        assert!(err.starts_with(E_TOO_BIG), "{err}");
+        assert_eq!(message.len(), MAX_MESSAGE_BYTES);
+        assert_eq!(encode_close(CLOSE_TOO_BIG), vec![0x88, 0x02, 0x03, 0xF1]);
+    }
+}
@@ -1,8 +1,9 @@
-use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 
 use crate::cli::sam_snapshot::apply_snapshot;
+use crate::core::fixed64::Fixed64;
 use crate::core::geoul::{
     audit_hash, decode_input_snapshot, geoul_state_hash_bytes, GeoulBundleReader, InputSnapshotV1,
 };
@@ -13,7 +14,7 @@ use crate::lang::lexer::Lexer;
 use crate::lang::parser::Parser;
 use crate::runtime::{Evaluator, RuntimeError};
 use serde::Deserialize;
-use serde_json::Value as JsonValue;
+use serde_json::{json, Value as JsonValue};
 
 pub fn run_geoul_hash(dir: &Path) -> Result<(), String> {
     let hash = audit_hash(&dir.join("audit.ddni"))?;
@@ -28,52 +29,220 @@ pub fn run_geoul_seek(dir: &Path, madi: u64) -> Result<(), String> {
     Ok(())
 }
 
+pub fn run_geoul_seek_where(
+    dir: &Path,
+    predicate: &str,
+    entry_override: Option<&Path>,
+) -> Result<(), String> {
+    let parsed = parse_seek_predicate(predicate)?;
+    let found = seek_where(dir, &parsed, entry_override)?;
+    println!("where={}", predicate.trim());
+    match found {
+        Some(madi) => println!("madi={}", madi),
+        None => println!("madi=never"),
+    }
+    Ok(())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SeekCompare {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+}
+
+#[derive(Debug, PartialEq)]
+enum SeekOperand {
+    Num(Fixed64),
+    Text(String),
+}
+
+/// `--where`의 한 줄 조건 `키 비교 글자값`. 비교는 `< <= > >= == !=`만 받는다.
+#[derive(Debug, PartialEq)]
+struct SeekPredicate {
+    key: Key,
+    compare: SeekCompare,
+    operand: SeekOperand,
+}
+
+fn parse_seek_predicate(input: &str) -> Result<SeekPredicate, String> {
+    let Some(op_start) = input.find(['<', '>', '=', '!']) else {
+        return Err(format!("E_GEOUL_WHERE 비교 기호가 없습니다: {}", input));
+    };
+    let rest = &input[op_start..];
+    let (compare, op_len) = if rest.starts_with("<=") {
+        (SeekCompare::Le, 2)
+    } else if rest.starts_with(">=") {
+        (SeekCompare::Ge, 2)
+    } else if rest.starts_with("==") {
+        (SeekCompare::Eq, 2)
+    } else if rest.starts_with("!=") {
+        (SeekCompare::Ne, 2)
+    } else if rest.starts_with('<') {
+        (SeekCompare::Lt, 1)
+    } else if rest.starts_with('>') {
+        (SeekCompare::Gt, 1)
+    } else {
+        return Err(format!("E_GEOUL_WHERE 알 수 없는 비교 기호: {}", input));
+    };
+    let key = parse_query_key(&input[..op_start])?;
+    let rhs = rest[op_len..].trim();
+    if rhs.is_empty() {
+        return Err(format!("E_GEOUL_WHERE 비교할 값이 없습니다: {}", input));
+    }
+    let operand = match rhs
+        .strip_prefix('"')
+        .and_then(|text| text.strip_suffix('"'))
+    {
+        Some(text) => SeekOperand::Text(text.to_string()),
+        None => match Fixed64::parse_literal(rhs) {
+            Some(number) => SeekOperand::Num(number),
+            None => SeekOperand::Text(rhs.to_string()),
+        },
+    };
+    Ok(SeekPredicate {
+        key,
+        compare,
+        operand,
+    })
+}
+
+impl SeekPredicate {
+    /// 수끼리는 값(단위 무시)으로, 나머지는 canon 글로 견준다. 글은 `==`/`!=`만 참이 될 수 있다.
+    fn holds(&self, state: &State) -> bool {
+        let ordering = match (state.get(&self.key), &self.operand) {
+            (Some(Value::Num(quantity)), SeekOperand::Num(number)) => quantity.raw.cmp(number),
+            (value, operand) => {
+                let left = value
+                    .map(Value::canon)
+                    .unwrap_or_else(|| Value::None.canon());
+                let right = match operand {
+                    SeekOperand::Num(number) => number.format(),
+                    SeekOperand::Text(text) => text.clone(),
+                };
+                return match self.compare {
+                    SeekCompare::Eq => left == right,
+                    SeekCompare::Ne => left != right,
+                    _ => false,
+                };
+            }
+        };
+        match self.compare {
+            SeekCompare::Lt => ordering.is_lt(),
+            SeekCompare::Le => ordering.is_le(),
+            SeekCompare::Gt => ordering.is_gt(),
+            SeekCompare::Ge => ordering.is_ge(),
+            SeekCompare::Eq => ordering.is_eq(),
+            SeekCompare::Ne => ordering.is_ne(),
+        }
+    }
+}
+
+/// 기록된 모든 마디를 다시 돌려 조건이 처음 참이 되는 마디를 찾는다.
+fn seek_where(
+    dir: &Path,
+    predicate: &SeekPredicate,
+    entry_override: Option<&Path>,
+) -> Result<Option<u64>, String> {
+    let frame_count = GeoulBundleReader::open(dir)?.frame_count();
+    if frame_count == 0 {
+        return Err("E_GEOUL_EMPTY_LOG geoul 로그에 프레임이 없습니다".to_string());
+    }
+    let mut found = None;
+    replay_geoul_states(dir, frame_count - 1, entry_override, |tick, state| {
+        if found.is_none() && predicate.holds(state) {
+            found = Some(tick);
+        }
+    })?;
+    Ok(found)
+}
+
 pub fn run_geoul_query(
     dir: &Path,
     madi: u64,
     key: &str,
     entry_override: Option<&Path>,
 ) -> Result<(), String> {
-    let entry_path = resolve_entry_path(dir, entry_override)?;
-    let source = std::fs::read_to_string(&entry_path)
-        .map_err(|err| format!("E_GEOUL_ENTRY_READ {} {}", entry_path.display(), err))?;
-    let snapshots = load_snapshots(dir, madi)?;
     let key = parse_query_key(key)?;
+    let result = query_geoul(dir, madi, &KeyMatch::Exact(key.clone()), entry_override)?;
+    let value = result
+        .entries
+        .into_iter()
+        .next()
+        .map(|(_, value)| value)
+        .unwrap_or_else(|| "없음".to_string());
 
-    let tokens = Lexer::tokenize(&source).map_err(|err| format!("E_GEOUL_LEX {:?}", err))?;
-    let default_root = Parser::default_root_for_source(&source);
-    let program = Parser::parse_with_default_root(tokens, default_root)
-        .map_err(|err| format!("E_GEOUL_PARSE {:?}", err))?;
-    let evaluator = Evaluator::with_state(State::new());
+    println!("madi={}", madi);
+    println!("key={}", key_label(&key));
+    println!("value={}", value);
+    println!("state_hash={}", result.state_hash);
+    Ok(())
+}
 
-    let mut value_out: Option<String> = None;
+pub fn run_geoul_query_prefix(
+    dir: &Path,
+    madi: u64,
+    prefix: &str,
+    entry_override: Option<&Path>,
+) -> Result<(), String> {
+    let prefix = parse_query_key(prefix)?.as_str().to_string();
+    let result = query_geoul(dir, madi, &KeyMatch::Prefix(prefix.clone()), entry_override)?;
+
+    println!("madi={}", madi);
+    println!("key_prefix={}", prefix);
+    println!("match_count={}", result.entries.len());
+    for (key, value) in &result.entries {
+        println!("key={} value={}", key, value);
+    }
+    println!("state_hash={}", result.state_hash);
+    Ok(())
+}
+
+enum KeyMatch {
+    Exact(Key),
+    Prefix(String),
+}
+
+struct GeoulQueryResult {
+    entries: Vec<(String, String)>,
+    state_hash: String,
+}
+
+/// madi 시점 상태에서 key를 찾는다. 접두 일치는 키 순서(BTreeMap)대로 돌려준다.
+fn query_geoul(
+    dir: &Path,
+    madi: u64,
+    matcher: &KeyMatch,
+    entry_override: Option<&Path>,
+) -> Result<GeoulQueryResult, String> {
+    let mut entries: Vec<(String, String)> = Vec::new();
     let mut hash_out: Option<[u8; 32]> = None;
-    let mut before_tick = |tick: u64, state: &mut State| -> Result<(), RuntimeError> {
-        if let Some(snapshot) = snapshots.get(tick as usize) {
-            apply_snapshot(state, snapshot);
-        }
-        Ok(())
-    };
-    let mut on_tick = |tick: u64, state: &State, _tick_requested: bool| {
-        if tick == madi {
-            value_out = Some(value_canon(state, &key));
-            hash_out = Some(geoul_state_hash_bytes(state));
+    replay_geoul_states(dir, madi, entry_override, |tick, state| {
+        if tick != madi {
+            return;
         }
-    };
-    evaluator
-        .run_with_ticks_observe_and_inject(&program, madi + 1, &mut before_tick, &mut on_tick)
-        .map_err(|err| format!("E_GEOUL_RUNTIME {:?}", err))?;
+        entries = match matcher {
+            KeyMatch::Exact(key) => vec![(key_label(key), value_canon(state, key))],
+            KeyMatch::Prefix(prefix) => state
+                .resources
+                .iter()
+                .filter(|(key, _)| key.as_str().starts_with(prefix.as_str()))
+                .map(|(key, value)| (key_label(key), value.canon()))
+                .collect(),
+        };
+        hash_out = Some(geoul_state_hash_bytes(state));
+    })?;
 
-    let value = value_out.unwrap_or_else(|| "없음".to_string());
-    let hash = hash_out
+    let state_hash = hash_out
         .map(|bytes| format!("blake3:{}", hex32(&bytes)))
         .unwrap_or_else(|| "blake3:".to_string());
-
-    println!("madi={}", madi);
-    println!("key={}", key_label(&key));
-    println!("value={}", value);
-    println!("state_hash={}", hash);
-    Ok(())
+    Ok(GeoulQueryResult {
+        entries,
+        state_hash,
+    })
 }
 
 pub fn run_geoul_backtrace(
@@ -82,15 +251,134 @@ pub fn run_geoul_backtrace(
     from: u64,
     to: u64,
     entry_override: Option<&Path>,
+    json: bool,
 ) -> Result<(), String> {
     if from > to {
         return Err(format!("E_GEOUL_RANGE from={} to={}", from, to));
     }
+    let key = parse_query_key(key)?;
+    if json {
+        let changes = backtrace_changes(dir, &key, from, to, entry_override)?;
+        let changes = changes
+            .iter()
+            .map(|change| {
+                json!({
+                    "madi": change.madi,
+                    "old": change.old,
+                    "new": change.new,
+                    "patch_op_index": change.patch_op_index,
+                    "patch_op_count": change.patch_op_count,
+                })
+            })
+            .collect::<Vec<_>>();
+        let payload = json!({
+            "schema": "ddn.geoul.backtrace.v1",
+            "key": key_label(&key),
+            "from": from,
+            "to": to,
+            "change_count": changes.len(),
+            "changes": changes,
+        });
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&payload).map_err(|e| e.to_string())?
+        );
+        return Ok(());
+    }
+
+    let mut changes: Vec<(u64, String)> = Vec::new();
+    let mut last_value: Option<String> = None;
+    replay_geoul_states(dir, to, entry_override, |tick, state| {
+        if tick < from || tick > to {
+            return;
+        }
+        let value = value_canon(state, &key);
+        let changed = match last_value.as_ref() {
+            None => true,
+            Some(prev) => prev != &value,
+        };
+        if changed {
+            changes.push((tick, value.clone()));
+            last_value = Some(value);
+        }
+    })?;
+
+    println!("key={}", key_label(&key));
+    println!("from={}", from);
+    println!("to={}", to);
+    println!("change_count={}", changes.len());
+    for (madi, value) in changes {
+        println!("change_madi={} value={}", madi, value);
+    }
+    Ok(())
+}
+
+/// 한 마디에서 추적 키가 바뀐 기록.
+struct BacktraceChange {
+    madi: u64,
+    old: String,
+    new: String,
+    /// 그 마디 상태 패치(값이 바뀐 키를 키 순서로 늘어놓은 목록)에서 추적 키의 위치.
+    patch_op_index: usize,
+    patch_op_count: usize,
+}
+
+/// from..=to에서 직전 마디와 값이 달라진 마디만 모은다. 0마디의 직전은 빈 상태다.
+fn backtrace_changes(
+    dir: &Path,
+    key: &Key,
+    from: u64,
+    to: u64,
+    entry_override: Option<&Path>,
+) -> Result<Vec<BacktraceChange>, String> {
+    let mut changes = Vec::new();
+    let mut prev: BTreeMap<Key, String> = BTreeMap::new();
+    replay_geoul_states(dir, to, entry_override, |tick, state| {
+        let current: BTreeMap<Key, String> = state
+            .resources
+            .iter()
+            .map(|(key, value)| (key.clone(), value.canon()))
+            .collect();
+        if tick >= from {
+            let none = Value::None.canon();
+            let old = prev.get(key).unwrap_or(&none);
+            let new = current.get(key).unwrap_or(&none);
+            if old != new {
+                let patch_ops = current
+                    .keys()
+                    .chain(prev.keys())
+                    .collect::<BTreeSet<_>>()
+                    .into_iter()
+                    .filter(|k| prev.get(*k) != current.get(*k))
+                    .collect::<Vec<_>>();
+                changes.push(BacktraceChange {
+                    madi: tick,
+                    old: old.clone(),
+                    new: new.clone(),
+                    patch_op_index: patch_ops.iter().position(|k| *k == key).unwrap_or(0),
+                    patch_op_count: patch_ops.len(),
+                });
+            }
+        }
+        prev = current;
+    })?;
+    Ok(changes)
+}
+
+/// geoul 묶음의 entry를 기록된 입력으로 다시 돌리며 0..=until 마디의 상태를 넘겨준다.
+pub(crate) fn replay_geoul_states<F>(
+    dir: &Path,
+    until: u64,
+    entry_override: Option<&Path>,
+    mut on_state: F,
+) -> Result<(), String>
+where
+    F: FnMut(u64, &State),
+{
     let entry_path = resolve_entry_path(dir, entry_override)?;
     let source = std::fs::read_to_string(&entry_path)
         .map_err(|err| format!("E_GEOUL_ENTRY_READ {} {}", entry_path.display(), err))?;
-    let snapshots = load_snapshots(dir, to)?;
-    let key = parse_query_key(key)?;
+    let snapshots = load_snapshots(dir, until)?;
 
     let tokens = Lexer::tokenize(&source).map_err(|err| format!("E_GEOUL_LEX {:?}", err))?;
     let default_root = Parser::default_root_for_source(&source);
@@ -98,8 +386,6 @@ pub fn run_geoul_backtrace(
         .map_err(|err| format!("E_GEOUL_PARSE {:?}", err))?;
     let evaluator = Evaluator::with_state(State::new());
 
-    let changes: RefCell<Vec<(u64, String)>> = RefCell::new(Vec::new());
-    let last_value: RefCell<Option<String>> = RefCell::new(None);
     let mut before_tick = |tick: u64, state: &mut State| -> Result<(), RuntimeError> {
         if let Some(snapshot) = snapshots.get(tick as usize) {
             apply_snapshot(state, snapshot);
@@ -107,36 +393,15 @@ pub fn run_geoul_backtrace(
         Ok(())
     };
     let mut on_tick = |tick: u64, state: &State, _tick_requested: bool| {
-        if tick < from || tick > to {
-            return;
-        }
-        let value = value_canon(state, &key);
-        let mut last = last_value.borrow_mut();
-        let changed = match last.as_ref() {
-            None => true,
-            Some(prev) => prev != &value,
-        };
-        if changed {
-            changes.borrow_mut().push((tick, value.clone()));
-            *last = Some(value);
-        }
+        on_state(tick, state);
     };
     evaluator
-        .run_with_ticks_observe_and_inject(&program, to + 1, &mut before_tick, &mut on_tick)
+        .run_with_ticks_observe_and_inject(&program, until + 1, &mut before_tick, &mut on_tick)
         .map_err(|err| format!("E_GEOUL_RUNTIME {:?}", err))?;
-
-    let changes = changes.into_inner();
-    println!("key={}", key_label(&key));
-    println!("from={}", from);
-    println!("to={}", to);
-    println!("change_count={}", changes.len());
-    for (madi, value) in changes {
-        println!("change_madi={} value={}", madi, value);
-    }
     Ok(())
 }
 
-fn hex32(bytes: &[u8; 32]) -> String {
+pub(crate) fn hex32(bytes: &[u8; 32]) -> String {
     let mut out = String::with_capacity(64);
     for b in bytes {
         use std::fmt::Write;
@@ -189,7 +454,7 @@ fn load_snapshots(dir: &Path, until: u64) -> Result<Vec<InputSnapshotV1>, String
     Ok(snapshots)
 }
 
-fn parse_query_key(input: &str) -> Result<Key, String> {
+pub(crate) fn parse_query_key(input: &str) -> Result<Key, String> {
     let trimmed = input.trim();
     if trimmed.is_empty() {
         return Err("E_GEOUL_KEY_EMPTY key가 비었습니다".to_string());
@@ -456,9 +721,139 @@ fn escape_json(input: &str) -> String {
     out
 }
 
+/// 테스트용: entry를 돌려 실제 상태 해시를 담은 geoul 묶음을 만든다.
+#[cfg(test)]
+pub(crate) fn write_test_geoul(dir: &Path, source: &str, madi_count: u64) {
+    use crate::cli::sam_snapshot::snapshot_from_held_mask;
+    use crate::core::geoul::{
+        encode_input_snapshot, encode_state_for_geoul, AuditHeader, GeoulBundleWriter,
+        GeoulFramePayload,
+    };
+
+    let tokens = Lexer::tokenize(source).expect("lex");
+    let default_root = Parser::default_root_for_source(source);
+    let program = Parser::parse_with_default_root(tokens, default_root).expect("parse");
+    let snapshots = (0..madi_count)
+        .map(|madi| snapshot_from_held_mask(madi, 0, 0, 0))
+        .collect::<Vec<_>>();
+    let mut states = Vec::new();
+    let mut before_tick = |tick: u64, state: &mut State| -> Result<(), RuntimeError> {
+        apply_snapshot(state, &snapshots[tick as usize]);
+        Ok(())
+    };
+    let mut on_tick = |_: u64, state: &State, _: bool| states.push(state.clone());
+    Evaluator::with_state(State::new())
+        .run_with_ticks_observe_and_inject(&program, madi_count, &mut before_tick, &mut on_tick)
+        .expect("run");
+
+    let mut writer =
+        GeoulBundleWriter::create(dir, AuditHeader::new(0, 0, 1, 0), 1, "test", "test")
+            .expect("geoul writer");
+    fs::write(dir.join("entry.ddn"), source).expect("write entry");
+    for (madi, state) in states.iter().enumerate() {
+        let payload = GeoulFramePayload {
+            patch: None,
+            alrim: None,
+            full: None,
+        };
+        writer
+            .record_frame(
+                madi as u64,
+                &encode_input_snapshot(&snapshots[madi]),
+                &encode_state_for_geoul(state),
+                payload,
+            )
+            .expect("record frame");
+    }
+    writer.finish().expect("finish geoul");
+}
+
 #[cfg(test)]
 mod tests {
-    use super::parse_query_key;
+    use super::{
+        backtrace_changes, parse_query_key, parse_seek_predicate, query_geoul, seek_where,
+        write_test_geoul, Key, KeyMatch,
+    };
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn temp_geoul(name: &str, source: &str, madi_count: u64) -> std::path::PathBuf {
+        let nonce = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("teul_cli_geoul_{name}_{nonce}"));
+        write_test_geoul(&dir, source, madi_count);
+        dir
+    }
+
+    const QUERY_SOURCE: &str = "채비 {\n  플레이어_체력:수 <- 10.\n  플레이어_속도:수 <- 2.\n  적_체력:수 <- 5.\n}.\n(매마디)마다 {\n  플레이어_체력 <- 플레이어_체력 - 1.\n}.\n";
+
+    #[test]
+    fn query_prefix_returns_sorted_matches() {
+        let dir = temp_geoul("query_prefix", QUERY_SOURCE, 3);
+        let prefix = KeyMatch::Prefix("플레이어_".to_string());
+        let by_prefix = query_geoul(&dir, 2, &prefix, None).expect("prefix query");
+        let exact = KeyMatch::Exact(Key::new("적_체력"));
+        let by_key = query_geoul(&dir, 2, &exact, None).expect("exact query");
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(
+            by_prefix.entries,
+            vec![
+                ("플레이어_속도".to_string(), "2".to_string()),
+                ("플레이어_체력".to_string(), "7".to_string()),
+            ]
+        );
+        assert_eq!(
+            by_key.entries,
+            vec![("적_체력".to_string(), "5".to_string())]
+        );
+        assert_eq!(by_prefix.state_hash, by_key.state_hash);
+    }
+
+    #[test]
+    fn seek_where_finds_first_madi_or_never() {
+        // 플레이어_체력은 0마디에 9이고 매 마디 1씩 준다 → 5마디에 처음 5 미만.
+        let dir = temp_geoul("seek_where", QUERY_SOURCE, 8);
+        let drop = parse_seek_predicate("플레이어_체력<5").expect("predicate");
+        let never = parse_seek_predicate("적_체력 >= 100").expect("predicate");
+        let first = seek_where(&dir, &drop, None).expect("seek");
+        let absent = seek_where(&dir, &never, None).expect("seek");
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(first, Some(5));
+        assert_eq!(absent, None);
+        let err = parse_seek_predicate("플레이어_체력").expect_err("no operator");
+        assert!(err.starts_with("E_GEOUL_WHERE"), "{err}");
+    }
+
+    #[test]
+    fn backtrace_lists_only_ticks_where_key_changed() {
+        let source = "채비 {\n  점수:수 <- 0.\n  체력:수 <- 10.\n}.\n(매마디)마다 {\n  점수 <- 점수 + 1.\n  { 점수 == 2 }인것 일때 {\n    체력 <- 체력 - 3.\n  }.\n  { 점수 == 4 }인것 일때 {\n    체력 <- 체력 - 20.\n  }.\n}.\n";
+        let dir = temp_geoul("backtrace_json", source, 6);
+        let key = Key::new("체력");
+        let changes = backtrace_changes(&dir, &key, 1, 5, None).expect("backtrace");
+        let from_zero = backtrace_changes(&dir, &key, 0, 5, None).expect("backtrace");
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let madis = changes.iter().map(|c| c.madi).collect::<Vec<_>>();
+        assert_eq!(madis, vec![1, 3]);
+        assert_eq!(
+            (changes[0].old.as_str(), changes[0].new.as_str()),
+            ("10", "7")
+        );
+        assert_eq!(
+            (changes[1].old.as_str(), changes[1].new.as_str()),
+            ("7", "-13")
+        );
+        // 1마디 패치: [점수, 체력]
+        assert_eq!(changes[0].patch_op_count, 2);
+        assert_eq!(changes[0].patch_op_index, 1);
+
+        let madis = from_zero.iter().map(|c| c.madi).collect::<Vec<_>>();
+        assert_eq!(madis, vec![0, 1, 3]);
+        assert_eq!(from_zero[0].old, "없음");
+    }
 
     #[test]
     fn parse_query_key_accepts_bare_key() {
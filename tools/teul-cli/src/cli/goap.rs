@@ -2,8 +2,10 @@ use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
 
-use ddonirang_core::seulgi::goal::{GoalCondition, TargetState};
-use ddonirang_core::seulgi::goap::{plan_detjson, Action, GoapPlanner, PlanError, WorldState};
+use ddonirang_core::seulgi::goal::{GoalCondition, TargetState, DEFAULT_GOAL_PRIORITY};
+use ddonirang_core::seulgi::goap::{
+    goal_priority_order, plan_detjson, Action, GoapPlanner, PlanError, WorldState,
+};
 use serde_json::Value;
 
 use super::detjson::write_text;
@@ -13,15 +15,19 @@ pub fn run_plan(input: &Path, out: Option<&Path>) -> Result<(), String> {
     let value: Value = serde_json::from_str(&raw).map_err(|e| e.to_string())?;
 
     let initial_state = parse_initial_state(&value)?;
-    let goal = parse_goal(&value)?;
+    let goals = parse_goals(&value)?;
     let actions = parse_actions(&value)?;
 
-    let plan = match GoapPlanner::plan(&initial_state, &goal, &actions) {
+    let plan = match GoapPlanner::plan_prioritized(&initial_state, &goals, &actions) {
         Ok(plan) => plan_detjson(&plan),
         Err(PlanError::NoSolution) => {
+            let goal_id = goal_priority_order(&goals)
+                .first()
+                .map(|idx| goals[*idx].goal_id)
+                .unwrap_or(0);
             format!(
                 "{{\"schema\":\"goap.plan.v1\",\"goal_id\":{},\"total_cost\":0,\"madi_count\":0,\"actions\":[],\"error\":\"NoSolution\"}}",
-                goal.goal_id
+                goal_id
             )
         }
     };
@@ -51,10 +57,23 @@ fn parse_initial_state(value: &Value) -> Result<WorldState, String> {
     Ok(WorldState { variables })
 }
 
-fn parse_goal(value: &Value) -> Result<TargetState, String> {
+fn parse_goals(value: &Value) -> Result<Vec<TargetState>, String> {
+    if let Some(goals) = value.get("goals") {
+        let arr = goals
+            .as_array()
+            .ok_or_else(|| "E_GOAP_INPUT goals 배열 필요".to_string())?;
+        if arr.is_empty() {
+            return Err("E_GOAP_INPUT goals 비어 있음".to_string());
+        }
+        return arr.iter().map(parse_goal).collect();
+    }
     let goal = value
         .get("goal")
         .ok_or_else(|| "E_GOAP_INPUT goal 없음".to_string())?;
+    Ok(vec![parse_goal(goal)?])
+}
+
+fn parse_goal(goal: &Value) -> Result<TargetState, String> {
     let obj = goal
         .as_object()
         .ok_or_else(|| "E_GOAP_INPUT goal 객체 필요".to_string())?;
@@ -68,7 +87,14 @@ fn parse_goal(value: &Value) -> Result<TargetState, String> {
         .ok_or_else(|| "E_GOAP_INPUT goal.value 없음".to_string())?;
     let goal_id = obj.get("goal_id").and_then(|v| v.as_u64()).unwrap_or(0);
     let agent_id = obj.get("agent_id").and_then(|v| v.as_u64()).unwrap_or(0);
-    let priority = obj.get("priority").and_then(|v| v.as_u64()).unwrap_or(128) as u8;
+    let priority = match obj.get("priority") {
+        Some(v) => v
+            .as_u64()
+            .filter(|p| *p <= u8::MAX as u64)
+            .ok_or_else(|| "E_GOAP_INPUT goal.priority 0~255 필요".to_string())?
+            as u8,
+        None => DEFAULT_GOAL_PRIORITY,
+    };
 
     Ok(TargetState {
         agent_id,
@@ -105,6 +131,8 @@ fn parse_actions(value: &Value) -> Result<Vec<Action>, String> {
             .get("cost")
             .and_then(|v| v.as_u64())
             .ok_or_else(|| "E_GOAP_INPUT action.cost 없음".to_string())?;
+        let cost =
+            u16::try_from(cost).map_err(|_| "E_GOAP_INPUT action.cost 0~65535 필요".to_string())?;
         let preconditions = parse_pairs(obj.get("preconditions"))?;
         let effects = parse_pairs(obj.get("effects"))?;
         actions.push(Action {
@@ -112,7 +140,7 @@ fn parse_actions(value: &Value) -> Result<Vec<Action>, String> {
             name: name.to_string(),
             preconditions,
             effects,
-            cost: cost as u16,
+            cost,
         });
     }
     Ok(actions)
@@ -6,6 +6,7 @@ use serde::Deserialize;
 
 use super::detjson::write_text;
 use super::paths;
+use crate::core::fixed64::Fixed64;
 
 #[derive(Deserialize)]
 struct MlpModel {
@@ -20,7 +21,8 @@ struct MlpModel {
 #[derive(Deserialize)]
 struct InferInput {
     schema: Option<String>,
-    input: Vec<i64>,
+    input: Option<Vec<i64>>,
+    inputs: Option<Vec<Vec<i64>>>,
 }
 
 pub fn run_mlp(model_path: &Path, input_path: &Path, out_dir: Option<&Path>) -> Result<(), String> {
@@ -56,18 +58,49 @@ pub fn run_mlp(model_path: &Path, input_path: &Path, out_dir: Option<&Path>) ->
             return Err(format!("E_INFER_INPUT_SCHEMA {}", schema));
         }
     }
-    if input.input.len() != model.input_size {
-        return Err(format!(
-            "E_INFER_INPUT_LEN input_size {} != {}",
-            input.input.len(),
-            model.input_size
-        ));
-    }
 
     let weights_i16 = parse_weights_i16(&weights, model.expected_weight_count())?;
-    let output = run_inference(&model, activation, &input.input, &weights_i16);
     let model_hash = compute_model_hash(&model_bytes, &weights);
-    let output_detjson = build_output(&model_hash, &output);
+    let output_detjson = match (input.input, input.inputs) {
+        (Some(row), None) => {
+            if row.len() != model.input_size {
+                return Err(format!(
+                    "E_INFER_INPUT_LEN input_size {} != {}",
+                    row.len(),
+                    model.input_size
+                ));
+            }
+            let output = run_inference(&model, activation, &row, &weights_i16)
+                .ok_or_else(|| "E_INFER_OVERFLOW 값이 Fixed64 범위를 벗어났습니다".to_string())?;
+            build_output(&model_hash, &output)
+        }
+        (None, Some(rows)) => {
+            if rows.is_empty() {
+                return Err("E_INFER_INPUT_BATCH inputs가 비었습니다".to_string());
+            }
+            let mut outputs = Vec::with_capacity(rows.len());
+            for (idx, row) in rows.iter().enumerate() {
+                if row.len() != model.input_size {
+                    return Err(format!(
+                        "E_INFER_INPUT_LEN row {} input_size {} != {}",
+                        idx,
+                        row.len(),
+                        model.input_size
+                    ));
+                }
+                let output =
+                    run_inference(&model, activation, row, &weights_i16).ok_or_else(|| {
+                        format!(
+                            "E_INFER_OVERFLOW row {} 값이 Fixed64 범위를 벗어났습니다",
+                            idx
+                        )
+                    })?;
+                outputs.push(output);
+            }
+            build_batch_output(&model_hash, &outputs)
+        }
+        _ => return Err("E_INFER_INPUT_BATCH input과 inputs 중 하나만 있어야 합니다".to_string()),
+    };
 
     let out_dir = resolve_out_dir(out_dir);
     fs::create_dir_all(&out_dir).map_err(|e| e.to_string())?;
@@ -119,40 +152,48 @@ fn parse_weights_i16(bytes: &[u8], expected: usize) -> Result<Vec<i16>, String>
     Ok(out)
 }
 
-fn run_inference(model: &MlpModel, activation: &str, input: &[i64], weights: &[i16]) -> Vec<i64> {
+/// 입력 한 줄에 대한 MLP 출력. 모든 곱셈·덧셈은 `Fixed64`로 하되, 포화하지 않고
+/// 값이 범위를 벗어나면 `None`을 돌려준다.
+fn run_inference(
+    model: &MlpModel,
+    activation: &str,
+    input: &[i64],
+    weights: &[i16],
+) -> Option<Vec<Fixed64>> {
     let mut offset = 0usize;
-    let mut hidden = vec![0i64; model.hidden_size];
-
-    for h in 0..model.hidden_size {
-        let mut acc: i64 = 0;
-        for i in 0..model.input_size {
-            let w = weights[offset] as i64;
-            offset += 1;
-            acc = acc.saturating_add(input[i].saturating_mul(w));
-        }
-        let bias = weights[offset] as i64;
+    let mut next_weight = || {
+        let w = Fixed64::from_int(weights[offset] as i64);
         offset += 1;
-        acc = acc.saturating_add(bias);
-        hidden[h] = if activation == "relu" && acc < 0 {
-            0
+        w
+    };
+    let input = input
+        .iter()
+        .map(|v| Fixed64::checked_from_int(*v))
+        .collect::<Option<Vec<_>>>()?;
+    let mut hidden = vec![Fixed64::zero(); model.hidden_size];
+
+    for slot in hidden.iter_mut() {
+        let mut acc = Fixed64::zero();
+        for value in &input {
+            acc = acc.checked_add(value.checked_mul(next_weight())?)?;
+        }
+        acc = acc.checked_add(next_weight())?;
+        *slot = if activation == "relu" && acc < Fixed64::zero() {
+            Fixed64::zero()
         } else {
             acc
         };
     }
 
-    let mut output = vec![0i64; model.output_size];
-    for o in 0..model.output_size {
-        let mut acc: i64 = 0;
-        for h in 0..model.hidden_size {
-            let w = weights[offset] as i64;
-            offset += 1;
-            acc = acc.saturating_add(hidden[h].saturating_mul(w));
+    let mut output = vec![Fixed64::zero(); model.output_size];
+    for slot in output.iter_mut() {
+        let mut acc = Fixed64::zero();
+        for value in &hidden {
+            acc = acc.checked_add(value.checked_mul(next_weight())?)?;
         }
-        let bias = weights[offset] as i64;
-        offset += 1;
-        output[o] = acc.saturating_add(bias);
+        *slot = acc.checked_add(next_weight())?;
     }
-    output
+    Some(output)
 }
 
 fn compute_model_hash(model_bytes: &[u8], weights: &[u8]) -> String {
@@ -162,17 +203,131 @@ fn compute_model_hash(model_bytes: &[u8], weights: &[u8]) -> String {
     format!("blake3:{}", hasher.finalize().to_hex())
 }
 
-fn build_output(model_hash: &str, output: &[i64]) -> String {
+fn build_output(model_hash: &str, output: &[Fixed64]) -> String {
     let mut out = String::new();
     out.push_str("{\"schema\":\"seulgi.infer_output.v1\",\"model_hash\":\"");
     out.push_str(model_hash);
-    out.push_str("\",\"output\":[");
-    for (idx, value) in output.iter().enumerate() {
+    out.push_str("\",\"output\":");
+    push_row(&mut out, output);
+    out.push('}');
+    out
+}
+
+fn build_batch_output(model_hash: &str, outputs: &[Vec<Fixed64>]) -> String {
+    let mut out = String::new();
+    out.push_str("{\"schema\":\"seulgi.infer_batch_output.v1\",\"model_hash\":\"");
+    out.push_str(model_hash);
+    out.push_str("\",\"rows\":[");
+    for (idx, output) in outputs.iter().enumerate() {
         if idx > 0 {
             out.push(',');
         }
-        out.push_str(&value.to_string());
+        out.push_str(&format!("{{\"row\":{},\"output\":", idx));
+        push_row(&mut out, output);
+        out.push('}');
     }
     out.push_str("]}");
     out
 }
+
+fn push_row(out: &mut String, row: &[Fixed64]) {
+    out.push('[');
+    for (idx, value) in row.iter().enumerate() {
+        if idx > 0 {
+            out.push(',');
+        }
+        out.push_str(&value.format());
+    }
+    out.push(']');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value as JsonValue;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let stamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("ddn_infer_{}_{}", name, stamp));
+        fs::create_dir_all(&dir).expect("mkdir");
+        dir
+    }
+
+    fn infer(dir: &Path, model: &Path, name: &str, input: &str) -> Result<JsonValue, String> {
+        let input_path = dir.join(format!("{}.detjson", name));
+        fs::write(&input_path, input).expect("write input");
+        let out_dir = dir.join(name);
+        run_mlp(model, &input_path, Some(&out_dir))?;
+        let text = fs::read_to_string(out_dir.join("infer.output.detjson")).expect("output");
+        Ok(serde_json::from_str(&text).expect("json"))
+    }
+
+    #[test]
+    fn batch_rows_match_single_row_runs() {
+        let dir = temp_dir("batch");
+        // 입력 2 → 은닉 2(relu) → 출력 2
+        let weights: [i16; 12] = [3, -1, 2, -2, 4, -5, 1, 2, 1, -3, 1, 7];
+        let bytes: Vec<u8> = weights.iter().flat_map(|w| w.to_le_bytes()).collect();
+        fs::write(dir.join("weights.bin"), bytes).expect("write weights");
+        let model = dir.join("model.detjson");
+        fs::write(
+            &model,
+            "{\"schema\":\"seulgi.mlp.v1\",\"input_size\":2,\"hidden_size\":2,\"output_size\":2,\"activation\":\"relu\",\"weights_path\":\"weights.bin\"}",
+        )
+        .expect("write model");
+
+        let rows = [[1, 2], [-3, 4], [5, -6]];
+        let batch = infer(
+            &dir,
+            &model,
+            "batch",
+            "{\"schema\":\"seulgi.infer_input.v1\",\"inputs\":[[1,2],[-3,4],[5,-6]]}",
+        )
+        .expect("batch");
+        let batch_rows = batch["rows"].as_array().expect("rows");
+        assert_eq!(batch_rows.len(), rows.len());
+        for (idx, row) in rows.iter().enumerate() {
+            let single = infer(
+                &dir,
+                &model,
+                &format!("single_{}", idx),
+                &format!("{{\"input\":[{},{}]}}", row[0], row[1]),
+            )
+            .expect("single");
+            assert_eq!(batch_rows[idx]["row"], idx);
+            assert_eq!(batch_rows[idx]["output"], single["output"]);
+        }
+
+        let err = infer(&dir, &model, "bad", "{\"inputs\":[[1,2],[3]]}").expect_err("len");
+        assert!(err.starts_with("E_INFER_INPUT_LEN row 1 "), "{}", err);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn values_beyond_fixed64_range_are_rejected_not_clamped() {
+        let dir = temp_dir("overflow");
+        // 입력 1 → 은닉 1(linear) → 출력 1, 가중치 모두 1·치우침 0
+        let weights: [i16; 4] = [1, 0, 1, 0];
+        let bytes: Vec<u8> = weights.iter().flat_map(|w| w.to_le_bytes()).collect();
+        fs::write(dir.join("weights.bin"), bytes).expect("write weights");
+        let model = dir.join("model.detjson");
+        fs::write(
+            &model,
+            "{\"schema\":\"seulgi.mlp.v1\",\"input_size\":1,\"hidden_size\":1,\"output_size\":1,\"activation\":\"linear\",\"weights_path\":\"weights.bin\"}",
+        )
+        .expect("write model");
+
+        let ok = infer(&dir, &model, "ok", "{\"input\":[2147483647]}").expect("in range");
+        assert_eq!(ok["output"][0], 2147483647);
+        let err = infer(&dir, &model, "single", "{\"input\":[2147483648]}").expect_err("overflow");
+        assert!(err.starts_with("E_INFER_OVERFLOW "), "{}", err);
+        let err =
+            infer(&dir, &model, "batch", "{\"inputs\":[[1],[4294967296]]}").expect_err("overflow");
+        assert!(err.starts_with("E_INFER_OVERFLOW row 1 "), "{}", err);
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
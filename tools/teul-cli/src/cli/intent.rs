@@ -4,7 +4,10 @@ use std::path::{Path, PathBuf};
 
 use ddonirang_core::fixed64::Fixed64;
 use ddonirang_core::platform::SeulgiIntent;
-use ddonirang_core::seulgi::intent::{intent_bundle_detjson, IntentRecord};
+use ddonirang_core::seulgi::intent::{
+    decoded_bundle_detjson, intent_bundle_detjson, merge_records, merged_bundle_detjson,
+    IntentRecord,
+};
 use serde_json::Value;
 
 use super::detjson::write_text;
@@ -13,14 +16,10 @@ pub fn run_inspect(
     geoul: &Path,
     madi: Option<u64>,
     agent: Option<u64>,
+    decode: bool,
     out: Option<&Path>,
 ) -> Result<(), String> {
-    let jsonl_path = geoul.join("intent.jsonl");
-    if !jsonl_path.exists() {
-        return Err("E_INTENT_MISSING intent.jsonl이 없습니다".to_string());
-    }
-    let records = parse_intent_jsonl(&jsonl_path, madi, agent)?;
-    let detjson = intent_bundle_detjson(&records);
+    let detjson = inspect_text(geoul, madi, agent, decode)?;
     if let Some(path) = out {
         write_text(path, &detjson)?;
     } else {
@@ -29,6 +28,26 @@ pub fn run_inspect(
     Ok(())
 }
 
+/// `decode`면 동작·대상·핀으로 풀어 들여쓴 JSON을 돌려준다.
+fn inspect_text(
+    geoul: &Path,
+    madi: Option<u64>,
+    agent: Option<u64>,
+    decode: bool,
+) -> Result<String, String> {
+    let jsonl_path = geoul.join("intent.jsonl");
+    if !jsonl_path.exists() {
+        return Err("E_INTENT_MISSING intent.jsonl이 없습니다".to_string());
+    }
+    let records = parse_intent_jsonl(&jsonl_path, madi, agent)?;
+    if !decode {
+        return Ok(intent_bundle_detjson(&records));
+    }
+    let value: Value = serde_json::from_str(&decoded_bundle_detjson(&records))
+        .map_err(|e| format!("E_INTENT_DECODE {}", e))?;
+    serde_json::to_string_pretty(&value).map_err(|e| format!("E_INTENT_DECODE {}", e))
+}
+
 pub fn run_mock(
     input: &Path,
     out: &Path,
@@ -64,7 +83,7 @@ pub fn run_merge(
         let mut subset = parse_intent_jsonl(path.as_path(), madi, agent)?;
         records.append(&mut subset);
     }
-    let detjson = intent_bundle_detjson(&records);
+    let detjson = merged_bundle_detjson(&merge_records(records));
     if let Some(path) = out {
         write_text(path, &detjson)?;
     } else {
@@ -73,7 +92,7 @@ pub fn run_merge(
     Ok(())
 }
 
-fn parse_intent_jsonl(
+pub(crate) fn parse_intent_jsonl(
     path: &Path,
     madi: Option<u64>,
     agent: Option<u64>,
@@ -294,7 +313,7 @@ mod tests {
     use std::path::PathBuf;
     use std::time::{SystemTime, UNIX_EPOCH};
 
-    use super::parse_intent_jsonl;
+    use super::{inspect_text, parse_intent_jsonl, run_mock};
 
     fn write_temp_intent_jsonl(lines: &[&str]) -> PathBuf {
         let nanos = SystemTime::now()
@@ -332,4 +351,31 @@ mod tests {
         assert!(err.starts_with("E_INTENT_RECV_SEQ_REVERSE"), "{err}");
         let _ = fs::remove_file(path);
     }
+
+    #[test]
+    fn inspect_decode_reads_back_mocked_intent() {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("clock")
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("intent_cli_decode_{nanos}"));
+        fs::create_dir_all(&dir).expect("mkdir");
+        let input = dir.join("say.txt");
+        fs::write(&input, "앞으로 가자\n").expect("write input");
+        run_mock(&input, &dir.join("intent.jsonl"), 3, 2, 1).expect("mock");
+
+        let text = inspect_text(&dir, None, Some(3), true).expect("inspect");
+        let value: serde_json::Value = serde_json::from_str(&text).expect("json");
+        assert_eq!(value["schema"], "seulgi.intent_decoded.v1");
+        let items = value["items"].as_array().expect("items");
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0]["agent_id"], 3);
+        assert_eq!(items[0]["action"], "Say");
+        assert!(items[0]["target"].is_null());
+        assert_eq!(items[0]["pins"]["text"], "앞으로 가자");
+
+        let other = inspect_text(&dir, None, Some(4), true).expect("inspect other");
+        assert!(other.contains("\"items\": []"));
+        let _ = fs::remove_dir_all(dir);
+    }
 }
@@ -2,10 +2,11 @@ use std::path::Path;
 
 use ddonirang_core::seulgi::latency::{
     build_replay_header, simulate, LatencyEvent, LatencyMode, LatencyPolicy, LatencySchedule,
-    ScheduledPacket,
+    LatencySummary, ScheduledPacket,
 };
 
 use super::detjson::{sha256_hex, write_text};
+use super::intent::parse_intent_jsonl;
 
 pub fn run_simulate(
     l_madi: u64,
@@ -13,11 +14,13 @@ pub fn run_simulate(
     count: u64,
     seed: u64,
     current_madi: u64,
+    summary: bool,
     out: Option<&Path>,
 ) -> Result<(), String> {
     let policy = LatencyPolicy { l_madi, mode, seed };
     let events = simulate(policy, count);
-    let detjson = build_detjson(policy, &events, current_madi);
+    let summary = summary.then(|| LatencyPolicy::summarize(&events));
+    let detjson = build_detjson(policy, &events, current_madi, summary);
     let hash = sha256_hex(detjson.as_bytes());
     if let Some(path) = out {
         write_text(path, &detjson)?;
@@ -28,7 +31,12 @@ pub fn run_simulate(
     Ok(())
 }
 
-fn build_detjson(policy: LatencyPolicy, events: &[LatencyEvent], current_madi: u64) -> String {
+fn build_detjson(
+    policy: LatencyPolicy,
+    events: &[LatencyEvent],
+    current_madi: u64,
+    summary: Option<LatencySummary>,
+) -> String {
     let mode = match policy.mode {
         LatencyMode::Fixed => "fixed",
         LatencyMode::Jitter => "jitter",
@@ -72,6 +80,118 @@ fn build_detjson(policy: LatencyPolicy, events: &[LatencyEvent], current_madi: u
         out.push_str(if packet.dropped { "true" } else { "false" });
         out.push('}');
     }
-    out.push_str("]}");
+    out.push(']');
+    if let Some(summary) = summary {
+        push_summary(&mut out, &summary);
+    }
+    out.push('}');
     out
 }
+
+/// 거울의 intent.jsonl에서 실제 도착(`accepted_madi`)과 적용(`target_madi`) 간격을 뽑아
+/// `simulate --summary`와 같은 백분위 요약을 낸다.
+pub fn run_analyze(geoul: &Path, out: Option<&Path>) -> Result<(), String> {
+    let detjson = analyze_text(geoul)?;
+    let hash = sha256_hex(detjson.as_bytes());
+    if let Some(path) = out {
+        write_text(path, &detjson)?;
+    } else {
+        println!("{}", detjson);
+    }
+    println!("latency_hash=sha256:{}", hash);
+    Ok(())
+}
+
+fn analyze_text(geoul: &Path) -> Result<String, String> {
+    let jsonl_path = geoul.join("intent.jsonl");
+    if !jsonl_path.exists() {
+        return Err("E_LATENCY_INTENT_MISSING intent.jsonl이 없습니다".to_string());
+    }
+    let records = parse_intent_jsonl(&jsonl_path, None, None)?;
+    let events: Vec<LatencyEvent> = records
+        .iter()
+        .map(|record| LatencyEvent {
+            madi: record.accepted_madi,
+            deliver_madi: record.target_madi,
+        })
+        .collect();
+    let summary = LatencyPolicy::summarize(&events);
+    let mut out = String::new();
+    out.push_str("{\"schema\":\"latency.analysis.v1\",\"events\":[");
+    for (idx, (record, ev)) in records.iter().zip(events.iter()).enumerate() {
+        if idx > 0 {
+            out.push(',');
+        }
+        out.push_str("{\"agent_id\":");
+        out.push_str(&record.agent_id.to_string());
+        out.push_str(",\"recv_seq\":");
+        out.push_str(&record.recv_seq.to_string());
+        out.push_str(",\"accepted_madi\":");
+        out.push_str(&ev.madi.to_string());
+        out.push_str(",\"target_madi\":");
+        out.push_str(&ev.deliver_madi.to_string());
+        out.push_str(",\"latency\":");
+        out.push_str(&ev.deliver_madi.saturating_sub(ev.madi).to_string());
+        out.push('}');
+    }
+    out.push(']');
+    push_summary(&mut out, &summary);
+    out.push('}');
+    Ok(out)
+}
+
+fn push_summary(out: &mut String, summary: &LatencySummary) {
+    out.push_str(",\"summary\":{\"count\":");
+    out.push_str(&summary.count.to_string());
+    out.push_str(",\"min\":");
+    out.push_str(&summary.min.to_string());
+    out.push_str(",\"max\":");
+    out.push_str(&summary.max.to_string());
+    out.push_str(",\"p50\":");
+    out.push_str(&summary.p50.to_string());
+    out.push_str(",\"p95\":");
+    out.push_str(&summary.p95.to_string());
+    out.push_str(",\"p99\":");
+    out.push_str(&summary.p99.to_string());
+    out.push('}');
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use super::analyze_text;
+
+    #[test]
+    fn analyze_reports_recorded_intent_latency_percentiles() {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("clock")
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("latency_analyze_{nanos}"));
+        fs::create_dir_all(&dir).expect("mkdir");
+        // 지연 1, 2, 2, 5, 9 마디 → p50=2, max=9
+        let lines = [
+            r#"{"accepted_madi":0,"target_madi":1,"agent_id":1,"recv_seq":1,"kind":"Say","text":"a"}"#,
+            r#"{"accepted_madi":1,"target_madi":3,"agent_id":1,"recv_seq":2,"kind":"Say","text":"b"}"#,
+            r#"{"accepted_madi":2,"target_madi":4,"agent_id":2,"recv_seq":1,"kind":"Say","text":"c"}"#,
+            r#"{"accepted_madi":3,"target_madi":8,"agent_id":2,"recv_seq":2,"kind":"Say","text":"d"}"#,
+            r#"{"accepted_madi":4,"target_madi":13,"agent_id":1,"recv_seq":3,"kind":"Say","text":"e"}"#,
+        ];
+        fs::write(dir.join("intent.jsonl"), lines.join("\n")).expect("write");
+
+        let text = analyze_text(&dir).expect("analyze");
+        let value: serde_json::Value = serde_json::from_str(&text).expect("json");
+        assert_eq!(value["schema"], "latency.analysis.v1");
+        assert_eq!(value["summary"]["count"], 5);
+        assert_eq!(value["summary"]["min"], 1);
+        assert_eq!(value["summary"]["p50"], 2);
+        assert_eq!(value["summary"]["max"], 9);
+        assert_eq!(value["events"][3]["latency"], 5);
+
+        let missing = analyze_text(&dir.join("none")).expect_err("missing intent.jsonl");
+        assert!(missing.starts_with("E_LATENCY_INTENT_MISSING"), "{missing}");
+        let _ = fs::remove_dir_all(dir);
+    }
+}
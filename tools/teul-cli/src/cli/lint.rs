@@ -8,7 +8,7 @@ use crate::cli::run::RunError;
 use crate::lang::dialect::DialectConfig;
 use crate::lang::lexer::Lexer;
 use crate::lang::parser::Parser;
-use crate::lang::token::TokenKind;
+use crate::lang::token::{Token, TokenKind};
 
 struct LegacyTerm {
     input: &'static str,
@@ -52,18 +52,25 @@ struct LineChange {
     old_line: String,
     new_line: String,
     reason: String,
+    applied: usize,
 }
 
-pub fn run(file: &Path, suggest_patch: bool, out: Option<&Path>) -> Result<(), String> {
+pub fn run(file: &Path, suggest_patch: bool, fix: bool, out: Option<&Path>) -> Result<(), String> {
     let source = fs::read_to_string(file).map_err(|e| format!("E_LINT_READ {}", e))?;
     let file_label = file.display().to_string();
     let dialect = DialectConfig::from_source(&source);
-    let tokens = Lexer::tokenize(&source).map_err(|e| RunError::Lex(e).format(&file_label))?;
-    let default_root = Parser::default_root_for_source(&source);
-    Parser::parse_with_default_root(tokens.clone(), default_root)
-        .map_err(|e| RunError::Parse(e).format(&file_label))?;
+    let tokens = parse_checked(&source, &file_label).map_err(|err| {
+        if fix {
+            format!(
+                "E_LINT_FIX_PARSE 구문 오류가 있는 파일은 고치지 않습니다: {}",
+                err
+            )
+        } else {
+            err
+        }
+    })?;
 
-    let lines: Vec<String> = source.lines().map(|line| line.to_string()).collect();
+    let mut lines: Vec<String> = source.lines().map(|line| line.to_string()).collect();
     let mut line_counts: HashMap<String, usize> = HashMap::new();
     for line in &lines {
         *line_counts.entry(line.clone()).or_insert(0) += 1;
@@ -98,6 +105,7 @@ pub fn run(file: &Path, suggest_patch: bool, out: Option<&Path>) -> Result<(), S
     }
 
     let mut changes: Vec<serde_json::Value> = Vec::new();
+    let mut fixed_count = 0usize;
     let file_label = file.to_string_lossy().to_string();
 
     for (line_idx, replacements) in by_line {
@@ -109,14 +117,15 @@ pub fn run(file: &Path, suggest_patch: bool, out: Option<&Path>) -> Result<(), S
         if old_line.is_empty() {
             continue;
         }
-        if old_line.contains('{') {
+        // 줄 번호로 바로 고치는 --fix에는 anchor 제약이 없다.
+        if !fix && old_line.contains('{') {
             warnings.push(format!(
                 "E_LINT_SKIP_BLOCK line={} contains '{{'",
                 line_idx + 1
             ));
             continue;
         }
-        if line_counts.get(&old_line).copied().unwrap_or(0) > 1 {
+        if !fix && line_counts.get(&old_line).copied().unwrap_or(0) > 1 {
             warnings.push(format!(
                 "E_LINT_SKIP_AMBIGUOUS line={} anchor is not unique",
                 line_idx + 1
@@ -128,6 +137,11 @@ pub fn run(file: &Path, suggest_patch: bool, out: Option<&Path>) -> Result<(), S
         else {
             continue;
         };
+        if fix {
+            fixed_count += change.applied;
+            lines[line_idx] = change.new_line;
+            continue;
+        }
         changes.push(json!({
             "kind": "replace_block",
             "target": {
@@ -152,12 +166,46 @@ pub fn run(file: &Path, suggest_patch: bool, out: Option<&Path>) -> Result<(), S
         println!("patch_written={}", out_path.display());
     }
 
+    if fix {
+        if fixed_count > 0 {
+            let fixed = join_lines(&source, &lines);
+            parse_checked(&fixed, &file_label).map_err(|err| {
+                format!("E_LINT_FIX_VERIFY 고친 결과가 파싱되지 않습니다: {}", err)
+            })?;
+            fs::write(file, fixed).map_err(|e| format!("E_LINT_WRITE {}", e))?;
+        }
+        println!("fix_applied={}", fixed_count);
+        println!("manual_remaining={}", warnings.len());
+    }
+
     for warning in warnings {
         eprintln!("{}", warning);
     }
     Ok(())
 }
 
+fn parse_checked(source: &str, file_label: &str) -> Result<Vec<Token>, String> {
+    let tokens = Lexer::tokenize(source).map_err(|e| RunError::Lex(e).format(file_label))?;
+    let default_root = Parser::default_root_for_source(source);
+    Parser::parse_with_default_root(tokens.clone(), default_root)
+        .map_err(|e| RunError::Parse(e).format(file_label))?;
+    Ok(tokens)
+}
+
+/// 원본의 줄바꿈 방식과 끝 줄바꿈을 유지한 채 줄을 다시 잇는다.
+fn join_lines(source: &str, lines: &[String]) -> String {
+    let newline = if source.contains("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    };
+    let mut text = lines.join(newline);
+    if source.ends_with('\n') {
+        text.push_str(newline);
+    }
+    text
+}
+
 fn collect_i18n_warnings(source: &str) -> Vec<String> {
     let mut warnings = Vec::new();
     let active_tag = detect_active_dialect_tag(source);
@@ -313,13 +361,26 @@ fn apply_replacements(
     Some(LineChange {
         old_line: line.to_string(),
         new_line,
+        applied: reasons.len(),
         reason,
     })
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{collect_i18n_warnings, contains_ident_word, detect_active_dialect_tag};
+    use super::{collect_i18n_warnings, contains_ident_word, detect_active_dialect_tag, run};
+    use std::fs;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn temp_ddn(name: &str, source: &str) -> std::path::PathBuf {
+        let nonce = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!("teul_cli_lint_{name}_{nonce}.ddn"));
+        fs::write(&path, source).expect("write source");
+        path
+    }
 
     #[test]
     fn detect_active_dialect_header() {
@@ -353,4 +414,27 @@ mod tests {
         assert!(contains_ident_word("mana 조건", "mana"));
         assert!(!contains_ident_word("imanager", "mana"));
     }
+
+    #[test]
+    fn fix_rewrites_legacy_term_in_place() {
+        let path = temp_ddn("fix_legacy_term", "채비 {\n  변수:수 <- 1.\n}.\n");
+        run(&path, false, true, None).expect("lint --fix");
+        let fixed = fs::read_to_string(&path).expect("read fixed");
+        let _ = fs::remove_file(&path);
+        assert_eq!(fixed, "채비 {\n  이름:수 <- 1.\n}.\n");
+        super::parse_checked(&fixed, "fixed.ddn").expect("fixed file parses");
+        let canon = crate::canon::canonicalize(&fixed, false).expect("canonicalize");
+        assert_eq!(canon.ddn.trim_end(), fixed.trim_end());
+    }
+
+    #[test]
+    fn fix_refuses_file_with_parse_error() {
+        let source = "채비 {\n  변수:수 <- \n";
+        let path = temp_ddn("fix_parse_error", source);
+        let err = run(&path, false, true, None).expect_err("parse error must refuse");
+        let after = fs::read_to_string(&path).expect("read");
+        let _ = fs::remove_file(&path);
+        assert!(err.starts_with("E_LINT_FIX_PARSE"), "err={err}");
+        assert_eq!(after, source);
+    }
 }
@@ -7,7 +7,9 @@ pub mod bogae_bundle;
 pub mod bogae_console;
 pub mod bogae_edit;
 pub mod bogae_playback;
+pub mod bogae_png;
 pub mod bogae_web;
+pub mod build;
 pub mod canon;
 pub mod cert;
 pub mod check;
@@ -27,6 +29,8 @@ pub mod frontdoor_parse;
 pub mod gaji;
 pub mod gaji_registry;
 pub mod gateway;
+pub mod gateway_metrics;
+pub mod gateway_ws;
 pub mod geoul;
 pub mod goal;
 pub mod goap;
@@ -68,5 +72,6 @@ pub mod train;
 pub mod universe;
 pub mod view;
 pub mod warp;
+pub mod watch;
 pub mod worker;
 pub mod workshop;
@@ -17,11 +17,16 @@ use ddonirang_core::nurigym::gridmaze::{
 use ddonirang_core::nurigym::pendulum::{
     run_episode as run_pendulum_episode, PendulumConfig, PendulumEnv,
 };
-use ddonirang_core::nurigym::spec::{ActionSpec, ObservationSpec};
+use ddonirang_core::nurigym::spec::{ActionSpec, ContinuousAction, ObsNormalizer, ObservationSpec};
 use serde::Deserialize;
-use std::collections::HashMap;
+use serde_json::value::RawValue;
+use std::collections::{BTreeMap, HashMap};
 
 use super::detjson::{read_text, sha256_hex, write_text};
+use super::run::run_source_with_state;
+use super::sam_snapshot::is_sam_key;
+use crate::core::value::Value;
+use crate::core::State;
 
 #[derive(Debug, Deserialize)]
 struct NuriGymRunInput {
@@ -32,6 +37,7 @@ struct NuriGymRunInput {
     max_steps: Option<u64>,
     actions: Option<Vec<i64>>,
     agents: Option<Vec<NuriGymAgentInput>>,
+    agent_actions: Option<BTreeMap<u64, Vec<i64>>>,
     shared_env: Option<bool>,
     shared_env_mode: Option<String>,
     merge: Option<String>,
@@ -41,6 +47,25 @@ struct NuriGymRunInput {
     gridmaze_layouts: Option<Vec<GridMazeLayoutInput>>,
     emit_action_pipeline: Option<bool>,
     out_of_range_policy: Option<String>,
+    action_space: Option<NuriGymActionSpaceInput>,
+    continuous_actions: Option<Vec<Vec<Box<RawValue>>>>,
+    obs_norm: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ObsNormInput {
+    schema: String,
+    slot_count: u32,
+    count: u64,
+    mean_raw: Vec<i64>,
+    m2_raw: Vec<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NuriGymActionSpaceInput {
+    kind: String,
+    low: Option<Vec<Box<RawValue>>>,
+    high: Option<Vec<Box<RawValue>>>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -123,7 +148,11 @@ struct StepRecord {
     reward: Fixed64,
     next_observation: Vec<Fixed64>,
     done: bool,
+    /// max_steps에 걸려 끊긴 마지막 걸음. 저절로 끝난 걸음(terminated)과 나눠 적는다.
+    truncated: bool,
     action_pipeline: Option<ActionPipelineRecord>,
+    continuous_action: Option<ContinuousAction>,
+    tick: Option<u64>,
 }
 
 #[derive(Clone, Debug)]
@@ -150,16 +179,44 @@ struct EvaluatedAction {
     missing_input: bool,
 }
 
-pub fn run_spec(from: &Path, out_dir: &Path, slots: Option<u32>) -> Result<(), String> {
-    let _ = fs::read_to_string(from).map_err(|e| e.to_string())?;
-    std::fs::create_dir_all(out_dir).map_err(|e| e.to_string())?;
+pub fn run_spec(
+    from: &Path,
+    out_dir: &Path,
+    slots: Option<u32>,
+    action_low: Option<&str>,
+    action_high: Option<&str>,
+) -> Result<(), String> {
+    let source = fs::read_to_string(from).map_err(|e| e.to_string())?;
+    if let Some(requested) = slots {
+        let inferred = infer_world_slot_count(&source, &from.display().to_string())?;
+        if requested != inferred {
+            return Err(format!(
+                "E_GYM_SLOT_MISMATCH {} --slots {}이(가) 세계의 관찰 슬롯 {}개와 다릅니다",
+                from.display(),
+                requested,
+                inferred
+            ));
+        }
+    }
 
     let obs = if let Some(count) = slots {
         ObservationSpec { slot_count: count }
     } else {
         ObservationSpec::default_k64()
     };
-    let action = ActionSpec::empty();
+    let action = match (action_low, action_high) {
+        (None, None) => ActionSpec::empty(),
+        (Some(low), Some(high)) => {
+            ActionSpec::continuous(parse_fixed_csv(low)?, parse_fixed_csv(high)?)?
+        }
+        _ => {
+            return Err(
+                "E_NURIGYM_ACTION_SPEC --action-low/--action-high must be given together"
+                    .to_string(),
+            )
+        }
+    };
+    std::fs::create_dir_all(out_dir).map_err(|e| e.to_string())?;
 
     let obs_text = obs.to_detjson();
     let action_text = action.to_detjson();
@@ -177,6 +234,66 @@ pub fn run_spec(from: &Path, out_dir: &Path, slots: Option<u32>) -> Result<(), S
     Ok(())
 }
 
+/// 세계를 한 마디 돌려 관찰 슬롯이 될 수 자원(샘 키 제외) 개수를 센다.
+/// `dataset export`가 관찰 행을 채우는 규칙과 같다.
+fn infer_world_slot_count(source: &str, file_label: &str) -> Result<u32, String> {
+    let output =
+        run_source_with_state(source, State::new()).map_err(|err| err.format(file_label))?;
+    let count = output
+        .state
+        .resources
+        .iter()
+        .filter(|(key, value)| !is_sam_key(key.as_str()) && matches!(value, Value::Num(_)))
+        .count();
+    Ok(count as u32)
+}
+
+fn parse_fixed_csv(text: &str) -> Result<Vec<Fixed64>, String> {
+    text.split(',').map(parse_fixed_literal).collect()
+}
+
+/// 경계·연속 행동 값을 소수 글자 그대로 Fixed64로 읽는다. f64를 거치지 않는다.
+fn parse_fixed_literal(text: &str) -> Result<Fixed64, String> {
+    let text = text.trim();
+    crate::core::fixed64::Fixed64::parse_literal(text)
+        .map(|value| Fixed64::from_raw_i64(value.raw()))
+        .ok_or_else(|| format!("E_NURIGYM_ACTION_SPEC invalid bound {}", text))
+}
+
+/// JSON 숫자는 원문 그대로, 문자열이면 따옴표를 벗겨 읽는다.
+fn parse_fixed_json(values: &[Box<RawValue>]) -> Result<Vec<Fixed64>, String> {
+    values
+        .iter()
+        .map(|value| {
+            let text = value.get().trim();
+            let text = text
+                .strip_prefix('"')
+                .and_then(|inner| inner.strip_suffix('"'))
+                .unwrap_or(text);
+            parse_fixed_literal(text)
+        })
+        .collect()
+}
+
+/// `obs_norm.detjson`을 읽어 이전 실행의 정규화 통계를 이어 쓴다. raw 값만 믿는다.
+fn load_obs_normalizer(path: &Path) -> Result<ObsNormalizer, String> {
+    let text = read_text(path)?;
+    let input: ObsNormInput =
+        serde_json::from_str(&text).map_err(|err| format!("E_NURIGYM_OBS_NORM {}", err))?;
+    if input.schema != "nurigym.obs_norm.v1" {
+        return Err(format!(
+            "E_NURIGYM_OBS_NORM unknown schema={}",
+            input.schema
+        ));
+    }
+    ObsNormalizer::from_raw_parts(
+        input.slot_count,
+        input.count,
+        &input.mean_raw,
+        &input.m2_raw,
+    )
+}
+
 pub fn run_view(spec: &Path) -> Result<(), String> {
     let text = fs::read_to_string(spec).map_err(|e| e.to_string())?;
     let hash = sha256_hex(text.as_bytes());
@@ -209,45 +326,56 @@ pub fn run_episode_file(input_path: &Path, out_dir: &Path) -> Result<(), String>
     let emit_action_pipeline = input.emit_action_pipeline.unwrap_or(false);
     let out_of_range_mode = parse_out_of_range_mode(&input, emit_action_pipeline);
 
-    let agents = collect_agents(&input)?;
+    let continuous_spec = parse_action_space(&input)?;
+    let agents = if continuous_spec.is_some() {
+        vec![AgentRun {
+            agent_id: input.agent_id.unwrap_or(0),
+            actions: Vec::new(),
+            max_steps: input.max_steps,
+        }]
+    } else {
+        collect_agents(&input)?
+    };
     let reward_weights = build_reward_weights(&input, &agents)?;
     let gridmaze_layout = select_gridmaze_layout(input.seed, input.gridmaze_layouts.as_deref())?;
 
-    let (obs_slot_count, action_spec) = match env_id.as_str() {
+    let (obs_slot_count, mut action_spec) = match env_id.as_str() {
         "nurigym.bandit1d" => (
             2u32,
-            ActionSpec {
-                actions: vec!["arm_left".to_string(), "arm_right".to_string()],
-            },
+            ActionSpec::discrete(vec!["arm_left".to_string(), "arm_right".to_string()]),
         ),
         "nurigym.cartpole1d" => (
             4u32,
-            ActionSpec {
-                actions: vec!["left".to_string(), "right".to_string()],
-            },
+            ActionSpec::discrete(vec!["left".to_string(), "right".to_string()]),
         ),
         "nurigym.pendulum1d" => (
             2u32,
-            ActionSpec {
-                actions: vec!["left".to_string(), "right".to_string()],
-            },
+            ActionSpec::discrete(vec!["left".to_string(), "right".to_string()]),
         ),
         "nurigym.gridmaze2d" => (
             2u32,
-            ActionSpec {
-                actions: vec![
-                    "left".to_string(),
-                    "right".to_string(),
-                    "down".to_string(),
-                    "up".to_string(),
-                ],
-            },
+            ActionSpec::discrete(vec![
+                "left".to_string(),
+                "right".to_string(),
+                "down".to_string(),
+                "up".to_string(),
+            ]),
         ),
         other => {
             return Err(format!("E_NURIGYM_ENV unknown env_id={}", other));
         }
     };
-    let step_records = if shared_env {
+    let step_records = if let Some(spec) = continuous_spec {
+        if env_id != "nurigym.pendulum1d" || shared_env {
+            return Err(format!(
+                "E_NURIGYM_ACTION_SPACE continuous actions require unshared nurigym.pendulum1d (env_id={})",
+                env_id
+            ));
+        }
+        let records = run_pendulum_continuous(&input, &spec)?;
+        action_spec = spec;
+        records
+    } else if shared_env {
         run_shared_env(
             &env_id,
             input.seed,
@@ -289,6 +417,27 @@ pub fn run_episode_file(input_path: &Path, out_dir: &Path) -> Result<(), String>
         &format!("{}\n", action_text),
     )?;
 
+    let mut obs_norm = match &input.obs_norm {
+        Some(path) => {
+            let base = input_path.parent().unwrap_or_else(|| Path::new("."));
+            load_obs_normalizer(&base.join(path))?
+        }
+        None => obs_spec.normalizer(),
+    };
+    if obs_norm.slot_count != obs_slot_count {
+        return Err(format!(
+            "E_NURIGYM_OBS_NORM slot_count mismatch expected={} got={}",
+            obs_slot_count, obs_norm.slot_count
+        ));
+    }
+    for step in &step_records {
+        obs_norm.update(&step.observation)?;
+    }
+    write_text(
+        &out_dir.join("obs_norm.detjson"),
+        &format!("{}\n", obs_norm.to_detjson()),
+    )?;
+
     let count = step_records.len() as u64;
     let episode_header = build_episode_header(
         &env_id,
@@ -339,11 +488,120 @@ pub fn run_episode_file(input_path: &Path, out_dir: &Path) -> Result<(), String>
         &format!("{}\n", dataset_hash),
     )?;
 
+    let tick_lines = build_tick_report(input.episode_id, &step_records, obs_slot_count);
+    if !tick_lines.is_empty() {
+        write_text(
+            &out_dir.join("nurigym.ticks.jsonl"),
+            &(tick_lines.join("\n") + "\n"),
+        )?;
+    }
+
     println!("dataset_hash={}", dataset_hash);
     Ok(())
 }
 
+fn parse_action_space(input: &NuriGymRunInput) -> Result<Option<ActionSpec>, String> {
+    let Some(space) = &input.action_space else {
+        return Ok(None);
+    };
+    match space.kind.as_str() {
+        "discrete" => Ok(None),
+        "continuous" => {
+            let to_fixed = |values: &Option<Vec<Box<RawValue>>>, name: &str| {
+                let items = values
+                    .as_ref()
+                    .ok_or_else(|| format!("E_NURIGYM_ACTION_SPACE continuous {} missing", name))?;
+                parse_fixed_json(items)
+            };
+            let spec = ActionSpec::continuous(
+                to_fixed(&space.low, "low")?,
+                to_fixed(&space.high, "high")?,
+            )?;
+            Ok(Some(spec))
+        }
+        other => Err(format!("E_NURIGYM_ACTION_SPACE unknown kind={}", other)),
+    }
+}
+
+/// 연속 행동 진자: 각 행동을 `[low, high]`로 자른 뒤 토크 배율로 적용한다.
+fn run_pendulum_continuous(
+    input: &NuriGymRunInput,
+    spec: &ActionSpec,
+) -> Result<Vec<StepRecord>, String> {
+    let actions = input
+        .continuous_actions
+        .as_ref()
+        .filter(|items| !items.is_empty())
+        .ok_or_else(|| "E_NURIGYM_ACTIONS continuous_actions must not be empty".to_string())?;
+    if let ActionSpec::Continuous { dims, .. } = spec {
+        if *dims != PendulumEnv::CONTINUOUS_DIMS {
+            return Err(format!(
+                "E_NURIGYM_ACTION_SPACE nurigym.pendulum1d continuous dims={} got={}",
+                PendulumEnv::CONTINUOUS_DIMS,
+                dims
+            ));
+        }
+    }
+    let mut config = PendulumConfig::default_v1();
+    if let Some(limit) = input.max_steps {
+        config.max_steps = limit;
+    }
+    let agent_id = input.agent_id.unwrap_or(0);
+    let mut env = PendulumEnv::new(input.seed, config.clone());
+    let mut step_records = Vec::new();
+    for (idx, raw) in actions.iter().enumerate() {
+        if idx as u64 >= config.max_steps || env.is_done() {
+            break;
+        }
+        let raw = parse_fixed_json(raw)?;
+        let continuous = spec.clamp_continuous(&raw)?;
+        let mut step = env.step_continuous(&continuous.applied)?;
+        if idx as u64 + 1 >= config.max_steps && !step.done {
+            step.done = true;
+            step.truncated = true;
+            step.reward = Fixed64::ZERO;
+        }
+        let done = step.done;
+        step_records.push(StepRecord {
+            agent_id,
+            observation: step.observation.to_vec(),
+            action: step.action,
+            reward: step.reward,
+            next_observation: step.next_observation.to_vec(),
+            done,
+            truncated: step.truncated,
+            action_pipeline: None,
+            continuous_action: Some(continuous),
+            tick: None,
+        });
+        if done {
+            break;
+        }
+    }
+    Ok(step_records)
+}
+
 fn collect_agents(input: &NuriGymRunInput) -> Result<Vec<AgentRun>, String> {
+    if let Some(streams) = &input.agent_actions {
+        if input.agents.is_some() {
+            return Err("E_NURIGYM_AGENTS agents and agent_actions are exclusive".to_string());
+        }
+        if streams.is_empty() {
+            return Err("E_NURIGYM_AGENTS agent_actions must not be empty".to_string());
+        }
+        let mut out = Vec::with_capacity(streams.len());
+        for (agent_id, actions) in streams {
+            if actions.is_empty() {
+                return Err("E_NURIGYM_ACTIONS actions must not be empty".to_string());
+            }
+            out.push(AgentRun {
+                agent_id: *agent_id,
+                actions: actions.clone(),
+                max_steps: input.max_steps,
+            });
+        }
+        return Ok(out);
+    }
     if let Some(list) = &input.agents {
         if list.is_empty() {
             return Err("E_NURIGYM_AGENTS agents must not be empty".to_string());
@@ -527,7 +785,10 @@ fn run_cartpole(
             reward: step.reward,
             next_observation: step.next_observation.to_vec(),
             done: step.done,
+            truncated: step.truncated,
             action_pipeline: None,
+            continuous_action: None,
+            tick: None,
         })
         .collect())
 }
@@ -547,7 +808,10 @@ fn run_bandit(
             reward: step.reward,
             next_observation: step.next_observation.to_vec(),
             done: step.done,
+            truncated: step.truncated,
             action_pipeline: None,
+            continuous_action: None,
+            tick: None,
         })
         .collect())
 }
@@ -567,7 +831,10 @@ fn run_pendulum(
             reward: step.reward,
             next_observation: step.next_observation.to_vec(),
             done: step.done,
+            truncated: step.truncated,
             action_pipeline: None,
+            continuous_action: None,
+            tick: None,
         })
         .collect())
 }
@@ -597,7 +864,10 @@ fn run_gridmaze_with_layout(
             reward: step.reward,
             next_observation: step.next_observation.to_vec(),
             done: step.done,
+            truncated: step.truncated,
             action_pipeline: None,
+            continuous_action: None,
+            tick: None,
         })
         .collect())
 }
@@ -652,7 +922,10 @@ fn run_shared_bandit(
                     reward: step.reward,
                     next_observation: step.next_observation.to_vec(),
                     done: step.done,
+                    truncated: step.truncated,
                     action_pipeline: None,
+                    continuous_action: None,
+                    tick: None,
                 });
             } else {
                 let rewards = distribute_rewards(
@@ -677,7 +950,10 @@ fn run_shared_bandit(
                         reward: rewards[idx],
                         next_observation: step.next_observation.to_vec(),
                         done: step.done,
+                        truncated: step.truncated,
                         action_pipeline: None,
+                        continuous_action: None,
+                        tick: None,
                     });
                 }
             }
@@ -740,6 +1016,7 @@ fn run_shared_cartpole(
             let last_step = step_count + 1 >= config.max_steps;
             if last_step && !step.done {
                 step.done = true;
+                step.truncated = true;
                 step.reward = Fixed64::ZERO;
             }
             if reward_mode == RewardMode::Individual {
@@ -750,7 +1027,10 @@ fn run_shared_cartpole(
                     reward: step.reward,
                     next_observation: step.next_observation.to_vec(),
                     done: step.done,
+                    truncated: step.truncated,
                     action_pipeline: None,
+                    continuous_action: None,
+                    tick: None,
                 });
             } else {
                 let rewards = distribute_rewards(
@@ -775,7 +1055,10 @@ fn run_shared_cartpole(
                         reward: rewards[idx],
                         next_observation: step.next_observation.to_vec(),
                         done: step.done,
+                        truncated: step.truncated,
                         action_pipeline: None,
+                        continuous_action: None,
+                        tick: None,
                     });
                 }
             }
@@ -838,6 +1121,7 @@ fn run_shared_pendulum(
             let last_step = step_count + 1 >= config.max_steps;
             if last_step && !step.done {
                 step.done = true;
+                step.truncated = true;
                 step.reward = Fixed64::ZERO;
             }
             if reward_mode == RewardMode::Individual {
@@ -848,7 +1132,10 @@ fn run_shared_pendulum(
                     reward: step.reward,
                     next_observation: step.next_observation.to_vec(),
                     done: step.done,
+                    truncated: step.truncated,
                     action_pipeline: None,
+                    continuous_action: None,
+                    tick: None,
                 });
             } else {
                 let rewards = distribute_rewards(
@@ -873,7 +1160,10 @@ fn run_shared_pendulum(
                         reward: rewards[idx],
                         next_observation: step.next_observation.to_vec(),
                         done: step.done,
+                        truncated: step.truncated,
                         action_pipeline: None,
+                        continuous_action: None,
+                        tick: None,
                     });
                 }
             }
@@ -941,6 +1231,7 @@ fn run_shared_gridmaze(
             let last_step = step_count + 1 >= config.max_steps;
             if last_step && !step.done {
                 step.done = true;
+                step.truncated = true;
                 step.reward = Fixed64::ZERO;
             }
             if reward_mode == RewardMode::Individual {
@@ -951,7 +1242,10 @@ fn run_shared_gridmaze(
                     reward: step.reward,
                     next_observation: step.next_observation.to_vec(),
                     done: step.done,
+                    truncated: step.truncated,
                     action_pipeline: None,
+                    continuous_action: None,
+                    tick: None,
                 });
             } else {
                 let rewards = distribute_rewards(
@@ -976,7 +1270,10 @@ fn run_shared_gridmaze(
                         reward: rewards[idx],
                         next_observation: step.next_observation.to_vec(),
                         done: step.done,
+                        truncated: step.truncated,
                         action_pipeline: None,
+                        continuous_action: None,
+                        tick: None,
                     });
                 }
             }
@@ -1313,13 +1610,24 @@ fn build_step_record(
     out.push_str(",\"observation\":");
     out.push_str(&obs_text);
     out.push_str(",\"action\":");
-    out.push_str(&build_action(step.action));
+    match &step.continuous_action {
+        Some(continuous) => out.push_str(&build_continuous_action(continuous)),
+        None => out.push_str(&build_action(step.action)),
+    }
     out.push_str(",\"reward\":");
     out.push_str(&step.reward.to_string());
     out.push_str(",\"next_observation\":");
     out.push_str(&next_text);
     out.push_str(",\"done\":");
     out.push_str(if step.done { "true" } else { "false" });
+    out.push_str(",\"terminated\":");
+    out.push_str(if step.done && !step.truncated {
+        "true"
+    } else {
+        "false"
+    });
+    out.push_str(",\"truncated\":");
+    out.push_str(if step.truncated { "true" } else { "false" });
     if let Some(pipeline) = &step.action_pipeline {
         out.push_str(",\"raw_action\":");
         out.push_str(&pipeline.raw_action.to_string());
@@ -1353,6 +1661,46 @@ fn build_step_record(
     out
 }
 
+/// sync 모드 행을 마디별로 묶어 에이전트별 관측/행동/보상을 한 줄에 남긴다.
+fn build_tick_report(episode_id: u64, steps: &[StepRecord], slot_count: u32) -> Vec<String> {
+    let mut ticks: BTreeMap<u64, Vec<&StepRecord>> = BTreeMap::new();
+    for step in steps {
+        if let Some(tick) = step.tick {
+            ticks.entry(tick).or_default().push(step);
+        }
+    }
+    let mut lines = Vec::with_capacity(ticks.len());
+    for (tick, rows) in ticks {
+        let mut out = String::new();
+        out.push_str("{\"schema\":\"nurigym.tick.v1\",\"episode_id\":");
+        out.push_str(&episode_id.to_string());
+        out.push_str(",\"tick\":");
+        out.push_str(&tick.to_string());
+        out.push_str(",\"agents\":[");
+        for (idx, step) in rows.iter().enumerate() {
+            if idx > 0 {
+                out.push(',');
+            }
+            let (obs_text, _) = build_observation(&step.observation, slot_count);
+            out.push_str("{\"agent_id\":");
+            out.push_str(&step.agent_id.to_string());
+            out.push_str(",\"observation\":");
+            out.push_str(&obs_text);
+            out.push_str(",\"action\":");
+            out.push_str(&step.action.to_string());
+            out.push_str(",\"reward\":");
+            out.push_str(&step.reward.to_string());
+            out.push('}');
+        }
+        out.push_str("],\"done\":");
+        let done = rows.iter().any(|step| step.done);
+        out.push_str(if done { "true" } else { "false" });
+        out.push('}');
+        lines.push(out);
+    }
+    lines
+}
+
 fn build_observation(values: &[Fixed64], slot_count: u32) -> (String, String) {
     let mut base = String::new();
     base.push_str("{\"schema\":\"nurigym.obs.v1\",\"slot_count\":");
@@ -1392,6 +1740,32 @@ fn build_action(action: i64) -> String {
     out
 }
 
+fn build_continuous_action(action: &ContinuousAction) -> String {
+    let push_list = |out: &mut String, values: &[Fixed64]| {
+        out.push('[');
+        for (idx, value) in values.iter().enumerate() {
+            if idx > 0 {
+                out.push(',');
+            }
+            out.push_str(&value.to_string());
+        }
+        out.push(']');
+    };
+    let mut out = String::new();
+    out.push_str("{\"schema\":\"nurigym.action.v1\",\"kind\":\"continuous\",\"raw\":");
+    push_list(&mut out, &action.raw);
+    out.push_str(",\"value\":");
+    push_list(&mut out, &action.applied);
+    out.push_str(",\"clamped\":");
+    out.push_str(if action.any_clamped() {
+        "true"
+    } else {
+        "false"
+    });
+    out.push('}');
+    out
+}
+
 fn parse_shared_env_mode(input: &NuriGymRunInput) -> SharedEnvMode {
     match input.shared_env_mode.as_deref().unwrap_or("round_robin") {
         "sync" => SharedEnvMode::Sync,
@@ -1698,6 +2072,7 @@ where
         let last_tick = tick + 1 >= max_steps;
         if last_tick && !step.done {
             step.done = true;
+            step.truncated = true;
             step.reward = Fixed64::ZERO;
         }
         if allowed.contains(&merged_action) {
@@ -1748,7 +2123,10 @@ where
                 reward,
                 next_observation: step.next_observation.to_vec(),
                 done: step.done,
+                truncated: step.truncated,
                 action_pipeline,
+                continuous_action: None,
+                tick: Some(tick),
             });
         }
         if step.done {
@@ -1769,6 +2147,7 @@ struct SyncStep {
     reward: Fixed64,
     next_observation: Vec<Fixed64>,
     done: bool,
+    truncated: bool,
 }
 
 impl SyncStepEnv for CartPoleEnv {
@@ -1783,6 +2162,7 @@ impl SyncStepEnv for CartPoleEnv {
             reward: step.reward,
             next_observation: step.next_observation.to_vec(),
             done: step.done,
+            truncated: step.truncated,
         })
     }
 }
@@ -1799,6 +2179,7 @@ impl SyncStepEnv for BanditEnv {
             reward: step.reward,
             next_observation: step.next_observation.to_vec(),
             done: step.done,
+            truncated: step.truncated,
         })
     }
 }
@@ -1815,6 +2196,7 @@ impl SyncStepEnv for PendulumEnv {
             reward: step.reward,
             next_observation: step.next_observation.to_vec(),
             done: step.done,
+            truncated: step.truncated,
         })
     }
 }
@@ -1831,6 +2213,7 @@ impl SyncStepEnv for GridMazeEnv {
             reward: step.reward,
             next_observation: step.next_observation.to_vec(),
             done: step.done,
+            truncated: step.truncated,
         })
     }
 }
@@ -1948,10 +2331,14 @@ fn distribute_rewards(
 #[cfg(test)]
 mod tests {
     use super::{
-        build_step_record, evaluate_action, merge_actions, ActionPipelineRecord, AgentRun,
-        EvaluatedAction, OutOfRangeMode, SharedMerge, StepRecord,
+        build_reward_weights, build_step_record, build_tick_report, collect_agents,
+        evaluate_action, load_obs_normalizer, merge_actions, parse_action_space, parse_fixed_csv,
+        parse_reward_mode, run_episode_file, run_pendulum_continuous, run_shared_env, run_spec,
+        ActionPipelineRecord, AgentRun, EvaluatedAction, NuriGymRunInput, OutOfRangeMode,
+        SharedEnvMode, SharedMerge, StepRecord,
     };
     use ddonirang_core::fixed64::Fixed64;
+    use ddonirang_core::nurigym::spec::ActionSpec;
 
     fn eval(
         agent_id: u64,
@@ -2115,6 +2502,7 @@ mod tests {
             reward: Fixed64::ZERO,
             next_observation: vec![Fixed64::ZERO],
             done: false,
+            truncated: false,
             action_pipeline: Some(ActionPipelineRecord {
                 raw_action: 5,
                 resolved_action: 5,
@@ -2125,6 +2513,8 @@ mod tests {
                 applied_action: 1,
                 missing_input: false,
             }),
+            continuous_action: None,
+            tick: None,
         };
         let text = build_step_record(1, row.agent_id, 0, &row, 1);
         assert!(text.contains("\"raw_action\":5"));
@@ -2136,4 +2526,211 @@ mod tests {
         assert!(text.contains("\"applied_action\":1"));
         assert!(text.contains("\"missing_input\":false"));
     }
+
+    #[test]
+    fn nurigym_episode_hitting_max_steps_is_truncated_not_terminated() {
+        let stamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("ddn_nurigym_truncated_{}", stamp));
+        std::fs::create_dir_all(&dir).expect("mkdir");
+        let input_path = dir.join("input.json");
+        std::fs::write(
+            &input_path,
+            r#"{"env_id":"nurigym.cartpole1d","seed":11,"episode_id":1,"max_steps":3,
+                "actions":[1,-1,1,-1,1]}"#,
+        )
+        .expect("write input");
+        let out_dir = dir.join("out");
+        run_episode_file(&input_path, &out_dir).expect("episode");
+
+        let dataset =
+            std::fs::read_to_string(out_dir.join("nurigym.dataset.jsonl")).expect("dataset");
+        let steps: Vec<&str> = dataset.lines().skip(1).collect();
+        assert_eq!(steps.len(), 3);
+        for step in &steps[..2] {
+            assert!(step.contains("\"done\":false,\"terminated\":false,\"truncated\":false"));
+        }
+        assert!(steps[2].contains("\"done\":true,\"terminated\":false,\"truncated\":true"));
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn nurigym_run_saves_obs_norm_and_resumes_from_it() {
+        let stamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("ddn_nurigym_obs_norm_{}", stamp));
+        std::fs::create_dir_all(&dir).expect("mkdir");
+        let first_input = dir.join("first.json");
+        std::fs::write(
+            &first_input,
+            r#"{"env_id":"nurigym.cartpole1d","seed":11,"episode_id":1,"max_steps":3,
+                "actions":[1,-1,1]}"#,
+        )
+        .expect("write input");
+        let first_out = dir.join("first");
+        run_episode_file(&first_input, &first_out).expect("first episode");
+
+        let saved_path = first_out.join("obs_norm.detjson");
+        let saved_text = std::fs::read_to_string(&saved_path).expect("obs_norm");
+        let saved = load_obs_normalizer(&saved_path).expect("load");
+        assert_eq!(saved.slot_count, 4);
+        assert_eq!(saved.count, 3);
+        assert_eq!(format!("{}\n", saved.to_detjson()), saved_text);
+
+        let second_input = dir.join("second.json");
+        std::fs::write(
+            &second_input,
+            r#"{"env_id":"nurigym.cartpole1d","seed":11,"episode_id":2,"max_steps":3,
+                "actions":[1,-1,1],"obs_norm":"first/obs_norm.detjson"}"#,
+        )
+        .expect("write input");
+        let second_out = dir.join("second");
+        run_episode_file(&second_input, &second_out).expect("second episode");
+        let resumed = load_obs_normalizer(&second_out.join("obs_norm.detjson")).expect("load");
+        assert_eq!(resumed.count, 6);
+
+        let again_out = dir.join("again");
+        run_episode_file(&second_input, &again_out).expect("again");
+        assert_eq!(
+            std::fs::read_to_string(again_out.join("obs_norm.detjson")).expect("again"),
+            std::fs::read_to_string(second_out.join("obs_norm.detjson")).expect("second")
+        );
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn nurigym_continuous_action_is_clamped_and_recorded() {
+        let input: NuriGymRunInput = serde_json::from_str(
+            r#"{"env_id":"nurigym.pendulum1d","seed":7,"episode_id":1,
+                "action_space":{"kind":"continuous","low":[-1.0],"high":[1.0]},
+                "continuous_actions":[[3.5],[0.5]]}"#,
+        )
+        .expect("input");
+        let spec =
+            ActionSpec::continuous(vec![Fixed64::from_i64(-1)], vec![Fixed64::ONE]).expect("spec");
+        let records = run_pendulum_continuous(&input, &spec).expect("run");
+        assert_eq!(records.len(), 2);
+
+        let first = records[0].continuous_action.as_ref().expect("continuous");
+        assert_eq!(first.applied, vec![Fixed64::ONE]);
+        assert!(first.any_clamped());
+        let text = build_step_record(1, records[0].agent_id, 0, &records[0], 2);
+        assert!(text.contains("\"kind\":\"continuous\""));
+        assert!(text.contains("\"value\":[1]"));
+        assert!(text.contains("\"clamped\":true"));
+
+        let second = build_step_record(1, records[1].agent_id, 1, &records[1], 2);
+        assert!(second.contains("\"clamped\":false"));
+    }
+
+    #[test]
+    fn nurigym_continuous_bounds_parse_exactly_and_clamp_every_dim() {
+        let tenth = Fixed64::from_raw_i64(Fixed64::ONE_RAW / 10);
+        assert_eq!(
+            parse_fixed_csv("-1.5, 0.1,2").expect("csv"),
+            vec![
+                Fixed64::from_raw_i64(-3 * Fixed64::ONE_RAW / 2),
+                tenth,
+                Fixed64::from_i64(2)
+            ]
+        );
+        assert!(parse_fixed_csv("1,abc").is_err());
+
+        let input: NuriGymRunInput = serde_json::from_str(
+            r#"{"env_id":"nurigym.pendulum1d","seed":7,"episode_id":1,
+                "action_space":{"kind":"continuous","low":[-1,0.1,"-0.5"],"high":[1,0.2,2.5e-1]}}"#,
+        )
+        .expect("input");
+        let spec = parse_action_space(&input)
+            .expect("parse")
+            .expect("continuous");
+        let quarter = Fixed64::from_raw_i64(Fixed64::ONE_RAW / 4);
+        let clamped = spec
+            .clamp_continuous(&[Fixed64::from_i64(-3), Fixed64::ZERO, Fixed64::ONE])
+            .expect("clamp");
+        assert_eq!(clamped.applied, vec![Fixed64::from_i64(-1), tenth, quarter]);
+        assert_eq!(clamped.clamped, vec![true, true, true]);
+    }
+
+    #[test]
+    fn nurigym_continuous_pendulum_rejects_extra_dims() {
+        let input: NuriGymRunInput = serde_json::from_str(
+            r#"{"env_id":"nurigym.pendulum1d","seed":7,"episode_id":1,
+                "action_space":{"kind":"continuous","low":[-1,-1],"high":[1,1]},
+                "continuous_actions":[[0.5,0.5]]}"#,
+        )
+        .expect("input");
+        let spec = parse_action_space(&input)
+            .expect("parse")
+            .expect("continuous");
+        let err = run_pendulum_continuous(&input, &spec).expect_err("dims");
+        assert!(err.starts_with("E_NURIGYM_ACTION_SPACE"), "{err}");
+    }
+
+    #[test]
+    fn nurigym_two_agent_sync_reports_each_agent_per_tick() {
+        let input: NuriGymRunInput = serde_json::from_str(
+            r#"{"env_id":"nurigym.pendulum1d","seed":5,"episode_id":2,
+                "shared_env":true,"shared_env_mode":"sync",
+                "agent_actions":{"2":[1,1,-1],"1":[-1,1,1]}}"#,
+        )
+        .expect("input");
+        let agents = collect_agents(&input).expect("agents");
+        assert_eq!(
+            agents
+                .iter()
+                .map(|agent| agent.agent_id)
+                .collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        let weights = build_reward_weights(&input, &agents).expect("weights");
+        let rows = run_shared_env(
+            "nurigym.pendulum1d",
+            input.seed,
+            &agents,
+            None,
+            SharedEnvMode::Sync,
+            SharedMerge::SumClamp,
+            None,
+            parse_reward_mode(&input),
+            &weights,
+            None,
+            false,
+            OutOfRangeMode::Reject,
+        )
+        .expect("run");
+        let lines = build_tick_report(2, &rows, 2);
+        assert_eq!(lines.len(), 3);
+        for (tick, line) in lines.iter().enumerate() {
+            assert!(line.contains(&format!("\"tick\":{}", tick)));
+            assert!(line.contains("{\"agent_id\":1,\"observation\":"));
+            assert!(line.contains("{\"agent_id\":2,\"observation\":"));
+        }
+    }
+
+    #[test]
+    fn spec_slots_must_match_world_observation_surface() {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("clock")
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("nurigym_spec_slots_{nanos}"));
+        std::fs::create_dir_all(&dir).expect("mkdir");
+        let world = dir.join("world.ddn");
+        std::fs::write(&world, "위치 <- 1.\n속도 <- 2.\n이름 <- \"막대\".\n").expect("write");
+        let out = dir.join("out");
+
+        let err = run_spec(&world, &out, Some(3), None, None).expect_err("mismatch");
+        assert!(err.starts_with("E_GYM_SLOT_MISMATCH"), "{err}");
+        assert!(err.contains("관찰 슬롯 2개"), "{err}");
+
+        run_spec(&world, &out, Some(2), None, None).expect("matching slots");
+        let obs = std::fs::read_to_string(out.join("obs_spec.detjson")).expect("obs spec");
+        assert!(obs.contains("\"slot_count\":2"), "{obs}");
+        let _ = std::fs::remove_dir_all(dir);
+    }
 }
@@ -1,13 +1,16 @@
 use std::fs;
 use std::path::Path;
 
-use ddonirang_core::seulgi::observation::{observation_detjson, observation_from_detjson};
+use ddonirang_core::seulgi::observation::{
+    observation_detjson, observation_from_detjson, validate_observation,
+};
 
 use super::detjson::write_text;
 
 pub fn run_canon(input: &Path, out: Option<&Path>) -> Result<(), String> {
     let raw = fs::read_to_string(input).map_err(|e| e.to_string())?;
     let obs = observation_from_detjson(&raw)?;
+    validate_observation(&obs)?;
     let detjson = observation_detjson(&obs);
     if let Some(path) = out {
         write_text(path, &format!("{}\n", detjson))?;
@@ -16,3 +19,66 @@ pub fn run_canon(input: &Path, out: Option<&Path>) -> Result<(), String> {
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let stamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("ddn_observation_{}_{}", name, stamp));
+        fs::create_dir_all(&dir).expect("mkdir");
+        dir
+    }
+
+    fn observation_json(agents: &[(u64, &str)]) -> String {
+        let agents = agents
+            .iter()
+            .map(|(id, name)| {
+                format!(
+                    "{{\"id\":{},\"position\":{{\"x\":{},\"y\":0}},\"name\":\"{}\"}}",
+                    id, id, name
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        format!(
+            "{{\"schema\":\"seulgi.observation.v1\",\"agent_id\":1,\"madi\":3,\"timestamp_ms\":30,\
+\"self_state\":{{\"position\":{{\"x\":0,\"y\":0}},\"velocity\":{{\"x\":0,\"y\":0}},\"health\":100,\"status\":\"idle\"}},\
+\"visible_objects\":[],\"visible_agents\":[{}],\
+\"world_state\":{{\"gravity\":0,\"time_of_day\":0,\"weather\":\"clear\"}}}}",
+            agents
+        )
+    }
+
+    #[test]
+    fn canon_rejects_duplicate_agent_name() {
+        let dir = temp_dir("dup_name");
+        let input = dir.join("obs.detjson");
+        fs::write(&input, observation_json(&[(2, "ally"), (3, "ally")])).expect("write");
+        let err = run_canon(&input, Some(&dir.join("out.detjson"))).expect_err("must fail");
+        assert!(err.starts_with("E_OBS_DUP_NAME"), "{}", err);
+    }
+
+    #[test]
+    fn canon_output_is_independent_of_input_order() {
+        let dir = temp_dir("order");
+        let forward = dir.join("forward.detjson");
+        let reversed = dir.join("reversed.detjson");
+        fs::write(&forward, observation_json(&[(2, "ally"), (3, "foe")])).expect("write");
+        fs::write(&reversed, observation_json(&[(3, "foe"), (2, "ally")])).expect("write");
+        let forward_out = dir.join("forward.out.detjson");
+        let reversed_out = dir.join("reversed.out.detjson");
+        run_canon(&forward, Some(&forward_out)).expect("canon forward");
+        run_canon(&reversed, Some(&reversed_out)).expect("canon reversed");
+        assert_eq!(
+            fs::read(&forward_out).expect("read forward"),
+            fs::read(&reversed_out).expect("read reversed")
+        );
+    }
+}
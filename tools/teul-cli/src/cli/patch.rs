@@ -1,7 +1,7 @@
 use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::io::{self, Write};
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 use std::process::Command;
 
 use blake3;
@@ -32,6 +32,8 @@ pub struct PatchPreview {
 #[derive(Clone, Debug)]
 struct PatchFile {
     patch_version: Option<String>,
+    /// propose 시점의 원본 파일 해시. 없으면(예전 patch) 검사하지 않는다.
+    base_hashes: BTreeMap<String, String>,
     changes: Vec<PatchChange>,
 }
 
@@ -48,6 +50,8 @@ struct PatchChange {
 struct PatchTarget {
     file: String,
     anchor: String,
+    /// 만들 때 앵커가 있던 줄(1부터). 있으면 앵커가 다른 줄로 옮겨졌을 때 적용을 막는다.
+    line: Option<usize>,
 }
 
 #[derive(Clone, Debug)]
@@ -201,6 +205,7 @@ pub fn run_propose(file: &Path, out: Option<&Path>) -> Result<(), String> {
         .unwrap_or_else(|| PathBuf::from("ddn.patch.json"));
     let patch_json = json!({
         "patch_version": "0.1-draft",
+        "base_hashes": { file_label.clone(): patch_hash_string(source.as_bytes()) },
         "changes": changes,
     });
     let text = serde_json::to_string_pretty(&patch_json).map_err(|e| e.to_string())? + "\n";
@@ -262,6 +267,7 @@ pub fn run_apply(
     approval: &Path,
     out: Option<&Path>,
     in_place: bool,
+    force: bool,
 ) -> Result<(), String> {
     let patch_bytes = fs::read(path).map_err(|e| format!("E_PATCH_READ {}", e))?;
     let patch_hash = patch_hash_string(&patch_bytes);
@@ -269,7 +275,8 @@ pub fn run_apply(
     validate_approval(&approval, &patch_hash)?;
 
     let patch = load_patch(path)?;
-    let mut buffers = apply_patch_to_buffers(&patch)?;
+    check_base_hashes(&patch, force)?;
+    let mut buffers = apply_patch_to_buffers(&patch, Path::new(""))?;
 
     let (out_dir, apply_in_place) = resolve_apply_mode(out, in_place)?;
 
@@ -342,6 +349,26 @@ pub fn run_verify(
     Ok(())
 }
 
+/// propose 뒤에 원본이 바뀌었으면 적용을 막는다. `force`면 경고만 남긴다.
+fn check_base_hashes(patch: &PatchFile, force: bool) -> Result<(), String> {
+    for (file, expected) in &patch.base_hashes {
+        let bytes = fs::read(file).map_err(|e| format!("E_PATCH_READ {}", e))?;
+        let actual = patch_hash_string(&bytes);
+        if &actual == expected {
+            continue;
+        }
+        let message = format!(
+            "E_PATCH_BASE_DRIFT {} 원본이 propose 이후 바뀌었습니다 (expected={} actual={})",
+            file, expected, actual
+        );
+        if !force {
+            return Err(message);
+        }
+        eprintln!("{} --force로 계속합니다", message);
+    }
+    Ok(())
+}
+
 fn resolve_apply_mode(
     out: Option<&Path>,
     in_place: bool,
@@ -375,12 +402,25 @@ fn load_patch(path: &Path) -> Result<PatchFile, String> {
         .get("changes")
         .and_then(|v| v.as_array())
         .ok_or_else(|| "E_PATCH_JSON changes must be array".to_string())?;
+    let mut base_hashes = BTreeMap::new();
+    if let Some(value) = obj.get("base_hashes") {
+        let map = value
+            .as_object()
+            .ok_or_else(|| "E_PATCH_JSON base_hashes must be object".to_string())?;
+        for (file, hash) in map {
+            let hash = hash
+                .as_str()
+                .ok_or_else(|| "E_PATCH_JSON base_hashes values must be strings".to_string())?;
+            base_hashes.insert(file.clone(), hash.to_string());
+        }
+    }
     let mut parsed_changes = Vec::new();
     for change in changes {
         parsed_changes.push(parse_change(change)?);
     }
     Ok(PatchFile {
         patch_version,
+        base_hashes,
         changes: parsed_changes,
     })
 }
@@ -456,11 +496,21 @@ fn parse_target(value: &Value) -> Result<PatchTarget, String> {
         .and_then(|v| v.as_str())
         .ok_or_else(|| "E_PATCH_JSON target.anchor is required".to_string())?
         .to_string();
-    Ok(PatchTarget { file, anchor })
+    let line = match obj.get("line") {
+        Some(value) => Some(
+            value
+                .as_u64()
+                .filter(|line| *line >= 1)
+                .ok_or_else(|| "E_PATCH_JSON target.line must be integer >= 1".to_string())?
+                as usize,
+        ),
+        None => None,
+    };
+    Ok(PatchTarget { file, anchor, line })
 }
 
 fn build_preview(patch: &PatchFile) -> Result<Vec<PatchPreview>, String> {
-    let mut buffers = read_patch_files(patch)?;
+    let mut buffers = read_patch_files(patch, Path::new(""))?;
     let mut previews = Vec::new();
     for change in &patch.changes {
         if change.kind != "replace_block" {
@@ -559,14 +609,16 @@ fn validate_approval(approval: &PatchApproval, expected_hash: &str) -> Result<()
     Ok(())
 }
 
-fn read_patch_files(patch: &PatchFile) -> Result<BTreeMap<String, FileBuffer>, String> {
+fn read_patch_files(
+    patch: &PatchFile,
+    root: &Path,
+) -> Result<BTreeMap<String, FileBuffer>, String> {
     let mut buffers = BTreeMap::new();
     for change in &patch.changes {
         if buffers.contains_key(&change.target.file) {
             continue;
         }
-        let path = PathBuf::from(&change.target.file);
-        let buffer = read_file_buffer(&path)?;
+        let buffer = read_file_buffer(&root.join(&change.target.file))?;
         buffers.insert(change.target.file.clone(), buffer);
     }
     Ok(buffers)
@@ -588,8 +640,45 @@ fn read_file_buffer(path: &Path) -> Result<FileBuffer, String> {
     })
 }
 
-fn apply_patch_to_buffers(patch: &PatchFile) -> Result<BTreeMap<String, FileBuffer>, String> {
-    let mut buffers = read_patch_files(patch)?;
+/// patch를 `root` 기준 파일들에 적용한 결과를 파일 경로(patch에 적힌 그대로) → 새 내용으로 돌려준다.
+/// 파일에는 쓰지 않는다. 대상 경로가 `root` 밖을 가리키면(절대 경로, `..`) `E_PATCH_PATH`다.
+pub(crate) fn patch_files_under(
+    root: &Path,
+    patch_path: &Path,
+) -> Result<BTreeMap<String, String>, String> {
+    let patch = load_patch(patch_path)?;
+    for change in &patch.changes {
+        ensure_path_under_root(&change.target.file)?;
+    }
+    let buffers = apply_patch_to_buffers(&patch, root)?;
+    Ok(buffers
+        .iter()
+        .map(|(file, buffer)| (file.clone(), buffer_to_string(buffer)))
+        .collect())
+}
+
+fn ensure_path_under_root(file: &str) -> Result<(), String> {
+    let path = Path::new(file);
+    let escapes = path.components().any(|component| {
+        matches!(
+            component,
+            Component::ParentDir | Component::RootDir | Component::Prefix(_)
+        )
+    });
+    if file.is_empty() || !path.is_relative() || escapes {
+        return Err(format!(
+            "E_PATCH_PATH 대상 파일은 root 안의 상대 경로여야 합니다: {}",
+            file
+        ));
+    }
+    Ok(())
+}
+
+fn apply_patch_to_buffers(
+    patch: &PatchFile,
+    root: &Path,
+) -> Result<BTreeMap<String, FileBuffer>, String> {
+    let mut buffers = read_patch_files(patch, root)?;
     for change in &patch.changes {
         if change.kind != "replace_block" {
             return Err(format!("E_PATCH_KIND unsupported kind: {}", change.kind));
@@ -598,6 +687,16 @@ fn apply_patch_to_buffers(patch: &PatchFile) -> Result<BTreeMap<String, FileBuff
             .get_mut(&change.target.file)
             .ok_or_else(|| "E_PATCH_READ target file not loaded".to_string())?;
         let (start, end) = find_block_range(&buffer.lines, &change.target.anchor)?;
+        if let Some(line) = change.target.line {
+            if start + 1 != line {
+                return Err(format!(
+                    "E_PATCH_LINE_DRIFT '{}' expected line {} found line {}",
+                    change.target.anchor,
+                    line,
+                    start + 1
+                ));
+            }
+        }
         if let Some(before) = &change.before {
             let current = &buffer.lines[start..=end];
             if current != before {
@@ -726,3 +825,41 @@ fn apply_replacements(
         reason,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{run_apply, run_approve, run_propose};
+    use std::fs;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    #[test]
+    fn apply_refuses_when_source_drifted_after_propose() {
+        let nonce = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        let root = std::env::temp_dir().join(format!("teul_cli_patch_drift_{nonce}"));
+        fs::create_dir_all(&root).expect("mkdir");
+        let source = root.join("main.ddn");
+        fs::write(&source, "변수 <- 1.\n").expect("source");
+        let patch = root.join("ddn.patch.json");
+        let approval = root.join("approval.json");
+        run_propose(&source, Some(&patch)).expect("propose");
+        run_approve(&patch, &approval, true, None).expect("approve");
+
+        fs::write(&source, "변수 <- 1.\n값 <- 2.\n").expect("mutate");
+        let err = run_apply(&patch, &approval, None, true, false).expect_err("drift");
+        assert!(err.starts_with("E_PATCH_BASE_DRIFT"), "{err}");
+        assert_eq!(
+            fs::read_to_string(&source).expect("source"),
+            "변수 <- 1.\n값 <- 2.\n"
+        );
+
+        run_apply(&patch, &approval, None, true, true).expect("forced apply");
+        assert!(fs::read_to_string(&source)
+            .expect("source")
+            .starts_with("이름 <- 1."));
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
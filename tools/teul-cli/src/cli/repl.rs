@@ -1,21 +1,36 @@
 use std::io::{self, Write};
 
+use ddonirang_lang::{
+    Expr, ExprKind, Literal, NormalizationLevel, ParseMode, SeedKind, Stmt, TopLevelItem, TypeRef,
+};
+
 use crate::cli::run;
 use crate::core::hash;
 use crate::core::{State, Trace};
 
+const REPL_FILE: &str = "<repl>";
+const TYPE_PROBE_NAME: &str = "__repl_type";
+
 pub fn repl() -> Result<(), String> {
     let mut state = State::new();
     let stdin = io::stdin();
+    let mut pending = String::new();
+    let mut last_input: Option<String> = None;
 
     println!("또니랑 REPL (WALK01)");
     println!(":hash - 현재 state_hash 출력");
     println!(":reset - 상태 초기화");
+    println!(":type <식> - 식의 씨 종류/타입 출력");
+    println!(":normalize - 직전 입력의 N1 정본 출력");
     println!(":quit - 종료");
     println!();
 
     loop {
-        print!("또니랑> ");
+        if pending.is_empty() {
+            print!("또니랑> ");
+        } else {
+            print!("......> ");
+        }
         io::stdout().flush().map_err(|e| e.to_string())?;
 
         let mut line = String::new();
@@ -23,24 +38,48 @@ pub fn repl() -> Result<(), String> {
             break;
         }
 
-        let line = line.trim();
-        if line == ":quit" || line == ":q" {
-            break;
-        }
-        if line == ":hash" || line == ":h" {
-            println!("state_hash={}", hash::state_hash(&state));
-            continue;
-        }
-        if line == ":reset" {
-            state = State::new();
-            println!("reset=ok");
-            continue;
+        if pending.is_empty() {
+            let line = line.trim();
+            if line == ":quit" || line == ":q" {
+                break;
+            }
+            if line == ":hash" || line == ":h" {
+                println!("state_hash={}", hash::state_hash(&state));
+                continue;
+            }
+            if line == ":reset" {
+                state = State::new();
+                println!("reset=ok");
+                continue;
+            }
+            if let Some(expr) = line.strip_prefix(":type") {
+                match describe_type(expr.trim()) {
+                    Ok(text) => println!("{}", text),
+                    Err(err) => eprintln!("{}", err),
+                }
+                continue;
+            }
+            if line == ":normalize" || line == ":n" {
+                match last_input.as_deref() {
+                    Some(input) => match normalize_input(input) {
+                        Ok(text) => println!("{}", text),
+                        Err(err) => eprintln!("{}", err),
+                    },
+                    None => eprintln!("E_REPL_NO_INPUT 정본화할 직전 입력이 없습니다"),
+                }
+                continue;
+            }
+            if line.is_empty() {
+                continue;
+            }
         }
-        if line.is_empty() {
+
+        let Some(input) = push_line(&mut pending, &line) else {
             continue;
-        }
+        };
+        last_input = Some(input.clone());
 
-        match run_line(&mut state, line) {
+        match run_line(&mut state, &input) {
             Ok(trace) => {
                 for log in trace.log_lines() {
                     println!("{}", log);
@@ -60,3 +99,206 @@ fn run_line(state: &mut State, line: &str) -> Result<Trace, String> {
     *state = output.state;
     Ok(output.trace)
 }
+
+/// 줄을 쌓다가 중괄호가 닫히면 모인 입력 전체를 돌려준다.
+fn push_line(pending: &mut String, line: &str) -> Option<String> {
+    pending.push_str(line.trim_end_matches(['\r', '\n']));
+    pending.push('\n');
+    if brace_depth(pending) > 0 {
+        return None;
+    }
+    let input = pending.trim().to_string();
+    pending.clear();
+    if input.is_empty() {
+        None
+    } else {
+        Some(input)
+    }
+}
+
+/// 글 조각과 `//` 주석 안의 중괄호는 세지 않는다.
+fn brace_depth(text: &str) -> i64 {
+    let mut depth = 0i64;
+    for line in text.lines() {
+        let mut in_string = false;
+        let mut escaped = false;
+        let mut prev = '\0';
+        for ch in line.chars() {
+            if in_string {
+                if escaped {
+                    escaped = false;
+                } else if ch == '\\' {
+                    escaped = true;
+                } else if ch == '"' {
+                    in_string = false;
+                }
+                continue;
+            }
+            match ch {
+                '"' => in_string = true,
+                '/' if prev == '/' => break,
+                '{' => depth += 1,
+                '}' => depth -= 1,
+                _ => {}
+            }
+            prev = ch;
+        }
+    }
+    depth
+}
+
+/// 씨 정의면 씨 종류를, 아니면 식의 타입을 설명한다.
+fn describe_type(input: &str) -> Result<String, String> {
+    if input.is_empty() {
+        return Err("E_REPL_TYPE 사용법: :type <식>".to_string());
+    }
+    if let Ok(program) = ddonirang_lang::parse_with_mode(input, REPL_FILE, ParseMode::Strict) {
+        if !program.items.is_empty() {
+            let lines = program
+                .items
+                .iter()
+                .map(|item| {
+                    let TopLevelItem::SeedDef(seed) = item;
+                    format!(
+                        "{}: {}",
+                        seed.canonical_name,
+                        seed_kind_name(&seed.seed_kind)
+                    )
+                })
+                .collect::<Vec<_>>();
+            return Ok(lines.join("\n"));
+        }
+    }
+    let probe = format!("{TYPE_PROBE_NAME}:셈씨 = {{\n    {input} 돌려줘.\n}}\n");
+    let program = ddonirang_lang::parse_with_mode(&probe, REPL_FILE, ParseMode::Strict)
+        .map_err(|err| format!("E_REPL_TYPE {}", err.message))?;
+    let TopLevelItem::SeedDef(seed) = program
+        .items
+        .first()
+        .ok_or_else(|| "E_REPL_TYPE 식을 찾지 못했습니다".to_string())?;
+    let value = seed
+        .body
+        .as_ref()
+        .and_then(|body| body.stmts.first())
+        .and_then(|stmt| match stmt {
+            Stmt::Return { value, .. } => Some(value),
+            _ => None,
+        })
+        .ok_or_else(|| "E_REPL_TYPE 식을 찾지 못했습니다".to_string())?;
+    Ok(type_ref_text(&infer_type_ref(value)))
+}
+
+fn seed_kind_name(kind: &SeedKind) -> &str {
+    match kind {
+        SeedKind::Imeumssi => "이름씨",
+        SeedKind::Umjikssi => "움직씨",
+        SeedKind::ValueFunc | SeedKind::Semssi => "셈씨",
+        SeedKind::Gallaessi => "갈래씨",
+        SeedKind::Relationssi => "관계씨",
+        SeedKind::Sam => "샘",
+        SeedKind::Heureumssi => "흐름씨",
+        SeedKind::Ieumssi => "이음씨",
+        SeedKind::Named(name) => name,
+    }
+}
+
+/// 모양만 보고 알 수 있는 타입만 채우고 나머지는 `_`(Infer)로 둔다.
+fn infer_type_ref(expr: &Expr) -> TypeRef {
+    match &expr.kind {
+        ExprKind::Literal(lit) => match lit {
+            Literal::Int(_) | Literal::Fixed64(_) => TypeRef::Named("수".to_string()),
+            Literal::String(_) => TypeRef::Named("글".to_string()),
+            Literal::Bool(_) => TypeRef::Named("참거짓".to_string()),
+            Literal::None => TypeRef::Named("없음".to_string()),
+            _ => TypeRef::Infer,
+        },
+        ExprKind::Infix { left, op, right } => match op.as_str() {
+            "==" | "!=" | "<" | "<=" | ">" | ">=" | "&&" | "||" | "그리고" | "또는" => {
+                TypeRef::Named("참거짓".to_string())
+            }
+            "+" | "-" | "*" | "/" | "%" => {
+                let left = infer_type_ref(left);
+                let right = infer_type_ref(right);
+                if type_ref_text(&left) == type_ref_text(&right) {
+                    left
+                } else {
+                    TypeRef::Infer
+                }
+            }
+            _ => TypeRef::Infer,
+        },
+        ExprKind::Suffix { value, .. } | ExprKind::Nuance { expr: value, .. } => {
+            infer_type_ref(value)
+        }
+        ExprKind::Template(_) | ExprKind::TemplateRender { .. } => TypeRef::Named("글".to_string()),
+        ExprKind::Assertion(_) => TypeRef::Named("참거짓".to_string()),
+        ExprKind::Pack { fields } => TypeRef::Applied {
+            name: "묶음".to_string(),
+            args: fields
+                .iter()
+                .map(|(_, value)| infer_type_ref(value))
+                .collect(),
+        },
+        _ => TypeRef::Infer,
+    }
+}
+
+fn type_ref_text(type_ref: &TypeRef) -> String {
+    match type_ref {
+        TypeRef::Named(name) => name.clone(),
+        TypeRef::Applied { name, args } => {
+            let args = args.iter().map(type_ref_text).collect::<Vec<_>>();
+            format!("({}) {}", args.join(" "), name)
+        }
+        TypeRef::Infer => "_".to_string(),
+    }
+}
+
+fn normalize_input(input: &str) -> Result<String, String> {
+    let program = ddonirang_lang::parse_with_mode(input, REPL_FILE, ParseMode::Strict)
+        .map_err(|err| format!("E_REPL_NORMALIZE {}", err.message))?;
+    Ok(ddonirang_lang::normalize(&program, NormalizationLevel::N1)
+        .trim_end()
+        .to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{brace_depth, describe_type, push_line};
+
+    #[test]
+    fn push_line_waits_until_braces_balance() {
+        let mut pending = String::new();
+        assert_eq!(push_line(&mut pending, "더하:셈씨 = {\n"), None);
+        assert_eq!(push_line(&mut pending, "    1 + 2 돌려줘.\n"), None);
+        let input = push_line(&mut pending, "}\n").expect("complete input");
+        assert_eq!(input, "더하:셈씨 = {\n    1 + 2 돌려줘.\n}");
+        assert!(pending.is_empty());
+    }
+
+    #[test]
+    fn push_line_returns_single_line_input_immediately() {
+        let mut pending = String::new();
+        assert_eq!(
+            push_line(&mut pending, "x <- 1.\n").as_deref(),
+            Some("x <- 1.")
+        );
+        assert_eq!(push_line(&mut pending, "   \n"), None);
+        assert!(pending.is_empty());
+    }
+
+    #[test]
+    fn brace_depth_ignores_strings_and_comments() {
+        assert_eq!(brace_depth("a <- \"{\". // {"), 0);
+        assert_eq!(brace_depth("채비 { // }"), 1);
+        assert_eq!(brace_depth("s <- \"\\\"{\"."), 0);
+        assert_eq!(brace_depth("{ { } }"), 0);
+    }
+
+    #[test]
+    fn type_of_literal_and_comparison() {
+        assert_eq!(describe_type("1 + 2").as_deref(), Ok("수"));
+        assert_eq!(describe_type("(1 < 2)").as_deref(), Ok("참거짓"));
+        assert_eq!(describe_type("\"안녕\"").as_deref(), Ok("글"));
+    }
+}
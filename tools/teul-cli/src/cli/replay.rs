@@ -1,9 +1,13 @@
 use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
 use std::path::Path;
 
 use crate::cli::sam_snapshot::apply_snapshot;
-use crate::core::geoul::{decode_input_snapshot, geoul_state_hash_bytes, GeoulBundleReader};
+use crate::core::fixed64::Fixed64;
+use crate::core::geoul::{
+    decode_input_snapshot, encode_state_for_geoul, geoul_state_hash_bytes, GeoulBundleReader,
+};
 use crate::core::State;
 use crate::lang::lexer::Lexer;
 use crate::lang::parser::Parser;
@@ -13,6 +17,23 @@ struct FrameData {
     snapshot: crate::core::geoul::InputSnapshotV1,
     expected_hash: [u8; 32],
     patch_blob: Option<Vec<u8>>,
+    full_blob: Option<Vec<u8>>,
+}
+
+/// 해시 대신 수 값을 비교할 때 지금까지 본 가장 큰 차이.
+struct DriftPeak {
+    madi: u64,
+    key: String,
+    drift: Fixed64,
+}
+
+/// 허용 오차를 넘었거나 수로 비교할 수 없는 차이가 난 곳.
+struct DriftFailure {
+    madi: u64,
+    key: String,
+    expected: String,
+    actual: String,
+    drift: Option<Fixed64>,
 }
 
 struct ReplayMismatch {
@@ -27,7 +48,15 @@ pub fn run_replay_verify(
     entry_override: Option<&Path>,
     until: Option<u64>,
     seek: Option<u64>,
+    tolerance: Option<&str>,
 ) -> Result<(), String> {
+    let tolerance = tolerance
+        .map(|text| {
+            Fixed64::parse_literal(text)
+                .filter(|value| value.raw() >= 0)
+                .ok_or_else(|| format!("E_REPLAY_TOLERANCE 0 이상의 수가 필요합니다: {}", text))
+        })
+        .transpose()?;
     let entry_path = entry_override
         .map(|path| path.to_path_buf())
         .unwrap_or_else(|| geoul_dir.join("entry.ddn"));
@@ -79,10 +108,16 @@ pub fn run_replay_verify(
         } else {
             None
         };
+        let full_blob = if frame.header.full_bytes > 0 {
+            Some(frame.full_blob)
+        } else {
+            None
+        };
         frames.push(FrameData {
             snapshot,
             expected_hash: frame.header.state_hash,
             patch_blob,
+            full_blob,
         });
     }
 
@@ -93,6 +128,8 @@ pub fn run_replay_verify(
     let evaluator = Evaluator::with_state(State::new());
 
     let mismatch = RefCell::new(None);
+    let drift_failure: RefCell<Option<Result<DriftFailure, String>>> = RefCell::new(None);
+    let drift_peak: RefCell<Option<DriftPeak>> = RefCell::new(None);
     let mut before_tick = |madi: u64, state: &mut State| -> Result<(), RuntimeError> {
         if let Some(frame) = frames.get(madi as usize) {
             apply_snapshot(state, &frame.snapshot);
@@ -103,14 +140,24 @@ pub fn run_replay_verify(
         if madi < seek_madi {
             return;
         }
-        if mismatch.borrow().is_some() {
+        if mismatch.borrow().is_some() || drift_failure.borrow().is_some() {
             return;
         }
         let Some(frame) = frames.get(madi as usize) else {
             return;
         };
         let actual_bytes = geoul_state_hash_bytes(state);
-        if actual_bytes != frame.expected_hash {
+        if actual_bytes == frame.expected_hash {
+            return;
+        }
+        if let Some(tolerance) = tolerance {
+            let outcome = reference_state(geoul_dir, madi, frame).and_then(|expected| {
+                compare_within_tolerance(madi, &expected, state, tolerance, &drift_peak)
+            });
+            if let Some(failure) = outcome.transpose() {
+                drift_failure.replace(Some(failure));
+            }
+        } else {
             let patch_hex = frame.patch_blob.as_deref().map(hex_bytes);
             mismatch.replace(Some(ReplayMismatch {
                 madi,
@@ -126,6 +173,26 @@ pub fn run_replay_verify(
         .run_with_ticks_observe_and_inject(&program, ticks, &mut before_tick, &mut on_tick)
         .map_err(|err| format!("E_REPLAY_RUNTIME {:?}", err))?;
 
+    if let Some(failure) = drift_failure.into_inner() {
+        let failure = failure?;
+        let tolerance = tolerance.unwrap_or(Fixed64::zero()).format();
+        let drift = failure
+            .drift
+            .map(|drift| drift.format())
+            .unwrap_or_else(|| "null".to_string());
+        println!("verify_ok=false");
+        println!("first_diverge_madi={}", failure.madi);
+        println!("drift_key={}", failure.key);
+        println!("expected_value={}", failure.expected);
+        println!("actual_value={}", failure.actual);
+        println!("drift={}", drift);
+        println!("tolerance={}", tolerance);
+        return Err(format!(
+            "E_REPLAY_TOLERANCE_EXCEEDED madi={} key={} drift={} tolerance={}",
+            failure.madi, failure.key, drift, tolerance
+        ));
+    }
+
     if let Some(mismatch) = mismatch.into_inner() {
         println!("verify_ok=false");
         println!("first_diverge_madi={}", mismatch.madi);
@@ -142,9 +209,114 @@ pub fn run_replay_verify(
 
     println!("verify_ok=true");
     println!("first_diverge_madi=null");
+    if tolerance.is_some() {
+        match drift_peak.into_inner() {
+            Some(peak) => {
+                println!("max_drift={}", peak.drift.format());
+                println!("max_drift_madi={}", peak.madi);
+                println!("max_drift_key={}", peak.key);
+            }
+            None => {
+                println!("max_drift=0");
+                println!("max_drift_madi=null");
+            }
+        }
+    }
     Ok(())
 }
 
+/// 기록된 madi의 상태를 `키 → canon` 표로 읽는다. full 추적이 있으면 그것을, 없으면 체크포인트를 쓴다.
+fn reference_state(
+    geoul_dir: &Path,
+    madi: u64,
+    frame: &FrameData,
+) -> Result<BTreeMap<String, String>, String> {
+    let bytes = match frame.full_blob.as_ref() {
+        Some(full) => full.clone(),
+        None => {
+            let path = geoul_dir
+                .join("checkpoints")
+                .join(format!("cp_{:06}.detbin", madi));
+            fs::read(&path).map_err(|_| {
+                format!(
+                    "E_REPLAY_TOLERANCE_NO_STATE madi={} full 추적이나 체크포인트가 필요합니다",
+                    madi
+                )
+            })?
+        }
+    };
+    let text = String::from_utf8(bytes)
+        .map_err(|_| format!("E_REPLAY_STATE_DECODE madi={} UTF-8이 아닙니다", madi))?;
+    Ok(state_canon_map(&text))
+}
+
+fn state_canon_map(detbin_text: &str) -> BTreeMap<String, String> {
+    detbin_text
+        .lines()
+        .skip(1)
+        .filter_map(|line| line.split_once('\t'))
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+/// 키마다 canon이 다르면 수로 읽어 차이를 재고, `tolerance`를 넘는 첫 키를 돌려준다.
+/// 수가 아니거나 단위가 다른 차이는 허용 오차와 상관없이 실패로 본다.
+fn compare_within_tolerance(
+    madi: u64,
+    expected: &BTreeMap<String, String>,
+    state: &State,
+    tolerance: Fixed64,
+    peak: &RefCell<Option<DriftPeak>>,
+) -> Result<Option<DriftFailure>, String> {
+    let actual_text = String::from_utf8(encode_state_for_geoul(state))
+        .map_err(|_| format!("E_REPLAY_STATE_DECODE madi={} UTF-8이 아닙니다", madi))?;
+    let actual = state_canon_map(&actual_text);
+    let keys = expected
+        .keys()
+        .chain(actual.keys())
+        .collect::<BTreeSet<_>>();
+    for key in keys {
+        let expected_value = expected.get(key).map(String::as_str).unwrap_or("없음");
+        let actual_value = actual.get(key).map(String::as_str).unwrap_or("없음");
+        if expected_value == actual_value {
+            continue;
+        }
+        let drift = match (parse_numeric(expected_value), parse_numeric(actual_value)) {
+            (Some((lhs, lhs_unit)), Some((rhs, rhs_unit))) if lhs_unit == rhs_unit => {
+                let diff = lhs.saturating_sub(rhs);
+                Some(Fixed64::from_raw(diff.raw().saturating_abs()))
+            }
+            _ => None,
+        };
+        if let Some(drift) = drift {
+            let mut peak = peak.borrow_mut();
+            if peak.as_ref().map(|p| drift > p.drift).unwrap_or(true) {
+                *peak = Some(DriftPeak {
+                    madi,
+                    key: key.clone(),
+                    drift,
+                });
+            }
+            if drift <= tolerance {
+                continue;
+            }
+        }
+        return Ok(Some(DriftFailure {
+            madi,
+            key: key.clone(),
+            expected: expected_value.to_string(),
+            actual: actual_value.to_string(),
+            drift,
+        }));
+    }
+    Ok(None)
+}
+
+fn parse_numeric(canon: &str) -> Option<(Fixed64, &str)> {
+    let (number, unit) = canon.split_once('@').unwrap_or((canon, ""));
+    Fixed64::parse_literal(number).map(|value| (value, unit))
+}
+
 fn hex32(bytes: &[u8; 32]) -> String {
     let mut out = String::with_capacity(64);
     for b in bytes {
@@ -162,3 +334,50 @@ fn hex_bytes(bytes: &[u8]) -> String {
     }
     out
 }
+
+#[cfg(test)]
+mod tests {
+    use super::run_replay_verify;
+    use crate::cli::geoul::write_test_geoul;
+    use std::fs;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn temp_geoul(name: &str) -> std::path::PathBuf {
+        let nonce = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("teul_cli_replay_{name}_{nonce}"));
+        // 기준 실행은 마디마다 1씩, 다시 돌리는 entry는 1.25씩 올린다.
+        write_test_geoul(
+            &dir,
+            "채비 {\n  위치:수 <- 0.\n}.\n(매마디)마다 {\n  위치 <- 위치 + 1.\n}.\n",
+            4,
+        );
+        fs::write(
+            dir.join("drifted.ddn"),
+            "채비 {\n  위치:수 <- 0.\n}.\n(매마디)마다 {\n  위치 <- 위치 + 1.25.\n}.\n",
+        )
+        .expect("drifted entry");
+        dir
+    }
+
+    #[test]
+    fn tolerance_accepts_small_drift_and_reports_where_large_drift_occurs() {
+        let dir = temp_geoul("tolerance");
+        let drifted = dir.join("drifted.ddn");
+
+        let err = run_replay_verify(&dir, Some(&drifted), None, None, None).expect_err("exact");
+        assert!(err.starts_with("E_REPLAY_MISMATCH"), "{err}");
+
+        run_replay_verify(&dir, Some(&drifted), None, None, Some("1")).expect("within tolerance");
+
+        let err = run_replay_verify(&dir, Some(&drifted), None, None, Some("0.5"))
+            .expect_err("beyond tolerance");
+        assert!(
+            err.starts_with("E_REPLAY_TOLERANCE_EXCEEDED madi=2 key=위치 drift=0.75"),
+            "{err}"
+        );
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
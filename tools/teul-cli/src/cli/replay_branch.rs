@@ -1,6 +1,7 @@
 use std::cell::RefCell;
+use std::collections::BTreeMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use blake3;
 
@@ -28,10 +29,13 @@ struct BranchMismatch {
     actual_hash: String,
 }
 
+/// 갈래 `at` 뒤에 샘 입력을 끼운다. `inject_sams`의 각 항목은 `<madi>:<path>`(그 마디부터)
+/// 또는 `<path>`(`at + 1`부터)이고, 시작 마디 순으로 정렬해 적용한다. 어느 샘도 덮지 않는
+/// 마디는 원래 기록의 누름 상태를 따르며, 갈래는 마지막 샘이 끝나는 마디까지 이어진다.
 pub fn run_replay_branch(
     geoul_dir: &Path,
     at: u64,
-    inject_sam: &Path,
+    inject_sams: &[String],
     out_dir: &Path,
     entry_override: Option<&Path>,
 ) -> Result<(), String> {
@@ -79,21 +83,37 @@ pub fn run_replay_branch(
         });
     }
 
-    let tape = read_input_tape(inject_sam)?;
-    let mut inject_masks = Vec::with_capacity(tape.records.len());
-    for (idx, record) in tape.records.iter().enumerate() {
-        if record.madi != idx as u32 {
+    if inject_sams.is_empty() {
+        return Err("E_REPLAY_BRANCH_INJECT_EMPTY --inject-sam이 필요합니다".to_string());
+    }
+    let mut injections = Vec::with_capacity(inject_sams.len());
+    for spec in inject_sams {
+        let (start, path) = parse_inject_spec(spec, at)?;
+        if start <= at {
             return Err(format!(
-                "E_REPLAY_BRANCH_RECORD_ORDER_MISMATCH record.madi={} idx={}",
-                record.madi, idx
+                "E_REPLAY_BRANCH_INJECT_RANGE madi={} at={} 샘은 갈래 뒤에만 끼울 수 있습니다",
+                start, at
             ));
         }
-        let mask = mask_from_bytes(&record.held_mask)?;
-        inject_masks.push(mask);
+        injections.push((start, read_inject_masks(&path)?));
     }
+    injections.sort_by_key(|(start, _)| *start);
 
-    let inject_len = inject_masks.len() as u64;
-    let branch_ticks = at + 1 + inject_len;
+    let mut inject_masks = BTreeMap::new();
+    let mut branch_ticks = at + 1;
+    for (start, masks) in &injections {
+        if *start < branch_ticks && !inject_masks.is_empty() {
+            return Err(format!(
+                "E_REPLAY_BRANCH_INJECT_OVERLAP madi={} 앞 샘이 madi={}까지 이어집니다",
+                start,
+                branch_ticks - 1
+            ));
+        }
+        for (idx, mask) in masks.iter().enumerate() {
+            inject_masks.insert(start + idx as u64, *mask);
+        }
+        branch_ticks = branch_ticks.max(start + masks.len() as u64);
+    }
     if branch_ticks == 0 {
         return Err("E_REPLAY_BRANCH_EMPTY branch ticks가 0입니다".to_string());
     }
@@ -107,9 +127,15 @@ pub fn run_replay_branch(
         branch_snapshots.push(base_frames[madi as usize].snapshot.clone());
     }
     let mut last_mask = base_frames[at as usize].snapshot.held_mask;
-    for (idx, held_mask) in inject_masks.iter().enumerate() {
-        let madi = at + 1 + idx as u64;
-        let snapshot = snapshot_from_held_mask(madi, base_seed, *held_mask, last_mask);
+    for madi in at + 1..branch_ticks {
+        let held_mask = match inject_masks.get(&madi) {
+            Some(mask) => *mask,
+            None => base_frames
+                .get(madi as usize)
+                .map(|frame| frame.snapshot.held_mask)
+                .unwrap_or(last_mask),
+        };
+        let snapshot = snapshot_from_held_mask(madi, base_seed, held_mask, last_mask);
         last_mask = snapshot.held_mask;
         branch_snapshots.push(snapshot);
     }
@@ -257,6 +283,36 @@ pub fn run_replay_branch(
     Ok(())
 }
 
+fn parse_inject_spec(spec: &str, at: u64) -> Result<(u64, PathBuf), String> {
+    if let Some((prefix, path)) = spec.split_once(':') {
+        if !prefix.is_empty() && prefix.bytes().all(|b| b.is_ascii_digit()) {
+            let madi = prefix
+                .parse::<u64>()
+                .map_err(|_| format!("E_REPLAY_BRANCH_INJECT_SPEC {}", spec))?;
+            if path.is_empty() {
+                return Err(format!("E_REPLAY_BRANCH_INJECT_SPEC {}", spec));
+            }
+            return Ok((madi, PathBuf::from(path)));
+        }
+    }
+    Ok((at + 1, PathBuf::from(spec)))
+}
+
+fn read_inject_masks(path: &Path) -> Result<Vec<u16>, String> {
+    let tape = read_input_tape(path)?;
+    let mut masks = Vec::with_capacity(tape.records.len());
+    for (idx, record) in tape.records.iter().enumerate() {
+        if record.madi != idx as u32 {
+            return Err(format!(
+                "E_REPLAY_BRANCH_RECORD_ORDER_MISMATCH record.madi={} idx={}",
+                record.madi, idx
+            ));
+        }
+        masks.push(mask_from_bytes(&record.held_mask)?);
+    }
+    Ok(masks)
+}
+
 fn hex32(bytes: &[u8; 32]) -> String {
     let mut out = String::with_capacity(64);
     for b in bytes {
@@ -265,3 +321,83 @@ fn hex32(bytes: &[u8; 32]) -> String {
     }
     out
 }
+
+#[cfg(test)]
+mod tests {
+    use super::run_replay_branch;
+    use crate::cli::geoul::{replay_geoul_states, write_test_geoul};
+    use crate::cli::input_tape::{mask_to_bytes, write_input_tape, InputRecord, InputTape};
+    use crate::core::state::Key;
+    use std::fs;
+    use std::path::Path;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn write_sam(path: &Path, masks: &[u16]) {
+        let tape = InputTape {
+            madi_hz: 60,
+            records: masks
+                .iter()
+                .enumerate()
+                .map(|(idx, mask)| InputRecord {
+                    madi: idx as u32,
+                    held_mask: mask_to_bytes(*mask),
+                })
+                .collect(),
+        };
+        write_input_tape(path, &tape).expect("write sam");
+    }
+
+    #[test]
+    fn branch_applies_multiple_sams_in_madi_order() {
+        let nonce = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        let root = std::env::temp_dir().join(format!("teul_cli_replay_branch_{nonce}"));
+        let base = root.join("base");
+        write_test_geoul(
+            &base,
+            "채비 {\n  오른쪽:수 <- 0.\n  왼쪽:수 <- 0.\n}.\n(매마디)마다 {\n  오른쪽 <- 오른쪽 + 샘.키보드.누르고있음.ArrowRight.\n  왼쪽 <- 왼쪽 + 샘.키보드.누르고있음.ArrowLeft.\n}.\n",
+            10,
+        );
+        let right = root.join("right.sam");
+        let left = root.join("left.sam");
+        write_sam(&right, &[0b10, 0b10]);
+        write_sam(&left, &[0b01]);
+
+        let out = root.join("branch");
+        let specs = vec![
+            format!("7:{}", left.display()),
+            format!("3:{}", right.display()),
+        ];
+        run_replay_branch(&base, 2, &specs, &out, None).expect("branch");
+
+        let mut totals = Vec::new();
+        replay_geoul_states(&out, 7, None, |madi, state| {
+            let read = |key: &str| state.get(&Key::new(key)).map(|v| v.canon());
+            totals.push((madi, read("오른쪽"), read("왼쪽")));
+        })
+        .expect("replay branch");
+        let at = |madi: usize| {
+            let (_, right, left) = &totals[madi];
+            (right.clone().unwrap(), left.clone().unwrap())
+        };
+        assert_eq!(totals.len(), 8);
+        assert_eq!(at(2), ("0".to_string(), "0".to_string()));
+        assert_eq!(at(4), ("2".to_string(), "0".to_string()));
+        assert_eq!(at(6), ("2".to_string(), "0".to_string()));
+        assert_eq!(at(7), ("2".to_string(), "1".to_string()));
+
+        let overlap = vec![
+            format!("3:{}", right.display()),
+            format!("4:{}", left.display()),
+        ];
+        let err = run_replay_branch(&base, 2, &overlap, &root.join("overlap"), None)
+            .expect_err("overlap");
+        assert!(
+            err.starts_with("E_REPLAY_BRANCH_INJECT_OVERLAP madi=4"),
+            "{err}"
+        );
+        let _ = fs::remove_dir_all(&root);
+    }
+}
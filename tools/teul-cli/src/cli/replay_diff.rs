@@ -4,6 +4,11 @@ use std::path::{Path, PathBuf};
 
 use serde_json::Value;
 
+use crate::cli::geoul::{hex32, replay_geoul_states};
+use crate::core::geoul::GeoulBundleReader;
+use crate::core::value::Value as StateValue;
+use crate::core::State;
+
 pub struct ReplayDiffOptions {
     pub a: PathBuf,
     pub b: PathBuf,
@@ -30,9 +35,24 @@ struct FirstDiverge {
     bogae_hash_b: Option<String>,
 }
 
+/// 같은 (엔티티, 성분) 묶음에서 처음 갈라진 마디와 그때의 값.
+#[derive(Debug)]
+struct ComponentDiverge {
+    entity: String,
+    component: String,
+    first_madi: u64,
+    value_a: String,
+    value_b: String,
+    madi_count: u64,
+}
+
+type ComponentValues = BTreeMap<(String, String), String>;
+/// 마디 번호별 성분 값.
+type TickComponents = BTreeMap<u64, ComponentValues>;
+
 pub fn run_diff(options: ReplayDiffOptions) -> Result<(), String> {
-    let a_manifest = load_manifest(&options.a)?;
-    let b_manifest = load_manifest(&options.b)?;
+    let a_manifest = load_replay(&options.a)?;
+    let b_manifest = load_replay(&options.b)?;
 
     let mut all_madis = BTreeMap::new();
     for madi in a_manifest.frames.keys() {
@@ -69,20 +89,170 @@ pub fn run_diff(options: ReplayDiffOptions) -> Result<(), String> {
     }
 
     let equal = first_diverge.is_none();
+    let components = if is_geoul_bundle(&options.a) && is_geoul_bundle(&options.b) {
+        let last_madi = a_manifest
+            .frames
+            .len()
+            .min(b_manifest.frames.len())
+            .checked_sub(1);
+        match (equal, last_madi) {
+            (false, Some(last_madi)) => Some(diff_geoul_components(
+                &options.a,
+                &options.b,
+                last_madi as u64,
+            )?),
+            _ => Some(Vec::new()),
+        }
+    } else {
+        None
+    };
     fs::create_dir_all(&options.out).map_err(|e| e.to_string())?;
     let detjson_path = options.out.join("diff.detjson");
-    let detjson = build_detjson_report(&a_manifest, &b_manifest, equal, &first_diverge);
+    let detjson = build_detjson_report(
+        &a_manifest,
+        &b_manifest,
+        equal,
+        &first_diverge,
+        components.as_deref(),
+    );
     fs::write(&detjson_path, detjson).map_err(|e| e.to_string())?;
 
     if options.write_summary {
         let summary_path = options.out.join("diff.txt");
-        let summary = build_summary_text(equal, &first_diverge);
+        let summary = build_summary_text(equal, &first_diverge, components.as_deref());
         fs::write(summary_path, summary).map_err(|e| e.to_string())?;
     }
 
     Ok(())
 }
 
+fn is_geoul_bundle(path: &Path) -> bool {
+    path.is_dir() && path.join("audit.ddni").is_file()
+}
+
+fn load_replay(path: &Path) -> Result<ManifestInfo, String> {
+    if is_geoul_bundle(path) {
+        load_geoul_frames(path)
+    } else {
+        load_manifest(path)
+    }
+}
+
+/// geoul 묶음은 manifest에 프레임 목록이 없으므로 audit 프레임 헤더에서 state_hash를 읽는다.
+fn load_geoul_frames(dir: &Path) -> Result<ManifestInfo, String> {
+    let mut reader = GeoulBundleReader::open(dir)
+        .map_err(|e| format!("E_REPLAY_DIFF_GEOUL {} {}", dir.display(), e))?;
+    let mut frames = BTreeMap::new();
+    for idx in 0..reader.frame_count() {
+        let header = reader
+            .read_frame_header(idx)
+            .map_err(|e| format!("E_REPLAY_DIFF_GEOUL {} {}", dir.display(), e))?;
+        let state_hash = format!("blake3:{}", hex32(&header.state_hash));
+        frames.insert(
+            header.madi,
+            FrameInfo {
+                state_hash,
+                bogae_hash: None,
+            },
+        );
+    }
+    let start_madi = frames.keys().next().copied().unwrap_or(0);
+    let end_madi = frames.keys().next_back().map(|madi| madi + 1).unwrap_or(0);
+    Ok(ManifestInfo {
+        start_madi,
+        end_madi,
+        frames,
+    })
+}
+
+/// 두 geoul을 다시 돌려 마디마다 (엔티티, 성분) 값을 견주고, 묶음별로 처음 갈라진 마디를 모은다.
+fn diff_geoul_components(
+    a: &Path,
+    b: &Path,
+    last_madi: u64,
+) -> Result<Vec<ComponentDiverge>, String> {
+    let mut a_ticks = TickComponents::new();
+    replay_geoul_states(a, last_madi, None, |madi, state| {
+        a_ticks.insert(madi, component_values(state));
+    })?;
+    let mut b_ticks = TickComponents::new();
+    replay_geoul_states(b, last_madi, None, |madi, state| {
+        b_ticks.insert(madi, component_values(state));
+    })?;
+    Ok(group_component_diverges(&a_ticks, &b_ticks))
+}
+
+fn group_component_diverges(
+    a_ticks: &TickComponents,
+    b_ticks: &TickComponents,
+) -> Vec<ComponentDiverge> {
+    let none = StateValue::None.canon();
+    let mut groups: BTreeMap<(String, String), ComponentDiverge> = BTreeMap::new();
+    for (madi, a_values) in a_ticks {
+        let Some(b_values) = b_ticks.get(madi) else {
+            continue;
+        };
+        let mut keys: Vec<&(String, String)> = a_values.keys().chain(b_values.keys()).collect();
+        keys.sort();
+        keys.dedup();
+        for key in keys {
+            let value_a = a_values.get(key).unwrap_or(&none);
+            let value_b = b_values.get(key).unwrap_or(&none);
+            if value_a == value_b {
+                continue;
+            }
+            groups
+                .entry(key.clone())
+                .and_modify(|group| group.madi_count += 1)
+                .or_insert_with(|| ComponentDiverge {
+                    entity: key.0.clone(),
+                    component: key.1.clone(),
+                    first_madi: *madi,
+                    value_a: value_a.clone(),
+                    value_b: value_b.clone(),
+                    madi_count: 1,
+                });
+        }
+    }
+    let mut out: Vec<ComponentDiverge> = groups.into_values().collect();
+    out.sort_by(|left, right| {
+        (left.first_madi, &left.entity, &left.component).cmp(&(
+            right.first_madi,
+            &right.entity,
+            &right.component,
+        ))
+    });
+    out
+}
+
+/// 상태 키가 엔티티다. 묶음/짝맞춤 값은 필드마다, `샘.키보드.x` 같은 점 키는 첫 마디 뒤를 성분으로 본다.
+fn component_values(state: &State) -> ComponentValues {
+    let mut out = ComponentValues::new();
+    for (key, value) in &state.resources {
+        let name = key.as_str();
+        if let Some((entity, component)) = name.split_once('.') {
+            out.insert((entity.to_string(), component.to_string()), value.canon());
+            continue;
+        }
+        match value {
+            StateValue::Pack(pack) => {
+                for (field, field_value) in &pack.fields {
+                    out.insert((name.to_string(), field.clone()), field_value.canon());
+                }
+            }
+            StateValue::Map(map) => {
+                for entry in map.entries.values() {
+                    out.insert((name.to_string(), entry.key.display()), entry.value.canon());
+                }
+            }
+            _ => {
+                out.insert((name.to_string(), "값".to_string()), value.canon());
+            }
+        }
+    }
+    out
+}
+
 fn load_manifest(path: &Path) -> Result<ManifestInfo, String> {
     let manifest_path = if path.is_dir() {
         path.join("manifest.detjson")
@@ -180,6 +350,7 @@ fn build_detjson_report(
     b: &ManifestInfo,
     equal: bool,
     first_diverge: &Option<FirstDiverge>,
+    components: Option<&[ComponentDiverge]>,
 ) -> String {
     let mut out = String::new();
     out.push_str("{\n");
@@ -234,10 +405,38 @@ fn build_detjson_report(
                 diverge.bogae_hash_b.as_deref(),
                 false,
             );
-            out.push_str("  }\n");
+            out.push_str("  },\n");
+        }
+        None => {
+            out.push_str("  \"first_diverge\": null,\n");
+        }
+    }
+    match components {
+        Some([]) => {
+            out.push_str("  \"component_diverge\": []\n");
+        }
+        Some(components) => {
+            out.push_str("  \"component_diverge\": [\n");
+            for (idx, group) in components.iter().enumerate() {
+                out.push_str(&format!(
+                    "    {{\"entity\": \"{}\", \"component\": \"{}\", \"first_madi\": {}, \"madi_count\": {}, \"value_a\": \"{}\", \"value_b\": \"{}\"}}",
+                    escape_json(&group.entity),
+                    escape_json(&group.component),
+                    group.first_madi,
+                    group.madi_count,
+                    escape_json(&group.value_a),
+                    escape_json(&group.value_b)
+                ));
+                out.push_str(if idx + 1 < components.len() {
+                    ",\n"
+                } else {
+                    "\n"
+                });
+            }
+            out.push_str("  ]\n");
         }
         None => {
-            out.push_str("  \"first_diverge\": null\n");
+            out.push_str("  \"component_diverge\": null\n");
         }
     }
     out.push_str("}\n");
@@ -278,7 +477,11 @@ fn escape_json(input: &str) -> String {
     out
 }
 
-fn build_summary_text(equal: bool, first_diverge: &Option<FirstDiverge>) -> String {
+fn build_summary_text(
+    equal: bool,
+    first_diverge: &Option<FirstDiverge>,
+    components: Option<&[ComponentDiverge]>,
+) -> String {
     let mut out = String::new();
     out.push_str(&format!(
         "equal: {}\n",
@@ -300,5 +503,113 @@ fn build_summary_text(equal: bool, first_diverge: &Option<FirstDiverge>) -> Stri
             out.push_str("first_diverge_madi: null\n");
         }
     }
+    if let Some(components) = components {
+        out.push_str(&format!("component_diverge_count: {}\n", components.len()));
+        for group in components {
+            out.push_str(&format!(
+                "component_diverge: entity={} component={} first_madi={} madi_count={} a={} b={}\n",
+                group.entity,
+                group.component,
+                group.first_madi,
+                group.madi_count,
+                group.value_a,
+                group.value_b
+            ));
+        }
+    }
     out
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::geoul::write_test_geoul;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let nonce = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        std::env::temp_dir().join(format!("teul_cli_replay_diff_{name}_{nonce}"))
+    }
+
+    fn entry_source(hp_expr: &str) -> String {
+        format!(
+            "채비 {{\n  점수:수 <- 0.\n  적 <- (\"체력\", 5) 짝맞춤.\n}}.\n(매마디)마다 {{\n  플레이어 <- (\"체력\", {hp_expr}, \"속도\", 1) 짝맞춤.\n  점수 <- 점수 + 1.\n}}.\n"
+        )
+    }
+
+    #[test]
+    fn geoul_diff_groups_divergence_by_entity_component() {
+        let root = temp_dir("component_groups");
+        let a = root.join("a");
+        let b = root.join("b");
+        write_test_geoul(&a, &entry_source("10 - 점수"), 4);
+        write_test_geoul(&b, &entry_source("10 - 점수 * 2"), 4);
+
+        let out = root.join("diff");
+        run_diff(ReplayDiffOptions {
+            a,
+            b,
+            out: out.clone(),
+            write_summary: true,
+        })
+        .expect("replay diff");
+        let report: Value =
+            serde_json::from_str(&fs::read_to_string(out.join("diff.detjson")).expect("read"))
+                .expect("detjson");
+        let summary = fs::read_to_string(out.join("diff.txt")).expect("read summary");
+        let _ = fs::remove_dir_all(&root);
+
+        assert_eq!(report["equal"], false);
+        assert_eq!(report["first_diverge_madi"], 1);
+        let groups = report["component_diverge"].as_array().expect("groups");
+        assert_eq!(groups.len(), 1, "groups={groups:?}");
+        assert_eq!(groups[0]["entity"], "플레이어");
+        assert_eq!(groups[0]["component"], "체력");
+        assert_eq!(groups[0]["first_madi"], 1);
+        assert_eq!(groups[0]["madi_count"], 3);
+        assert_eq!(groups[0]["value_a"], "9");
+        assert_eq!(groups[0]["value_b"], "8");
+        assert!(summary.contains("component_diverge: entity=플레이어 component=체력 first_madi=1"));
+    }
+
+    #[test]
+    fn component_groups_use_record_madi_not_position() {
+        let values = |hp: &str| {
+            ComponentValues::from([(("적".to_string(), "체력".to_string()), hp.to_string())])
+        };
+        let a_ticks = TickComponents::from([(7, values("5")), (8, values("4"))]);
+        let b_ticks = TickComponents::from([(7, values("5")), (8, values("3"))]);
+        let groups = group_component_diverges(&a_ticks, &b_ticks);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].first_madi, 8);
+        assert_eq!(groups[0].madi_count, 1);
+    }
+
+    #[test]
+    fn geoul_diff_equal_runs_have_no_component_groups() {
+        let root = temp_dir("component_equal");
+        let a = root.join("a");
+        let b = root.join("b");
+        write_test_geoul(&a, &entry_source("10 - 점수"), 3);
+        write_test_geoul(&b, &entry_source("10 - 점수"), 3);
+
+        let out = root.join("diff");
+        run_diff(ReplayDiffOptions {
+            a,
+            b,
+            out: out.clone(),
+            write_summary: false,
+        })
+        .expect("replay diff");
+        let report: Value =
+            serde_json::from_str(&fs::read_to_string(out.join("diff.detjson")).expect("read"))
+                .expect("detjson");
+        let _ = fs::remove_dir_all(&root);
+
+        assert_eq!(report["equal"], true);
+        assert_eq!(report["component_diverge"], serde_json::json!([]));
+    }
+}
@@ -11,10 +11,19 @@ use super::detjson::{sha256_hex, write_text};
 #[derive(Deserialize)]
 struct RewardCheckInput {
     schema: Option<String>,
-    script: String,
+    #[serde(default)]
+    script: Option<String>,
+    #[serde(default)]
+    terms: Option<Vec<RewardTermInput>>,
     cases: Vec<RewardCaseInput>,
 }
 
+#[derive(Deserialize)]
+struct RewardTermInput {
+    term: String,
+    weight: JsonValue,
+}
+
 #[derive(Deserialize)]
 struct RewardCaseInput {
     realm_id: u64,
@@ -42,6 +51,90 @@ struct RealmSummary {
     total: Fixed64,
 }
 
+/// 보상 정의: 식 하나(`script`) 또는 가중 항 목록(`terms`).
+/// 항 목록은 선언 순서대로 `weight * term`을 더해 스칼라 하나로 합친다.
+enum RewardSpec {
+    Script(RewardExpr),
+    Terms(Vec<RewardTerm>),
+}
+
+struct RewardTerm {
+    source: String,
+    weight: Fixed64,
+    expr: RewardExpr,
+}
+
+/// 표본 상태에서 항 하나가 합계에 보탠 몫.
+struct TermContribution {
+    value: Fixed64,
+    contribution: Fixed64,
+}
+
+impl RewardSpec {
+    fn from_input(
+        script: Option<String>,
+        terms: Option<Vec<RewardTermInput>>,
+    ) -> Result<Self, String> {
+        match (script, terms) {
+            (Some(script), None) => Ok(RewardSpec::Script(RewardExpr::parse(&script)?)),
+            (None, Some(terms)) => {
+                if terms.is_empty() {
+                    return Err("E_REWARD_TERMS terms는 비어 있을 수 없습니다".to_string());
+                }
+                let mut parsed = Vec::with_capacity(terms.len());
+                for item in terms {
+                    // 가중치는 실수(JSON number)가 아니라 Fixed64 문자열로만 받는다.
+                    let weight = parse_fixed64_value(&item.weight)
+                        .map_err(|e| format!("E_REWARD_TERM_WEIGHT {} {}", item.term, e))?;
+                    let expr = RewardExpr::parse(&item.term)?;
+                    parsed.push(RewardTerm {
+                        source: item.term,
+                        weight,
+                        expr,
+                    });
+                }
+                Ok(RewardSpec::Terms(parsed))
+            }
+            _ => Err("E_REWARD_TERMS script와 terms 중 하나만 있어야 합니다".to_string()),
+        }
+    }
+
+    fn eval(&self, vars: &BTreeMap<String, Fixed64>) -> Result<Fixed64, String> {
+        match self {
+            RewardSpec::Script(expr) => reward_result_num(expr.eval(vars)?),
+            RewardSpec::Terms(terms) => {
+                let mut total = Fixed64::ZERO;
+                for contribution in term_contributions(terms, vars)? {
+                    total = total.saturating_add(contribution.contribution);
+                }
+                Ok(total)
+            }
+        }
+    }
+}
+
+fn term_contributions(
+    terms: &[RewardTerm],
+    vars: &BTreeMap<String, Fixed64>,
+) -> Result<Vec<TermContribution>, String> {
+    let mut out = Vec::with_capacity(terms.len());
+    for term in terms {
+        let value = reward_result_num(term.expr.eval(vars)?)?;
+        out.push(TermContribution {
+            value,
+            contribution: term.weight * value,
+        });
+    }
+    Ok(out)
+}
+
+fn reward_result_num(value: RewardValue) -> Result<Fixed64, String> {
+    match value {
+        RewardValue::Num(value) => Ok(value),
+        RewardValue::Bool(_) => Err("E_REWARD_RESULT_TYPE 보상식 결과는 수여야 합니다".to_string()),
+    }
+}
+
 pub fn run_reward_check(input: &Path, out_dir: Option<&Path>) -> Result<(), String> {
     let text = fs::read_to_string(input)
         .map_err(|e| format!("E_REWARD_INPUT_READ {} {}", input.display(), e))?;
@@ -59,7 +152,7 @@ pub fn run_reward_check(input: &Path, out_dir: Option<&Path>) -> Result<(), Stri
         }
     }
 
-    let expr = RewardExpr::parse(&input.script)?;
+    let spec = RewardSpec::from_input(input.script, input.terms)?;
     let mut cases = Vec::with_capacity(input.cases.len());
     for (idx, item) in input.cases.into_iter().enumerate() {
         let mut vars = BTreeMap::new();
@@ -82,15 +175,19 @@ pub fn run_reward_check(input: &Path, out_dir: Option<&Path>) -> Result<(), Stri
         (a.realm_id, a.step, *a_idx).cmp(&(b.realm_id, b.step, *b_idx))
     });
 
+    // 항 목록이면 정렬 후 첫 사례를 표본 상태로 삼아 항별 몫을 보고서에 싣는다.
+    let sample = match (&spec, cases.first()) {
+        (RewardSpec::Terms(terms), Some((_, case))) => Some((
+            case.realm_id,
+            case.step,
+            term_contributions(terms, &case.vars)?,
+        )),
+        _ => None,
+    };
+
     let mut entries = Vec::with_capacity(cases.len());
     for (_, case) in cases {
-        let value = expr.eval(&case.vars)?;
-        let reward = match value {
-            RewardValue::Num(value) => value,
-            RewardValue::Bool(_) => {
-                return Err("E_REWARD_RESULT_TYPE 보상식 결과는 수여야 합니다".to_string());
-            }
-        };
+        let reward = spec.eval(&case.vars)?;
         entries.push(RewardEntry {
             realm_id: case.realm_id,
             step: case.step,
@@ -112,8 +209,20 @@ pub fn run_reward_check(input: &Path, out_dir: Option<&Path>) -> Result<(), Stri
 
     let log_detjson = build_reward_log(&input_file, &input_hash, &entries);
     let log_hash = format!("sha256:{}", sha256_hex(log_detjson.as_bytes()));
-    let report_detjson =
-        build_reward_report(&input_file, &input_hash, &log_hash, &summaries, total);
+    let term_sample = match (&spec, &sample) {
+        (RewardSpec::Terms(terms), Some((realm_id, step, contributions))) => {
+            Some(build_term_sample(*realm_id, *step, terms, contributions))
+        }
+        _ => None,
+    };
+    let report_detjson = build_reward_report(
+        &input_file,
+        &input_hash,
+        &log_hash,
+        &summaries,
+        total,
+        term_sample.as_deref(),
+    );
 
     if let Some(out_dir) = out_dir {
         fs::create_dir_all(out_dir).map_err(|e| e.to_string())?;
@@ -159,6 +268,7 @@ fn build_reward_report(
     log_hash: &str,
     summaries: &BTreeMap<u64, RealmSummary>,
     total: Fixed64,
+    term_sample: Option<&str>,
 ) -> String {
     let mut out = String::new();
     out.push_str("{\"schema\":\"reward.report.v1\",\"source_hash\":\"");
@@ -188,6 +298,41 @@ fn build_reward_report(
         out.push_str(&fixed64_raw_string(summary.total));
         out.push_str("\"}");
     }
+    out.push(']');
+    if let Some(term_sample) = term_sample {
+        out.push_str(",\"term_sample\":");
+        out.push_str(term_sample);
+    }
+    out.push('}');
+    out
+}
+
+fn build_term_sample(
+    realm_id: u64,
+    step: u64,
+    terms: &[RewardTerm],
+    contributions: &[TermContribution],
+) -> String {
+    let mut out = String::new();
+    out.push_str("{\"realm_id\":");
+    out.push_str(&realm_id.to_string());
+    out.push_str(",\"step\":");
+    out.push_str(&step.to_string());
+    out.push_str(",\"terms\":[");
+    for (idx, (term, contribution)) in terms.iter().zip(contributions).enumerate() {
+        if idx > 0 {
+            out.push(',');
+        }
+        out.push_str("{\"term\":");
+        out.push_str(&JsonValue::String(term.source.clone()).to_string());
+        out.push_str(",\"weight_raw\":\"");
+        out.push_str(&fixed64_raw_string(term.weight));
+        out.push_str("\",\"value_raw\":\"");
+        out.push_str(&fixed64_raw_string(contribution.value));
+        out.push_str("\",\"contribution_raw\":\"");
+        out.push_str(&fixed64_raw_string(contribution.contribution));
+        out.push_str("\"}");
+    }
     out.push_str("]}");
     out
 }
@@ -787,3 +932,85 @@ impl RewardParser {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let stamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("ddn_reward_{}_{}", name, stamp));
+        fs::create_dir_all(&dir).expect("mkdir");
+        dir
+    }
+
+    #[test]
+    fn weighted_terms_combine_into_weighted_sum() {
+        let dir = temp_dir("terms");
+        let input = dir.join("input.json");
+        let out_dir = dir.join("out");
+        let spec = serde_json::json!({
+            "schema": "reward.check.v1",
+            "terms": [
+                { "term": "x", "weight": "0.5" },
+                { "term": "y * 2", "weight": "2" }
+            ],
+            "cases": [
+                { "realm_id": 1, "step": 0, "vars": { "x": "4", "y": "3" } }
+            ]
+        });
+        fs::write(&input, serde_json::to_string(&spec).expect("spec")).expect("write input");
+
+        run_reward_check(&input, Some(&out_dir)).expect("reward check");
+
+        let expected = Fixed64::from_i64(14);
+        let log: JsonValue = serde_json::from_str(
+            &fs::read_to_string(out_dir.join("reward.log.detjson")).expect("read log"),
+        )
+        .expect("log json");
+        assert_eq!(
+            log["entries"][0]["reward_raw"].as_str(),
+            Some(fixed64_raw_string(expected).as_str())
+        );
+
+        let report: JsonValue = serde_json::from_str(
+            &fs::read_to_string(out_dir.join("reward.report.detjson")).expect("read report"),
+        )
+        .expect("report json");
+        let terms = report["term_sample"]["terms"]
+            .as_array()
+            .expect("term sample");
+        let contributions = terms
+            .iter()
+            .map(|term| term["contribution_raw"].as_str().unwrap_or("").to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            contributions,
+            vec![
+                fixed64_raw_string(Fixed64::from_i64(2)),
+                fixed64_raw_string(Fixed64::from_i64(12)),
+            ]
+        );
+        assert_eq!(
+            report["total_raw"].as_str(),
+            Some(fixed64_raw_string(expected).as_str())
+        );
+    }
+
+    #[test]
+    fn weighted_terms_reject_float_weight() {
+        let terms = vec![RewardTermInput {
+            term: "x".to_string(),
+            weight: serde_json::json!(0.5),
+        }];
+        let err = RewardSpec::from_input(None, Some(terms))
+            .err()
+            .expect("float weight must fail");
+        assert!(err.starts_with("E_REWARD_TERM_WEIGHT"), "{}", err);
+    }
+}
@@ -1,5 +1,5 @@
 use std::cell::RefCell;
-use std::collections::{BTreeSet, VecDeque};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::fs;
 use std::fs::OpenOptions;
 use std::io::{self, BufRead, Write};
@@ -18,11 +18,14 @@ use time::OffsetDateTime;
 
 use crate::canon;
 use crate::cli::bogae::{
-    default_bogae_out_dir, is_bogae_out_dir, resolve_bogae_out_dir, BogaeMode, OverlayConfig,
+    default_bogae_out_dir, is_bogae_out_dir, resolve_bogae_out_dir, resolve_bogae_png_path,
+    BogaeMode, OverlayConfig,
 };
 use crate::cli::bogae_console::{render_drawlist_ascii, ConsoleLive, ConsoleRenderConfig};
 use crate::cli::bogae_playback::{write_manifest, write_viewer_assets, PlaybackFrameMeta};
+use crate::cli::bogae_png::write_drawlist_png;
 use crate::cli::bogae_web::write_web_assets;
+use crate::cli::build::GajiModule;
 use crate::cli::cert;
 use crate::cli::frontdoor_parse::{
     parse_program_for_runtime, parse_program_for_runtime_with_mode, FrontdoorParseFailure,
@@ -55,7 +58,7 @@ use crate::lang::parser::{ParseError, ParseMode};
 use crate::runtime::{
     ContractDiag, DiagnosticFailure, DiagnosticRecord, EvalFailure, EvalOutput, Evaluator,
     OpenDiagConfig, OpenInputFrame, OpenMode, OpenPolicy, OpenRuntime, ProofRuntimeEvent,
-    RuntimeError,
+    RuntimeError, SeedProfile,
 };
 use ddonirang_core::gogae3::{
     compute_w24_state_hash, compute_w25_state_hash, compute_w26_state_hash, compute_w27_state_hash,
@@ -230,11 +233,62 @@ pub fn parse_artifact_pins(values: &[String]) -> Result<Vec<ArtifactPin>, String
     Ok(pins)
 }
 
+/// `--env KEY=VAL` 목록을 읽는다. 같은 키를 두 번 주면 거부한다.
+pub fn parse_run_env(values: &[String]) -> Result<BTreeMap<String, String>, String> {
+    let mut env = BTreeMap::new();
+    for value in values {
+        let Some((key, val)) = value.split_once('=') else {
+            return Err(format!("형식은 KEY=VAL 이어야 합니다: {}", value));
+        };
+        let key = key.trim();
+        if key.is_empty() {
+            return Err(format!("키가 비었습니다: {}", value));
+        }
+        if env.insert(key.to_string(), val.to_string()).is_some() {
+            return Err(format!("키가 두 번 주어졌습니다: {}", key));
+        }
+    }
+    Ok(env)
+}
+
+/// `--max-faults` 한도로 멈춘 실행의 종료 코드.
+pub const EXIT_MAX_FAULTS: i32 = 3;
+
+/// 산술고장을 종류별로 세어 `overflow=2, div0=1`처럼 적는다. 순서는 종류 이름순이다.
+fn fault_kind_summary(faults: &[ddonirang_core::Signal]) -> String {
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for signal in faults {
+        if let ddonirang_core::Signal::ArithmeticFault { kind, .. } = signal {
+            let label = match kind {
+                ddonirang_core::ArithmeticFaultKind::DivByZero => "div0",
+                ddonirang_core::ArithmeticFaultKind::DimensionMismatch { .. } => "dim_mismatch",
+                ddonirang_core::ArithmeticFaultKind::Overflow => "overflow",
+            };
+            *counts.entry(label).or_default() += 1;
+        }
+    }
+    counts
+        .iter()
+        .map(|(label, count)| format!("{}={}", label, count))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// 실행 오류 문자열에 맞는 종료 코드를 고른다.
+pub fn run_error_exit_code(error: &str) -> i32 {
+    if error.starts_with("E_RUN_MAX_FAULTS") {
+        EXIT_MAX_FAULTS
+    } else {
+        1
+    }
+}
+
 pub struct RunOptions {
     pub diag_jsonl: Option<PathBuf>,
     pub diag_report_out: Option<PathBuf>,
     pub repro_json: Option<PathBuf>,
     pub trace_json: Option<PathBuf>,
+    pub profile: Option<PathBuf>,
     pub proof_out: Option<PathBuf>,
     pub proof_cert_key: Option<PathBuf>,
     pub geoul_out: Option<PathBuf>,
@@ -243,6 +297,7 @@ pub struct RunOptions {
     pub run_command: Option<String>,
     pub init_state: Vec<String>,
     pub init_state_files: Vec<PathBuf>,
+    pub init_state_json: Vec<String>,
     pub trace_tier: TraceTier,
     pub age_target: Option<String>,
     pub lang_mode: Option<crate::cli::lang_mode::LangModeArg>,
@@ -256,6 +311,7 @@ pub struct RunOptions {
     pub console_config: ConsoleRenderConfig,
     pub until_gameover: bool,
     pub gameover_key: String,
+    pub max_faults: Option<u64>,
     pub sam_path: Option<PathBuf>,
     pub record_sam_path: Option<PathBuf>,
     pub sam_live: Option<SamLiveMode>,
@@ -271,6 +327,8 @@ pub struct RunOptions {
     pub run_manifest: Option<PathBuf>,
     pub summary_json: Option<PathBuf>,
     pub artifact_pins: Vec<ArtifactPin>,
+    pub deterministic_check: bool,
+    pub run_env: BTreeMap<String, String>,
 }
 
 #[derive(Clone, Debug)]
@@ -293,6 +351,10 @@ fn apply_init_state(
         let mut file_entries = parse_state_file(path)?;
         entries.append(&mut file_entries);
     }
+    for text in &options.init_state_json {
+        let mut json_entries = parse_inline_state_json(text, base_dir)?;
+        entries.append(&mut json_entries);
+    }
     for entry in entries {
         state.set(Key::new(entry.key), entry.value);
     }
@@ -332,6 +394,50 @@ fn parse_state_file(path: &Path) -> Result<Vec<StateEntry>, String> {
     Ok(entries)
 }
 
+/// `--state-json`. 중첩 객체는 `--state a.b=값`과 같은 점 키로 펼친다.
+fn parse_inline_state_json(text: &str, base_dir: &Path) -> Result<Vec<StateEntry>, String> {
+    let root: JsonValue = serde_json::from_str(text).map_err(|e| {
+        format!(
+            "E_CLI_STATE_JSON line={} column={} {}",
+            e.line(),
+            e.column(),
+            e
+        )
+    })?;
+    let JsonValue::Object(map) = root else {
+        return Err("E_CLI_STATE_JSON 최상위는 객체여야 합니다".to_string());
+    };
+    let mut entries = Vec::new();
+    for (key, value) in &map {
+        flatten_state_json(&normalize_state_key(key), value, base_dir, &mut entries)?;
+    }
+    Ok(entries)
+}
+
+fn flatten_state_json(
+    key: &str,
+    value: &JsonValue,
+    base_dir: &Path,
+    out: &mut Vec<StateEntry>,
+) -> Result<(), String> {
+    if key.is_empty() {
+        return Err("E_CLI_STATE_JSON 키가 비었습니다".to_string());
+    }
+    if let JsonValue::Object(map) = value {
+        for (child, child_value) in map {
+            flatten_state_json(&format!("{}.{}", key, child), child_value, base_dir, out)?;
+        }
+        return Ok(());
+    }
+    let value =
+        json_to_value(value, base_dir).map_err(|e| format!("E_CLI_STATE_JSON {} {}", key, e))?;
+    out.push(StateEntry {
+        key: key.to_string(),
+        value,
+    });
+    Ok(())
+}
+
 fn is_state_json_path(path: &Path) -> bool {
     match path.extension().and_then(|ext| ext.to_str()) {
         Some(ext) if ext.eq_ignore_ascii_case("json") => true,
@@ -3027,7 +3133,7 @@ pub fn run_file(
     run_file_with_emitter(path, madi, seed, options, &mut emitter)
 }
 
-fn extract_setting_madi(source: &str) -> Result<Option<u64>, String> {
+pub(crate) fn extract_setting_madi(source: &str) -> Result<Option<u64>, String> {
     let mut search_start = 0;
     while let Some(rel_idx) = source[search_start..].find("설정") {
         let setting_start = search_start + rel_idx;
@@ -3131,7 +3237,12 @@ pub fn run_file_with_emitter(
     options: RunOptions,
     emit: &mut dyn RunEmitSink,
 ) -> Result<(), String> {
-    let source = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let (source, artifact_gaji) = if crate::cli::build::is_artifact_path(path) {
+        let artifact = crate::cli::build::load_artifact(path)?;
+        (artifact.source, Some(artifact.gaji))
+    } else {
+        (fs::read_to_string(path).map_err(|e| e.to_string())?, None)
+    };
     let configured_madi = extract_setting_madi(&source)?;
     let file_label = path.display().to_string();
     let open_source = canonical_open_source_path(path);
@@ -3148,6 +3259,16 @@ pub fn run_file_with_emitter(
         })?;
     let parse_warnings = collect_lang_parse_warnings_for_run(&prepared_source);
     emit_lang_parse_warnings_for_run(&parse_warnings, emit);
+    let gaji_modules = if crate::cli::build::uses_gaji(&program_for_gate) {
+        // 산출물은 묶어 둔 가지만 쓰고, 원본 실행은 ddn.lock이 가리키는 가지를 읽는다.
+        let gaji = match artifact_gaji {
+            Some(gaji) => gaji,
+            None => crate::cli::build::project_gaji(path)?,
+        };
+        crate::cli::build::load_gaji_modules(&program_for_gate, &gaji)?
+    } else {
+        Vec::new()
+    };
     let exec_policy_extract = extract_exec_policy(&program_for_gate)?;
     for kind in extract_exec_policy_open_allow(&program_for_gate) {
         if !open_allow.iter().any(|entry| entry == &kind) {
@@ -3326,9 +3447,13 @@ pub fn run_file_with_emitter(
     let wants_live = options.bogae_live;
     let wants_geoul = options.geoul_out.is_some();
     let wants_geoul_record = options.geoul_record_out.is_some();
+    let wants_png = matches!(options.bogae_mode, Some(BogaeMode::Png));
     if wants_live && options.bogae_mode.is_none() {
         return Err("E_BOGAE_LIVE_MODE --bogae-live는 --bogae 모드가 필요합니다".to_string());
     }
+    if wants_live && wants_png {
+        return Err("E_BOGAE_LIVE_MODE --bogae-live는 png 모드를 지원하지 않습니다".to_string());
+    }
     let force_bogae = options.bogae_out.is_some() || options.bogae_mode.is_some();
     let wants_web_assets = matches!(options.bogae_mode, Some(BogaeMode::Web));
     let bogae_codec = options.bogae_codec;
@@ -3423,7 +3548,7 @@ pub fn run_file_with_emitter(
                 live_web = Some(writer);
                 web_index_path = Some(live_index_path(&out_dir));
             }
-            None => {}
+            Some(BogaeMode::Png) | None => {}
         }
         if let (Some(BogaeMode::Web), Some(index_path)) =
             (options.bogae_mode, web_index_path.as_ref())
@@ -3444,7 +3569,7 @@ pub fn run_file_with_emitter(
         None
     };
     let stop_enabled = stop_flag.is_some() || gameover_key.is_some();
-    let mut should_stop = |_: u64, state: &State| {
+    let mut should_stop = |_madi: u64, state: &State| {
         if let Some(flag) = stop_flag.as_ref() {
             if flag.load(Ordering::Relaxed) {
                 return true;
@@ -3510,9 +3635,22 @@ pub fn run_file_with_emitter(
     let cache_log = options.bogae_cache_log;
     let mut initial_state = State::new();
     apply_init_state(&mut initial_state, &options, path)?;
+    if options.deterministic_check {
+        check_run_determinism(
+            &source,
+            &gaji_modules,
+            parse_mode,
+            &initial_state,
+            ticks,
+            seed,
+            &options,
+            &file_label,
+        )?;
+    }
     let input_open_site = input_open_site_id(&open_source);
     let run_result = run_source_with_state_ticks_observe(
         &source,
+        &gaji_modules,
         parse_mode,
         initial_state,
         ticks,
@@ -3526,6 +3664,9 @@ pub fn run_file_with_emitter(
         wants_playback,
         wants_playback || wants_live || wants_geoul || wants_geoul_record,
         force_bogae,
+        options.profile.is_some(),
+        &options.run_env,
+        options.max_faults,
         &mut tick_snapshots,
         sam_plan.as_mut(),
         live_input.as_mut(),
@@ -3695,6 +3836,20 @@ pub fn run_file_with_emitter(
     if let Some(finish_error) = finish_error {
         return Err(finish_error);
     }
+    if let (Some(limit), Some(stop)) = (options.max_faults, output.fault_stop.as_ref()) {
+        emit.out(&format!(
+            "fault_stop: madi={} faults={} limit={}",
+            stop.tick_id, stop.faults, limit
+        ));
+        emit.out(&format!(
+            "fault_kinds: {}",
+            fault_kind_summary(&output.faults)
+        ));
+        return Err(format!(
+            "E_RUN_MAX_FAULTS 산술고장 {}회로 {}마디에서 실행을 멈췄습니다.",
+            stop.faults, stop.tick_id
+        ));
+    }
     if sam_used {
         clear_sam_keys(&mut output.state);
     }
@@ -3854,6 +4009,11 @@ pub fn run_file_with_emitter(
         write_trace_json(trace_path, &output.trace, &state_hash, &trace_hash)?;
     }
 
+    if let (Some(profile_path), Some(profile)) = (options.profile.as_ref(), output.profile.as_ref())
+    {
+        write_profile_json(profile_path, profile)?;
+    }
+
     if let Some(mode) = options.bogae_mode {
         if matches!(mode, BogaeMode::Console) && !options.bogae_live {
             if let Some(bogae_output) = &bogae_output {
@@ -3864,6 +4024,15 @@ pub fn run_file_with_emitter(
             }
         }
     }
+    if let Some(bogae_output) = bogae_output.as_ref().filter(|_| wants_png) {
+        let png_path = resolve_bogae_png_path(options.bogae_out.as_deref());
+        write_drawlist_png(
+            &png_path,
+            &bogae_output.drawlist,
+            options.bogae_skin.as_deref(),
+        )?;
+        emit.out(&format!("bogae_png={}", png_path.display()));
+    }
     if wants_playback {
         let out_dir = resolve_bogae_out_dir(options.bogae_out.as_deref());
         let index_path = match write_playback_outputs(
@@ -3907,8 +4076,8 @@ pub fn run_file_with_emitter(
         web_index_path = Some(index_path);
     }
     if let Some(bogae_output) = &bogae_output {
-        if wants_playback {
-            // playback output already written
+        if wants_playback || wants_png {
+            // playback/png output already written
         } else if let Some(path) = options.bogae_out.as_ref() {
             if is_bogae_out_dir(path) {
                 if wants_web_assets {
@@ -4256,6 +4425,159 @@ fn fixed64_to_json(value: Fixed64) -> JsonValue {
     json!((raw as f64) / (Fixed64::SCALE as f64))
 }
 
+/// `--deterministic-check`: 같은 초기 상태와 seed로 두 번 돌려 마디별 상태 해시 흐름을 맞춰 본다.
+/// 거울/보개/열림 기록 없이 돌리고 열림은 막는다. 어긋나면 한 번 더 돌려 처음 어긋난 키를 찾는다.
+///
+/// 재현은 같은 명령을 `--geoul-out a`, `--geoul-out b`로 두 번 실행한 뒤
+/// `teul-cli replay diff --a a --b b --out diff`로 어긋난 마디를 본다.
+#[allow(clippy::too_many_arguments)]
+fn check_run_determinism(
+    source: &str,
+    gaji: &[GajiModule],
+    parse_mode: ParseMode,
+    initial_state: &State,
+    ticks: u64,
+    seed: u64,
+    options: &RunOptions,
+    file_label: &str,
+) -> Result<(), String> {
+    if options.sam_live.is_some() {
+        return Err(
+            "E_NONDETERMINISM_CHECK_INPUT --deterministic-check는 --sam-live와 함께 쓸 수 없습니다"
+                .to_string(),
+        );
+    }
+    if ticks == u64::MAX {
+        return Err(
+            "E_NONDETERMINISM_CHECK_MADI --deterministic-check는 유한한 --madi가 필요합니다"
+                .to_string(),
+        );
+    }
+    let run = |reference: Option<&[String]>, capture_madi: Option<u64>| {
+        collect_state_hash_stream(
+            source,
+            gaji,
+            parse_mode,
+            initial_state,
+            ticks,
+            seed,
+            options,
+            file_label,
+            reference,
+            capture_madi,
+        )
+    };
+    let first = run(None, None)?;
+    let second = run(Some(&first.hashes), None)?;
+    let Some(madi) = second.diverged_madi else {
+        return Ok(());
+    };
+    let replay = run(None, Some(madi))?;
+    let key = match (replay.captured.as_ref(), second.captured.as_ref()) {
+        (Some(a), Some(b)) => first_diverging_key(a, b),
+        _ => None,
+    };
+    Err(format!(
+        "E_NONDETERMINISM madi={} key={} 같은 seed로 두 번 돌린 상태 해시가 다릅니다",
+        madi,
+        key.as_deref().unwrap_or("-")
+    ))
+}
+
+struct StateHashStream {
+    hashes: Vec<String>,
+    diverged_madi: Option<u64>,
+    captured: Option<State>,
+}
+
+/// 마디마다 상태 해시를 모은다. `reference`와 처음 달라진 마디(또는 `capture_madi`)의 상태는 복사해 둔다.
+#[allow(clippy::too_many_arguments)]
+fn collect_state_hash_stream(
+    source: &str,
+    gaji: &[GajiModule],
+    parse_mode: ParseMode,
+    initial_state: &State,
+    ticks: u64,
+    seed: u64,
+    options: &RunOptions,
+    file_label: &str,
+    reference: Option<&[String]>,
+    capture_madi: Option<u64>,
+) -> Result<StateHashStream, String> {
+    let mut sam_plan = build_sam_plan(
+        Some(ticks),
+        options.sam_path.as_deref(),
+        None,
+        options.madi_hz,
+    )?
+    .plan;
+    let mut stream = StateHashStream {
+        hashes: Vec::new(),
+        diverged_madi: None,
+        captured: None,
+    };
+    let mut snapshots = Vec::new();
+    run_source_with_state_ticks_observe(
+        source,
+        gaji,
+        parse_mode,
+        initial_state.clone(),
+        ticks,
+        seed,
+        options.latency_madi,
+        OpenRuntime::deny(),
+        "<deterministic-check>",
+        OpenMode::Deny,
+        false,
+        "",
+        false,
+        true,
+        false,
+        false,
+        &options.run_env,
+        None,
+        &mut snapshots,
+        sam_plan.as_mut(),
+        None,
+        None,
+        file_label,
+        false,
+        |_, _| false,
+        |madi, state, _| {
+            let state_hash = hash::state_hash(state);
+            if let Some(reference) = reference {
+                let same = reference.get(madi as usize) == Some(&state_hash);
+                if !same && stream.diverged_madi.is_none() {
+                    stream.diverged_madi = Some(madi);
+                    stream.captured = Some(state.clone());
+                }
+            }
+            if capture_madi == Some(madi) {
+                stream.captured = Some(state.clone());
+            }
+            stream.hashes.push(state_hash);
+            Ok(())
+        },
+    )
+    .map_err(|failure| failure.error.format(file_label))?;
+    if let Some(reference) = reference {
+        if stream.diverged_madi.is_none() && stream.hashes.len() != reference.len() {
+            stream.diverged_madi = Some(stream.hashes.len().min(reference.len()) as u64);
+        }
+    }
+    Ok(stream)
+}
+
+fn first_diverging_key(a: &State, b: &State) -> Option<String> {
+    a.resources
+        .keys()
+        .chain(b.resources.keys())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .find(|key| a.get(key).map(Value::canon) != b.get(key).map(Value::canon))
+        .map(|key| key.as_str().to_string())
+}
+
 pub fn run_source_with_state_ticks(
     source: &str,
     state: State,
@@ -4290,6 +4612,7 @@ pub fn run_source_with_state_seed_ticks(
 
 fn run_source_with_state_ticks_observe<F, G>(
     source: &str,
+    gaji: &[GajiModule],
     parse_mode: ParseMode,
     state: State,
     ticks: u64,
@@ -4303,6 +4626,9 @@ fn run_source_with_state_ticks_observe<F, G>(
     wants_snapshots: bool,
     observe_ticks: bool,
     force_bogae: bool,
+    profile: bool,
+    run_env: &BTreeMap<String, String>,
+    max_faults: Option<u64>,
     snapshots: &mut Vec<TickSnapshot>,
     sam_plan: Option<&mut SamPlan>,
     live_input: Option<&mut LiveInput>,
@@ -4337,7 +4663,25 @@ where
         open_runtime,
         open_source.to_string(),
         Some(prepared_source),
-    );
+    )
+    .with_run_env(run_env.clone());
+    let evaluator = gaji.iter().fold(evaluator, |evaluator, module| {
+        module
+            .programs
+            .iter()
+            .fold(evaluator, |evaluator, program| {
+                evaluator.with_gaji_module(&module.alias, program)
+            })
+    });
+    let evaluator = if profile {
+        evaluator.with_profile()
+    } else {
+        evaluator
+    };
+    let evaluator = match max_faults {
+        Some(limit) => evaluator.with_max_faults(limit as usize),
+        None => evaluator,
+    };
     let input_open_active = uses_input_surface
         && open_mode != OpenMode::Deny
         && (sam_plan.is_some() || live_input.is_some() || open_mode == OpenMode::Replay);
@@ -4600,6 +4944,8 @@ fn lex_line(err: &LexError) -> usize {
         LexError::BadEscape { line, .. } => *line,
         LexError::BadIdentStart { line, .. } => *line,
         LexError::UnexpectedChar { line, .. } => *line,
+        LexError::BadNumber { line, .. } => *line,
+        LexError::NumberOverflow { line, .. } => *line,
     }
 }
 
@@ -4612,6 +4958,8 @@ fn lex_col(err: &LexError) -> usize {
         LexError::BadEscape { col, .. } => *col,
         LexError::BadIdentStart { col, .. } => *col,
         LexError::UnexpectedChar { col, .. } => *col,
+        LexError::BadNumber { col, .. } => *col,
+        LexError::NumberOverflow { col, .. } => *col,
     }
 }
 
@@ -4624,6 +4972,12 @@ fn lex_message(err: &LexError) -> String {
         LexError::BadEscape { ch, .. } => format!("잘못된 이스케이프: {}", ch),
         LexError::BadIdentStart { .. } => "식별자는 숫자로 시작할 수 없습니다".to_string(),
         LexError::UnexpectedChar { ch, .. } => format!("예상치 못한 문자: {}", ch),
+        LexError::BadNumber { .. } => {
+            "수 표기가 잘못되었습니다 (`0x` 뒤 숫자, `_`는 숫자 사이에 하나씩)".to_string()
+        }
+        LexError::NumberOverflow { .. } => {
+            "수가 고정소수 정수부 범위(2^31 미만)를 넘습니다".to_string()
+        }
     }
 }
 
@@ -7288,6 +7642,27 @@ fn write_trace_json(
     fs::write(path, out).map_err(|e| e.to_string())
 }
 
+/// 씨앗 이름 순으로 호출·문장 수를 쓴다. 계수만 담으므로 같은 실행이면 바이트가 같다.
+fn write_profile_json(path: &Path, profile: &SeedProfile) -> Result<(), String> {
+    let seeds: serde_json::Map<String, JsonValue> = profile
+        .seeds
+        .iter()
+        .map(|(name, entry)| {
+            (
+                name.clone(),
+                json!({ "calls": entry.calls, "stmts": entry.stmts }),
+            )
+        })
+        .collect();
+    let doc = json!({
+        "schema": "ddn.run.profile.v1",
+        "seeds": seeds,
+        "top_level_stmts": profile.top_level_stmts,
+    });
+    let text = serde_json::to_string_pretty(&doc).map_err(|e| e.to_string())?;
+    fs::write(path, text + "\n").map_err(|e| format!("E_PROFILE_WRITE {} {}", path.display(), e))
+}
+
 fn escape_json(input: &str) -> String {
     let mut out = String::with_capacity(input.len());
     for ch in input.chars() {
@@ -7367,6 +7742,7 @@ mod tests {
             diag_report_out: None,
             repro_json: None,
             trace_json: None,
+            profile: None,
             proof_out: None,
             proof_cert_key: None,
             geoul_out: None,
@@ -7375,6 +7751,7 @@ mod tests {
             run_command: None,
             init_state: Vec::new(),
             init_state_files: Vec::new(),
+            init_state_json: Vec::new(),
             trace_tier: TraceTier::Off,
             age_target: None,
             lang_mode: None,
@@ -7388,6 +7765,7 @@ mod tests {
             console_config: ConsoleRenderConfig::default(),
             until_gameover: false,
             gameover_key: "게임끝".to_string(),
+            max_faults: None,
             sam_path: None,
             record_sam_path: None,
             sam_live: None,
@@ -7403,6 +7781,8 @@ mod tests {
             run_manifest: None,
             summary_json: None,
             artifact_pins: Vec::new(),
+            deterministic_check: false,
+            run_env: BTreeMap::new(),
         }
     }
 
@@ -7559,6 +7939,181 @@ mod tests {
             .starts_with(&["1".to_string(), "2".to_string(), "3".to_string()]));
     }
 
+    #[test]
+    fn artifact_run_uses_bundled_gaji_without_files_on_disk() {
+        let unique = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("teul_artifact_gaji_{}", unique));
+        let pkg_dir = dir.join("gaji").join("baesu");
+        fs::create_dir_all(&pkg_dir).expect("mkdir gaji");
+        fs::write(
+            pkg_dir.join("lib.ddn"),
+            "(a:수) 두배:셈씨 = {\n  b <- a * 2.\n  b 돌려줘.\n}.\n",
+        )
+        .expect("write gaji");
+        let lock = json!({
+            "packages": [{
+                "id": "나눔/배수",
+                "version": "0.1.0",
+                "hash": "blake3:test",
+                "path": "baesu",
+                "files": [{ "path": "lib.ddn" }],
+            }],
+        });
+        fs::write(dir.join("ddn.lock"), lock.to_string()).expect("write lock");
+        let entry = dir.join("main.ddn");
+        fs::write(
+            &entry,
+            "쓰임 {\n  배수: \"나눔/배수@0.1.0\".\n}.\n\n(3) 배수.두배 보여주기.\n",
+        )
+        .expect("write entry");
+        let artifact = dir.join("main.ddnc");
+        crate::cli::build::run(&entry, Some(&artifact)).expect("build artifact");
+        fs::remove_dir_all(dir.join("gaji")).expect("remove gaji");
+        fs::remove_file(dir.join("ddn.lock")).expect("remove lock");
+
+        let mut emitter = CaptureEmitter::new();
+        run_file_with_emitter(&artifact, None, 0, default_run_options(), &mut emitter)
+            .expect("run artifact");
+        let _ = fs::remove_dir_all(&dir);
+        assert_eq!(emitter.out.first().map(String::as_str), Some("6"));
+    }
+
+    #[test]
+    fn gaji_import_binds_package_seeds_under_alias_only() {
+        let unique = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("teul_gaji_alias_{}", unique));
+        let pkg_dir = dir.join("gaji").join("baesu");
+        fs::create_dir_all(&pkg_dir).expect("mkdir gaji");
+        fs::write(
+            pkg_dir.join("lib.ddn"),
+            "999 보여주기.\n\n(a:수) 합:셈씨 = {\n  a + a 돌려줘.\n}.\n\n(a:수) 두배:셈씨 = {\n  b <- (a) 합.\n  b 돌려줘.\n}.\n",
+        )
+        .expect("write gaji");
+        let lock = json!({
+            "packages": [{
+                "id": "나눔/배수",
+                "version": "0.1.0",
+                "hash": "blake3:test",
+                "path": "baesu",
+                "files": [{ "path": "lib.ddn" }],
+            }],
+        });
+        fs::write(dir.join("ddn.lock"), lock.to_string()).expect("write lock");
+        let run_entry = |name: &str, body: &str| {
+            let entry = dir.join(name);
+            fs::write(
+                &entry,
+                format!("쓰임 {{\n  배수: \"나눔/배수\".\n}}.\n\n{}", body),
+            )
+            .expect("write entry");
+            let mut emitter = CaptureEmitter::new();
+            run_file_with_emitter(&entry, None, 0, default_run_options(), &mut emitter)
+                .map(|_| emitter.out)
+        };
+
+        let out = run_entry("main.ddn", "(3) 배수.두배 보여주기.\n").expect("run");
+        let bare = run_entry("bare.ddn", "(3) 합 보여주기.\n");
+        let _ = fs::remove_dir_all(&dir);
+        assert_eq!(out.first().map(String::as_str), Some("6"));
+        assert!(!out.iter().any(|line| line == "999"), "{:?}", out);
+        assert!(
+            bare.is_err(),
+            "가지 씨앗이 별명 없이 불렸습니다: {:?}",
+            bare
+        );
+    }
+
+    #[test]
+    fn unresolved_gaji_import_is_rejected() {
+        let unique = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("teul_gaji_unresolved_{}", unique));
+        fs::create_dir_all(&dir).expect("mkdir");
+        fs::write(dir.join("ddn.lock"), json!({ "packages": [] }).to_string()).expect("write lock");
+        let entry = dir.join("main.ddn");
+        fs::write(
+            &entry,
+            "쓰임 {\n  셈틀: \"나눔/셈틀\".\n}.\n\n(3) 셈틀.두배 보여주기.\n",
+        )
+        .expect("write entry");
+
+        let mut emitter = CaptureEmitter::new();
+        let run = run_file_with_emitter(&entry, None, 0, default_run_options(), &mut emitter);
+        let build = crate::cli::build::build_artifact(&entry);
+        let _ = fs::remove_dir_all(&dir);
+        let err = run.expect_err("unresolved import must fail");
+        assert!(err.starts_with("E_IMPORT_UNRESOLVED 셈틀"), "{}", err);
+        let err = build.expect_err("unresolved import must fail build");
+        assert!(err.starts_with("E_IMPORT_UNRESOLVED 셈틀"), "{}", err);
+    }
+
+    #[test]
+    fn deterministic_check_passes_for_deterministic_program() {
+        let source = r#"
+설정 {
+  마디수: 5.
+}.
+
+채비 {
+  t: 수 <- 0.
+  눈: 수 <- 0.
+}.
+
+(매마디)마다 {
+  t <- t + 1.
+  눈 <- (1, 6) 무작위정수.
+}.
+"#;
+        let path = write_temp_ddn("deterministic_check", source);
+        let mut options = default_run_options();
+        options.deterministic_check = true;
+        let mut emitter = CaptureEmitter::new();
+        run_file_with_emitter(&path, None, 7, options, &mut emitter)
+            .expect("deterministic program passes");
+        let _ = fs::remove_file(path);
+        assert!(emitter
+            .out
+            .iter()
+            .any(|line| line.starts_with("state_hash=")));
+
+        let mut a = State::new();
+        let mut b = State::new();
+        a.set(Key::new("t".to_string()), Value::Str("1".to_string()));
+        b.set(Key::new("t".to_string()), Value::Str("1".to_string()));
+        a.set(Key::new("눈".to_string()), Value::Str("2".to_string()));
+        b.set(Key::new("눈".to_string()), Value::Str("5".to_string()));
+        assert_eq!(first_diverging_key(&a, &b).as_deref(), Some("눈"));
+        assert_eq!(first_diverging_key(&a, &a), None);
+    }
+
+    #[test]
+    fn run_env_values_are_readable_through_hwangyeong() {
+        let source = r#"
+난이도 <- ("난이도") 환경.
+난이도 보여주기.
+("없는키") 환경 보여주기.
+"#;
+        let path = write_temp_ddn("run_env", source);
+        let mut options = default_run_options();
+        options.run_env = parse_run_env(&["난이도=3".to_string()]).expect("parse env");
+        let mut emitter = CaptureEmitter::new();
+        run_file_with_emitter(&path, None, 0, options, &mut emitter).expect("run with env");
+        let _ = fs::remove_file(path);
+        assert!(emitter
+            .out
+            .starts_with(&["3".to_string(), "없음".to_string()]));
+        assert!(parse_run_env(&["a=1".to_string(), "a=2".to_string()]).is_err());
+        assert!(parse_run_env(&["값만".to_string()]).is_err());
+    }
+
     #[test]
     fn setting_madi_bad_value_rejects_run() {
         let source = r#"
@@ -9679,6 +10234,9 @@ mod tests {
             diagnostics: Vec::new(),
             diagnostic_failures: Vec::new(),
             proof_runtime: Vec::new(),
+            profile: None,
+            faults: Vec::new(),
+            fault_stop: None,
         };
         let doc = build_proof_detjson(
             "sample.ddn",
@@ -9722,6 +10280,9 @@ mod tests {
             diagnostics: Vec::new(),
             diagnostic_failures: Vec::new(),
             proof_runtime: Vec::new(),
+            profile: None,
+            faults: Vec::new(),
+            fault_stop: None,
         };
         let doc = build_proof_detjson(
             "sample_abort.ddn",
@@ -9768,6 +10329,9 @@ y 가 정수 중 딱 하나가 {
             diagnostics: Vec::new(),
             diagnostic_failures: Vec::new(),
             proof_runtime: Vec::new(),
+            profile: None,
+            faults: Vec::new(),
+            fault_stop: None,
         };
         let doc = build_proof_detjson(
             "proof_surface.ddn",
@@ -9838,6 +10402,9 @@ y 가 정수 중 딱 하나가 {
             diagnostics: Vec::new(),
             diagnostic_failures: Vec::new(),
             proof_runtime: Vec::new(),
+            profile: None,
+            faults: Vec::new(),
+            fault_stop: None,
         };
         let doc = build_proof_detjson(
             "proof_solver.ddn",
@@ -9890,6 +10457,9 @@ y 가 정수 중 딱 하나가 {
             diagnostics: Vec::new(),
             diagnostic_failures: Vec::new(),
             proof_runtime: Vec::new(),
+            profile: None,
+            faults: Vec::new(),
+            fault_stop: None,
         };
         let doc = build_proof_detjson(
             "proof_case_solver.ddn",
@@ -9953,6 +10523,9 @@ y 가 정수 중 딱 하나가 {
             diagnostics: Vec::new(),
             diagnostic_failures: Vec::new(),
             proof_runtime: Vec::new(),
+            profile: None,
+            faults: Vec::new(),
+            fault_stop: None,
         };
         let doc = build_proof_detjson(
             "proof_case_solver_search.ddn",
@@ -10025,6 +10598,9 @@ y 가 정수 중 딱 하나가 {
             diagnostics: Vec::new(),
             diagnostic_failures: Vec::new(),
             proof_runtime: Vec::new(),
+            profile: None,
+            faults: Vec::new(),
+            fault_stop: None,
         };
         let doc = build_proof_detjson(
             "proof_case_solver_open_search.ddn",
@@ -10096,6 +10672,9 @@ y 가 정수 중 딱 하나가 {
             diagnostics: Vec::new(),
             diagnostic_failures: Vec::new(),
             proof_runtime: Vec::new(),
+            profile: None,
+            faults: Vec::new(),
+            fault_stop: None,
         };
         let doc = build_proof_detjson(
             "proof_case_exists_solver_open_search.ddn",
@@ -10167,6 +10746,9 @@ y 가 정수 중 딱 하나가 {
             diagnostics: Vec::new(),
             diagnostic_failures: Vec::new(),
             proof_runtime: Vec::new(),
+            profile: None,
+            faults: Vec::new(),
+            fault_stop: None,
         };
         let doc = build_proof_detjson(
             "proof_case_forall_solver_open_search.ddn",
@@ -10240,6 +10822,9 @@ y 가 정수 중 딱 하나가 {
             diagnostics: Vec::new(),
             diagnostic_failures: Vec::new(),
             proof_runtime: Vec::new(),
+            profile: None,
+            faults: Vec::new(),
+            fault_stop: None,
         };
         let doc = build_proof_detjson(
             "proof_case_else_solver_open_search.ddn",
@@ -10299,6 +10884,9 @@ y 가 정수 중 딱 하나가 {
             diagnostics: Vec::new(),
             diagnostic_failures: Vec::new(),
             proof_runtime: Vec::new(),
+            profile: None,
+            faults: Vec::new(),
+            fault_stop: None,
         };
         let doc = build_proof_detjson(
             "proof_solver_search.ddn",
@@ -10346,6 +10934,9 @@ y 가 정수 중 딱 하나가 {
             diagnostics: Vec::new(),
             diagnostic_failures: Vec::new(),
             proof_runtime: Vec::new(),
+            profile: None,
+            faults: Vec::new(),
+            fault_stop: None,
         };
         let doc = build_proof_detjson(
             "proof_immediate.ddn",
@@ -10421,6 +11012,9 @@ y 가 정수 중 딱 하나가 {
                     span: Span::new(12, 3, 12, 30),
                 },
             ],
+            profile: None,
+            faults: Vec::new(),
+            fault_stop: None,
         };
         let doc = build_proof_detjson(
             "proof_assertion_check.ddn",
@@ -10515,6 +11109,9 @@ y 가 정수 중 딱 하나가 {
                     span: Span::new(11, 3, 11, 21),
                 },
             ],
+            profile: None,
+            faults: Vec::new(),
+            fault_stop: None,
         };
         let doc = build_proof_detjson(
             "proof_assertion_check_solve.ddn",
@@ -10609,6 +11206,9 @@ y 가 정수 중 딱 하나가 {
                     span: Span::new(7, 3, 7, 20),
                 },
             ],
+            profile: None,
+            faults: Vec::new(),
+            fault_stop: None,
         };
         let doc = build_proof_detjson(
             "proof_assertion_check_case.ddn",
@@ -10701,6 +11301,9 @@ y 가 정수 중 딱 하나가 {
                     span: Span::new(13, 5, 13, 32),
                 },
             ],
+            profile: None,
+            faults: Vec::new(),
+            fault_stop: None,
         };
         let doc = build_proof_detjson(
             "proof_assertion_check_case_solver_open.ddn",
@@ -10794,6 +11397,9 @@ y 가 정수 중 딱 하나가 {
                     span: Span::new(13, 5, 13, 35),
                 },
             ],
+            profile: None,
+            faults: Vec::new(),
+            fault_stop: None,
         };
         let doc = build_proof_detjson(
             "proof_assertion_check_case_solver_search.ddn",
@@ -10896,6 +11502,9 @@ y 가 정수 중 딱 하나가 {
                     span: Span::new(13, 5, 13, 25),
                 },
             ],
+            profile: None,
+            faults: Vec::new(),
+            fault_stop: None,
         };
         let doc = build_proof_detjson(
             "proof_assertion_check_case_solver_search_solve.ddn",
@@ -11020,6 +11629,9 @@ y 가 정수 중 딱 하나가 {
                     error_code: None,
                 },
             ],
+            profile: None,
+            faults: Vec::new(),
+            fault_stop: None,
         };
         let doc = build_proof_detjson(
             "proof_assertion_check_case_solver_open_search.ddn",
@@ -11150,6 +11762,9 @@ y 가 정수 중 딱 하나가 {
                     error_code: None,
                 },
             ],
+            profile: None,
+            faults: Vec::new(),
+            fault_stop: None,
         };
         let doc = build_proof_detjson(
             "proof_assertion_check_case_else_solver_open_search.ddn",
@@ -11240,6 +11855,9 @@ y 가 정수 중 딱 하나가 {
                     error_code: Some("E_OPEN_DENIED".to_string()),
                 },
             ],
+            profile: None,
+            faults: Vec::new(),
+            fault_stop: None,
         };
         let doc = build_proof_detjson(
             "proof_runtime_fail.ddn",
@@ -11298,6 +11916,9 @@ y 가 정수 중 딱 하나가 {
                     error_code: Some("E_OPEN_REPLAY_MISS".to_string()),
                 },
             ],
+            profile: None,
+            faults: Vec::new(),
+            fault_stop: None,
         };
         let doc = build_proof_detjson(
             "proof_runtime_fail_state.ddn",
@@ -11331,6 +11952,9 @@ y 가 정수 중 딱 하나가 {
             diagnostics: Vec::new(),
             diagnostic_failures: Vec::new(),
             proof_runtime: Vec::new(),
+            profile: None,
+            faults: Vec::new(),
+            fault_stop: None,
         };
         let clean_doc = build_proof_detjson(
             "proof_clean.ddn",
@@ -11385,6 +12009,9 @@ y 가 정수 중 딱 하나가 {
             diagnostics: Vec::new(),
             diagnostic_failures: Vec::new(),
             proof_runtime: Vec::new(),
+            profile: None,
+            faults: Vec::new(),
+            fault_stop: None,
         };
         let abort_doc = build_proof_detjson(
             "proof_abort.ddn",
@@ -11592,6 +12219,85 @@ y 가 정수 중 딱 하나가 {
         let _ = fs::remove_dir(&dir);
     }
 
+    #[test]
+    fn run_file_loads_build_artifact_with_same_output() {
+        let nonce = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("teul_build_run_{nonce}"));
+        fs::create_dir_all(&dir).expect("mkdir");
+        let input_path = dir.join("main.ddn");
+        let artifact_path = dir.join("main.ddnc");
+        fs::write(
+            &input_path,
+            "x <- 1 + 2.\ny <- x * 4.\ny 보여주기.\n(x < y) 일때 {\n  \"작다\" 보여주기.\n}.\n",
+        )
+        .expect("write source");
+        crate::cli::build::run(&input_path, Some(&artifact_path)).expect("build");
+
+        let run_once = |path: &Path| {
+            let mut emitter = CaptureEmitter::new();
+            run_file_with_emitter(
+                path,
+                Some(MadiLimit::Finite(1)),
+                0,
+                default_run_options(),
+                &mut emitter,
+            )
+            .expect("run");
+            // trace_hash는 실행한 원문(정본)까지 묶으므로 비교에서 뺀다.
+            emitter
+                .out
+                .into_iter()
+                .filter(|line| !line.starts_with("trace_hash="))
+                .collect::<Vec<_>>()
+        };
+        let from_source = run_once(&input_path);
+        let from_artifact = run_once(&artifact_path);
+        assert!(from_source.iter().any(|line| line == "작다"));
+        assert_eq!(from_source, from_artifact);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn run_file_profile_counts_seed_invocations() {
+        let nonce = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("teul_run_profile_{nonce}"));
+        fs::create_dir_all(&dir).expect("mkdir");
+        let input_path = dir.join("main.ddn");
+        let profile_path = dir.join("profile.json");
+        fs::write(
+            &input_path,
+            "(a:수, b:수) 더하:셈씨 = {\n  c <- a + b.\n  c 돌려줘.\n}.\n\
+             합 <- 0.\n목록 <- [1, 2, 3, 4, 5, 6, 7, 8, 9, 10].\n\
+             (x) 목록에 대해 {\n  합 <- (합, x) 더하기.\n}.\n합 보여주기.\n",
+        )
+        .expect("write source");
+        let mut options = default_run_options();
+        options.profile = Some(profile_path.clone());
+        let mut emitter = CaptureEmitter::new();
+        run_file_with_emitter(
+            &input_path,
+            Some(MadiLimit::Finite(1)),
+            0,
+            options,
+            &mut emitter,
+        )
+        .expect("run");
+        assert!(emitter.out.iter().any(|line| line == "55"));
+
+        let text = fs::read_to_string(&profile_path).expect("read profile");
+        let doc: JsonValue = serde_json::from_str(&text).expect("profile json");
+        assert_eq!(doc["schema"], "ddn.run.profile.v1");
+        assert_eq!(doc["seeds"]["더하"]["calls"], 10);
+        assert_eq!(doc["seeds"]["더하"]["stmts"], 20);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
     #[test]
     fn run_file_writes_signed_proof_certificate_v1_bundle_abort_profile() {
         let mut dir = std::env::temp_dir();
@@ -12057,4 +12763,42 @@ x <- () 너머.시각.지금.
         assert_eq!(code, "E_FRONTDOOR");
         assert_eq!(detail, "E_FRONTDOOR_CUSTOM detail=custom");
     }
+    #[test]
+    fn state_json_nested_matches_state_flags() {
+        let source = Path::new("main.ddn");
+        let mut flags = default_run_options();
+        flags.init_state = vec![
+            "player.hp=10".to_string(),
+            "player.name=\"돌이\"".to_string(),
+            "살림.score=3".to_string(),
+        ];
+        let mut by_flags = State::new();
+        apply_init_state(&mut by_flags, &flags, source).expect("flags");
+
+        let mut inline = default_run_options();
+        inline.init_state_json = vec![
+            r#"{"player":{"hp":10,"name":"돌이"},"살림.score":1}"#.to_string(),
+            r#"{"score":3}"#.to_string(),
+        ];
+        let mut by_json = State::new();
+        apply_init_state(&mut by_json, &inline, source).expect("json");
+
+        assert_eq!(
+            format!("{:?}", by_flags.resources),
+            format!("{:?}", by_json.resources)
+        );
+    }
+
+    #[test]
+    fn state_json_reports_parse_position() {
+        let mut options = default_run_options();
+        options.init_state_json = vec!["{\"hp\": }".to_string()];
+        let err = apply_init_state(&mut State::new(), &options, Path::new("main.ddn"))
+            .expect_err("malformed");
+        assert!(
+            err.starts_with("E_CLI_STATE_JSON line=1 column=8"),
+            "{}",
+            err
+        );
+    }
 }
@@ -1,54 +1,198 @@
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use ddonirang_core::seulgi::safety::{check, SafetyMode, SafetyRule};
+use ddonirang_core::seulgi::safety::{check, format_rule_path, SafetyMode, SafetyRule};
 use serde_json::Value;
 
 use super::detjson::write_text;
 
-pub fn run_check(rules: &Path, intent: &Path, out: Option<&Path>) -> Result<(), String> {
-    let rule_text = fs::read_to_string(rules).map_err(|e| e.to_string())?;
+pub fn run_check(
+    rules: &[PathBuf],
+    intent: &Path,
+    explain: bool,
+    out: Option<&Path>,
+) -> Result<(), String> {
+    let detjson = check_text(rules, intent, explain)?;
+    if let Some(path) = out {
+        write_text(path, &detjson)?;
+    } else {
+        println!("{}", detjson);
+    }
+
+    Ok(())
+}
+
+/// 규칙 파일 하나에서 읽은 최상위 규칙. `id`가 없으면 다른 규칙을 덮어쓰지 않는다.
+struct NamedRule {
+    id: Option<String>,
+    source: String,
+    rule: SafetyRule,
+}
+
+/// 같은 `id`의 규칙이 뒤 파일에 다시 나와 앞 규칙을 대체한 기록.
+#[derive(Debug, PartialEq, Eq)]
+struct RuleOverride {
+    id: String,
+    from: String,
+    to: String,
+}
+
+/// `--rules`를 여러 번 주면 주어진 순서대로 합친다.
+/// 충돌 정책: 같은 `id`는 뒤 파일이 이기고, 자리는 처음 나온 위치를 유지한다.
+/// 합친 규칙이 둘 이상이면 `all_of`로 묶어 모두 허용해야 통과한다.
+fn merge_rule_files(paths: &[PathBuf]) -> Result<(SafetyRule, Vec<RuleOverride>), String> {
+    let mut merged: Vec<NamedRule> = Vec::new();
+    let mut overrides = Vec::new();
+    for path in paths {
+        let text = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        let value: Value = serde_json::from_str(&text).map_err(|e| e.to_string())?;
+        let id = match value.get("id") {
+            None => None,
+            Some(Value::String(id)) => Some(id.clone()),
+            Some(_) => return Err(format!("E_SAFETY_RULE id 형식 오류: {}", path.display())),
+        };
+        let incoming = NamedRule {
+            id,
+            source: path.display().to_string(),
+            rule: parse_rule(&value)?,
+        };
+        let existing = incoming.id.as_ref().and_then(|id| {
+            merged
+                .iter_mut()
+                .find(|named| named.id.as_ref() == Some(id))
+        });
+        match existing {
+            Some(slot) => {
+                overrides.push(RuleOverride {
+                    id: incoming.id.clone().unwrap_or_default(),
+                    from: slot.source.clone(),
+                    to: incoming.source.clone(),
+                });
+                *slot = incoming;
+            }
+            None => merged.push(incoming),
+        }
+    }
+    let mut rules: Vec<SafetyRule> = merged.into_iter().map(|named| named.rule).collect();
+    let rule = match rules.len() {
+        0 => return Err("E_SAFETY_RULE 규칙 파일 없음".to_string()),
+        1 => rules.remove(0),
+        _ => SafetyRule {
+            mode: SafetyMode::AllOf,
+            intents: Vec::new(),
+            children: rules,
+        },
+    };
+    Ok((rule, overrides))
+}
+
+fn check_text(rules: &[PathBuf], intent: &Path, explain: bool) -> Result<String, String> {
     let intent_text = fs::read_to_string(intent).map_err(|e| e.to_string())?;
-    let rule_value: Value = serde_json::from_str(&rule_text).map_err(|e| e.to_string())?;
     let intent_value: Value = serde_json::from_str(&intent_text).map_err(|e| e.to_string())?;
 
-    let mode_str = rule_value
+    let (mut rule, overrides) = merge_rule_files(rules)?;
+    if explain && rule.mode != SafetyMode::Explain {
+        rule = SafetyRule::explain(rule);
+    }
+    let kind = intent_value
+        .get("kind")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| "E_SAFETY_INTENT kind 없음".to_string())?;
+
+    let decision = check(&rule, kind);
+    let mut detjson = format!(
+        "{{\"schema\":\"seulgi.safety_check.v1\",\"allowed\":{},\"reason\":\"{}\"",
+        if decision.allowed { "true" } else { "false" },
+        escape_json(&decision.reason)
+    );
+    if rule.mode == SafetyMode::Explain {
+        detjson.push_str(",\"rule_path\":");
+        match &decision.rule_path {
+            Some(path) => {
+                detjson.push('"');
+                detjson.push_str(&format_rule_path(path));
+                detjson.push('"');
+            }
+            None => detjson.push_str("null"),
+        }
+        detjson.push_str(",\"explanation\":\"");
+        detjson.push_str(&escape_json(
+            decision.explanation.as_deref().unwrap_or_default(),
+        ));
+        detjson.push_str("\",\"trace\":[");
+        for (idx, entry) in decision.trace.iter().enumerate() {
+            if idx > 0 {
+                detjson.push(',');
+            }
+            detjson.push_str(&format!(
+                "{{\"rule_path\":\"{}\",\"mode\":\"{}\",\"allowed\":{},\"reason\":\"{}\"}}",
+                format_rule_path(&entry.rule_path),
+                entry.mode.as_str(),
+                if entry.allowed { "true" } else { "false" },
+                escape_json(&entry.reason)
+            ));
+        }
+        detjson.push(']');
+    }
+    if rules.len() > 1 {
+        detjson.push_str(",\"overrides\":[");
+        for (idx, item) in overrides.iter().enumerate() {
+            if idx > 0 {
+                detjson.push(',');
+            }
+            detjson.push_str(&format!(
+                "{{\"id\":\"{}\",\"from\":\"{}\",\"to\":\"{}\"}}",
+                escape_json(&item.id),
+                escape_json(&item.from),
+                escape_json(&item.to)
+            ));
+        }
+        detjson.push(']');
+    }
+    detjson.push('}');
+    Ok(detjson)
+}
+
+fn parse_rule(value: &Value) -> Result<SafetyRule, String> {
+    let mode_str = value
         .get("mode")
         .and_then(|v| v.as_str())
         .ok_or_else(|| "E_SAFETY_RULE mode 없음".to_string())?;
     let mode = match mode_str {
         "allowlist" => SafetyMode::AllowList,
         "denylist" => SafetyMode::DenyList,
+        "all_of" => SafetyMode::AllOf,
+        "any_of" => SafetyMode::AnyOf,
+        "explain" => {
+            let inner = value
+                .get("rule")
+                .ok_or_else(|| "E_SAFETY_RULE rule 없음: explain".to_string())?;
+            return Ok(SafetyRule::explain(parse_rule(inner)?));
+        }
         other => return Err(format!("E_SAFETY_RULE mode 오류: {}", other)),
     };
-    let intents = match rule_value.get("intents") {
+    let intents = match value.get("intents") {
         Some(Value::Array(items)) => items
             .iter()
             .filter_map(|v| v.as_str().map(|s| s.to_string()))
             .collect(),
         _ => Vec::new(),
     };
-
-    let rule = SafetyRule { mode, intents };
-    let kind = intent_value
-        .get("kind")
-        .and_then(|v| v.as_str())
-        .ok_or_else(|| "E_SAFETY_INTENT kind 없음".to_string())?;
-
-    let decision = check(&rule, kind);
-    let detjson = format!(
-        "{{\"schema\":\"seulgi.safety_check.v1\",\"allowed\":{},\"reason\":\"{}\"}}",
-        if decision.allowed { "true" } else { "false" },
-        escape_json(&decision.reason)
-    );
-
-    if let Some(path) = out {
-        write_text(path, &detjson)?;
-    } else {
-        println!("{}", detjson);
-    }
-
-    Ok(())
+    let children = match (&mode, value.get("rules")) {
+        (SafetyMode::AllOf | SafetyMode::AnyOf, Some(Value::Array(items))) => items
+            .iter()
+            .map(parse_rule)
+            .collect::<Result<Vec<_>, _>>()?,
+        (SafetyMode::AllOf | SafetyMode::AnyOf, _) => {
+            return Err(format!("E_SAFETY_RULE rules 없음: {}", mode_str))
+        }
+        _ => Vec::new(),
+    };
+    Ok(SafetyRule {
+        mode,
+        intents,
+        children,
+    })
 }
 
 fn escape_json(input: &str) -> String {
@@ -65,3 +209,55 @@ fn escape_json(input: &str) -> String {
     }
     out
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use super::{check_text, merge_rule_files, RuleOverride};
+    use ddonirang_core::seulgi::safety::SafetyMode;
+
+    #[test]
+    fn later_rule_file_overrides_same_id_and_reports_it() {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("clock")
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("safety_merge_{nanos}"));
+        fs::create_dir_all(&dir).expect("mkdir");
+        let base = dir.join("base.json");
+        let extra = dir.join("extra.json");
+        let intent = dir.join("intent.json");
+        fs::write(
+            &base,
+            r#"{"id":"talk","mode":"allowlist","intents":["Say"]}"#,
+        )
+        .expect("write base");
+        fs::write(
+            &extra,
+            r#"{"id":"talk","mode":"denylist","intents":["Say"]}"#,
+        )
+        .expect("write extra");
+        fs::write(&intent, r#"{"kind":"Say"}"#).expect("write intent");
+        let paths: Vec<PathBuf> = vec![base.clone(), extra.clone()];
+
+        let (rule, overrides) = merge_rule_files(&paths).expect("merge");
+        assert_eq!(rule.mode, SafetyMode::DenyList);
+        assert_eq!(
+            overrides,
+            vec![RuleOverride {
+                id: "talk".to_string(),
+                from: base.display().to_string(),
+                to: extra.display().to_string(),
+            }]
+        );
+
+        let text = check_text(&paths, &intent, false).expect("check");
+        let value: serde_json::Value = serde_json::from_str(&text).expect("json");
+        assert_eq!(value["allowed"], false);
+        assert_eq!(value["overrides"][0]["id"], "talk");
+        let _ = fs::remove_dir_all(dir);
+    }
+}
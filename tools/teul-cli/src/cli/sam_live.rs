@@ -235,6 +235,29 @@ fn update_held_mask(held: &AtomicU16, bit: u16, pressed: bool) {
     }
 }
 
+/// 수신 소켓이 끊겼을 때 다시 묶는 최대 횟수와 첫 대기 시간. 대기는 매번 두 배로 는다.
+const RECONNECT_MAX_ATTEMPTS: u32 = 5;
+const RECONNECT_BASE_DELAY: Duration = Duration::from_millis(50);
+
+trait LiveListener {
+    fn accept_stream(&self) -> io::Result<TcpStream>;
+}
+
+impl LiveListener for TcpListener {
+    fn accept_stream(&self) -> io::Result<TcpStream> {
+        self.accept().map(|(stream, _)| stream)
+    }
+}
+
+fn bind_web_listener(addr: &str) -> Result<TcpListener, String> {
+    let listener =
+        TcpListener::bind(addr).map_err(|e| format!("E_SAM_LIVE_BIND {} {}", addr, e))?;
+    listener
+        .set_nonblocking(true)
+        .map_err(|e| format!("E_SAM_LIVE_NONBLOCK {}", e))?;
+    Ok(listener)
+}
+
 fn start_web_input_server(
     host: String,
     port: u16,
@@ -242,28 +265,78 @@ fn start_web_input_server(
     stop: Arc<AtomicBool>,
 ) -> Result<JoinHandle<()>, String> {
     let addr = format!("{}:{}", host, port);
-    let listener =
-        TcpListener::bind(&addr).map_err(|e| format!("E_SAM_LIVE_BIND {} {}", addr, e))?;
-    listener
-        .set_nonblocking(true)
-        .map_err(|e| format!("E_SAM_LIVE_NONBLOCK {}", e))?;
+    let listener = bind_web_listener(&addr)?;
     let handle = thread::spawn(move || {
-        let poll_wait = Duration::from_millis(10);
-        while !stop.load(Ordering::Relaxed) {
-            match listener.accept() {
-                Ok((mut stream, _)) => {
-                    let _ = handle_web_connection(&mut stream, &held);
-                }
-                Err(err) if err.kind() == io::ErrorKind::WouldBlock => {
-                    thread::sleep(poll_wait);
-                }
-                Err(_) => break,
-            }
-        }
+        serve_web_input(listener, || bind_web_listener(&addr), &held, &stop);
     });
     Ok(handle)
 }
 
+/// 수신 소켓 오류로 멈추지 않고 `rebind`로 다시 연다. 눌린 키 상태는 그대로 두므로
+/// 다시 붙은 뒤에도 현재 마디부터 입력이 이어진다.
+fn serve_web_input<L, B>(listener: L, mut rebind: B, held: &AtomicU16, stop: &AtomicBool)
+where
+    L: LiveListener,
+    B: FnMut() -> Result<L, String>,
+{
+    let poll_wait = Duration::from_millis(10);
+    let mut listener = Some(listener);
+    while !stop.load(Ordering::Relaxed) {
+        let Some(current) = listener.as_ref() else {
+            break;
+        };
+        match current.accept_stream() {
+            Ok(mut stream) => {
+                let _ = handle_web_connection(&mut stream, held);
+            }
+            Err(err) if err.kind() == io::ErrorKind::WouldBlock => {
+                thread::sleep(poll_wait);
+            }
+            Err(err) => {
+                eprintln!("sam_live_disconnected {}", err);
+                // 같은 주소를 다시 묶어야 하므로 이전 소켓을 먼저 닫는다.
+                drop(listener.take());
+                listener = reconnect_with_backoff(&mut rebind, stop);
+            }
+        }
+    }
+}
+
+fn reconnect_with_backoff<L, B>(rebind: &mut B, stop: &AtomicBool) -> Option<L>
+where
+    B: FnMut() -> Result<L, String>,
+{
+    let mut delay = RECONNECT_BASE_DELAY;
+    for attempt in 1..=RECONNECT_MAX_ATTEMPTS {
+        if stop.load(Ordering::Relaxed) {
+            return None;
+        }
+        thread::sleep(delay);
+        match rebind() {
+            Ok(listener) => {
+                eprintln!(
+                    "sam_live_reconnect attempt={} delay_ms={} ok",
+                    attempt,
+                    delay.as_millis()
+                );
+                return Some(listener);
+            }
+            Err(err) => eprintln!(
+                "sam_live_reconnect attempt={} delay_ms={} {}",
+                attempt,
+                delay.as_millis(),
+                err
+            ),
+        }
+        delay = delay.saturating_mul(2);
+    }
+    eprintln!(
+        "E_SAM_LIVE_RECONNECT {}회 재연결 실패, 실시간 입력을 멈춥니다",
+        RECONNECT_MAX_ATTEMPTS
+    );
+    None
+}
+
 fn handle_web_connection(stream: &mut TcpStream, held: &AtomicU16) -> Result<(), String> {
     stream
         .set_read_timeout(Some(Duration::from_millis(200)))
@@ -465,4 +538,69 @@ mod tests {
 
         input.finish().expect("finish live input");
     }
+
+    struct FlakyListener {
+        inner: TcpListener,
+        fail_once: Arc<AtomicBool>,
+    }
+
+    impl LiveListener for FlakyListener {
+        fn accept_stream(&self) -> io::Result<TcpStream> {
+            if self.fail_once.swap(false, Ordering::SeqCst) {
+                return Err(io::Error::new(io::ErrorKind::ConnectionAborted, "dropped"));
+            }
+            self.inner.accept_stream()
+        }
+    }
+
+    #[test]
+    fn web_input_server_rebinds_after_listener_drops() {
+        let port = free_port();
+        let addr = format!("127.0.0.1:{port}");
+        let fail_once = Arc::new(AtomicBool::new(true));
+        let listener = FlakyListener {
+            inner: bind_web_listener(&addr).expect("bind"),
+            fail_once: fail_once.clone(),
+        };
+        let held = Arc::new(AtomicU16::new(0));
+        let stop = Arc::new(AtomicBool::new(false));
+        let rebinds = Arc::new(AtomicU16::new(0));
+        let handle = {
+            let (held, stop, rebinds) = (held.clone(), stop.clone(), rebinds.clone());
+            thread::spawn(move || {
+                let rebind = || {
+                    rebinds.fetch_add(1, Ordering::SeqCst);
+                    bind_web_listener(&addr).map(|inner| FlakyListener {
+                        inner,
+                        fail_once: fail_once.clone(),
+                    })
+                };
+                serve_web_input(listener, rebind, &held, &stop);
+            })
+        };
+
+        let request = "GET /input?code=ArrowLeft&kind=down HTTP/1.1\r\nHost: 127.0.0.1\r\n\r\n";
+        let send = || -> Option<String> {
+            let mut stream = TcpStream::connect(("127.0.0.1", port)).ok()?;
+            stream.write_all(request.as_bytes()).ok()?;
+            let mut response = String::new();
+            stream.read_to_string(&mut response).ok()?;
+            (!response.is_empty()).then_some(response)
+        };
+        // 끊긴 소켓에 걸린 첫 요청은 버려지므로 다시 묶일 때까지 되풀이한다.
+        let response = (0..100)
+            .find_map(|_| {
+                send().or_else(|| {
+                    thread::sleep(Duration::from_millis(20));
+                    None
+                })
+            })
+            .expect("request after reconnect");
+        assert!(response.starts_with("HTTP/1.1 200 OK"), "{response}");
+        assert_eq!(held.load(Ordering::SeqCst), 0b0000_0001);
+        assert_eq!(rebinds.load(Ordering::SeqCst), 1);
+
+        stop.store(true, Ordering::SeqCst);
+        handle.join().expect("join");
+    }
 }
@@ -37,14 +37,16 @@ pub fn snapshot_from_held_mask(
     }
 }
 
+/// 입력 스냅샷이 매 마디 덮어쓰는 키인지.
+pub fn is_sam_key(name: &str) -> bool {
+    name.starts_with("샘.") || name.starts_with("입력상태.")
+}
+
 fn clear_sam_keys(state: &mut State) {
     let keys = state
         .resources
         .keys()
-        .filter(|key| {
-            let name = key.as_str();
-            name.starts_with("샘.") || name.starts_with("입력상태.")
-        })
+        .filter(|key| is_sam_key(key.as_str()))
         .cloned()
         .collect::<Vec<_>>();
     for key in keys {
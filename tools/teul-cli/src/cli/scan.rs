@@ -1,5 +1,15 @@
+use std::collections::BTreeSet;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+use ddonirang_lang::stdlib::canonicalize_stdlib_alias;
+use serde_json::{json, Value};
+
+use crate::cli::frontdoor_parse::{parse_program_for_runtime, FrontdoorParseFailure};
+use crate::cli::run::RunError;
+use crate::lang::ast::{Binding, Expr, Program, Stmt};
+use crate::runtime::eval::CALL_TAILS;
+use crate::runtime::Evaluator;
 
 pub fn run(root: &Path) -> Result<(), String> {
     let gaji_root = root.join("gaji");
@@ -40,6 +50,315 @@ pub fn run(root: &Path) -> Result<(), String> {
     Ok(())
 }
 
+/// `scan --graph`: `.ddn` 파일마다 씨가 부르는 씨를 모아 호출 그래프 JSON으로 쓴다.
+pub fn run_graph(root: &Path, out: &Path) -> Result<(), String> {
+    let files = collect_ddn_files(root)?;
+    let mut entries = Vec::new();
+    let mut edge_count = 0usize;
+    let mut unresolved_count = 0usize;
+    for path in &files {
+        let rel = path
+            .strip_prefix(root)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        let source = fs::read_to_string(path).map_err(|e| format!("E_SCAN_READ {}", e))?;
+        let graph = match build_call_graph(&source, &rel) {
+            Ok(graph) => graph,
+            Err(err) => {
+                print_warn("W_SCAN_GRAPH_PARSE", &format!("{} {}", rel, err));
+                entries.push(json!({ "file": rel, "error": err }));
+                continue;
+            }
+        };
+        edge_count += graph.edges.len();
+        unresolved_count += graph.unresolved.len();
+        entries.push(graph.to_json(&rel));
+    }
+    let report = json!({
+        "schema": "ddn.scan.call_graph.v1",
+        "files": entries,
+    });
+    let text = serde_json::to_string_pretty(&report).map_err(|e| format!("E_SCAN_GRAPH {}", e))?;
+    if let Some(parent) = out.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        fs::create_dir_all(parent).map_err(|e| format!("E_SCAN_GRAPH_WRITE {}", e))?;
+    }
+    fs::write(out, text + "\n")
+        .map_err(|e| format!("E_SCAN_GRAPH_WRITE {} {}", out.display(), e))?;
+    println!("scan_graph_files={}", files.len());
+    println!("scan_graph_edges={}", edge_count);
+    println!("scan_graph_unresolved={}", unresolved_count);
+    println!("scan_graph_written={}", out.display());
+    Ok(())
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct CallGraph {
+    pub(crate) seeds: Vec<String>,
+    pub(crate) edges: BTreeSet<(String, String)>,
+    pub(crate) unresolved: BTreeSet<(String, String)>,
+}
+
+impl CallGraph {
+    fn to_json(&self, file: &str) -> Value {
+        json!({
+            "file": file,
+            "seeds": self.seeds,
+            "edges": self
+                .edges
+                .iter()
+                .map(|(from, to)| json!({ "from": from, "to": to }))
+                .collect::<Vec<_>>(),
+            "unresolved": self
+                .unresolved
+                .iter()
+                .map(|(from, func)| json!({ "from": from, "func": func }))
+                .collect::<Vec<_>>(),
+        })
+    }
+}
+
+/// 최상위 문장에서 나온 호출의 `from` 이름.
+const TOP_LEVEL_CALLER: &str = "<top>";
+
+/// 같은 파일의 씨 정의로 풀리는 호출은 간선으로, 내장 함수도 아닌 호출은 미해결로 남긴다.
+/// 씨 이름 풀이는 런타임처럼 정확한 이름을 먼저 보고, 없으면 부름 꼬리를 뗀 줄기가 하나일 때만 잇는다.
+fn build_call_graph(source: &str, file: &str) -> Result<CallGraph, String> {
+    let (program, _) = parse_program_for_runtime(source).map_err(|err| match err {
+        FrontdoorParseFailure::Guard(message) => message,
+        FrontdoorParseFailure::Lex(err) => RunError::Lex(err).format(file),
+        FrontdoorParseFailure::Parse(err) => RunError::Parse(err).format(file),
+    })?;
+    Ok(call_graph_for_program(&program))
+}
+
+/// 이미 파싱한 프로그램에서 호출 그래프를 만든다. `check`의 DEAD-SEED도 이걸 쓴다.
+pub(crate) fn call_graph_for_program(program: &Program) -> CallGraph {
+    let mut graph = CallGraph::default();
+    let mut calls = Vec::new();
+    for stmt in &program.stmts {
+        match stmt {
+            Stmt::SeedDef { name, body, .. } => {
+                graph.seeds.push(name.clone());
+                collect_body_calls(name, body, &mut calls);
+            }
+            other => collect_stmt_calls(TOP_LEVEL_CALLER, other, &mut calls),
+        }
+    }
+    let defined: BTreeSet<&str> = graph.seeds.iter().map(String::as_str).collect();
+    for (from, func) in calls {
+        if Evaluator::is_builtin_name(canonicalize_stdlib_alias(&func)) {
+            continue;
+        }
+        match resolve_seed(&defined, &func) {
+            Some(to) => {
+                graph.edges.insert((from, to));
+            }
+            None => {
+                graph.unresolved.insert((from, func));
+            }
+        }
+    }
+    graph
+}
+
+fn resolve_seed(defined: &BTreeSet<&str>, func: &str) -> Option<String> {
+    if defined.contains(func) {
+        return Some(func.to_string());
+    }
+    let stems: Vec<&str> = CALL_TAILS
+        .iter()
+        .filter_map(|tail| func.strip_suffix(tail))
+        .filter(|stem| defined.contains(stem))
+        .collect();
+    match stems.as_slice() {
+        [stem] => Some(stem.to_string()),
+        _ => None,
+    }
+}
+
+fn collect_body_calls(from: &str, body: &[Stmt], out: &mut Vec<(String, String)>) {
+    for stmt in body {
+        collect_stmt_calls(from, stmt, out);
+    }
+}
+
+fn collect_stmt_calls(from: &str, stmt: &Stmt, out: &mut Vec<(String, String)>) {
+    match stmt {
+        Stmt::DeclBlock { items, .. } => {
+            for item in items {
+                if let Some(value) = &item.value {
+                    collect_expr_calls(from, value, out);
+                }
+            }
+        }
+        Stmt::SeedDef { name, body, .. } => collect_body_calls(name, body, out),
+        Stmt::Assign { value, .. }
+        | Stmt::FlowAssign { value, .. }
+        | Stmt::Expr { value, .. }
+        | Stmt::Return { value, .. }
+        | Stmt::Show { value, .. }
+        | Stmt::Inspect { value, .. } => collect_expr_calls(from, value, out),
+        Stmt::Receive {
+            condition, body, ..
+        } => {
+            if let Some(condition) = condition {
+                collect_expr_calls(from, condition, out);
+            }
+            collect_body_calls(from, body, out);
+        }
+        Stmt::Send {
+            sender,
+            payload,
+            receiver,
+            ..
+        } => {
+            if let Some(sender) = sender {
+                collect_expr_calls(from, sender, out);
+            }
+            collect_expr_calls(from, payload, out);
+            collect_expr_calls(from, receiver, out);
+        }
+        Stmt::Boim { entries, .. } => collect_binding_calls(from, entries, out),
+        Stmt::If {
+            condition,
+            then_body,
+            else_body,
+            ..
+        } => {
+            collect_expr_calls(from, condition, out);
+            collect_body_calls(from, then_body, out);
+            if let Some(else_body) = else_body {
+                collect_body_calls(from, else_body, out);
+            }
+        }
+        Stmt::Choose {
+            branches,
+            else_body,
+            ..
+        } => {
+            for branch in branches {
+                collect_expr_calls(from, &branch.condition, out);
+                collect_body_calls(from, &branch.body, out);
+            }
+            if let Some(else_body) = else_body {
+                collect_body_calls(from, else_body, out);
+            }
+        }
+        Stmt::HookWhenBecomes {
+            condition, body, ..
+        }
+        | Stmt::HookWhile {
+            condition, body, ..
+        }
+        | Stmt::While {
+            condition, body, ..
+        } => {
+            collect_expr_calls(from, condition, out);
+            collect_body_calls(from, body, out);
+        }
+        Stmt::ForEach { iterable, body, .. } => {
+            collect_expr_calls(from, iterable, out);
+            collect_body_calls(from, body, out);
+        }
+        Stmt::Contract {
+            condition,
+            then_body,
+            else_body,
+            ..
+        } => {
+            collect_expr_calls(from, condition, out);
+            if let Some(then_body) = then_body {
+                collect_body_calls(from, then_body, out);
+            }
+            collect_body_calls(from, else_body, out);
+        }
+        Stmt::Hook { body, .. }
+        | Stmt::OpenBlock { body, .. }
+        | Stmt::BeatBlock { body, .. }
+        | Stmt::LifecycleBlock { body, .. }
+        | Stmt::Repeat { body, .. }
+        | Stmt::Quantifier { body, .. } => collect_body_calls(from, body, out),
+        Stmt::ImportBlock { .. }
+        | Stmt::ExportBlock { .. }
+        | Stmt::BogaeDraw { .. }
+        | Stmt::Break { .. }
+        | Stmt::ContinueLoop { .. }
+        | Stmt::Pragma { .. } => {}
+    }
+}
+
+fn collect_binding_calls(from: &str, bindings: &[Binding], out: &mut Vec<(String, String)>) {
+    for binding in bindings {
+        collect_expr_calls(from, &binding.value, out);
+    }
+}
+
+fn collect_expr_calls(from: &str, expr: &Expr, out: &mut Vec<(String, String)>) {
+    match expr {
+        Expr::Call { name, args, .. } => {
+            out.push((from.to_string(), name.clone()));
+            for arg in args {
+                collect_expr_calls(from, &arg.expr, out);
+            }
+        }
+        Expr::FieldAccess { target, .. } => collect_expr_calls(from, target, out),
+        Expr::Unary { expr, .. } => collect_expr_calls(from, expr, out),
+        Expr::Binary { left, right, .. } => {
+            collect_expr_calls(from, left, out);
+            collect_expr_calls(from, right, out);
+        }
+        Expr::SeedLiteral { body, .. } => collect_expr_calls(from, body, out),
+        Expr::FormulaEval { bindings, .. } | Expr::Pack { bindings, .. } => {
+            collect_binding_calls(from, bindings, out)
+        }
+        Expr::TemplateFill {
+            template: inner,
+            bindings,
+            ..
+        }
+        | Expr::FormulaFill {
+            formula: inner,
+            bindings,
+            ..
+        } => {
+            collect_expr_calls(from, inner, out);
+            collect_binding_calls(from, bindings, out);
+        }
+        Expr::Literal(..)
+        | Expr::Path(_)
+        | Expr::Atom { .. }
+        | Expr::Formula { .. }
+        | Expr::Assertion { .. }
+        | Expr::Template { .. } => {}
+    }
+}
+
+fn collect_ddn_files(root: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut out = Vec::new();
+    visit_dir(root, &mut out)?;
+    out.sort();
+    Ok(out)
+}
+
+fn visit_dir(dir: &Path, out: &mut Vec<PathBuf>) -> Result<(), String> {
+    let entries = fs::read_dir(dir).map_err(|e| format!("E_SCAN_READ {}", e))?;
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("E_SCAN_READ {}", e))?;
+        let path = entry.path();
+        if path.is_dir() {
+            let name = path.file_name().and_then(|s| s.to_str()).unwrap_or("");
+            if name.starts_with('.') || matches!(name, "target" | "build" | "node_modules") {
+                continue;
+            }
+            visit_dir(&path, out)?;
+        } else if path.extension().and_then(|s| s.to_str()) == Some("ddn") {
+            out.push(path);
+        }
+    }
+    Ok(())
+}
+
 fn print_warn(code: &str, message: &str) {
     println!(
         "{{\"kind\":\"scan\",\"level\":\"warn\",\"code\":\"{}\",\"message\":\"{}\"}}",
@@ -62,3 +381,22 @@ fn json_escape(input: &str) -> String {
     }
     out
 }
+
+#[cfg(test)]
+mod tests {
+    use super::build_call_graph;
+
+    #[test]
+    fn graph_links_caller_to_callee_and_flags_unknown_call() {
+        let source = "(x:수) 앞셈:셈씨 = {\n  (x)뒤셈 돌려줘.\n}\n\n(x:수) 뒤셈:셈씨 = {\n  (x)없는셈 돌려줘.\n}\n";
+        let graph = build_call_graph(source, "graph.ddn").expect("graph");
+        assert_eq!(graph.seeds, vec!["앞셈".to_string(), "뒤셈".to_string()]);
+        assert!(graph
+            .edges
+            .contains(&("앞셈".to_string(), "뒤셈".to_string())));
+        assert_eq!(graph.edges.len(), 1);
+        assert!(graph
+            .unresolved
+            .contains(&("뒤셈".to_string(), "없는셈".to_string())));
+    }
+}
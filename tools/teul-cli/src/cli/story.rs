@@ -1,12 +1,41 @@
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
 use std::path::Path;
 
+use serde_json::Value as JsonValue;
+
+use super::geoul::replay_geoul_states;
+use crate::core::fixed64::Fixed64;
 use crate::core::geoul::GeoulBundleReader;
+use crate::core::state::Key;
+use crate::core::value::Value;
+
+/// 마디 사이에 한 키가 바뀐 사건. 중요도는 수 값이면 변화량의 정수 부분, 그 밖의 변화는 1이다.
+struct StoryBeat {
+    madi: u64,
+    key: String,
+    old: String,
+    new: String,
+    importance: u64,
+}
 
-pub fn run_make(geoul_dir: &Path, out_path: &Path) -> Result<(), String> {
+/// `min_importance`를 주면 요약 장면 뒤에 마디별 변화 장면(`change`)을 붙이되,
+/// 중요도가 그보다 낮은 장면은 버린다. 남은 장면은 (마디, 키) 순서를 유지한다.
+pub fn run_make(
+    geoul_dir: &Path,
+    out_path: &Path,
+    min_importance: Option<u64>,
+) -> Result<(), String> {
     let reader = GeoulBundleReader::open(geoul_dir)?;
     let frame_count = reader.frame_count();
     let t1 = if frame_count == 0 { 0 } else { frame_count - 1 };
+    let beats = match min_importance {
+        Some(min) if frame_count > 0 => collect_beats(geoul_dir, t1)?
+            .into_iter()
+            .filter(|beat| beat.importance >= min)
+            .collect(),
+        _ => Vec::new(),
+    };
     let summary = format!("frames={}", frame_count);
     let mut out = String::new();
     out.push_str("{\n");
@@ -14,9 +43,20 @@ pub fn run_make(geoul_dir: &Path, out_path: &Path) -> Result<(), String> {
     out.push_str(&format!("  \"summary\": \"{}\",\n", summary));
     out.push_str("  \"scenes\": [\n");
     out.push_str(&format!(
-        "    {{\"t0\": 0, \"t1\": {}, \"kind\": \"summary\", \"text\": \"{}\"}}\n",
+        "    {{\"t0\": 0, \"t1\": {}, \"kind\": \"summary\", \"text\": \"{}\"}}",
         t1, summary
     ));
+    for beat in &beats {
+        let text = format!("{}: {} -> {}", beat.key, beat.old, beat.new);
+        out.push_str(&format!(
+            ",\n    {{\"t0\": {madi}, \"t1\": {madi}, \"kind\": \"change\", \"key\": {}, \"importance\": {}, \"text\": {}}}",
+            JsonValue::String(beat.key.clone()),
+            beat.importance,
+            JsonValue::String(text),
+            madi = beat.madi,
+        ));
+    }
+    out.push('\n');
     out.push_str("  ],\n");
     out.push_str("  \"suggested_intents\": [\n");
     if frame_count > 0 {
@@ -35,3 +75,103 @@ pub fn run_make(geoul_dir: &Path, out_path: &Path) -> Result<(), String> {
     println!("story_written={}", out_path.display());
     Ok(())
 }
+
+fn collect_beats(geoul_dir: &Path, until: u64) -> Result<Vec<StoryBeat>, String> {
+    let mut beats = Vec::new();
+    let mut prev: Option<BTreeMap<Key, Value>> = None;
+    replay_geoul_states(geoul_dir, until, None, |madi, state| {
+        if let Some(prev) = prev.as_ref() {
+            let keys = prev
+                .keys()
+                .chain(state.resources.keys())
+                .collect::<BTreeSet<_>>();
+            for key in keys {
+                let old = prev.get(key).unwrap_or(&Value::None);
+                let new = state.get(key).unwrap_or(&Value::None);
+                let (old_canon, new_canon) = (old.canon(), new.canon());
+                if old_canon == new_canon {
+                    continue;
+                }
+                beats.push(StoryBeat {
+                    madi,
+                    key: key.as_str().to_string(),
+                    old: old_canon,
+                    new: new_canon,
+                    importance: change_importance(old, new),
+                });
+            }
+        }
+        prev = Some(state.resources.clone());
+    })?;
+    Ok(beats)
+}
+
+fn change_importance(old: &Value, new: &Value) -> u64 {
+    match (old, new) {
+        (Value::Num(old), Value::Num(new)) => {
+            let delta = new.raw.saturating_sub(old.raw);
+            delta.raw().unsigned_abs() >> Fixed64::SCALE_BITS
+        }
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::geoul::write_test_geoul;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn temp_dir(name: &str) -> std::path::PathBuf {
+        let stamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("ddn_story_{}_{}", name, stamp));
+        fs::create_dir_all(&dir).expect("mkdir");
+        dir
+    }
+
+    fn change_texts(path: &Path) -> Vec<String> {
+        let text = fs::read_to_string(path).expect("read");
+        let json: JsonValue = serde_json::from_str(&text).expect("json");
+        let key = if json.get("scenes").is_some() {
+            "scenes"
+        } else {
+            "items"
+        };
+        json[key]
+            .as_array()
+            .expect("scenes")
+            .iter()
+            .filter(|scene| scene["kind"] == "change")
+            .map(|scene| scene["text"].as_str().expect("text").to_string())
+            .collect()
+    }
+
+    #[test]
+    fn min_importance_drops_small_beats_and_keeps_order() {
+        let root = temp_dir("importance");
+        let geoul = root.join("geoul");
+        let source = "채비 {\n  걸음:수 <- 0.\n  보물:수 <- 0.\n}.\n(매마디)마다 {\n  걸음 <- 걸음 + 1.\n  { 걸음 == 2 }인것 일때 {\n    보물 <- 보물 + 50.\n  }.\n}.\n";
+        write_test_geoul(&geoul, source, 4);
+
+        let all_path = root.join("all.detjson");
+        run_make(&geoul, &all_path, Some(0)).expect("story all");
+        let top_path = root.join("top.detjson");
+        run_make(&geoul, &top_path, Some(10)).expect("story top");
+
+        let all = change_texts(&all_path);
+        let top = change_texts(&top_path);
+        assert!(top.len() < all.len(), "{:?} / {:?}", top, all);
+        assert_eq!(top, vec!["보물: 0 -> 50".to_string()]);
+        let kept: Vec<&String> = all.iter().filter(|text| top.contains(text)).collect();
+        assert_eq!(kept, top.iter().collect::<Vec<_>>());
+
+        let timeline_path = root.join("timeline.detjson");
+        crate::cli::timeline::run_make(&geoul, &all_path, &timeline_path, Some(10))
+            .expect("timeline");
+        assert_eq!(change_texts(&timeline_path), top);
+        let _ = fs::remove_dir_all(&root);
+    }
+}
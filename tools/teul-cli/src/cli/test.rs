@@ -1,5 +1,6 @@
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use ddonirang_core::realms::{MultiRealmManager, RealmStepInput, ThreadMode};
 use hex::encode as hex_encode;
@@ -21,6 +22,21 @@ struct RealmsStepInput {
     delta: i64,
 }
 
+/// 한 파일에 이름 붙은 시험 여러 개를 담는 형식. 예전 단일 형식도 그대로 읽는다.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum RealmsTestFile {
+    Suite { tests: Vec<NamedRealmsTestInput> },
+    Single(RealmsTestInput),
+}
+
+#[derive(Debug, Deserialize)]
+struct NamedRealmsTestInput {
+    name: String,
+    #[serde(flatten)]
+    input: RealmsTestInput,
+}
+
 #[derive(Debug, Serialize)]
 struct RealmsTestOutput {
     realm_count: usize,
@@ -28,13 +44,65 @@ struct RealmsTestOutput {
     state_hashes: Vec<String>,
 }
 
-pub fn run_realms_test(path: &Path, threads: usize, out: Option<&Path>) -> Result<(), String> {
+#[derive(Debug, Serialize)]
+struct NamedRealmsTestOutput {
+    name: String,
+    #[serde(flatten)]
+    output: RealmsTestOutput,
+}
+
+#[derive(Debug, Serialize)]
+struct RealmsSuiteOutput {
+    tests: Vec<NamedRealmsTestOutput>,
+}
+
+/// `filter`가 있으면 정본 이름(앞뒤 공백을 뗀 이름)에 그 조각이 든 시험만 돌린다.
+/// 단일 형식 파일의 이름은 파일 이름(확장자 제외)이다.
+pub fn run_realms_test(
+    path: &Path,
+    threads: usize,
+    filter: Option<&str>,
+    out: Option<&Path>,
+) -> Result<(), String> {
     let text = read_text(path)?;
-    let input: RealmsTestInput =
+    let file: RealmsTestFile =
         serde_json::from_str(&text).map_err(|err| format!("E_REALM_INPUT {}", err))?;
 
-    if input.realm_count == 0 {
-        return Err("E_REALM_INPUT realm_count must be > 0".to_string());
+    let (cases, is_suite) = match file {
+        RealmsTestFile::Suite { tests } => (
+            tests
+                .into_iter()
+                .map(|test| (test.name.trim().to_string(), test.input))
+                .collect::<Vec<_>>(),
+            true,
+        ),
+        RealmsTestFile::Single(input) => {
+            let name = path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_default();
+            (vec![(name, input)], false)
+        }
+    };
+    let total = cases.len();
+    let selected = cases
+        .into_iter()
+        .filter(|(name, _)| filter.is_none_or(|needle| name.contains(needle)))
+        .collect::<Vec<_>>();
+    let skipped = total - selected.len();
+    if let Some(needle) = filter {
+        if selected.is_empty() {
+            return Err(format!(
+                "E_TEST_NO_MATCH no tests matched filter \"{}\" (total={})",
+                needle, total
+            ));
+        }
+        eprintln!(
+            "test_filter={} selected={} skipped={}",
+            needle,
+            selected.len(),
+            skipped
+        );
     }
 
     let thread_mode = if threads <= 1 {
@@ -43,13 +111,44 @@ pub fn run_realms_test(path: &Path, threads: usize, out: Option<&Path>) -> Resul
         ThreadMode::Rayon(threads)
     };
 
-    let mut manager = MultiRealmManager::new(input.realm_count, input.master_seed, thread_mode);
+    let mut outputs = Vec::with_capacity(selected.len());
+    for (name, input) in &selected {
+        outputs.push(NamedRealmsTestOutput {
+            name: name.clone(),
+            output: run_realms_case(input, &thread_mode)?,
+        });
+    }
+
+    let json = if is_suite {
+        serde_json::to_string_pretty(&RealmsSuiteOutput { tests: outputs })
+    } else {
+        serde_json::to_string_pretty(&outputs[0].output)
+    }
+    .map_err(|err| format!("E_REALM_OUTPUT {}", err))?;
+    if let Some(out_path) = out {
+        std::fs::write(out_path, format!("{json}\n")).map_err(|err| err.to_string())?;
+    }
+    println!("{json}");
+    Ok(())
+}
+
+fn run_realms_case(
+    input: &RealmsTestInput,
+    thread_mode: &ThreadMode,
+) -> Result<RealmsTestOutput, String> {
+    if input.realm_count == 0 {
+        return Err("E_REALM_INPUT realm_count must be > 0".to_string());
+    }
+
+    let mut manager =
+        MultiRealmManager::new(input.realm_count, input.master_seed, thread_mode.clone());
     let batch: Vec<RealmStepInput> = input
         .step_batch
         .iter()
         .map(|item| RealmStepInput {
             realm_id: item.realm_id,
             delta: item.delta,
+            send: Vec::new(),
         })
         .collect();
 
@@ -63,19 +162,11 @@ pub fn run_realms_test(path: &Path, threads: usize, out: Option<&Path>) -> Resul
         .map(|hash| format!("blake3:{}", hex_encode(hash.as_bytes())))
         .collect::<Vec<_>>();
 
-    let output = RealmsTestOutput {
+    Ok(RealmsTestOutput {
         realm_count: input.realm_count,
         steps: input.steps,
         state_hashes,
-    };
-
-    let json =
-        serde_json::to_string_pretty(&output).map_err(|err| format!("E_REALM_OUTPUT {}", err))?;
-    if let Some(out_path) = out {
-        std::fs::write(out_path, format!("{json}\n")).map_err(|err| err.to_string())?;
-    }
-    println!("{json}");
-    Ok(())
+    })
 }
 
 #[derive(Debug, Clone)]
@@ -84,6 +175,7 @@ pub struct GoldenRunnerOptions {
     pub all: bool,
     pub record: bool,
     pub update: bool,
+    pub junit: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone)]
@@ -149,8 +241,134 @@ pub fn run_pack_golden_runner(options: GoldenRunnerOptions) -> Result<(), String
     if options.update {
         args.push("--update".to_string());
     }
+    // JUnit은 러너의 JSON 보고서를 받아 바꾼다. 러너는 root에서 돌므로 절대 경로를 넘긴다.
+    let report_path = options.junit.as_ref().map(|_| {
+        let nonce = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_nanos())
+            .unwrap_or_default();
+        std::env::temp_dir().join(format!("teul_cli_golden_report_{nonce}.json"))
+    });
+    if let Some(path) = report_path.as_ref() {
+        args.push("--report-out".to_string());
+        args.push(path.display().to_string());
+    }
     args.extend(options.packs.iter().cloned());
-    run_python_runner(&root, "tests/run_pack_golden.py", &args)
+    let result = run_python_runner(&root, "tests/run_pack_golden.py", &args);
+    if let (Some(junit_path), Some(report_path)) = (options.junit.as_ref(), report_path) {
+        let written = write_junit_from_golden_report(&report_path, junit_path);
+        let _ = std::fs::remove_file(&report_path);
+        written?;
+    }
+    result
+}
+
+/// `ddn.pack.golden.report.v1` 보고서를 팩마다 testsuite, 사례마다 testcase인
+/// JUnit XML로 옮긴다. 사례를 읽기 전에 실패한 팩은 `load` testcase 하나로 남긴다.
+fn write_junit_from_golden_report(report_path: &Path, junit_path: &Path) -> Result<(), String> {
+    let text = read_text(report_path)?;
+    let report: serde_json::Value =
+        serde_json::from_str(&text).map_err(|err| format!("E_TEST_JUNIT_REPORT {}", err))?;
+    let xml = golden_report_to_junit(&report);
+    if let Some(parent) = junit_path
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+    {
+        std::fs::create_dir_all(parent).map_err(|err| format!("E_TEST_JUNIT_WRITE {}", err))?;
+    }
+    std::fs::write(junit_path, xml)
+        .map_err(|err| format!("E_TEST_JUNIT_WRITE {}: {}", junit_path.display(), err))
+}
+
+fn golden_report_to_junit(report: &serde_json::Value) -> String {
+    let empty = Vec::new();
+    let packs = report["packs"].as_array().unwrap_or(&empty);
+    let mut suites = String::new();
+    let mut total_tests = 0usize;
+    let mut total_failures = 0usize;
+    for pack in packs {
+        let name = pack["pack"].as_str().unwrap_or("-");
+        let seconds = pack["elapsed_ms"].as_u64().unwrap_or(0) as f64 / 1000.0;
+        let mut cases = String::new();
+        let mut tests = 0usize;
+        let mut failures = 0usize;
+        for case in pack["cases"].as_array().unwrap_or(&empty) {
+            let idx = case["index"].as_u64().unwrap_or(0);
+            tests += 1;
+            cases.push_str(&format!(
+                "    <testcase classname=\"{}\" name=\"case_{}\"",
+                escape_xml(name),
+                idx
+            ));
+            if case["ok"].as_bool().unwrap_or(false) {
+                cases.push_str("/>\n");
+                continue;
+            }
+            failures += 1;
+            let mut body = Vec::new();
+            for (label, key) in [
+                ("expected", "expected"),
+                ("got", "got"),
+                ("issues", "issues"),
+                ("stderr", "stderr"),
+            ] {
+                if let Some(lines) = case[key].as_array().filter(|lines| !lines.is_empty()) {
+                    let lines = lines
+                        .iter()
+                        .map(|line| {
+                            line.as_str()
+                                .map_or_else(|| line.to_string(), str::to_string)
+                        })
+                        .collect::<Vec<_>>();
+                    body.push(format!("{}: {}", label, lines.join(" | ")));
+                }
+            }
+            cases.push_str(&format!(
+                ">\n      <failure message=\"pack={} case={}\">{}</failure>\n    </testcase>\n",
+                escape_xml(name),
+                idx,
+                escape_xml(&body.join("\n"))
+            ));
+        }
+        let errors = pack["errors"]
+            .as_array()
+            .unwrap_or(&empty)
+            .iter()
+            .filter_map(|error| error.as_str())
+            .collect::<Vec<_>>();
+        if !errors.is_empty() {
+            tests += 1;
+            failures += 1;
+            cases.push_str(&format!(
+                "    <testcase classname=\"{0}\" name=\"load\">\n      <failure message=\"pack={0}\">{1}</failure>\n    </testcase>\n",
+                escape_xml(name),
+                escape_xml(&errors.join("\n"))
+            ));
+        }
+        total_tests += tests;
+        total_failures += failures;
+        suites.push_str(&format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" time=\"{:.3}\">\n{}  </testsuite>\n",
+            escape_xml(name),
+            tests,
+            failures,
+            seconds,
+            cases
+        ));
+    }
+    let seconds = report["elapsed_ms"].as_u64().unwrap_or(0) as f64 / 1000.0;
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites name=\"pack golden\" tests=\"{}\" failures=\"{}\" time=\"{:.3}\">\n{}</testsuites>\n",
+        total_tests, total_failures, seconds, suites
+    )
+}
+
+fn escape_xml(input: &str) -> String {
+    input
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
 }
 
 pub fn run_wasm_smoke_runner(options: SmokeRunnerOptions) -> Result<(), String> {
@@ -168,3 +386,97 @@ pub fn run_wasm_smoke_runner(options: SmokeRunnerOptions) -> Result<(), String>
     args.extend(options.packs.iter().cloned());
     run_python_runner(&root, "tests/run_seamgrim_wasm_smoke.py", &args)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{run_realms_test, write_junit_from_golden_report};
+    use std::fs;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    #[test]
+    fn realms_test_filter_selects_named_subset() {
+        let nonce = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        let root = std::env::temp_dir().join(format!("teul_cli_realms_filter_{nonce}"));
+        fs::create_dir_all(&root).expect("mkdir");
+        let suite = root.join("suite.json");
+        let case = |name: &str, seed: u64| {
+            format!(
+                "{{\"name\":\"{name}\",\"master_seed\":{seed},\"realm_count\":2,\"steps\":3,\"step_batch\":[{{\"realm_id\":0,\"delta\":1}}]}}"
+            )
+        };
+        fs::write(
+            &suite,
+            format!(
+                "{{\"tests\":[{},{},{}]}}",
+                case("회복_기본", 1),
+                case("공격_기본", 2),
+                case(" 회복_연속 ", 3)
+            ),
+        )
+        .expect("suite");
+
+        let out = root.join("out.json");
+        run_realms_test(&suite, 1, Some("회복"), Some(&out)).expect("filtered run");
+        let json: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&out).expect("out")).expect("json");
+        let names = json["tests"]
+            .as_array()
+            .expect("tests")
+            .iter()
+            .map(|test| test["name"].as_str().unwrap().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["회복_기본", "회복_연속"]);
+        assert_eq!(
+            json["tests"][0]["state_hashes"].as_array().unwrap().len(),
+            2
+        );
+
+        let err = run_realms_test(&suite, 1, Some("방어"), None).expect_err("no match");
+        assert!(err.starts_with("E_TEST_NO_MATCH no tests matched"), "{err}");
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn junit_report_marks_failing_golden_pack() {
+        let nonce = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        let root = std::env::temp_dir().join(format!("teul_cli_junit_{nonce}"));
+        fs::create_dir_all(&root).expect("mkdir");
+        let report = root.join("report.json");
+        fs::write(
+            &report,
+            r#"{
+  "schema": "ddn.pack.golden.report.v1",
+  "overall_ok": false,
+  "elapsed_ms": 1500,
+  "packs": [
+    {"pack": "ok_pack", "ok": true, "elapsed_ms": 500, "cases": [{"index": 1, "ok": true}], "errors": []},
+    {"pack": "broken_pack", "ok": false, "elapsed_ms": 1000, "cases": [
+      {"index": 1, "ok": true},
+      {"index": 2, "ok": false, "expected": ["state_hash=a"], "got": ["state_hash=<b>"]}
+    ], "errors": []}
+  ]
+}"#,
+        )
+        .expect("report");
+
+        let junit = root.join("out/junit.xml");
+        write_junit_from_golden_report(&report, &junit).expect("junit");
+        let xml = fs::read_to_string(&junit).expect("xml");
+        assert!(
+            xml.contains(r#"<testsuites name="pack golden" tests="3" failures="1" time="1.500">"#)
+        );
+        assert!(xml.contains(r#"<testcase classname="ok_pack" name="case_1"/>"#));
+        assert!(xml.contains(r#"<failure message="pack=broken_pack case=2">"#));
+        assert!(xml.contains("got: state_hash=&lt;b&gt;"));
+        assert_eq!(xml.matches("<failure").count(), 1);
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
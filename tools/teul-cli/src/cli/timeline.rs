@@ -5,7 +5,13 @@ use serde_json::{Map, Value};
 
 use crate::core::geoul::GeoulBundleReader;
 
-pub fn run_make(geoul_dir: &Path, story_path: &Path, out_path: &Path) -> Result<(), String> {
+/// `min_importance`를 주면 `importance`가 그보다 낮은 장면을 뺀다. 중요도가 없는 장면은 남긴다.
+pub fn run_make(
+    geoul_dir: &Path,
+    story_path: &Path,
+    out_path: &Path,
+    min_importance: Option<u64>,
+) -> Result<(), String> {
     let reader = GeoulBundleReader::open(geoul_dir)?;
     let frame_count = reader.frame_count();
     let story_text = fs::read_to_string(story_path).map_err(|e| e.to_string())?;
@@ -21,6 +27,12 @@ pub fn run_make(geoul_dir: &Path, story_path: &Path, out_path: &Path) -> Result<
         let Some(obj) = scene.as_object() else {
             continue;
         };
+        let importance = obj.get("importance").and_then(|value| value.as_u64());
+        if let (Some(min), Some(importance)) = (min_importance, importance) {
+            if importance < min {
+                continue;
+            }
+        }
         let t0 = obj.get("t0").cloned().unwrap_or(Value::Number(0.into()));
         let t1 = obj.get("t1").cloned().unwrap_or(Value::Number(0.into()));
         let kind = obj
@@ -35,6 +47,9 @@ pub fn run_make(geoul_dir: &Path, story_path: &Path, out_path: &Path) -> Result<
         item.insert("t0".to_string(), t0);
         item.insert("t1".to_string(), t1);
         item.insert("kind".to_string(), kind);
+        if let Some(importance) = importance {
+            item.insert("importance".to_string(), Value::Number(importance.into()));
+        }
         item.insert("text".to_string(), text);
         items.push(Value::Object(item));
     }
@@ -27,7 +27,27 @@ struct TrainOutcome {
     pass: bool,
 }
 
-pub fn run_train(config_path: &Path, out_dir: Option<&Path>) -> Result<(), String> {
+/// 한 epoch이 끝난 뒤의 학습 상태. 체크포인트는 이 값을 그대로 저장·복원한다.
+///
+/// `rng_state`는 epoch마다 앞 상태에서 이어서 갱신되므로, 재개할 때 시드에서 다시
+/// 만들지 않고 저장된 값을 이어 받아야 끊김 없이 돌린 것과 같은 가중치가 나온다.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct TrainProgress {
+    epoch: u64,
+    score: u64,
+    rng_state: u64,
+    weights: Vec<u8>,
+}
+
+const TRAIN_CHECKPOINT_SCHEMA: &str = "seulgi.train_checkpoint.v1";
+const EPOCH_RNG_STRIDE: u64 = 0x9E3779B97F4A7C15;
+
+pub fn run_train(
+    config_path: &Path,
+    out_dir: Option<&Path>,
+    resume: Option<&Path>,
+    checkpoint_every: u64,
+) -> Result<(), String> {
     let text = fs::read_to_string(config_path)
         .map_err(|e| format!("E_TRAIN_CONFIG_READ {} {}", config_path.display(), e))?;
     let value: JsonValue =
@@ -55,8 +75,41 @@ pub fn run_train(config_path: &Path, out_dir: Option<&Path>) -> Result<(), Strin
     let mix_seed =
         config.train_seed ^ hash_to_u64(&format!("{}:{}", config.dataset_hash, config.recipe_hash));
     let (base, step) = derive_curve(mix_seed);
+    let weights_len = config.weights_len.unwrap_or(16);
+    let out_dir = resolve_out_dir(out_dir);
 
-    let outcome = run_toy_training(base, step, config.target_score, config.max_epochs);
+    let start = match resume {
+        Some(path) => {
+            let progress = read_checkpoint(path, &config, weights_len)?;
+            if progress.epoch > config.max_epochs {
+                return Err(format!(
+                    "E_TRAIN_RESUME_EPOCH 체크포인트 epoch {}가 max_epochs {}를 넘습니다",
+                    progress.epoch, config.max_epochs
+                ));
+            }
+            progress
+        }
+        None => TrainProgress {
+            epoch: 0,
+            score: base,
+            rng_state: mix_seed,
+            weights: Vec::new(),
+        },
+    };
+    let (outcome, progress) = run_toy_training(
+        start,
+        base,
+        step,
+        config.target_score,
+        config.max_epochs,
+        weights_len,
+        |progress| {
+            if checkpoint_every > 0 && progress.epoch % checkpoint_every == 0 {
+                write_checkpoint(&out_dir, &run_id, &config, progress)?;
+            }
+            Ok(())
+        },
+    )?;
     let report_detjson = build_train_report(
         &run_id,
         &outcome,
@@ -72,8 +125,7 @@ pub fn run_train(config_path: &Path, out_dir: Option<&Path>) -> Result<(), Strin
     let mut artifact_detjson = None;
     let mut weights = Vec::new();
     if outcome.pass {
-        let len = config.weights_len.unwrap_or(16);
-        weights = build_weights(mix_seed, outcome.epoch, len);
+        weights = progress.weights;
         let weights_hash = format!("sha256:{}", sha256_hex(&weights));
         artifact_detjson = Some(build_artifact(
             &config.model_id,
@@ -88,7 +140,6 @@ pub fn run_train(config_path: &Path, out_dir: Option<&Path>) -> Result<(), Strin
         ));
     }
 
-    let out_dir = resolve_out_dir(out_dir);
     fs::create_dir_all(&out_dir).map_err(|e| e.to_string())?;
     write_text(&out_dir.join("train.report.detjson"), &report_detjson)?;
     if let Some(artifact) = artifact_detjson.as_deref() {
@@ -131,25 +182,168 @@ fn derive_curve(seed: u64) -> (u64, u64) {
     (base, step)
 }
 
-fn run_toy_training(base: u64, step: u64, target: u64, max_epochs: u64) -> TrainOutcome {
-    let mut epoch = 1;
-    let mut score = base;
-    for idx in 1..=max_epochs {
-        epoch = idx;
-        score = base + step.saturating_mul(idx.saturating_sub(1));
-        if score >= target {
-            return TrainOutcome {
+/// `progress`에서 이어 epoch을 하나씩 돌리고, epoch이 끝날 때마다 `on_epoch`을 부른다.
+fn run_toy_training(
+    mut progress: TrainProgress,
+    base: u64,
+    step: u64,
+    target: u64,
+    max_epochs: u64,
+    weights_len: usize,
+    mut on_epoch: impl FnMut(&TrainProgress) -> Result<(), String>,
+) -> Result<(TrainOutcome, TrainProgress), String> {
+    if progress.epoch > 0 && progress.score >= target {
+        let outcome = TrainOutcome {
+            epoch: progress.epoch,
+            score: progress.score,
+            pass: true,
+        };
+        return Ok((outcome, progress));
+    }
+    while progress.epoch < max_epochs {
+        let prev = progress.epoch;
+        let epoch = prev + 1;
+        progress.epoch = epoch;
+        progress.score = base + step.saturating_mul(prev);
+        progress.rng_state ^=
+            epoch.wrapping_mul(EPOCH_RNG_STRIDE) ^ prev.wrapping_mul(EPOCH_RNG_STRIDE);
+        progress.weights = build_weights(progress.rng_state, weights_len);
+        on_epoch(&progress)?;
+        if progress.score >= target {
+            let outcome = TrainOutcome {
                 epoch,
-                score,
+                score: progress.score,
                 pass: true,
             };
+            return Ok((outcome, progress));
         }
     }
-    TrainOutcome {
-        epoch,
-        score,
+    let outcome = TrainOutcome {
+        epoch: progress.epoch.max(1),
+        score: progress.score,
         pass: false,
+    };
+    Ok((outcome, progress))
+}
+
+fn checkpoint_path(out_dir: &Path, epoch: u64) -> PathBuf {
+    out_dir
+        .join("checkpoints")
+        .join(format!("epoch_{:06}.ckpt.detjson", epoch))
+}
+
+fn write_checkpoint(
+    out_dir: &Path,
+    run_id: &str,
+    config: &TrainConfig,
+    progress: &TrainProgress,
+) -> Result<(), String> {
+    let mut map = serde_json::Map::new();
+    map.insert(
+        "schema".to_string(),
+        JsonValue::String(TRAIN_CHECKPOINT_SCHEMA.to_string()),
+    );
+    map.insert("run_id".to_string(), JsonValue::String(run_id.to_string()));
+    map.insert(
+        "dataset_hash".to_string(),
+        JsonValue::String(config.dataset_hash.clone()),
+    );
+    map.insert(
+        "recipe_hash".to_string(),
+        JsonValue::String(config.recipe_hash.clone()),
+    );
+    map.insert(
+        "train_seed".to_string(),
+        JsonValue::String(config.train_seed.to_string()),
+    );
+    map.insert(
+        "epoch".to_string(),
+        JsonValue::Number(progress.epoch.into()),
+    );
+    map.insert(
+        "score".to_string(),
+        JsonValue::Number(progress.score.into()),
+    );
+    map.insert(
+        "rng_state".to_string(),
+        JsonValue::String(format!("0x{:016x}", progress.rng_state)),
+    );
+    map.insert(
+        "weights_hex".to_string(),
+        JsonValue::String(hex::encode(&progress.weights)),
+    );
+    let path = checkpoint_path(out_dir, progress.epoch);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("E_TRAIN_CHECKPOINT_WRITE {}", e))?;
+    }
+    write_text(&path, &JsonValue::Object(map).to_string())
+}
+
+/// 체크포인트를 읽어 학습 상태를 복원한다. 다른 데이터셋·레시피·시드로 만든
+/// 체크포인트는 이어 돌려도 같은 결과가 되지 않으므로 거부한다.
+fn read_checkpoint(
+    path: &Path,
+    config: &TrainConfig,
+    weights_len: usize,
+) -> Result<TrainProgress, String> {
+    let text = fs::read_to_string(path)
+        .map_err(|e| format!("E_TRAIN_RESUME_READ {} {}", path.display(), e))?;
+    let value: JsonValue =
+        serde_json::from_str(&text).map_err(|e| format!("E_TRAIN_RESUME_PARSE {}", e))?;
+    let field = |key: &str| {
+        value
+            .get(key)
+            .ok_or_else(|| format!("E_TRAIN_RESUME_PARSE {} 없음", key))
+    };
+    let text_field = |key: &str| {
+        field(key)?
+            .as_str()
+            .ok_or_else(|| format!("E_TRAIN_RESUME_PARSE {}는 문자열이어야 합니다", key))
+    };
+    let u64_field = |key: &str| {
+        field(key)?
+            .as_u64()
+            .ok_or_else(|| format!("E_TRAIN_RESUME_PARSE {}는 정수여야 합니다", key))
+    };
+
+    let schema = text_field("schema")?;
+    if schema != TRAIN_CHECKPOINT_SCHEMA {
+        return Err(format!("E_TRAIN_RESUME_SCHEMA {}", schema));
+    }
+    let lineage = [
+        ("dataset_hash", config.dataset_hash.clone()),
+        ("recipe_hash", config.recipe_hash.clone()),
+        ("train_seed", config.train_seed.to_string()),
+    ];
+    for (key, expected) in lineage {
+        let actual = text_field(key)?;
+        if actual != expected {
+            return Err(format!(
+                "E_TRAIN_RESUME_MISMATCH {} 체크포인트={} 설정={}",
+                key, actual, expected
+            ));
+        }
+    }
+    let rng_text = text_field("rng_state")?;
+    let rng_state = rng_text
+        .strip_prefix("0x")
+        .and_then(|hex| u64::from_str_radix(hex, 16).ok())
+        .ok_or_else(|| format!("E_TRAIN_RESUME_PARSE rng_state {}", rng_text))?;
+    let weights = hex::decode(text_field("weights_hex")?)
+        .map_err(|e| format!("E_TRAIN_RESUME_PARSE weights_hex {}", e))?;
+    if weights.len() != weights_len {
+        return Err(format!(
+            "E_TRAIN_RESUME_MISMATCH weights_len 체크포인트={} 설정={}",
+            weights.len(),
+            weights_len
+        ));
     }
+    Ok(TrainProgress {
+        epoch: u64_field("epoch")?,
+        score: u64_field("score")?,
+        rng_state,
+        weights,
+    })
 }
 
 fn build_train_report(
@@ -289,8 +483,8 @@ fn build_artifact(
     JsonValue::Object(map).to_string()
 }
 
-fn build_weights(seed: u64, epoch: u64, len: usize) -> Vec<u8> {
-    let mut state = seed ^ epoch.wrapping_mul(0x9E3779B97F4A7C15);
+fn build_weights(rng_state: u64, len: usize) -> Vec<u8> {
+    let mut state = rng_state;
     let mut out = Vec::with_capacity(len);
     for _ in 0..len {
         state = state
@@ -300,3 +494,78 @@ fn build_weights(seed: u64, epoch: u64, len: usize) -> Vec<u8> {
     }
     out
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let stamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("ddn_train_{}_{}", name, stamp));
+        fs::create_dir_all(&dir).expect("mkdir");
+        dir
+    }
+
+    fn write_config(dir: &Path, name: &str, target: u64, max_epochs: u64) -> PathBuf {
+        let path = dir.join(name);
+        let text = format!(
+            "{{\"schema\":\"seulgi.train_config.v1\",\"model_id\":\"toy\",\"dataset_hash\":\"sha256:data\",\"recipe_hash\":\"sha256:recipe\",\"ssot_bundle_hash\":\"sha256:ssot\",\"train_seed\":7,\"target_score\":{},\"max_epochs\":{},\"weights_len\":16}}",
+            target, max_epochs
+        );
+        fs::write(&path, text).expect("write config");
+        path
+    }
+
+    #[test]
+    fn resume_from_checkpoint_matches_uninterrupted_run() {
+        let dir = temp_dir("resume");
+        let mix_seed = 7 ^ hash_to_u64("sha256:data:sha256:recipe");
+        let (base, step) = derive_curve(mix_seed);
+        // 10번째 epoch에서 처음 통과하도록 목표를 잡는다.
+        let target = base + step * 9;
+
+        let full_config = write_config(&dir, "full.json", target, 10);
+        let full_out = dir.join("full");
+        run_train(&full_config, Some(&full_out), None, 0).expect("full run");
+
+        let half_config = write_config(&dir, "half.json", target, 5);
+        let half_out = dir.join("half");
+        let err = run_train(&half_config, Some(&half_out), None, 5).expect_err("half run");
+        assert!(err.starts_with("E_TRAIN_THRESHOLD"), "{}", err);
+        let checkpoint = checkpoint_path(&half_out, 5);
+        assert!(checkpoint.exists());
+
+        let resumed_out = dir.join("resumed");
+        run_train(&full_config, Some(&resumed_out), Some(&checkpoint), 0).expect("resume");
+
+        let full = fs::read(full_out.join("weights.bin")).expect("full weights");
+        let resumed = fs::read(resumed_out.join("weights.bin")).expect("resumed weights");
+        assert_eq!(full.len(), 16);
+        assert_eq!(full, resumed);
+    }
+
+    #[test]
+    fn resume_rejects_checkpoint_from_other_seed() {
+        let dir = temp_dir("mismatch");
+        let config = write_config(&dir, "train.json", u64::MAX, 2);
+        let out = dir.join("out");
+        let _ = run_train(&config, Some(&out), None, 1);
+        let checkpoint = checkpoint_path(&out, 1);
+        let text = fs::read_to_string(&checkpoint).expect("checkpoint");
+        fs::write(
+            &checkpoint,
+            text.replace("\"train_seed\":\"7\"", "\"train_seed\":\"8\""),
+        )
+        .expect("rewrite");
+        let err = run_train(&config, Some(&out), Some(&checkpoint), 0).expect_err("mismatch");
+        assert!(
+            err.starts_with("E_TRAIN_RESUME_MISMATCH train_seed"),
+            "{}",
+            err
+        );
+    }
+}
@@ -2,12 +2,16 @@ use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
-use crate::cli::bogae::{resolve_bogae_out_dir, BogaeMode, OverlayConfig};
+use crate::cli::bogae::{resolve_bogae_out_dir, resolve_bogae_png_path, BogaeMode, OverlayConfig};
 use crate::cli::bogae_console::{render_drawlist_ascii, ConsoleRenderConfig};
+use crate::cli::bogae_png::write_drawlist_png;
 use crate::cli::bogae_web::write_web_assets;
+use crate::cli::run::{extract_setting_madi, run_source_with_state_ticks};
 use crate::core::bogae::{
-    decode_drawlist_detbin_any, hash_drawlist_detbin, BogaeCodec, BogaeError,
+    build_bogae_output_with_trace, decode_drawlist_detbin_any, hash_drawlist_detbin,
+    load_css4_pack, BogaeCodec, BogaeDrawListV1, BogaeError, CmdPolicyConfig,
 };
+use crate::core::State;
 
 pub struct ViewOptions {
     pub bogae: BogaeMode,
@@ -16,13 +20,12 @@ pub struct ViewOptions {
     pub bogae_skin: Option<PathBuf>,
     pub overlay: OverlayConfig,
     pub console_config: ConsoleRenderConfig,
+    pub at_madi: Option<u64>,
     pub no_open: bool,
 }
 
 pub fn run_view(path: &Path, options: ViewOptions) -> Result<(), String> {
-    let bytes = fs::read(path).map_err(|e| e.to_string())?;
-    let (drawlist, codec) =
-        decode_drawlist_detbin_any(&bytes).map_err(|err| format_error(path, err))?;
+    let (drawlist, bytes, codec) = load_frame(path, &options)?;
     if let Some(expected) = options.bogae_codec {
         if expected != codec {
             return Err(format!(
@@ -70,11 +73,62 @@ pub fn run_view(path: &Path, options: ViewOptions) -> Result<(), String> {
                 render_drawlist_ascii(&drawlist, options.console_config)
             );
         }
+        BogaeMode::Png => {
+            let png_path = resolve_bogae_png_path(options.bogae_out.as_deref());
+            write_drawlist_png(&png_path, &drawlist, options.bogae_skin.as_deref())?;
+            println!(
+                "bogae_hash={} cmd_count={} codec={} png={}",
+                hash,
+                cmd_count,
+                codec.tag(),
+                png_path.display()
+            );
+        }
     }
 
     Ok(())
 }
 
+/// `.ddn`이면 `--at-madi`(없으면 설정의 마디, 그것도 없으면 1)만큼 돌린 뒤의 화면을,
+/// 아니면 드로우리스트 detbin 파일을 그대로 읽는다.
+fn load_frame(
+    path: &Path,
+    options: &ViewOptions,
+) -> Result<(BogaeDrawListV1, Vec<u8>, BogaeCodec), String> {
+    let is_source = path.extension().is_some_and(|ext| ext == "ddn");
+    if !is_source {
+        if options.at_madi.is_some() {
+            return Err(format!(
+                "E_VIEW_AT_MADI {}:1:1 --at-madi는 .ddn 입력에만 쓸 수 있습니다",
+                path.display()
+            ));
+        }
+        let bytes = fs::read(path).map_err(|e| e.to_string())?;
+        let (drawlist, codec) =
+            decode_drawlist_detbin_any(&bytes).map_err(|err| format_error(path, err))?;
+        return Ok((drawlist, bytes, codec));
+    }
+    let source = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let ticks = match options.at_madi {
+        Some(madi) => madi,
+        None => extract_setting_madi(&source)?.unwrap_or(1),
+    };
+    let file_label = path.display().to_string();
+    let output = run_source_with_state_ticks(&source, State::new(), ticks)
+        .map_err(|err| err.format(&file_label))?;
+    let pack = load_css4_pack().ok();
+    let trace_lines = output.trace.log_lines();
+    let (built, _) = build_bogae_output_with_trace(
+        &output.state,
+        &trace_lines,
+        pack.as_ref(),
+        CmdPolicyConfig::none(),
+        options.bogae_codec.unwrap_or(BogaeCodec::Bdl1),
+    )
+    .map_err(|err| format_error(path, err))?;
+    Ok((built.drawlist, built.detbin, built.codec))
+}
+
 fn format_error(path: &Path, err: BogaeError) -> String {
     format!("{} {}:1:1 {}", err.code(), path.display(), err.message())
 }
@@ -100,3 +154,99 @@ fn open_in_browser(path: &Path) -> Result<(), String> {
         .map_err(|e| e.to_string())?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{run_view, ViewOptions};
+    use crate::cli::bogae::{BogaeMode, OverlayConfig};
+    use crate::cli::bogae_console::ConsoleRenderConfig;
+    use crate::core::bogae::{encode_drawlist_detbin, AssetRefV1, BogaeCmd, BogaeDrawListV1, Rgba};
+    use crate::core::bogae_raster::{decode_png, encode_png, RasterImage};
+    use std::fs;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    #[test]
+    fn png_mode_writes_canvas_sized_png_with_skin_sprite() {
+        let nonce = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        let root = std::env::temp_dir().join(format!("teul_cli_view_png_{nonce}"));
+        fs::create_dir_all(root.join("assets")).expect("mkdir");
+
+        let mut block = RasterImage::new(2, 2);
+        for pixel in block.pixels.chunks_exact_mut(4) {
+            pixel.copy_from_slice(&[0, 0, 255, 255]);
+        }
+        fs::write(root.join("assets/block.png"), encode_png(&block)).expect("asset");
+        fs::write(
+            root.join("skin.detjson"),
+            r#"{"kind":"bogae_skin_manifest_v1","symbols":[{"key":"sym:block","web":{"asset_uri":"assets/block.png"}}]}"#,
+        )
+        .expect("skin");
+
+        let white = Rgba {
+            r: 255,
+            g: 255,
+            b: 255,
+            a: 255,
+        };
+        let drawlist = BogaeDrawListV1 {
+            width_px: 12,
+            height_px: 7,
+            cmds: vec![
+                BogaeCmd::Clear {
+                    color: Rgba {
+                        r: 0,
+                        g: 0,
+                        b: 0,
+                        a: 255,
+                    },
+                    aa: false,
+                },
+                BogaeCmd::Sprite {
+                    x: 4.0,
+                    y: 2.0,
+                    w: 4.0,
+                    h: 4.0,
+                    tint: white,
+                    asset: AssetRefV1 {
+                        uri: "sym:block".to_string(),
+                        hash_kind: 0,
+                        hash32: None,
+                    },
+                    aa: false,
+                },
+            ],
+        };
+        let input = root.join("frame.bdl1");
+        fs::write(&input, encode_drawlist_detbin(&drawlist)).expect("drawlist");
+
+        let out = root.join("shots/frame.png");
+        run_view(
+            &input,
+            ViewOptions {
+                bogae: BogaeMode::Png,
+                bogae_codec: None,
+                bogae_out: Some(out.clone()),
+                bogae_skin: Some(root.join("skin.detjson")),
+                overlay: OverlayConfig::empty(),
+                console_config: ConsoleRenderConfig::default(),
+                at_madi: None,
+                no_open: true,
+            },
+        )
+        .expect("view png");
+
+        let bytes = fs::read(&out).expect("png");
+        assert_eq!(&bytes[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&bytes[12..16], b"IHDR");
+        assert_eq!(u32::from_be_bytes(bytes[16..20].try_into().unwrap()), 12);
+        assert_eq!(u32::from_be_bytes(bytes[20..24].try_into().unwrap()), 7);
+        let image = decode_png(&bytes).expect("decode");
+        assert_eq!(image.pixel(5, 3).b, 255);
+        assert_eq!(image.pixel(1, 1).b, 0);
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
@@ -1,7 +1,8 @@
 use std::path::Path;
 
 use ddonirang_core::{
-    run_warp_bench, RealmStepInput, StepBatchSoA, WarpBackend, WarpBenchInput, WarpPolicy,
+    run_warp_bench, run_warp_bench_with_probe, GpuProbe, RealmStepInput, StepBatchSoA, WarpBackend,
+    WarpBenchInput, WarpPolicy,
 };
 use serde::{Deserialize, Serialize};
 
@@ -28,6 +29,19 @@ struct WarpBenchOutputView {
     speedup: f64,
     realm_count: usize,
     step_count: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    backend_used: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    breakdown: Option<WarpStageBreakdownView>,
+}
+
+#[derive(Debug, Serialize)]
+struct WarpStageBreakdownView {
+    setup_us: u64,
+    kernel_us: u64,
+    reduction_us: u64,
+    teardown_us: u64,
+    total_us: u64,
 }
 
 fn calc_speedup(cpu_ms: u64, gpu_ms: u64) -> f64 {
@@ -35,12 +49,15 @@ fn calc_speedup(cpu_ms: u64, gpu_ms: u64) -> f64 {
     cpu_ms as f64 / den
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn run_bench(
     path: &Path,
     backend: WarpBackend,
     policy: WarpPolicy,
     threads: usize,
     measure: bool,
+    require_gpu: bool,
+    gpu_probe: Option<GpuProbe>,
     out: Option<&Path>,
 ) -> Result<(), String> {
     let text = read_text(path)?;
@@ -52,6 +69,7 @@ pub fn run_bench(
         .map(|item| RealmStepInput {
             realm_id: item.realm_id,
             delta: item.delta,
+            send: Vec::new(),
         })
         .collect::<Vec<_>>();
     let bench_input = WarpBenchInput {
@@ -61,13 +79,37 @@ pub fn run_bench(
         step_batch: StepBatchSoA::from_inputs(&batch_inputs),
     };
 
-    let output = run_warp_bench(bench_input, backend, policy, threads, measure)?;
+    let simd_requested = matches!(backend, WarpBackend::CpuSimd);
+    let output = match gpu_probe {
+        Some(probe) => run_warp_bench_with_probe(
+            bench_input,
+            backend,
+            policy,
+            threads,
+            measure,
+            require_gpu,
+            probe,
+        )?,
+        None => run_warp_bench(bench_input, backend, policy, threads, measure, require_gpu)?,
+    };
     let view = WarpBenchOutputView {
         cpu_ms: output.cpu_ms,
         gpu_ms: output.gpu_ms,
         speedup: calc_speedup(output.cpu_ms, output.gpu_ms),
         realm_count: output.realm_count,
         step_count: output.step_count,
+        // 기존 출력 형식을 지키려고 cpu-simd를 요청했거나 GPU가 없어 물러섰을 때만 실제 backend를 붙인다.
+        backend_used: (simd_requested || output.gpu_unavailable).then(|| output.backend_label()),
+        breakdown: output
+            .breakdown
+            .as_ref()
+            .map(|item| WarpStageBreakdownView {
+                setup_us: item.setup_us,
+                kernel_us: item.kernel_us,
+                reduction_us: item.reduction_us,
+                teardown_us: item.teardown_us,
+                total_us: item.total_us,
+            }),
     };
     let json =
         serde_json::to_string_pretty(&view).map_err(|err| format!("E_WARP_OUTPUT {}", err))?;
@@ -0,0 +1,108 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread::sleep;
+use std::time::{Duration, SystemTime};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// 연속 저장을 한 번으로 묶기 위해 이 시간 동안 변화가 없어야 다시 실행한다.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+pub const WATCH_SEPARATOR: &str = "----- watch: 변경 감지, 다시 실행 -----";
+
+type Snapshot = BTreeMap<PathBuf, Option<(SystemTime, u64)>>;
+
+/// 감시 대상: 실행 파일과, 실행이 읽는 `ddn.lock` 및 거기 적힌 가지 `.ddn` 파일들.
+pub fn watch_paths(file: &Path) -> Vec<PathBuf> {
+    let mut out = vec![file.to_path_buf()];
+    out.extend(crate::cli::build::project_gaji_paths(file));
+    out.sort();
+    out.dedup();
+    out
+}
+
+fn snapshot(file: &Path) -> Snapshot {
+    watch_paths(file)
+        .into_iter()
+        .map(|path| {
+            let stamp = fs::metadata(&path)
+                .ok()
+                .and_then(|meta| meta.modified().ok().map(|time| (time, meta.len())));
+            (path, stamp)
+        })
+        .collect()
+}
+
+/// 파일이 바뀔 때마다 `run_once`를 다시 부른다. 실행 오류는 출력만 하고 다음 변경을 기다린다.
+/// 사용자가 중단할 때까지 돌아오지 않는다.
+pub fn run_watch<F>(file: &Path, mut run_once: F) -> Result<(), String>
+where
+    F: FnMut() -> Result<(), String>,
+{
+    loop {
+        if let Err(err) = run_once() {
+            eprintln!("{}", err);
+        }
+        let mut last = snapshot(file);
+        wait_for_change(file, &mut last);
+        println!("{}", WATCH_SEPARATOR);
+    }
+}
+
+fn wait_for_change(file: &Path, last: &mut Snapshot) {
+    loop {
+        sleep(POLL_INTERVAL);
+        let current = snapshot(file);
+        if current != *last {
+            *last = settle(file, current);
+            return;
+        }
+    }
+}
+
+fn settle(file: &Path, mut current: Snapshot) -> Snapshot {
+    loop {
+        sleep(DEBOUNCE);
+        let next = snapshot(file);
+        if next == current {
+            return next;
+        }
+        current = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn watch_paths_include_gaji_sources_and_snapshot_tracks_edits() {
+        let root = std::env::temp_dir().join(format!("teul_watch_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("gaji").join("pkg")).expect("mkdir");
+        let main = root.join("main.ddn");
+        fs::write(&main, "x <- 1.\n").expect("write main");
+        let lib = root.join("gaji").join("pkg").join("lib.ddn");
+        fs::write(&lib, "y <- 2.\n").expect("write lib");
+        fs::write(root.join("gaji").join("pkg").join("other.ddn"), "z <- 3.\n")
+            .expect("write unlocked");
+        let lock = serde_json::json!({
+            "packages": [{
+                "id": "나눔/pkg",
+                "version": "0.1.0",
+                "path": "pkg",
+                "files": [{ "path": "lib.ddn" }, { "path": "gaji.toml" }],
+            }],
+        });
+        fs::write(root.join("ddn.lock"), lock.to_string()).expect("write lock");
+
+        let paths = watch_paths(&main);
+        assert_eq!(paths, vec![root.join("ddn.lock"), lib, main.clone()]);
+
+        let before = snapshot(&main);
+        fs::write(&main, "x <- 10.\n").expect("rewrite main");
+        assert_ne!(before, snapshot(&main));
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
@@ -4,7 +4,7 @@ use std::io::{self, BufRead, BufReader, Read, Write};
 use std::path::Path;
 use std::process::Command;
 
-use crate::cli::run::RunEmitSink;
+use crate::cli::run::{run_error_exit_code, RunEmitSink};
 use crate::{build_command_string_from_parts, execute_run_command, Cli, Commands, RunCommandArgs};
 
 pub fn run() -> Result<(), String> {
@@ -272,6 +272,8 @@ fn run_file_inproc_inner(path: &str, args: &[String]) -> Result<InprocReport, St
         age_target,
         state,
         state_file,
+        state_json,
+        env,
         diag_jsonl,
         diag_report_out,
         enable_repro,
@@ -280,6 +282,7 @@ fn run_file_inproc_inner(path: &str, args: &[String]) -> Result<InprocReport, St
         summary_json,
         artifact,
         trace_json,
+        profile,
         proof_out,
         proof_cert_key,
         geoul_out,
@@ -298,8 +301,10 @@ fn run_file_inproc_inner(path: &str, args: &[String]) -> Result<InprocReport, St
         console_cell_aspect,
         console_grid,
         console_panel_cols,
+        console_palette,
         until_gameover,
         gameover_key,
+        max_faults,
         sam,
         record_sam,
         sam_live,
@@ -311,7 +316,9 @@ fn run_file_inproc_inner(path: &str, args: &[String]) -> Result<InprocReport, St
         open_bundle,
         no_open,
         unsafe_open,
+        deterministic_check,
         lang_mode,
+        watch,
     } = cli.command
     else {
         return Ok(InprocReport {
@@ -325,6 +332,18 @@ fn run_file_inproc_inner(path: &str, args: &[String]) -> Result<InprocReport, St
         });
     };
 
+    if watch {
+        return Ok(InprocReport {
+            ok: false,
+            exit_code: 2,
+            stdout: Vec::new(),
+            stderr: vec!["worker에서는 --watch를 쓸 수 없습니다".to_string()],
+            state_hash: None,
+            trace_hash: None,
+            bogae_hash: None,
+        });
+    }
+
     let mut emitter = CaptureEmitter::new();
     let run_args = RunCommandArgs {
         file,
@@ -333,6 +352,8 @@ fn run_file_inproc_inner(path: &str, args: &[String]) -> Result<InprocReport, St
         age_target,
         state,
         state_file,
+        state_json,
+        env,
         diag_jsonl,
         diag_report_out,
         enable_repro,
@@ -341,6 +362,7 @@ fn run_file_inproc_inner(path: &str, args: &[String]) -> Result<InprocReport, St
         summary_json,
         artifact,
         trace_json,
+        profile,
         proof_out,
         proof_cert_key,
         geoul_out,
@@ -359,8 +381,10 @@ fn run_file_inproc_inner(path: &str, args: &[String]) -> Result<InprocReport, St
         console_cell_aspect,
         console_grid,
         console_panel_cols,
+        console_palette,
         until_gameover,
         gameover_key,
+        max_faults,
         sam,
         record_sam,
         sam_live,
@@ -372,13 +396,17 @@ fn run_file_inproc_inner(path: &str, args: &[String]) -> Result<InprocReport, St
         open_bundle,
         no_open,
         unsafe_open,
+        deterministic_check,
         lang_mode,
         run_command_override: Some(build_command_string_from_parts(&cli_args)),
     };
 
     let result = execute_run_command(run_args, &mut emitter);
     let ok = result.is_ok();
-    let exit_code = if ok { 0 } else { 1 };
+    let exit_code = match result.as_ref() {
+        Ok(()) => 0,
+        Err(err) => run_error_exit_code(err) as i64,
+    };
     if let Err(err) = result {
         emitter.err(&err);
     }
@@ -496,3 +524,49 @@ fn order_value(value: &Value) -> Value {
         _ => value.clone(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_cli_max_faults_stops_at_first_fault() {
+        let path = std::env::temp_dir().join(format!(
+            "ddn_max_faults_{}.ddn",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .expect("time")
+                .as_nanos()
+        ));
+        std::fs::write(
+            &path,
+            "채비 {\n  t:수 <- 2000000000.\n}.\n\n(매마디)마다 {\n  t <- t * t.\n}.\n",
+        )
+        .expect("write ddn");
+        let args = ["--madi", "5", "--max-faults", "1"].map(String::from);
+        let report = run_file_inproc_inner(&path.display().to_string(), &args).expect("run inproc");
+        // 포화된 값은 마디마다 다시 포화하므로 한도 3은 세 번째 마디에서 닿는다.
+        let args = ["--madi", "5", "--max-faults", "3"].map(String::from);
+        let later = run_file_inproc_inner(&path.display().to_string(), &args).expect("run inproc");
+        let _ = std::fs::remove_file(&path);
+        assert!(!report.ok);
+        assert_eq!(report.exit_code, crate::cli::run::EXIT_MAX_FAULTS as i64);
+        assert!(report
+            .stdout
+            .contains(&"fault_stop: madi=0 faults=1 limit=1".to_string()));
+        assert!(report
+            .stdout
+            .contains(&"fault_kinds: overflow=1".to_string()));
+        assert!(report
+            .stderr
+            .iter()
+            .any(|line| line.starts_with("E_RUN_MAX_FAULTS")));
+        assert!(report.state_hash.is_none());
+        assert!(later
+            .stdout
+            .contains(&"fault_stop: madi=2 faults=3 limit=3".to_string()));
+        assert!(later
+            .stdout
+            .contains(&"fault_kinds: overflow=3".to_string()));
+    }
+}
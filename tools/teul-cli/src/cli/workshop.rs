@@ -1,7 +1,19 @@
 use std::fs;
 use std::path::Path;
 
+use serde_json::Value;
+
 use super::detjson::write_text;
+use super::patch::patch_files_under;
+use crate::canon;
+
+/// 대상이 옮겨졌거나 바뀌어 patch가 더는 맞지 않음을 뜻하는 patch 오류들.
+const PATCH_DRIFT_CODES: &[&str] = &[
+    "E_PATCH_ANCHOR",
+    "E_PATCH_BLOCK",
+    "E_PATCH_BEFORE_MISMATCH",
+    "E_PATCH_LINE_DRIFT",
+];
 
 pub fn run_gen(geoul: &Path, out_dir: &Path) -> Result<(), String> {
     fs::create_dir_all(out_dir).map_err(|e| e.to_string())?;
@@ -17,9 +29,33 @@ pub fn run_gen(geoul: &Path, out_dir: &Path) -> Result<(), String> {
     Ok(())
 }
 
+/// `changes`가 있는 patch는 workshop 안의 대상 파일에 먼저 맞춰 본다. 앵커가 사라졌거나
+/// 옮겨졌거나 `before`가 달라졌으면 `E_WORKSHOP_PATCH_DRIFT`, 적용한 `.ddn`이 파싱되지
+/// 않으면 `E_WORKSHOP_PATCH_PARSE`로 멈추고, 이때는 어떤 파일도 바꾸지 않는다.
 pub fn run_apply(workshop_dir: &Path, patch: &Path) -> Result<(), String> {
     fs::create_dir_all(workshop_dir).map_err(|e| e.to_string())?;
     let content = fs::read_to_string(patch).map_err(|e| e.to_string())?;
+    let has_changes = serde_json::from_str::<Value>(&content)
+        .map(|value| value.get("changes").is_some())
+        .unwrap_or(false);
+    if has_changes {
+        let patched = patch_files_under(workshop_dir, patch).map_err(|err| {
+            if PATCH_DRIFT_CODES.iter().any(|code| err.starts_with(code)) {
+                format!("E_WORKSHOP_PATCH_DRIFT {}", err)
+            } else {
+                err
+            }
+        })?;
+        for (file, text) in &patched {
+            if Path::new(file).extension().and_then(|ext| ext.to_str()) == Some("ddn") {
+                canon::canonicalize(text, false)
+                    .map_err(|err| format!("E_WORKSHOP_PATCH_PARSE {}: {}", file, err))?;
+            }
+        }
+        for (file, text) in &patched {
+            fs::write(workshop_dir.join(file), text).map_err(|e| e.to_string())?;
+        }
+    }
     let target = workshop_dir.join("applied.patch.detjson");
     write_text(&target, &content)?;
     println!("applied_patch={}", target.display());
@@ -64,3 +100,78 @@ fn escape_html(input: &str) -> String {
         .replace('>', "&gt;")
         .replace('"', "&quot;")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::run_apply;
+    use std::fs;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    const SOURCE: &str = "채비 {\n  점수:수 <- 0.\n}.\n(매마디)마다 {\n  점수 <- 점수 + 1.\n}.\n";
+    const PATCH: &str = r#"{"changes":[{"kind":"replace_block","target":{"file":"main.ddn","anchor":"(매마디)마다","line":4},"before":["(매마디)마다 {","  점수 <- 점수 + 1.","}."],"after":["(매마디)마다 {","  점수 <- 점수 + 2.","}."]}]}"#;
+
+    fn temp_workshop(name: &str) -> std::path::PathBuf {
+        let nonce = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("teul_cli_workshop_{name}_{nonce}"));
+        fs::create_dir_all(&dir).expect("mkdir");
+        dir
+    }
+
+    #[test]
+    fn apply_refuses_patch_whose_target_moved() {
+        let fresh = temp_workshop("fresh");
+        fs::write(fresh.join("main.ddn"), SOURCE).expect("source");
+        fs::write(fresh.join("change.patch.json"), PATCH).expect("patch");
+        run_apply(&fresh, &fresh.join("change.patch.json")).expect("apply");
+        assert!(fs::read_to_string(fresh.join("main.ddn"))
+            .expect("source")
+            .contains("점수 <- 점수 + 2."));
+
+        let edited = temp_workshop("edited");
+        let moved = format!("채비 {{\n  보너스:수 <- 3.\n}}.\n{}", SOURCE);
+        fs::write(edited.join("main.ddn"), &moved).expect("source");
+        fs::write(edited.join("change.patch.json"), PATCH).expect("patch");
+        let err = run_apply(&edited, &edited.join("change.patch.json")).expect_err("drift");
+        assert!(
+            err.starts_with("E_WORKSHOP_PATCH_DRIFT E_PATCH_LINE_DRIFT"),
+            "{err}"
+        );
+        assert_eq!(
+            fs::read_to_string(edited.join("main.ddn")).expect("source"),
+            moved
+        );
+        assert!(!edited.join("applied.patch.detjson").exists());
+
+        let _ = fs::remove_dir_all(&fresh);
+        let _ = fs::remove_dir_all(&edited);
+    }
+
+    fn assert_apply_rejects_target(workshop: &std::path::Path, target: &str) {
+        let patch = PATCH.replace("\"file\":\"main.ddn\"", &format!("\"file\":\"{target}\""));
+        fs::write(workshop.join("change.patch.json"), patch).expect("patch");
+        let err = run_apply(workshop, &workshop.join("change.patch.json")).expect_err("path");
+        assert!(err.starts_with("E_PATCH_PATH "), "{err}");
+        assert!(!workshop.join("applied.patch.detjson").exists());
+    }
+
+    #[test]
+    fn apply_refuses_targets_outside_the_workshop() {
+        let outside = temp_workshop("outside");
+        fs::write(outside.join("x.ddn"), SOURCE).expect("source");
+        let workshop = outside.join("workshop");
+        fs::create_dir_all(&workshop).expect("mkdir");
+
+        assert_apply_rejects_target(&workshop, "../x.ddn");
+        let absolute = outside.join("x.ddn");
+        assert_apply_rejects_target(&workshop, &absolute.to_string_lossy());
+        assert_eq!(
+            fs::read_to_string(outside.join("x.ddn")).expect("source"),
+            SOURCE
+        );
+
+        let _ = fs::remove_dir_all(&outside);
+    }
+}
@@ -7,18 +7,41 @@ const CODEC_BDL1: &[u8; 4] = b"BDL1";
 #[derive(Debug)]
 pub enum BdlPacketError {
     InvalidMagic,
-    UnsupportedVersion { version: u32 },
-    UnsupportedCodec { codec: [u8; 4] },
-    LengthMismatch { expected: u32, actual: u32 },
-    HashMismatch,
-    Truncated,
-    PayloadTooLarge { len: usize },
+    UnsupportedVersion {
+        version: u32,
+    },
+    UnsupportedCodec {
+        codec: [u8; 4],
+    },
+    ReadFailed,
+    /// payload 뒤에 남는 바이트가 있다. `offset`은 payload가 끝나는 자리.
+    LengthMismatch {
+        expected: u32,
+        actual: u32,
+        offset: usize,
+    },
+    /// `offset`은 payload가 시작하는 자리.
+    HashMismatch {
+        offset: usize,
+    },
+    /// `offset`에서 시작하는 `needed`바이트 필드를 읽어야 하는데 `available`바이트만 남았다.
+    Truncated {
+        offset: usize,
+        needed: usize,
+        available: usize,
+    },
+    PayloadTooLarge {
+        len: usize,
+    },
 }
 
 impl BdlPacketError {
     pub fn code(&self) -> &'static str {
         match self {
             BdlPacketError::PayloadTooLarge { .. } => "E_BDL1_PACKET_TOO_LARGE",
+            BdlPacketError::LengthMismatch { .. }
+            | BdlPacketError::HashMismatch { .. }
+            | BdlPacketError::Truncated { .. } => "E_BDL_CORRUPT",
             _ => "E_BDL1_PACKET_INVALID",
         }
     }
@@ -32,14 +55,28 @@ impl BdlPacketError {
             BdlPacketError::UnsupportedCodec { codec } => {
                 format!("지원하지 않는 codec: {}", String::from_utf8_lossy(codec))
             }
-            BdlPacketError::LengthMismatch { expected, actual } => {
+            BdlPacketError::ReadFailed => "packet 파일을 읽을 수 없음".to_string(),
+            BdlPacketError::LengthMismatch {
+                expected,
+                actual,
+                offset,
+            } => {
                 format!(
-                    "payload 길이 불일치 expected={} actual={}",
-                    expected, actual
+                    "payload 길이 불일치 offset={} expected={} actual={}",
+                    offset, expected, actual
                 )
             }
-            BdlPacketError::HashMismatch => "payload_hash 불일치".to_string(),
-            BdlPacketError::Truncated => "packet 길이가 부족함".to_string(),
+            BdlPacketError::HashMismatch { offset } => {
+                format!("payload_hash 불일치 offset={}", offset)
+            }
+            BdlPacketError::Truncated {
+                offset,
+                needed,
+                available,
+            } => format!(
+                "packet 길이가 부족함 offset={} needed={} available={}",
+                offset, needed, available
+            ),
             BdlPacketError::PayloadTooLarge { len } => {
                 format!("payload 길이가 u32 범위를 초과: {}", len)
             }
@@ -66,11 +103,13 @@ pub fn encode_bdl1_packet(payload: &[u8]) -> Result<Vec<u8>, BdlPacketError> {
 }
 
 pub fn decode_bdl1_packet(bytes: &[u8]) -> Result<(Vec<u8>, BdlPacketInfo), BdlPacketError> {
-    let mut idx = 0usize;
-    let magic = take(bytes, &mut idx, 4)?;
-    if magic != MAGIC {
+    // 앞부분부터 magic과 다르면 잘린 packet이 아니라 다른 형식이다.
+    let prefix = &bytes[..bytes.len().min(MAGIC.len())];
+    if prefix != &MAGIC[..prefix.len()] {
         return Err(BdlPacketError::InvalidMagic);
     }
+    let mut idx = 0usize;
+    take(bytes, &mut idx, 4)?;
     let version = read_u32(bytes, &mut idx)?;
     if version != VERSION {
         return Err(BdlPacketError::UnsupportedVersion { version });
@@ -83,16 +122,20 @@ pub fn decode_bdl1_packet(bytes: &[u8]) -> Result<(Vec<u8>, BdlPacketInfo), BdlP
     }
     let len = read_u32(bytes, &mut idx)?;
     let hash = take(bytes, &mut idx, 32)?;
+    let payload_offset = idx;
     let payload = take(bytes, &mut idx, len as usize)?;
     if idx != bytes.len() {
         return Err(BdlPacketError::LengthMismatch {
             expected: len,
-            actual: (payload.len()) as u32,
+            actual: (bytes.len() - payload_offset) as u32,
+            offset: idx,
         });
     }
     let expected = blake3::hash(payload);
     if expected.as_bytes() != hash {
-        return Err(BdlPacketError::HashMismatch);
+        return Err(BdlPacketError::HashMismatch {
+            offset: payload_offset,
+        });
     }
     let mut hash_out = [0u8; 32];
     hash_out.copy_from_slice(hash);
@@ -112,7 +155,11 @@ pub fn payload_hash_string(hash: &[u8; 32]) -> String {
 fn take<'a>(bytes: &'a [u8], idx: &mut usize, len: usize) -> Result<&'a [u8], BdlPacketError> {
     let end = idx.saturating_add(len);
     if end > bytes.len() {
-        return Err(BdlPacketError::Truncated);
+        return Err(BdlPacketError::Truncated {
+            offset: *idx,
+            needed: len,
+            available: bytes.len().saturating_sub(*idx),
+        });
     }
     let out = &bytes[*idx..end];
     *idx = end;
@@ -0,0 +1,559 @@
+use std::collections::BTreeMap;
+use std::io::{Read, Write};
+
+use flate2::read::ZlibDecoder;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+
+use crate::core::bogae::{BogaeCmd, BogaeDrawListV1, Rgba};
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+const CURVE_SEGMENTS: u32 = 16;
+const ARC_SEGMENTS_PER_TURN: f32 = 64.0;
+
+/// RGBA8 픽셀 버퍼. 한 줄은 `width * 4` 바이트다.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RasterImage {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<u8>,
+}
+
+impl RasterImage {
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![0; width as usize * height as usize * 4],
+        }
+    }
+
+    pub fn pixel(&self, x: u32, y: u32) -> Rgba {
+        let idx = (y as usize * self.width as usize + x as usize) * 4;
+        Rgba {
+            r: self.pixels[idx],
+            g: self.pixels[idx + 1],
+            b: self.pixels[idx + 2],
+            a: self.pixels[idx + 3],
+        }
+    }
+
+    fn blend(&mut self, x: i64, y: i64, color: Rgba) {
+        if x < 0 || y < 0 || x >= self.width as i64 || y >= self.height as i64 {
+            return;
+        }
+        let idx = (y as usize * self.width as usize + x as usize) * 4;
+        let src_a = color.a as u32;
+        if src_a == 0 {
+            return;
+        }
+        if src_a == 255 {
+            self.pixels[idx..idx + 4].copy_from_slice(&[color.r, color.g, color.b, color.a]);
+            return;
+        }
+        let dst_a = self.pixels[idx + 3] as u32;
+        let inv = 255 - src_a;
+        let out_a = src_a + dst_a * inv / 255;
+        for (offset, src) in [color.r, color.g, color.b].into_iter().enumerate() {
+            let dst = self.pixels[idx + offset] as u32;
+            let num = src as u32 * src_a + dst * dst_a * inv / 255;
+            self.pixels[idx + offset] = (num / out_a.max(1)) as u8;
+        }
+        self.pixels[idx + 3] = out_a as u8;
+    }
+
+    /// 픽셀 중심이 `inside`를 만족하는 칸만 칠한다.
+    fn fill_where(
+        &mut self,
+        bounds: (f32, f32, f32, f32),
+        color: Rgba,
+        inside: impl Fn(f32, f32) -> bool,
+    ) {
+        let (x0, y0, x1, y1) = bounds;
+        let px0 = (x0.floor() as i64).max(0);
+        let py0 = (y0.floor() as i64).max(0);
+        let px1 = (x1.ceil() as i64).min(self.width as i64);
+        let py1 = (y1.ceil() as i64).min(self.height as i64);
+        for py in py0..py1 {
+            for px in px0..px1 {
+                if inside(px as f32 + 0.5, py as f32 + 0.5) {
+                    self.blend(px, py, color);
+                }
+            }
+        }
+    }
+
+    fn fill_rect(&mut self, x: f32, y: f32, w: f32, h: f32, color: Rgba) {
+        let (x0, x1) = ordered(x, x + w);
+        let (y0, y1) = ordered(y, y + h);
+        self.fill_where((x0, y0, x1, y1), color, |px, py| {
+            px >= x0 && px < x1 && py >= y0 && py < y1
+        });
+    }
+
+    fn stroke_segment(&mut self, a: (f32, f32), b: (f32, f32), thickness: f32, color: Rgba) {
+        let half = thickness.abs().max(1.0) / 2.0;
+        let bounds = (
+            a.0.min(b.0) - half,
+            a.1.min(b.1) - half,
+            a.0.max(b.0) + half,
+            a.1.max(b.1) + half,
+        );
+        self.fill_where(bounds, color, |px, py| {
+            segment_distance((px, py), a, b) <= half
+        });
+    }
+
+    fn stroke_polyline(&mut self, points: &[(f32, f32)], thickness: f32, color: Rgba) {
+        for pair in points.windows(2) {
+            self.stroke_segment(pair[0], pair[1], thickness, color);
+        }
+    }
+
+    fn draw_sprite(&mut self, x: f32, y: f32, w: f32, h: f32, image: &RasterImage, tint: Rgba) {
+        let (x0, x1) = ordered(x, x + w);
+        let (y0, y1) = ordered(y, y + h);
+        if x1 <= x0 || y1 <= y0 || image.width == 0 || image.height == 0 {
+            return;
+        }
+        let px0 = (x0.floor() as i64).max(0);
+        let py0 = (y0.floor() as i64).max(0);
+        let px1 = (x1.ceil() as i64).min(self.width as i64);
+        let py1 = (y1.ceil() as i64).min(self.height as i64);
+        for py in py0..py1 {
+            let cy = py as f32 + 0.5;
+            if cy < y0 || cy >= y1 {
+                continue;
+            }
+            let sy = (((cy - y0) / (y1 - y0)) * image.height as f32) as u32;
+            for px in px0..px1 {
+                let cx = px as f32 + 0.5;
+                if cx < x0 || cx >= x1 {
+                    continue;
+                }
+                let sx = (((cx - x0) / (x1 - x0)) * image.width as f32) as u32;
+                let src = image.pixel(sx.min(image.width - 1), sy.min(image.height - 1));
+                self.blend(px, py, modulate(src, tint));
+            }
+        }
+    }
+}
+
+/// 드로우리스트를 캔버스 크기 그대로 그린다.
+///
+/// 안티에일리어싱(`aa`)은 쓰지 않고 픽셀 중심 포함 여부로만 칠해서 같은 입력이면
+/// 같은 픽셀이 나온다. 글꼴은 없으므로 글자는 글자 칸마다 상자로 그린다.
+/// `sprites`에 없는 스프라이트는 tint 색 사각형으로 대신한다.
+pub fn rasterize_drawlist(
+    drawlist: &BogaeDrawListV1,
+    sprites: &BTreeMap<String, RasterImage>,
+) -> RasterImage {
+    let mut image = RasterImage::new(drawlist.width_px, drawlist.height_px);
+    for cmd in &drawlist.cmds {
+        match cmd {
+            BogaeCmd::Clear { color, .. } => {
+                for pixel in image.pixels.chunks_exact_mut(4) {
+                    pixel.copy_from_slice(&[color.r, color.g, color.b, color.a]);
+                }
+            }
+            BogaeCmd::RectFill {
+                x, y, w, h, color, ..
+            } => image.fill_rect(*x, *y, *w, *h, *color),
+            BogaeCmd::RectStroke {
+                x,
+                y,
+                w,
+                h,
+                thickness,
+                color,
+                ..
+            } => {
+                let (x0, x1) = ordered(*x, *x + *w);
+                let (y0, y1) = ordered(*y, *y + *h);
+                let t = thickness.abs().max(1.0);
+                image.fill_where((x0, y0, x1, y1), *color, |px, py| {
+                    let inside = px >= x0 && px < x1 && py >= y0 && py < y1;
+                    let inner = px >= x0 + t && px < x1 - t && py >= y0 + t && py < y1 - t;
+                    inside && !inner
+                });
+            }
+            BogaeCmd::Line {
+                x1,
+                y1,
+                x2,
+                y2,
+                thickness,
+                color,
+                ..
+            } => image.stroke_segment((*x1, *y1), (*x2, *y2), *thickness, *color),
+            BogaeCmd::Text {
+                x,
+                y,
+                size_px,
+                color,
+                text,
+                ..
+            } => {
+                let size = size_px.abs().max(1.0);
+                let advance = size * 0.6;
+                for (idx, ch) in text.chars().enumerate() {
+                    if ch.is_whitespace() {
+                        continue;
+                    }
+                    let left = *x + advance * idx as f32 + size * 0.05;
+                    image.fill_rect(left, *y + size * 0.2, size * 0.5, size * 0.7, *color);
+                }
+            }
+            BogaeCmd::Sprite {
+                x,
+                y,
+                w,
+                h,
+                tint,
+                asset,
+                ..
+            } => {
+                if tint.a == 0 {
+                    continue;
+                }
+                match sprites.get(&asset.uri) {
+                    Some(sprite) => image.draw_sprite(*x, *y, *w, *h, sprite, *tint),
+                    None => image.fill_rect(*x, *y, *w, *h, *tint),
+                }
+            }
+            BogaeCmd::CircleFill {
+                cx, cy, r, color, ..
+            } => {
+                let (cx, cy, r) = (*cx, *cy, r.abs());
+                image.fill_where((cx - r, cy - r, cx + r, cy + r), *color, |px, py| {
+                    (px - cx).powi(2) + (py - cy).powi(2) <= r * r
+                });
+            }
+            BogaeCmd::CircleStroke {
+                cx,
+                cy,
+                r,
+                thickness,
+                color,
+                ..
+            } => {
+                let (cx, cy, r) = (*cx, *cy, r.abs());
+                let half = thickness.abs().max(1.0) / 2.0;
+                let outer = r + half;
+                image.fill_where(
+                    (cx - outer, cy - outer, cx + outer, cy + outer),
+                    *color,
+                    |px, py| ((px - cx).hypot(py - cy) - r).abs() <= half,
+                );
+            }
+            BogaeCmd::ArcStroke {
+                cx,
+                cy,
+                r,
+                start_turn,
+                sweep_turn,
+                thickness,
+                color,
+                ..
+            } => {
+                let segments = ((sweep_turn.abs() * ARC_SEGMENTS_PER_TURN).ceil() as u32).max(1);
+                let points = (0..=segments)
+                    .map(|step| {
+                        let turn = start_turn + sweep_turn * step as f32 / segments as f32;
+                        let angle = turn * std::f32::consts::TAU;
+                        (cx + r * angle.cos(), cy + r * angle.sin())
+                    })
+                    .collect::<Vec<_>>();
+                image.stroke_polyline(&points, *thickness, *color);
+            }
+            BogaeCmd::CurveCubicStroke {
+                p0x,
+                p0y,
+                p1x,
+                p1y,
+                p2x,
+                p2y,
+                p3x,
+                p3y,
+                thickness,
+                color,
+                ..
+            } => {
+                let points = (0..=CURVE_SEGMENTS)
+                    .map(|step| {
+                        let t = step as f32 / CURVE_SEGMENTS as f32;
+                        let u = 1.0 - t;
+                        let (a, b, c, d) = (u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t);
+                        (
+                            a * p0x + b * p1x + c * p2x + d * p3x,
+                            a * p0y + b * p1y + c * p2y + d * p3y,
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                image.stroke_polyline(&points, *thickness, *color);
+            }
+        }
+    }
+    image
+}
+
+/// 필터 없이(filter 0) RGBA8 한 장짜리 PNG로 묶는다.
+pub fn encode_png(image: &RasterImage) -> Vec<u8> {
+    let stride = image.width as usize * 4;
+    let mut raw = Vec::with_capacity((stride + 1) * image.height as usize);
+    for row in image
+        .pixels
+        .chunks_exact(stride.max(1))
+        .take(image.height as usize)
+    {
+        raw.push(0);
+        raw.extend_from_slice(row);
+    }
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(&raw)
+        .expect("zlib encoding into a Vec cannot fail");
+    let idat = encoder
+        .finish()
+        .expect("zlib encoding into a Vec cannot fail");
+
+    let mut ihdr = Vec::with_capacity(13);
+    ihdr.extend_from_slice(&image.width.to_be_bytes());
+    ihdr.extend_from_slice(&image.height.to_be_bytes());
+    ihdr.extend_from_slice(&[8, 6, 0, 0, 0]);
+
+    let mut out = PNG_SIGNATURE.to_vec();
+    write_chunk(&mut out, b"IHDR", &ihdr);
+    write_chunk(&mut out, b"IDAT", &idat);
+    write_chunk(&mut out, b"IEND", &[]);
+    out
+}
+
+/// 스킨 에셋용 PNG 디코더. 8비트, 비인터레이스의 RGB/RGBA/회색조만 읽는다.
+pub fn decode_png(bytes: &[u8]) -> Result<RasterImage, String> {
+    if bytes.len() < 8 || bytes[..8] != PNG_SIGNATURE {
+        return Err("E_BOGAE_PNG_DECODE PNG 서명이 아닙니다".to_string());
+    }
+    let mut pos = 8;
+    let mut header = None;
+    let mut idat = Vec::new();
+    while pos + 8 <= bytes.len() {
+        let len = u32::from_be_bytes([bytes[pos], bytes[pos + 1], bytes[pos + 2], bytes[pos + 3]])
+            as usize;
+        let kind = &bytes[pos + 4..pos + 8];
+        let body_start = pos + 8;
+        let Some(body_end) = body_start
+            .checked_add(len)
+            .filter(|end| end + 4 <= bytes.len())
+        else {
+            return Err("E_BOGAE_PNG_DECODE 청크 길이가 파일을 넘습니다".to_string());
+        };
+        let body = &bytes[body_start..body_end];
+        match kind {
+            b"IHDR" if body.len() == 13 => {
+                let width = u32::from_be_bytes([body[0], body[1], body[2], body[3]]);
+                let height = u32::from_be_bytes([body[4], body[5], body[6], body[7]]);
+                header = Some((width, height, body[8], body[9], body[12]));
+            }
+            b"IDAT" => idat.extend_from_slice(body),
+            b"IEND" => break,
+            _ => {}
+        }
+        pos = body_end + 4;
+    }
+    let Some((width, height, depth, color_type, interlace)) = header else {
+        return Err("E_BOGAE_PNG_DECODE IHDR이 없습니다".to_string());
+    };
+    let channels = match color_type {
+        0 => 1,
+        2 => 3,
+        4 => 2,
+        6 => 4,
+        other => {
+            return Err(format!(
+                "E_BOGAE_PNG_DECODE 지원하지 않는 색 형식입니다: {}",
+                other
+            ))
+        }
+    };
+    if depth != 8 || interlace != 0 {
+        return Err("E_BOGAE_PNG_DECODE 8비트 비인터레이스 PNG만 읽습니다".to_string());
+    }
+    let mut raw = Vec::new();
+    ZlibDecoder::new(idat.as_slice())
+        .read_to_end(&mut raw)
+        .map_err(|e| format!("E_BOGAE_PNG_DECODE {}", e))?;
+    let stride = width as usize * channels;
+    if raw.len() < (stride + 1) * height as usize {
+        return Err("E_BOGAE_PNG_DECODE 픽셀 데이터가 모자랍니다".to_string());
+    }
+    let mut rows = vec![0u8; stride * height as usize];
+    for y in 0..height as usize {
+        let filter = raw[y * (stride + 1)];
+        let line = &raw[y * (stride + 1) + 1..(y + 1) * (stride + 1)];
+        let (done, rest) = rows.split_at_mut(y * stride);
+        let prev = if y == 0 {
+            None
+        } else {
+            Some(&done[(y - 1) * stride..])
+        };
+        unfilter_row(filter, line, prev, &mut rest[..stride], channels)?;
+    }
+    let mut image = RasterImage::new(width, height);
+    for (dst, src) in image
+        .pixels
+        .chunks_exact_mut(4)
+        .zip(rows.chunks_exact(channels))
+    {
+        let rgba = match channels {
+            1 => [src[0], src[0], src[0], 255],
+            2 => [src[0], src[0], src[0], src[1]],
+            3 => [src[0], src[1], src[2], 255],
+            _ => [src[0], src[1], src[2], src[3]],
+        };
+        dst.copy_from_slice(&rgba);
+    }
+    Ok(image)
+}
+
+fn unfilter_row(
+    filter: u8,
+    line: &[u8],
+    prev: Option<&[u8]>,
+    out: &mut [u8],
+    bpp: usize,
+) -> Result<(), String> {
+    for idx in 0..line.len() {
+        let left = if idx >= bpp { out[idx - bpp] } else { 0 };
+        let up = prev.map_or(0, |row| row[idx]);
+        let up_left = match prev {
+            Some(row) if idx >= bpp => row[idx - bpp],
+            _ => 0,
+        };
+        let predictor = match filter {
+            0 => 0,
+            1 => left,
+            2 => up,
+            3 => ((left as u16 + up as u16) / 2) as u8,
+            4 => paeth(left, up, up_left),
+            other => {
+                return Err(format!(
+                    "E_BOGAE_PNG_DECODE 알 수 없는 필터입니다: {}",
+                    other
+                ))
+            }
+        };
+        out[idx] = line[idx].wrapping_add(predictor);
+    }
+    Ok(())
+}
+
+fn paeth(a: u8, b: u8, c: u8) -> u8 {
+    let p = a as i16 + b as i16 - c as i16;
+    let pa = (p - a as i16).abs();
+    let pb = (p - b as i16).abs();
+    let pc = (p - c as i16).abs();
+    if pa <= pb && pa <= pc {
+        a
+    } else if pb <= pc {
+        b
+    } else {
+        c
+    }
+}
+
+fn write_chunk(out: &mut Vec<u8>, kind: &[u8; 4], body: &[u8]) {
+    out.extend_from_slice(&(body.len() as u32).to_be_bytes());
+    out.extend_from_slice(kind);
+    out.extend_from_slice(body);
+    let mut hasher = crc32fast::Hasher::new();
+    hasher.update(kind);
+    hasher.update(body);
+    out.extend_from_slice(&hasher.finalize().to_be_bytes());
+}
+
+fn modulate(src: Rgba, tint: Rgba) -> Rgba {
+    let mul = |a: u8, b: u8| ((a as u16 * b as u16 + 127) / 255) as u8;
+    Rgba {
+        r: mul(src.r, tint.r),
+        g: mul(src.g, tint.g),
+        b: mul(src.b, tint.b),
+        a: mul(src.a, tint.a),
+    }
+}
+
+fn ordered(a: f32, b: f32) -> (f32, f32) {
+    if a <= b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+fn segment_distance(p: (f32, f32), a: (f32, f32), b: (f32, f32)) -> f32 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let len_sq = dx * dx + dy * dy;
+    let t = if len_sq == 0.0 {
+        0.0
+    } else {
+        (((p.0 - a.0) * dx + (p.1 - a.1) * dy) / len_sq).clamp(0.0, 1.0)
+    };
+    (p.0 - (a.0 + t * dx)).hypot(p.1 - (a.1 + t * dy))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Rgba = Rgba {
+        r: 255,
+        g: 0,
+        b: 0,
+        a: 255,
+    };
+    const BLACK: Rgba = Rgba {
+        r: 0,
+        g: 0,
+        b: 0,
+        a: 255,
+    };
+
+    #[test]
+    fn rect_fill_covers_pixel_centers_only() {
+        let drawlist = BogaeDrawListV1 {
+            width_px: 4,
+            height_px: 3,
+            cmds: vec![
+                BogaeCmd::Clear {
+                    color: BLACK,
+                    aa: false,
+                },
+                BogaeCmd::RectFill {
+                    x: 1.0,
+                    y: 1.0,
+                    w: 2.0,
+                    h: 1.0,
+                    color: RED,
+                    aa: false,
+                },
+            ],
+        };
+        let image = rasterize_drawlist(&drawlist, &BTreeMap::new());
+        assert_eq!(image.pixel(1, 1), RED);
+        assert_eq!(image.pixel(2, 1), RED);
+        assert_eq!(image.pixel(0, 1), BLACK);
+        assert_eq!(image.pixel(3, 1), BLACK);
+        assert_eq!(image.pixel(1, 2), BLACK);
+    }
+
+    #[test]
+    fn png_round_trips_through_decoder() {
+        let mut image = RasterImage::new(3, 2);
+        for (idx, byte) in image.pixels.iter_mut().enumerate() {
+            *byte = (idx * 11) as u8;
+        }
+        let bytes = encode_png(&image);
+        assert_eq!(bytes[..8], PNG_SIGNATURE);
+        assert_eq!(decode_png(&bytes).expect("decode"), image);
+    }
+}
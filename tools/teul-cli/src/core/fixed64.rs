@@ -1,7 +1,13 @@
+use std::cell::Cell;
 use std::sync::atomic::{AtomicU64, Ordering};
 
 static SATURATION_COUNT: AtomicU64 = AtomicU64::new(0);
 
+thread_local! {
+    /// 이 스레드에서 난 포화 수. 실행기는 마디마다 늘어난 만큼을 그 실행의 산술고장으로 옮긴다.
+    static THREAD_SATURATIONS: Cell<u64> = const { Cell::new(0) };
+}
+
 pub fn saturation_count() -> u64 {
     SATURATION_COUNT.load(Ordering::Relaxed)
 }
@@ -11,8 +17,13 @@ pub fn reset_saturation_count() {
     SATURATION_COUNT.store(0, Ordering::Relaxed);
 }
 
+pub fn thread_saturation_count() -> u64 {
+    THREAD_SATURATIONS.with(Cell::get)
+}
+
 fn record_saturation() {
     SATURATION_COUNT.fetch_add(1, Ordering::Relaxed);
+    THREAD_SATURATIONS.with(|count| count.set(count.get() + 1));
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -99,6 +110,20 @@ impl Fixed64 {
         Self::from_raw(raw)
     }
 
+    /// 정수를 넘침 없이 옮긴다. Fixed64 범위(±2^31)를 벗어나면 `None`이다.
+    pub fn checked_from_int(value: i64) -> Option<Self> {
+        value.checked_mul(Self::SCALE).map(Self::from_raw)
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.raw.checked_add(other.raw).map(Self::from_raw)
+    }
+
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        let prod = ((self.raw as i128) * (other.raw as i128)) >> Self::SCALE_BITS;
+        i64::try_from(prod).ok().map(Self::from_raw)
+    }
+
     pub fn checked_div(self, other: Self) -> Option<Self> {
         if other.raw == 0 {
             return None;
@@ -129,10 +154,18 @@ impl Fixed64 {
         format_fixed64(self.raw)
     }
 
+    /// `1.25` 같은 소수와 `1.5e3`/`2E-2` 같은 과학 표기를 읽는다.
+    /// 과학 표기는 지수가 Fixed64 범위를 벗어나면(넘치거나 0으로 사라지면) `None`이다.
     pub fn parse_literal(text: &str) -> Option<Self> {
         parse_fixed64(text).map(Self::from_raw)
     }
 
+    /// `format`과 같은 자릿수를 `가수e지수` 꼴로 쓴다. 예: 1500 → `1.5e3`, 0 → `0e0`.
+    #[allow(dead_code)]
+    pub fn to_scientific_string(self) -> String {
+        format_scientific(&self.format())
+    }
+
     pub fn sqrt(self) -> Option<Self> {
         if self.raw < 0 {
             return None;
@@ -172,6 +205,9 @@ fn parse_fixed64(text: &str) -> Option<i64> {
     if trimmed.is_empty() {
         return None;
     }
+    if let Some((mantissa, exponent)) = trimmed.split_once(['e', 'E']) {
+        return parse_scientific(mantissa, exponent);
+    }
 
     let negative = trimmed.starts_with('-');
     let digits = trimmed.strip_prefix('-').unwrap_or(trimmed);
@@ -202,6 +238,64 @@ fn parse_fixed64(text: &str) -> Option<i64> {
     Some(saturate_i128(signed))
 }
 
+/// 가수의 숫자를 정수 하나로 모은 뒤 10의 거듭제곱을 곱하거나 나눈다. 포화하지 않는다.
+fn parse_scientific(mantissa: &str, exponent: &str) -> Option<i64> {
+    let negative = mantissa.starts_with('-');
+    let digits = mantissa.strip_prefix('-').unwrap_or(mantissa);
+    let (int_part, frac_part) = digits.split_once('.').unwrap_or((digits, ""));
+    if int_part.is_empty() && frac_part.is_empty() {
+        return None;
+    }
+    let mut value: i128 = 0;
+    for ch in int_part.chars().chain(frac_part.chars()) {
+        let digit = ch.to_digit(10)? as i128;
+        value = value.checked_mul(10)?.checked_add(digit)?;
+    }
+    let exponent = exponent
+        .parse::<i32>()
+        .ok()?
+        .checked_sub(i32::try_from(frac_part.len()).ok()?)?;
+    if value == 0 {
+        return Some(0);
+    }
+    let pow10 = |exp: i32| 10_i128.checked_pow(exp.unsigned_abs());
+    let scaled = value.checked_mul(Fixed64::SCALE as i128)?;
+    let raw = if exponent >= 0 {
+        scaled.checked_mul(pow10(exponent)?)?
+    } else {
+        match pow10(exponent) {
+            Some(divisor) => scaled / divisor,
+            None => 0,
+        }
+    };
+    if raw == 0 {
+        return None;
+    }
+    let signed = if negative { -raw } else { raw };
+    i64::try_from(signed).ok()
+}
+
+fn format_scientific(decimal: &str) -> String {
+    let (sign, unsigned) = match decimal.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", decimal),
+    };
+    let (int_part, frac_part) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let digits: String = int_part.chars().chain(frac_part.chars()).collect();
+    let Some(first) = digits.find(|ch| ch != '0') else {
+        return "0e0".to_string();
+    };
+    let exponent = int_part.len() as i64 - 1 - first as i64;
+    let significant = digits[first..].trim_end_matches('0');
+    let mut out = format!("{sign}{}", &significant[..1]);
+    if significant.len() > 1 {
+        out.push('.');
+        out.push_str(&significant[1..]);
+    }
+    out.push_str(&format!("e{exponent}"));
+    out
+}
+
 fn format_fixed64(raw: i64) -> String {
     if raw == 0 {
         return "0".to_string();
@@ -256,18 +350,51 @@ fn int_sqrt(value: i128) -> i128 {
 
 #[cfg(test)]
 mod tests {
-    use super::{reset_saturation_count, saturation_count, Fixed64};
+    use super::{saturation_count, thread_saturation_count, Fixed64};
 
+    // 전역 수는 다른 시험 스레드의 포화도 세므로, 늘어나는 정확한 수는 스레드 수로 본다.
     #[test]
     fn saturation_count_only_increments_on_clamp() {
-        reset_saturation_count();
+        let base = thread_saturation_count();
         let _ = Fixed64::from_int(1);
-        assert_eq!(saturation_count(), 0);
+        assert_eq!(thread_saturation_count() - base, 0);
 
         let _ = Fixed64::from_int(i64::MAX);
-        assert_eq!(saturation_count(), 1);
+        assert_eq!(thread_saturation_count() - base, 1);
 
         let _ = Fixed64::from_raw(i64::MAX).saturating_add(Fixed64::from_raw(1));
-        assert_eq!(saturation_count(), 2);
+        assert_eq!(thread_saturation_count() - base, 2);
+        assert!(saturation_count() >= 2);
+    }
+
+    #[test]
+    fn parse_literal_reads_scientific_notation_within_range() {
+        assert_eq!(
+            Fixed64::parse_literal("1.5e3"),
+            Some(Fixed64::from_int(1500))
+        );
+        assert_eq!(
+            Fixed64::parse_literal("2E-2"),
+            Fixed64::parse_literal("0.02")
+        );
+        assert_eq!(Fixed64::parse_literal("-4e0"), Some(Fixed64::from_int(-4)));
+        assert_eq!(Fixed64::parse_literal("0e99"), Some(Fixed64::zero()));
+        // 2^31을 넘는 크기와 2^-32보다 작은 크기는 담을 수 없다.
+        assert_eq!(Fixed64::parse_literal("1e10"), None);
+        assert_eq!(Fixed64::parse_literal("1e-20"), None);
+        assert_eq!(Fixed64::parse_literal("1e"), None);
+        // 지수에서 소수 자릿수를 빼다 i32가 넘치면 패닉 없이 None이다.
+        assert_eq!(Fixed64::parse_literal("1.5e-2147483648"), None);
+        assert_eq!(Fixed64::parse_literal("1e2147483647"), None);
+
+        assert_eq!(Fixed64::from_int(1500).to_scientific_string(), "1.5e3");
+        assert_eq!(Fixed64::from_ratio(-1, 2).to_scientific_string(), "-5e-1");
+        assert_eq!(Fixed64::zero().to_scientific_string(), "0e0");
+        for value in [Fixed64::from_int(1500), Fixed64::from_ratio(-1, 2)] {
+            assert_eq!(
+                Fixed64::parse_literal(&value.to_scientific_string()),
+                Some(value)
+            );
+        }
     }
 }
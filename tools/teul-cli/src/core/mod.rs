@@ -1,5 +1,6 @@
 pub mod bdl_packet;
 pub mod bogae;
+pub mod bogae_raster;
 pub mod detbin;
 pub mod fixed64;
 pub mod geoul;
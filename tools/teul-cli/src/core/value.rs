@@ -223,9 +223,74 @@ pub struct MapEntry {
     pub value: Value,
 }
 
+/// 짝맞춤 칸. 열쇠의 정본 표기로 찾고, 돌 때는 처음 넣은 순서대로 돈다.
+/// 이미 있는 열쇠에 다시 넣으면 값만 바뀌고 자리는 그대로다.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MapEntries {
+    order: Vec<(String, MapEntry)>,
+    index: BTreeMap<String, usize>,
+}
+
+impl MapEntries {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, canon_key: &str) -> Option<&MapEntry> {
+        self.index.get(canon_key).map(|&at| &self.order[at].1)
+    }
+
+    pub fn contains_key(&self, canon_key: &str) -> bool {
+        self.index.contains_key(canon_key)
+    }
+
+    pub fn insert(&mut self, canon_key: String, entry: MapEntry) {
+        match self.index.get(&canon_key) {
+            Some(&at) => self.order[at].1 = entry,
+            None => {
+                self.index.insert(canon_key.clone(), self.order.len());
+                self.order.push((canon_key, entry));
+            }
+        }
+    }
+
+    pub fn remove(&mut self, canon_key: &str) -> Option<MapEntry> {
+        let at = self.index.remove(canon_key)?;
+        let (_, entry) = self.order.remove(at);
+        for slot in self.index.values_mut() {
+            if *slot > at {
+                *slot -= 1;
+            }
+        }
+        Some(entry)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &MapEntry)> {
+        self.order.iter().map(|(key, entry)| (key, entry))
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &MapEntry> {
+        self.order.iter().map(|(_, entry)| entry)
+    }
+
+    pub fn into_values(self) -> impl Iterator<Item = MapEntry> {
+        self.order.into_iter().map(|(_, entry)| entry)
+    }
+}
+
+impl From<BTreeMap<String, MapEntry>> for MapEntries {
+    fn from(sorted: BTreeMap<String, MapEntry>) -> Self {
+        let mut entries = Self::new();
+        for (key, entry) in sorted {
+            entries.insert(key, entry);
+        }
+        entries
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MapValue {
-    pub entries: BTreeMap<String, MapEntry>,
+    pub entries: MapEntries,
 }
 
 impl MapValue {
@@ -249,6 +314,16 @@ impl MapValue {
         entries.insert(key.canon(), MapEntry { key, value });
         Self { entries }
     }
+
+    pub fn map_contains(&self, key: &Value) -> bool {
+        self.entries.contains_key(&key.canon())
+    }
+
+    pub fn map_remove(&self, key: &Value) -> Self {
+        let mut entries = self.entries.clone();
+        entries.remove(&key.canon());
+        Self { entries }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -566,10 +641,10 @@ fn format_set(items: &BTreeMap<String, Value>, mode: ValueFormat) -> String {
     out
 }
 
-fn format_map(entries: &BTreeMap<String, MapEntry>, mode: ValueFormat) -> String {
+fn format_map(entries: &MapEntries, mode: ValueFormat) -> String {
     let mut out = String::from("짝맞춤{");
     let mut first = true;
-    for (_canon_key, entry) in entries {
+    for entry in entries.values() {
         if !first {
             out.push_str(", ");
         }
@@ -687,7 +762,8 @@ mod tests {
                     key: key.clone(),
                     value: Value::Str("ddn".to_string()),
                 },
-            )]),
+            )])
+            .into(),
         };
         assert_eq!(map.map_get(&key), Value::Str("ddn".to_string()));
         assert_eq!(map.map_get(&Value::Str("missing".to_string())), Value::None);
@@ -697,10 +773,31 @@ mod tests {
     fn map_set_overwrites_existing_key() {
         let key = Value::Str("k".to_string());
         let map = MapValue {
-            entries: BTreeMap::new(),
+            entries: MapEntries::new(),
         };
         let map = map.map_set(key.clone(), Value::Bool(true));
         let map = map.map_set(key.clone(), Value::Bool(false));
         assert_eq!(map.map_get(&key), Value::Bool(false));
     }
+
+    #[test]
+    fn map_keeps_insertion_order_across_overwrite_and_remove() {
+        let key = |text: &str| Value::Str(text.to_string());
+        let mut map = MapValue {
+            entries: MapEntries::new(),
+        };
+        for name in ["c", "a", "d", "b"] {
+            map = map.map_set(key(name), Value::Bool(true));
+        }
+        map = map.map_set(key("a"), Value::Bool(false));
+        map = map.map_remove(&key("d"));
+        let order: Vec<String> = map
+            .entries
+            .values()
+            .map(|entry| entry.key.display())
+            .collect();
+        assert_eq!(order, ["c", "a", "b"]);
+        assert_eq!(map.map_get(&key("b")), Value::Bool(true));
+        assert_eq!(map.map_get(&key("a")), Value::Bool(false));
+    }
 }
@@ -12,6 +12,8 @@ pub enum LexError {
     BadEscape { line: usize, col: usize, ch: char },
     BadIdentStart { line: usize, col: usize },
     UnexpectedChar { line: usize, col: usize, ch: char },
+    BadNumber { line: usize, col: usize },
+    NumberOverflow { line: usize, col: usize },
 }
 
 impl LexError {
@@ -24,6 +26,8 @@ impl LexError {
             LexError::BadEscape { .. } => "E_LEX_BAD_ESCAPE",
             LexError::BadIdentStart { .. } => "E_LEX_BAD_IDENT_START",
             LexError::UnexpectedChar { .. } => "E_LEX_UNEXPECTED_CHAR",
+            LexError::BadNumber { .. } => "E_LEX_BAD_NUMBER",
+            LexError::NumberOverflow { .. } => "E_LEX_NUMBER_OVERFLOW",
         }
     }
 }
@@ -408,27 +412,31 @@ impl Lexer {
         let (start_line, start_col) = (self.line, self.col);
         let mut text = String::new();
 
-        while let Some(ch) = self.peek() {
-            if ch.is_ascii_digit() {
-                text.push(ch);
+        let value = if self.peek() == Some('0') && matches!(self.peek_next(), Some('x' | 'X')) {
+            self.advance_n(2);
+            self.read_digit_group(&mut text, |ch| ch.is_ascii_hexdigit())?;
+            // Q32.32 정수부를 넘는 값은 포화시키지 않고 거부한다.
+            i64::from_str_radix(&text, 16)
+                .ok()
+                .and_then(|value| value.checked_mul(Fixed64::SCALE))
+                .map(Fixed64::from_raw)
+                .ok_or(LexError::NumberOverflow {
+                    line: start_line,
+                    col: start_col,
+                })?
+        } else {
+            self.read_digit_group(&mut text, |ch| ch.is_ascii_digit())?;
+            if self.peek() == Some('.') && self.peek_next().is_some_and(|c| c.is_ascii_digit()) {
+                text.push('.');
                 self.advance();
-            } else {
-                break;
-            }
-        }
-
-        if self.peek() == Some('.') && self.peek_next().map_or(false, |c| c.is_ascii_digit()) {
-            text.push('.');
-            self.advance();
-            while let Some(ch) = self.peek() {
-                if ch.is_ascii_digit() {
-                    text.push(ch);
-                    self.advance();
-                } else {
-                    break;
-                }
+                self.read_digit_group(&mut text, |ch| ch.is_ascii_digit())?;
             }
-        }
+            Fixed64::parse_literal(&text).ok_or(LexError::UnexpectedChar {
+                line: start_line,
+                col: start_col,
+                ch: '.',
+            })?
+        };
 
         if let Some(next) = self.peek() {
             if is_ident_start(next) {
@@ -444,18 +452,45 @@ impl Lexer {
             }
         }
 
-        let value = Fixed64::parse_literal(&text).ok_or(LexError::UnexpectedChar {
-            line: start_line,
-            col: start_col,
-            ch: '.',
-        })?;
-
         Ok(Token::new(
             TokenKind::Number(value.raw()),
             self.span_from(start_line, start_col),
         ))
     }
 
+    /// 숫자와 `_` 구분자를 읽어 `text`에 숫자만 쌓는다. `_`는 숫자 사이에 하나씩만 허용한다.
+    fn read_digit_group(
+        &mut self,
+        text: &mut String,
+        is_digit: fn(char) -> bool,
+    ) -> Result<(), LexError> {
+        let mut last_was_digit = false;
+        while let Some(ch) = self.peek() {
+            if is_digit(ch) {
+                text.push(ch);
+                last_was_digit = true;
+            } else if ch == '_' {
+                if !last_was_digit || !self.peek_next().is_some_and(is_digit) {
+                    return Err(LexError::BadNumber {
+                        line: self.line,
+                        col: self.col,
+                    });
+                }
+                last_was_digit = false;
+            } else {
+                break;
+            }
+            self.advance();
+        }
+        if text.is_empty() {
+            return Err(LexError::BadNumber {
+                line: self.line,
+                col: self.col,
+            });
+        }
+        Ok(())
+    }
+
     fn peek_ident_text(&self) -> Option<String> {
         let mut idx = self.pos;
         let mut ident = String::new();
@@ -1124,6 +1159,43 @@ mod tests {
         assert!(has_ascii_atom, "expected #ascii atom token");
     }
 
+    #[test]
+    fn hex_and_grouped_numbers_lex_to_same_value() {
+        let tokens = Lexer::tokenize("0xFF 1_000 2_5.0_5\n").expect("tokenize");
+        let numbers = tokens
+            .iter()
+            .filter_map(|token| match token.kind {
+                TokenKind::Number(raw) => Some(Fixed64::from_raw(raw)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(numbers[0], Fixed64::from_int(255));
+        assert_eq!(numbers[1], Fixed64::from_int(1000));
+        assert_eq!(numbers[2], Fixed64::parse_literal("25.05").expect("fixed"));
+    }
+
+    #[test]
+    fn hex_literal_beyond_fixed64_range_is_rejected() {
+        let tokens = Lexer::tokenize("0x7FFFFFFF\n").expect("max int part");
+        assert!(matches!(
+            tokens[0].kind,
+            TokenKind::Number(raw) if raw == Fixed64::from_int(0x7FFF_FFFF).raw()
+        ));
+        for source in ["0x80000000\n", "0xFFFFFFFFFFFFFFFFFF\n"] {
+            let err = Lexer::tokenize(source).expect_err(source);
+            assert!(matches!(err, LexError::NumberOverflow { .. }), "{source}");
+            assert_eq!(err.code(), "E_LEX_NUMBER_OVERFLOW");
+        }
+    }
+
+    #[test]
+    fn malformed_numbers_are_rejected() {
+        for source in ["0x\n", "1__0\n", "1_\n", "0x_1\n"] {
+            let err = Lexer::tokenize(source).expect_err(source);
+            assert!(matches!(err, LexError::BadNumber { .. }), "{source}");
+        }
+    }
+
     #[test]
     fn english_keyword_is_only_active_under_en_dialect() {
         let ko_tokens = Lexer::tokenize("if 참.\n").expect("tokenize");
@@ -27,15 +27,39 @@ enum WarpCommands {
         threads: usize,
         #[arg(long)]
         measure: bool,
+        /// GPU 장치가 확인되지 않으면 cpu로 물러서지 않고 E_WARP_GPU_UNAVAILABLE로 멈춘다.
+        #[arg(long = "require-gpu")]
+        require_gpu: bool,
+        /// 장치 확인 결과를 고정한다. 기본 `auto`는 실제 장치를 찾는다.
+        #[arg(long = "gpu-probe", value_enum, default_value_t = GpuProbeArg::Auto)]
+        gpu_probe: GpuProbeArg,
         #[arg(long)]
         out: Option<PathBuf>,
     },
 }
 
+#[derive(clap::ValueEnum, Clone, Debug)]
+enum GpuProbeArg {
+    Auto,
+    Available,
+    Unavailable,
+}
+
+impl GpuProbeArg {
+    fn into_core(self) -> Option<ddonirang_core::GpuProbe> {
+        match self {
+            GpuProbeArg::Auto => None,
+            GpuProbeArg::Available => Some(ddonirang_core::GpuProbe::Available),
+            GpuProbeArg::Unavailable => Some(ddonirang_core::GpuProbe::Unavailable),
+        }
+    }
+}
+
 #[derive(clap::ValueEnum, Clone, Debug)]
 enum WarpBackendArg {
     Off,
     Cpu,
+    CpuSimd,
     Gpu,
 }
 
@@ -44,6 +68,7 @@ impl WarpBackendArg {
         match self {
             WarpBackendArg::Off => ddonirang_core::WarpBackend::Off,
             WarpBackendArg::Cpu => ddonirang_core::WarpBackend::Cpu,
+            WarpBackendArg::CpuSimd => ddonirang_core::WarpBackend::CpuSimd,
             WarpBackendArg::Gpu => ddonirang_core::WarpBackend::Gpu,
         }
     }
@@ -64,6 +89,21 @@ impl WarpPolicyArg {
     }
 }
 
+#[derive(clap::ValueEnum, Clone, Debug)]
+enum CheckFormatArg {
+    Human,
+    Sarif,
+}
+
+impl CheckFormatArg {
+    fn into_cli(self) -> cli::check::CheckFormat {
+        match self {
+            CheckFormatArg::Human => cli::check::CheckFormat::Human,
+            CheckFormatArg::Sarif => cli::check::CheckFormat::Sarif,
+        }
+    }
+}
+
 #[derive(Parser)]
 #[command(name = "teul-cli")]
 #[command(about = "또니랑 실행 도구 (WALK02)")]
@@ -86,6 +126,12 @@ pub(crate) enum Commands {
         state: Vec<String>,
         #[arg(long = "state-file")]
         state_file: Vec<PathBuf>,
+        /// 인라인 JSON 초기 상태. 중첩 객체는 `a.b` 키로 펼친다. --state, --state-file 뒤에 적용된다.
+        #[arg(long = "state-json")]
+        state_json: Vec<String>,
+        /// `KEY=VAL` 읽기 전용 값. 실행 시작에 고정되며 `환경("KEY")`로 읽는다. 여러 번 쓸 수 있다.
+        #[arg(long = "env", value_name = "KEY=VAL")]
+        env: Vec<String>,
         #[arg(long = "diag-jsonl", alias = "diag")]
         diag_jsonl: Option<PathBuf>,
         #[arg(long = "diag-report-out")]
@@ -102,6 +148,9 @@ pub(crate) enum Commands {
         artifact: Vec<String>,
         #[arg(long = "trace-json")]
         trace_json: Option<PathBuf>,
+        /// 씨앗별 호출 횟수와 실행 문장 수를 JSON으로 쓴다. 벽시계가 아닌 계수라 재현된다.
+        #[arg(long = "profile")]
+        profile: Option<PathBuf>,
         #[arg(long = "proof-out")]
         proof_out: Option<PathBuf>,
         #[arg(long = "proof-cert-key")]
@@ -144,10 +193,20 @@ pub(crate) enum Commands {
         until_gameover: bool,
         #[arg(long = "gameover-key", default_value = "게임오버")]
         gameover_key: String,
+        /// 산술고장(고정소수 포화)이 N번 쌓이면 실행을 멈춘다.
+        #[arg(long = "max-faults", value_name = "N")]
+        max_faults: Option<u64>,
         #[arg(long = "console-grid", value_name = "COLSxROWS")]
         console_grid: Option<String>,
         #[arg(long = "console-panel-cols", default_value_t = 0)]
         console_panel_cols: usize,
+        /// 콘솔 보개 색을 바꿔 보여 준다. 상태와 다시보기에는 영향이 없다.
+        #[arg(
+            long = "console-palette",
+            value_enum,
+            default_value_t = cli::bogae_console::ConsolePalette::Default
+        )]
+        console_palette: cli::bogae_console::ConsolePalette,
         #[arg(long = "sam")]
         sam: Option<PathBuf>,
         #[arg(long = "record-sam")]
@@ -170,6 +229,13 @@ pub(crate) enum Commands {
         no_open: bool,
         #[arg(long = "unsafe-open")]
         unsafe_open: bool,
+        /// 같은 seed로 두 번 더 돌려 마디별 상태 해시가 같은지 먼저 본다. 다르면 E_NONDETERMINISM.
+        /// 재현: `--geoul-out a`, `--geoul-out b`로 두 번 실행한 뒤 `teul-cli replay diff --a a --b b --out d`.
+        #[arg(long = "deterministic-check")]
+        deterministic_check: bool,
+        /// 파일(과 ddn.lock이 가리키는 가지 .ddn)이 바뀌면 다시 실행한다. --madi infinite와 함께 쓸 수 없다.
+        #[arg(long = "watch")]
+        watch: bool,
     },
     #[command(name = "currentline-run")]
     CurrentlineRun {
@@ -204,16 +270,38 @@ pub(crate) enum Commands {
         console_grid: Option<String>,
         #[arg(long = "console-panel-cols", default_value_t = 0)]
         console_panel_cols: usize,
+        /// 콘솔 보개 색을 바꿔 보여 준다. 상태와 다시보기에는 영향이 없다.
+        #[arg(
+            long = "console-palette",
+            value_enum,
+            default_value_t = cli::bogae_console::ConsolePalette::Default
+        )]
+        console_palette: cli::bogae_console::ConsolePalette,
+        /// `.ddn` 입력을 이 마디까지 돌린 뒤의 화면을 보여 준다.
+        #[arg(long = "at-madi")]
+        at_madi: Option<u64>,
         #[arg(long = "no-open")]
         no_open: bool,
     },
     Check {
         file: PathBuf,
+        /// 진단 출력 형식. sarif는 CI 코드 스캐닝용 SARIF 2.1.0 문서를 낸다.
+        #[arg(long, value_enum, default_value_t = CheckFormatArg::Human)]
+        format: CheckFormatArg,
+        /// SARIF 문서를 쓸 경로. 없으면 stdout으로 낸다.
+        #[arg(long)]
+        out: Option<PathBuf>,
+        /// DEAD-SEED에서 빼는 진입 씨앗 이름 (기본 `시작`).
+        #[arg(long = "entry-seed")]
+        entry_seed: Option<String>,
     },
     Test {
         file: Option<PathBuf>,
         #[arg(long, default_value_t = 1)]
         threads: usize,
+        /// 이름에 이 조각이 든 시험만 돌린다.
+        #[arg(long)]
+        filter: Option<String>,
         #[arg(long)]
         out: Option<PathBuf>,
         #[arg(long)]
@@ -228,6 +316,9 @@ pub(crate) enum Commands {
         record: bool,
         #[arg(long)]
         update: bool,
+        /// --golden 결과를 JUnit XML로 쓸 경로.
+        #[arg(long)]
+        junit: Option<PathBuf>,
         #[arg(long = "skip-ui-common")]
         skip_ui_common: bool,
         #[arg(long = "skip-wrapper")]
@@ -239,11 +330,17 @@ pub(crate) enum Commands {
     },
     Build {
         file: PathBuf,
+        /// 빌드 산출물(.ddnc) 경로. 기본은 입력 파일 옆의 같은 이름.
+        #[arg(long)]
+        out: Option<PathBuf>,
     },
     Lint {
         file: PathBuf,
         #[arg(long = "suggest-patch")]
         suggest_patch: bool,
+        /// 자동으로 고칠 수 있는 경고를 파일에 바로 적용한다.
+        #[arg(long, conflicts_with = "suggest_patch")]
+        fix: bool,
         #[arg(long)]
         out: Option<PathBuf>,
     },
@@ -265,6 +362,9 @@ pub(crate) enum Commands {
         bridge: Option<cli::canon::BridgeKind>,
         #[arg(long)]
         check: bool,
+        /// 쓰지 않고 원본→정본 변경을 unified diff로 보여준다. 차이가 있으면 1로 끝난다.
+        #[arg(long)]
+        diff: bool,
     },
     Asset {
         #[command(subcommand)]
@@ -305,6 +405,11 @@ pub(crate) enum Commands {
     Scan {
         #[arg(long)]
         root: Option<PathBuf>,
+        /// 씨 호출 그래프를 JSON으로 쓴다. 경로는 --out(기본 graph.json).
+        #[arg(long)]
+        graph: bool,
+        #[arg(long)]
+        out: Option<PathBuf>,
     },
     Gaji {
         #[command(subcommand)]
@@ -371,6 +476,10 @@ pub(crate) enum Commands {
         config: PathBuf,
         #[arg(long)]
         out: Option<PathBuf>,
+        #[arg(long)]
+        resume: Option<PathBuf>,
+        #[arg(long = "checkpoint-every", default_value_t = 0)]
+        checkpoint_every: u64,
     },
     Imitation {
         config: PathBuf,
@@ -459,6 +568,9 @@ enum AiCommands {
         out: Option<PathBuf>,
         #[arg(long)]
         bundle: Option<PathBuf>,
+        /// 추정 토큰이 N 이하가 되도록 덜 중요한 SSOT 구역부터 버린다.
+        #[arg(long)]
+        budget_tokens: Option<usize>,
     },
 }
 
@@ -614,6 +726,19 @@ enum EcoCommands {
         #[arg(long)]
         out: Option<PathBuf>,
     },
+    MacroMicroSweep {
+        input: PathBuf,
+        #[arg(long)]
+        param: String,
+        #[arg(long, allow_hyphen_values = true)]
+        from: String,
+        #[arg(long, allow_hyphen_values = true)]
+        to: String,
+        #[arg(long)]
+        step: String,
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
     NetworkFlow {
         input: PathBuf,
         #[arg(long = "madi", default_value_t = 1)]
@@ -841,14 +966,16 @@ enum ReplayCommands {
         seek: Option<u64>,
         #[arg(long = "entry")]
         entry: Option<PathBuf>,
+        #[arg(long = "tolerance")]
+        tolerance: Option<String>,
     },
     Branch {
         #[arg(long = "geoul")]
         geoul: PathBuf,
         #[arg(long = "at")]
         at: u64,
-        #[arg(long = "inject-sam")]
-        inject_sam: PathBuf,
+        #[arg(long = "inject-sam", required = true)]
+        inject_sam: Vec<String>,
         #[arg(long = "out")]
         out: PathBuf,
         #[arg(long = "entry")]
@@ -865,16 +992,24 @@ enum GeoulCommands {
     Seek {
         #[arg(long = "geoul")]
         geoul: PathBuf,
-        #[arg(long = "madi")]
-        madi: u64,
+        #[arg(long = "madi", required_unless_present = "predicate")]
+        madi: Option<u64>,
+        /// `키<값`처럼 조건이 처음 참이 되는 마디를 찾는다. 없으면 never.
+        #[arg(long = "where", conflicts_with = "madi")]
+        predicate: Option<String>,
+        #[arg(long = "entry")]
+        entry: Option<PathBuf>,
     },
     Query {
         #[arg(long = "geoul")]
         geoul: PathBuf,
         #[arg(long = "madi")]
         madi: u64,
-        #[arg(long = "key")]
-        key: String,
+        #[arg(long = "key", required_unless_present = "key_prefix")]
+        key: Option<String>,
+        /// 이 접두로 시작하는 상태 키를 모두 키 순서대로 낸다.
+        #[arg(long = "key-prefix", conflicts_with = "key")]
+        key_prefix: Option<String>,
         #[arg(long = "entry")]
         entry: Option<PathBuf>,
     },
@@ -889,6 +1024,9 @@ enum GeoulCommands {
         to: u64,
         #[arg(long = "entry")]
         entry: Option<PathBuf>,
+        /// 값이 바뀐 마디마다 이전/새 값과 패치 위치를 JSON으로 낸다.
+        #[arg(long)]
+        json: bool,
     },
     Record {
         #[command(subcommand)]
@@ -937,6 +1075,9 @@ enum PatchCommands {
         out: Option<PathBuf>,
         #[arg(long = "in-place")]
         in_place: bool,
+        /// 원본이 propose 이후 바뀌었어도(E_PATCH_BASE_DRIFT) 적용한다.
+        #[arg(long)]
+        force: bool,
     },
     Verify {
         patch: PathBuf,
@@ -959,6 +1100,8 @@ enum IntentCommands {
         #[arg(long = "agent")]
         agent: Option<u64>,
         #[arg(long)]
+        decode: bool,
+        #[arg(long)]
         out: Option<PathBuf>,
     },
     Mock {
@@ -1026,6 +1169,10 @@ enum NuriGymCommands {
         out: Option<PathBuf>,
         #[arg(long)]
         slots: Option<u32>,
+        #[arg(long = "action-low")]
+        action_low: Option<String>,
+        #[arg(long = "action-high")]
+        action_high: Option<String>,
     },
     View {
         #[arg(long = "spec")]
@@ -1065,6 +1212,9 @@ enum GatewayCommands {
         send: Option<PathBuf>,
         #[arg(long, value_enum, default_value_t = GatewayInputFormatArg::Auto)]
         send_format: GatewayInputFormatArg,
+        /// 운영 지표(Prometheus text)를 낼 주소. 예: 127.0.0.1:9464
+        #[arg(long)]
+        metrics_listen: Option<String>,
     },
     #[command(name = "load-sim")]
     LoadSim {
@@ -1082,6 +1232,10 @@ enum GatewayCommands {
         threads: usize,
         #[arg(long)]
         out: Option<PathBuf>,
+        #[arg(long)]
+        latency_madi: Option<u64>,
+        #[arg(long)]
+        loss_pct: Option<u64>,
     },
 }
 
@@ -1099,6 +1253,14 @@ enum LatencyCommands {
         #[arg(long = "current-madi", default_value_t = 0)]
         current_madi: u64,
         #[arg(long)]
+        summary: bool,
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    Analyze {
+        #[arg(long = "geoul")]
+        geoul: PathBuf,
+        #[arg(long)]
         out: Option<PathBuf>,
     },
 }
@@ -1126,6 +1288,7 @@ impl GatewayInputFormatArg {
 enum GatewayListenProtoArg {
     Tcp,
     Udp,
+    Ws,
 }
 
 impl GatewayListenProtoArg {
@@ -1133,6 +1296,7 @@ impl GatewayListenProtoArg {
         match self {
             GatewayListenProtoArg::Tcp => cli::gateway::ListenProtocol::Tcp,
             GatewayListenProtoArg::Udp => cli::gateway::ListenProtocol::Udp,
+            GatewayListenProtoArg::Ws => cli::gateway::ListenProtocol::Ws,
         }
     }
 }
@@ -1140,11 +1304,13 @@ impl GatewayListenProtoArg {
 #[derive(Subcommand)]
 enum SafetyCommands {
     Check {
-        #[arg(long)]
-        rules: PathBuf,
+        #[arg(long, required = true)]
+        rules: Vec<PathBuf>,
         #[arg(long)]
         intent: PathBuf,
         #[arg(long)]
+        explain: bool,
+        #[arg(long)]
         out: Option<PathBuf>,
     },
 }
@@ -1367,6 +1533,8 @@ enum StoryCommands {
         geoul: PathBuf,
         #[arg(long)]
         out: PathBuf,
+        #[arg(long = "min-importance")]
+        min_importance: Option<u64>,
     },
 }
 
@@ -1379,6 +1547,8 @@ enum TimelineCommands {
         story: PathBuf,
         #[arg(long)]
         out: PathBuf,
+        #[arg(long = "min-importance")]
+        min_importance: Option<u64>,
     },
 }
 
@@ -1397,6 +1567,7 @@ impl LatencyModeArg {
     }
 }
 
+#[derive(Clone)]
 pub(crate) struct RunCommandArgs {
     pub(crate) file: PathBuf,
     pub(crate) madi: Option<String>,
@@ -1404,6 +1575,8 @@ pub(crate) struct RunCommandArgs {
     pub(crate) age_target: Option<String>,
     pub(crate) state: Vec<String>,
     pub(crate) state_file: Vec<PathBuf>,
+    pub(crate) state_json: Vec<String>,
+    pub(crate) env: Vec<String>,
     pub(crate) diag_jsonl: Option<PathBuf>,
     pub(crate) diag_report_out: Option<PathBuf>,
     pub(crate) enable_repro: bool,
@@ -1412,6 +1585,7 @@ pub(crate) struct RunCommandArgs {
     pub(crate) summary_json: Option<PathBuf>,
     pub(crate) artifact: Vec<String>,
     pub(crate) trace_json: Option<PathBuf>,
+    pub(crate) profile: Option<PathBuf>,
     pub(crate) proof_out: Option<PathBuf>,
     pub(crate) proof_cert_key: Option<PathBuf>,
     pub(crate) geoul_out: Option<PathBuf>,
@@ -1431,8 +1605,10 @@ pub(crate) struct RunCommandArgs {
     pub(crate) console_cell_aspect: cli::bogae_console::ConsoleCellAspect,
     pub(crate) console_grid: Option<String>,
     pub(crate) console_panel_cols: usize,
+    pub(crate) console_palette: cli::bogae_console::ConsolePalette,
     pub(crate) until_gameover: bool,
     pub(crate) gameover_key: String,
+    pub(crate) max_faults: Option<u64>,
     pub(crate) sam: Option<PathBuf>,
     pub(crate) record_sam: Option<PathBuf>,
     pub(crate) sam_live: Option<cli::sam_live::SamLiveMode>,
@@ -1444,6 +1620,7 @@ pub(crate) struct RunCommandArgs {
     pub(crate) open_bundle: Option<PathBuf>,
     pub(crate) no_open: bool,
     pub(crate) unsafe_open: bool,
+    pub(crate) deterministic_check: bool,
     pub(crate) run_command_override: Option<String>,
 }
 
@@ -1458,6 +1635,8 @@ pub(crate) fn execute_run_command(
         age_target,
         state,
         state_file,
+        state_json,
+        env,
         diag_jsonl,
         diag_report_out,
         enable_repro,
@@ -1466,6 +1645,7 @@ pub(crate) fn execute_run_command(
         summary_json,
         artifact,
         trace_json,
+        profile,
         proof_out,
         proof_cert_key,
         geoul_out,
@@ -1485,8 +1665,10 @@ pub(crate) fn execute_run_command(
         console_cell_aspect,
         console_grid,
         console_panel_cols,
+        console_palette,
         until_gameover,
         gameover_key,
+        max_faults,
         sam,
         record_sam,
         sam_live,
@@ -1498,6 +1680,7 @@ pub(crate) fn execute_run_command(
         open_bundle,
         no_open,
         unsafe_open,
+        deterministic_check,
         run_command_override,
     } = args;
 
@@ -1521,6 +1704,11 @@ pub(crate) fn execute_run_command(
     };
     let artifact_pins = cli::run::parse_artifact_pins(&artifact)
         .map_err(|message| format!("E_CLI_ARTIFACT {}", message))?;
+    let run_env =
+        cli::run::parse_run_env(&env).map_err(|message| format!("E_CLI_ENV {}", message))?;
+    if max_faults == Some(0) {
+        return Err("E_CLI_MAX_FAULTS --max-faults는 1 이상이어야 합니다.".to_string());
+    }
     let cmd_policy = match bogae_cmd_policy {
         cli::bogae::BogaeCmdPolicy::None => {
             if bogae_cmd_cap.is_some() {
@@ -1572,6 +1760,7 @@ pub(crate) fn execute_run_command(
     let mut console_config =
         cli::bogae_console::ConsoleRenderConfig::with_cell_aspect(console_cell_aspect);
     console_config.panel_cols = console_panel_cols;
+    console_config.palette = console_palette;
     if let Some((cols, rows)) = console_grid {
         console_config.grid_cols = Some(cols);
         console_config.grid_rows = Some(rows);
@@ -1582,6 +1771,7 @@ pub(crate) fn execute_run_command(
         diag_report_out,
         repro_json,
         trace_json,
+        profile,
         proof_out,
         proof_cert_key,
         geoul_out,
@@ -1601,6 +1791,7 @@ pub(crate) fn execute_run_command(
         console_config,
         until_gameover,
         gameover_key,
+        max_faults,
         sam_path: sam,
         record_sam_path: record_sam,
         sam_live,
@@ -1615,9 +1806,12 @@ pub(crate) fn execute_run_command(
         run_manifest,
         summary_json,
         artifact_pins,
+        deterministic_check,
+        run_env,
         run_command,
         init_state: state,
         init_state_files: state_file,
+        init_state_json: state_json,
     };
     cli::run::run_file_with_emitter(&file, madi, seed, options, emit)
 }
@@ -1646,6 +1840,8 @@ fn main() {
             age_target,
             state,
             state_file,
+            state_json,
+            env,
             diag_jsonl,
             diag_report_out,
             enable_repro,
@@ -1654,6 +1850,7 @@ fn main() {
             summary_json,
             artifact,
             trace_json,
+            profile,
             proof_out,
             proof_cert_key,
             geoul_out,
@@ -1673,8 +1870,10 @@ fn main() {
             console_cell_aspect,
             console_grid,
             console_panel_cols,
+            console_palette,
             until_gameover,
             gameover_key,
+            max_faults,
             sam,
             record_sam,
             sam_live,
@@ -1686,6 +1885,8 @@ fn main() {
             open_bundle,
             no_open,
             unsafe_open,
+            deterministic_check,
+            watch,
         } => {
             let mut emitter = cli::run::StdoutRunEmitter;
             let run_args = RunCommandArgs {
@@ -1695,6 +1896,8 @@ fn main() {
                 age_target,
                 state,
                 state_file,
+                state_json,
+                env,
                 diag_jsonl,
                 diag_report_out,
                 enable_repro,
@@ -1703,6 +1906,7 @@ fn main() {
                 summary_json,
                 artifact,
                 trace_json,
+                profile,
                 proof_out,
                 proof_cert_key,
                 geoul_out,
@@ -1722,8 +1926,10 @@ fn main() {
                 console_cell_aspect,
                 console_grid,
                 console_panel_cols,
+                console_palette,
                 until_gameover,
                 gameover_key,
+                max_faults,
                 sam,
                 record_sam,
                 sam_live,
@@ -1735,11 +1941,33 @@ fn main() {
                 open_bundle,
                 no_open,
                 unsafe_open,
+                deterministic_check,
                 run_command_override: None,
             };
+            if watch {
+                let infinite = run_args
+                    .madi
+                    .as_deref()
+                    .is_some_and(|raw| raw.trim().eq_ignore_ascii_case("infinite"));
+                if infinite {
+                    emitter.err(
+                        "E_CLI_WATCH_INFINITE --watch와 --madi infinite는 함께 쓸 수 없습니다.",
+                    );
+                    exit_with_saturation(1);
+                }
+                let watch_file = run_args.file.clone();
+                let result = cli::watch::run_watch(&watch_file, || {
+                    execute_run_command(run_args.clone(), &mut emitter)
+                });
+                if let Err(err) = result {
+                    emitter.err(&err);
+                    exit_with_saturation(1);
+                }
+                return;
+            }
             if let Err(err) = execute_run_command(run_args, &mut emitter) {
                 emitter.err(&err);
-                exit_with_saturation(1);
+                exit_with_saturation(cli::run::run_error_exit_code(&err));
             }
         }
         Commands::CurrentlineRun {
@@ -1804,6 +2032,8 @@ fn main() {
                 age_target: None,
                 state: Vec::new(),
                 state_file: Vec::new(),
+                state_json: Vec::new(),
+                env: Vec::new(),
                 diag_jsonl: None,
                 diag_report_out: None,
                 enable_repro: false,
@@ -1812,6 +2042,7 @@ fn main() {
                 summary_json,
                 artifact: Vec::new(),
                 trace_json: None,
+                profile: None,
                 proof_out: None,
                 proof_cert_key: None,
                 geoul_out: None,
@@ -1831,8 +2062,10 @@ fn main() {
                 console_cell_aspect: cli::bogae_console::ConsoleCellAspect::Auto,
                 console_grid: None,
                 console_panel_cols: 0,
+                console_palette: cli::bogae_console::ConsolePalette::Default,
                 until_gameover: false,
                 gameover_key: "게임오버".to_string(),
+                max_faults: None,
                 sam: None,
                 record_sam: None,
                 sam_live: None,
@@ -1844,6 +2077,7 @@ fn main() {
                 open_bundle: None,
                 no_open: true,
                 unsafe_open: false,
+                deterministic_check: false,
                 run_command_override: Some(format!(
                     "teul-cli currentline-run --cell {}",
                     cell.display()
@@ -1887,6 +2121,8 @@ fn main() {
             console_cell_aspect,
             console_grid,
             console_panel_cols,
+            console_palette,
+            at_madi,
             no_open,
         } => {
             let overlay = match bogae_overlay {
@@ -1917,6 +2153,7 @@ fn main() {
             let mut console_config =
                 cli::bogae_console::ConsoleRenderConfig::with_cell_aspect(console_cell_aspect);
             console_config.panel_cols = console_panel_cols;
+            console_config.palette = console_palette;
             if let Some((cols, rows)) = console_grid {
                 console_config.grid_cols = Some(cols);
                 console_config.grid_rows = Some(rows);
@@ -1928,6 +2165,7 @@ fn main() {
                 bogae_skin,
                 overlay,
                 console_config,
+                at_madi,
                 no_open,
             };
             if let Err(err) = cli::view::run_view(&file, options) {
@@ -1935,8 +2173,18 @@ fn main() {
                 exit_with_saturation(1);
             }
         }
-        Commands::Check { file } => {
-            let args = cli::check::CheckArgs { emit_schema: true };
+        Commands::Check {
+            file,
+            format,
+            out,
+            entry_seed,
+        } => {
+            let args = cli::check::CheckArgs {
+                emit_schema: true,
+                format: format.into_cli(),
+                out,
+                entry_seed,
+            };
             if let Err(err) = cli::check::run(&file, args) {
                 eprintln!("{}", err);
                 exit_with_saturation(1);
@@ -1945,6 +2193,7 @@ fn main() {
         Commands::Test {
             file,
             threads,
+            filter,
             out,
             smoke,
             golden,
@@ -1952,6 +2201,7 @@ fn main() {
             all,
             record,
             update,
+            junit,
             skip_ui_common,
             skip_wrapper,
         } => {
@@ -1964,9 +2214,13 @@ fn main() {
                     eprintln!("E_TEST_FILE_CONFLICT --smoke/--golden 모드에서는 file 위치 인자를 사용하지 않습니다.");
                     exit_with_saturation(1);
                 }
+                if filter.is_some() {
+                    eprintln!("E_TEST_FILTER_CONFLICT --smoke/--golden 모드에서는 --filter 를 사용할 수 없습니다.");
+                    exit_with_saturation(1);
+                }
                 if smoke {
-                    if all || record {
-                        eprintln!("E_TEST_SMOKE_OPTION --smoke 모드에서는 --all/--record 를 사용할 수 없습니다.");
+                    if all || record || junit.is_some() {
+                        eprintln!("E_TEST_SMOKE_OPTION --smoke 모드에서는 --all/--record/--junit 를 사용할 수 없습니다.");
                         exit_with_saturation(1);
                     }
                     let options = cli::test::SmokeRunnerOptions {
@@ -1989,6 +2243,7 @@ fn main() {
                         all,
                         record,
                         update,
+                        junit,
                     };
                     if let Err(err) = cli::test::run_pack_golden_runner(options) {
                         eprintln!("{}", err);
@@ -2002,11 +2257,20 @@ fn main() {
                     );
                     exit_with_saturation(1);
                 };
-                if all || record || update || skip_ui_common || skip_wrapper || !pack.is_empty() {
-                    eprintln!("E_TEST_OPTION_INVALID 기본 test 모드에서는 --all/--record/--update/--pack/--skip-* 옵션을 사용할 수 없습니다.");
+                if all
+                    || record
+                    || update
+                    || junit.is_some()
+                    || skip_ui_common
+                    || skip_wrapper
+                    || !pack.is_empty()
+                {
+                    eprintln!("E_TEST_OPTION_INVALID 기본 test 모드에서는 --all/--record/--update/--junit/--pack/--skip-* 옵션을 사용할 수 없습니다.");
                     exit_with_saturation(1);
                 }
-                if let Err(err) = cli::test::run_realms_test(&file, threads, out.as_deref()) {
+                if let Err(err) =
+                    cli::test::run_realms_test(&file, threads, filter.as_deref(), out.as_deref())
+                {
                     eprintln!("{}", err);
                     exit_with_saturation(1);
                 }
@@ -2019,6 +2283,8 @@ fn main() {
                 policy,
                 threads,
                 measure,
+                require_gpu,
+                gpu_probe,
                 out,
             } => {
                 if let Err(err) = cli::warp::run_bench(
@@ -2027,6 +2293,8 @@ fn main() {
                     policy.to_core(),
                     threads,
                     measure,
+                    require_gpu,
+                    gpu_probe.into_core(),
                     out.as_deref(),
                 ) {
                     eprintln!("{}", err);
@@ -2034,9 +2302,8 @@ fn main() {
                 }
             }
         },
-        Commands::Build { file } => {
-            let args = cli::check::CheckArgs { emit_schema: true };
-            if let Err(err) = cli::check::run(&file, args) {
+        Commands::Build { file, out } => {
+            if let Err(err) = cli::build::run(&file, out.as_deref()) {
                 eprintln!("{}", err);
                 exit_with_saturation(1);
             }
@@ -2044,9 +2311,10 @@ fn main() {
         Commands::Lint {
             file,
             suggest_patch,
+            fix,
             out,
         } => {
-            if let Err(err) = cli::lint::run(&file, suggest_patch, out.as_deref()) {
+            if let Err(err) = cli::lint::run(&file, suggest_patch, fix, out.as_deref()) {
                 eprintln!("{}", err);
                 exit_with_saturation(1);
             }
@@ -2072,6 +2340,7 @@ fn main() {
             meta_out,
             bridge,
             check,
+            diff,
         } => {
             let args = cli::canon::CanonArgs {
                 emit,
@@ -2081,6 +2350,7 @@ fn main() {
                 diag_jsonl,
                 meta_out,
                 check,
+                diff,
             };
             if let Err(err) = cli::canon::run(&file, args) {
                 eprintln!("{}", err);
@@ -2159,6 +2429,26 @@ fn main() {
                     exit_with_saturation(1);
                 }
             }
+            EcoCommands::MacroMicroSweep {
+                input,
+                param,
+                from,
+                to,
+                step,
+                out,
+            } => {
+                if let Err(err) = cli::eco::run_macro_micro_sweep(
+                    &input,
+                    &param,
+                    &from,
+                    &to,
+                    &step,
+                    out.as_deref(),
+                ) {
+                    eprintln!("{}", err);
+                    exit_with_saturation(1);
+                }
+            }
             EcoCommands::NetworkFlow {
                 input,
                 madi,
@@ -2227,11 +2517,13 @@ fn main() {
                 profile,
                 out,
                 bundle,
+                budget_tokens,
             } => {
                 let args = ai_prompt::AiPromptArgs {
                     profile,
                     out_path: out,
                     bundle_path: bundle,
+                    budget_tokens,
                 };
                 if let Err(err) = ai_prompt::run_ai_prompt(args) {
                     eprintln!("{}", err);
@@ -2278,10 +2570,15 @@ fn main() {
                 until,
                 seek,
                 entry,
+                tolerance,
             } => {
-                if let Err(err) =
-                    cli::replay::run_replay_verify(&geoul, entry.as_deref(), until, seek)
-                {
+                if let Err(err) = cli::replay::run_replay_verify(
+                    &geoul,
+                    entry.as_deref(),
+                    until,
+                    seek,
+                    tolerance.as_deref(),
+                ) {
                     eprintln!("{}", err);
                     exit_with_saturation(1);
                 }
@@ -2312,8 +2609,22 @@ fn main() {
                     exit_with_saturation(1);
                 }
             }
-            GeoulCommands::Seek { geoul, madi } => {
-                if let Err(err) = cli::geoul::run_geoul_seek(&geoul, madi) {
+            GeoulCommands::Seek {
+                geoul,
+                madi,
+                predicate,
+                entry,
+            } => {
+                let result = match (madi, predicate) {
+                    (_, Some(predicate)) => {
+                        cli::geoul::run_geoul_seek_where(&geoul, &predicate, entry.as_deref())
+                    }
+                    (Some(madi), None) => cli::geoul::run_geoul_seek(&geoul, madi),
+                    (None, None) => {
+                        Err("E_GEOUL_WHERE --madi 또는 --where가 필요합니다".to_string())
+                    }
+                };
+                if let Err(err) = result {
                     eprintln!("{}", err);
                     exit_with_saturation(1);
                 }
@@ -2322,10 +2633,21 @@ fn main() {
                 geoul,
                 madi,
                 key,
+                key_prefix,
                 entry,
             } => {
-                if let Err(err) = cli::geoul::run_geoul_query(&geoul, madi, &key, entry.as_deref())
-                {
+                let result = match (key, key_prefix) {
+                    (_, Some(prefix)) => {
+                        cli::geoul::run_geoul_query_prefix(&geoul, madi, &prefix, entry.as_deref())
+                    }
+                    (Some(key), None) => {
+                        cli::geoul::run_geoul_query(&geoul, madi, &key, entry.as_deref())
+                    }
+                    (None, None) => {
+                        Err("E_GEOUL_KEY_EMPTY --key 또는 --key-prefix가 필요합니다".to_string())
+                    }
+                };
+                if let Err(err) = result {
                     eprintln!("{}", err);
                     exit_with_saturation(1);
                 }
@@ -2336,9 +2658,10 @@ fn main() {
                 from,
                 to,
                 entry,
+                json,
             } => {
                 if let Err(err) =
-                    cli::geoul::run_geoul_backtrace(&geoul, &key, from, to, entry.as_deref())
+                    cli::geoul::run_geoul_backtrace(&geoul, &key, from, to, entry.as_deref(), json)
                 {
                     eprintln!("{}", err);
                     exit_with_saturation(1);
@@ -2390,8 +2713,10 @@ fn main() {
                 approval,
                 out,
                 in_place,
+                force,
             } => {
-                if let Err(err) = cli::patch::run_apply(&patch, &approval, out.as_deref(), in_place)
+                if let Err(err) =
+                    cli::patch::run_apply(&patch, &approval, out.as_deref(), in_place, force)
                 {
                     eprintln!("{}", err);
                     exit_with_saturation(1);
@@ -2411,9 +2736,17 @@ fn main() {
                 }
             }
         },
-        Commands::Scan { root } => {
+        Commands::Scan { root, graph, out } => {
             let root = root.unwrap_or_else(|| PathBuf::from("."));
-            if let Err(err) = cli::scan::run(&root) {
+            let result = if graph {
+                let out = out.unwrap_or_else(|| PathBuf::from("graph.json"));
+                cli::scan::run_graph(&root, &out)
+            } else if out.is_some() {
+                Err("E_SCAN_OUT_REQUIRES_GRAPH --out은 --graph와 함께 써야 합니다".to_string())
+            } else {
+                cli::scan::run(&root)
+            };
+            if let Err(err) = result {
                 eprintln!("{}", err);
                 exit_with_saturation(1);
             }
@@ -2429,9 +2762,12 @@ fn main() {
                 geoul,
                 madi,
                 agent,
+                decode,
                 out,
             } => {
-                if let Err(err) = cli::intent::run_inspect(&geoul, madi, agent, out.as_deref()) {
+                if let Err(err) =
+                    cli::intent::run_inspect(&geoul, madi, agent, decode, out.as_deref())
+                {
                     eprintln!("{}", err);
                     exit_with_saturation(1);
                 }
@@ -2495,9 +2831,21 @@ fn main() {
             }
         },
         Commands::NuriGym { command } => match command {
-            NuriGymCommands::Spec { from, out, slots } => {
+            NuriGymCommands::Spec {
+                from,
+                out,
+                slots,
+                action_low,
+                action_high,
+            } => {
                 let out = out.unwrap_or_else(|| cli::paths::build_dir().join("nurigym"));
-                if let Err(err) = cli::nurigym::run_spec(&from, &out, slots) {
+                if let Err(err) = cli::nurigym::run_spec(
+                    &from,
+                    &out,
+                    slots,
+                    action_low.as_deref(),
+                    action_high.as_deref(),
+                ) {
                     eprintln!("{}", err);
                     exit_with_saturation(1);
                 }
@@ -2621,6 +2969,7 @@ fn main() {
                 listen_timeout_ms,
                 send,
                 send_format,
+                metrics_listen,
             } => {
                 let options = cli::gateway::ServeOptions {
                     world,
@@ -2635,6 +2984,8 @@ fn main() {
                     listen_timeout_ms,
                     send_path: send,
                     send_format: send_format.to_core(),
+                    metrics_listen,
+                    shutdown: Default::default(),
                 };
                 if let Err(err) = cli::gateway::run_serve(options) {
                     eprintln!("{}", err);
@@ -2649,6 +3000,8 @@ fn main() {
                 tick_hz,
                 threads,
                 out,
+                latency_madi,
+                loss_pct,
             } => {
                 let options = cli::gateway::LoadSimOptions {
                     clients,
@@ -2658,6 +3011,8 @@ fn main() {
                     tick_hz,
                     threads,
                     out,
+                    latency_madi,
+                    loss_pct,
                 };
                 if let Err(err) = cli::gateway::run_load_sim(options) {
                     eprintln!("{}", err);
@@ -2672,6 +3027,7 @@ fn main() {
                 count,
                 seed,
                 current_madi,
+                summary,
                 out,
             } => {
                 if let Err(err) = cli::latency::run_simulate(
@@ -2680,16 +3036,28 @@ fn main() {
                     count,
                     seed,
                     current_madi,
+                    summary,
                     out.as_deref(),
                 ) {
                     eprintln!("{}", err);
                     exit_with_saturation(1);
                 }
             }
+            LatencyCommands::Analyze { geoul, out } => {
+                if let Err(err) = cli::latency::run_analyze(&geoul, out.as_deref()) {
+                    eprintln!("{}", err);
+                    exit_with_saturation(1);
+                }
+            }
         },
         Commands::Safety { command } => match command {
-            SafetyCommands::Check { rules, intent, out } => {
-                if let Err(err) = cli::safety::run_check(&rules, &intent, out.as_deref()) {
+            SafetyCommands::Check {
+                rules,
+                intent,
+                explain,
+                out,
+            } => {
+                if let Err(err) = cli::safety::run_check(&rules, &intent, explain, out.as_deref()) {
                     eprintln!("{}", err);
                     exit_with_saturation(1);
                 }
@@ -2732,8 +3100,15 @@ fn main() {
                 }
             }
         },
-        Commands::Train { config, out } => {
-            if let Err(err) = cli::train::run_train(&config, out.as_deref()) {
+        Commands::Train {
+            config,
+            out,
+            resume,
+            checkpoint_every,
+        } => {
+            if let Err(err) =
+                cli::train::run_train(&config, out.as_deref(), resume.as_deref(), checkpoint_every)
+            {
                 eprintln!("{}", err);
                 exit_with_saturation(1);
             }
@@ -2883,16 +3258,25 @@ fn main() {
             }
         },
         Commands::Story { command } => match command {
-            StoryCommands::Make { geoul, out } => {
-                if let Err(err) = cli::story::run_make(&geoul, &out) {
+            StoryCommands::Make {
+                geoul,
+                out,
+                min_importance,
+            } => {
+                if let Err(err) = cli::story::run_make(&geoul, &out, min_importance) {
                     eprintln!("{}", err);
                     exit_with_saturation(1);
                 }
             }
         },
         Commands::Timeline { command } => match command {
-            TimelineCommands::Make { geoul, story, out } => {
-                if let Err(err) = cli::timeline::run_make(&geoul, &story, &out) {
+            TimelineCommands::Make {
+                geoul,
+                story,
+                out,
+                min_importance,
+            } => {
+                if let Err(err) = cli::timeline::run_make(&geoul, &story, &out, min_importance) {
                     eprintln!("{}", err);
                     exit_with_saturation(1);
                 }
@@ -3426,6 +3810,21 @@ mod tests {
                 EcoCommands::MacroMicro { input, out } => {
                     cli::eco::run_macro_micro(&input, out.as_deref())
                 }
+                EcoCommands::MacroMicroSweep {
+                    input,
+                    param,
+                    from,
+                    to,
+                    step,
+                    out,
+                } => cli::eco::run_macro_micro_sweep(
+                    &input,
+                    &param,
+                    &from,
+                    &to,
+                    &step,
+                    out.as_deref(),
+                ),
                 EcoCommands::NetworkFlow {
                     input,
                     madi,
@@ -3574,6 +3973,19 @@ mod tests {
         assert!(rendered.contains("unexpected argument"));
     }
 
+    #[test]
+    fn warp_backend_cli_values_match_report_names() {
+        use clap::ValueEnum;
+        for arg in WarpBackendArg::value_variants() {
+            let name = arg
+                .to_possible_value()
+                .expect("value")
+                .get_name()
+                .to_string();
+            assert_eq!(arg.clone().to_core().as_str(), name);
+        }
+    }
+
     #[test]
     fn release_compat_flag_scan_blocks_compat_matic_entry() {
         let args = vec![
@@ -4146,7 +4558,7 @@ mod tests {
                 .expect("parse report");
         assert_eq!(
             report.get("schema").and_then(|v| v.as_str()),
-            Some("ddn.eco.network_flow_report.v0")
+            Some("ddn.eco.network_flow_report.v1")
         );
         assert_eq!(
             report.get("error_code").and_then(|v| v.as_str()),
@@ -0,0 +1,98 @@
+use std::cmp::Ordering;
+
+use crate::core::value::Value;
+use crate::lang::span::Span;
+use crate::runtime::error::RuntimeError;
+use crate::runtime::eval::value_type_name;
+
+/// 두 값의 순서를 정한다. 같은 종류끼리만 비교하고, 종류가 다르면 오류를 돌려준다.
+///
+/// 종류 안의 순서:
+/// - 수: 단위(차원)가 같을 때만 고정소수 값으로 비교한다. 단위가 다르면 `UnitMismatch`.
+/// - 글: 유니코드 코드포인트 순 사전식 비교(대소문자·정규화 변환 없음).
+/// - 참거짓: `거짓 < 참`.
+/// - 없음: 항상 같다.
+///
+/// 그 밖의 종류(묶음, 차림 등)는 순서가 정의되지 않아 `TypeMismatchDetail`이다.
+pub fn compare(left: &Value, right: &Value, span: Span) -> Result<Ordering, RuntimeError> {
+    match (left, right) {
+        (Value::Num(l), Value::Num(r)) => {
+            if l.dim != r.dim {
+                return Err(RuntimeError::UnitMismatch { span });
+            }
+            Ok(l.raw.raw().cmp(&r.raw.raw()))
+        }
+        (Value::Str(l), Value::Str(r)) => Ok(l.as_str().cmp(r.as_str())),
+        (Value::Bool(l), Value::Bool(r)) => Ok(l.cmp(r)),
+        (Value::None, Value::None) => Ok(Ordering::Equal),
+        (Value::Num(_) | Value::Str(_) | Value::Bool(_) | Value::None, other) => {
+            Err(RuntimeError::TypeMismatchDetail {
+                expected: comparable_type_name(left),
+                actual: value_type_name(other),
+                span,
+            })
+        }
+        (other, _) => Err(RuntimeError::TypeMismatchDetail {
+            expected: "comparable value",
+            actual: value_type_name(other),
+            span,
+        }),
+    }
+}
+
+fn comparable_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Num(_) => "number",
+        Value::Str(_) => "string",
+        Value::Bool(_) => "boolean",
+        _ => "none",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fixed64::Fixed64;
+    use crate::core::unit::UnitDim;
+    use crate::core::value::Quantity;
+
+    fn span() -> Span {
+        Span::new(1, 1, 1, 1)
+    }
+
+    fn num(value: i64) -> Value {
+        Value::Num(Quantity::new(Fixed64::from_int(value), UnitDim::zero()))
+    }
+
+    #[test]
+    fn numbers_and_strings_order_within_their_type() {
+        assert_eq!(compare(&num(1), &num(2), span()).unwrap(), Ordering::Less);
+        assert_eq!(compare(&num(2), &num(2), span()).unwrap(), Ordering::Equal);
+        let apple = Value::Str("사과".to_string());
+        let pear = Value::Str("배".to_string());
+        assert_eq!(compare(&pear, &apple, span()).unwrap(), Ordering::Less);
+        assert_eq!(
+            compare(
+                &Value::Str("b".to_string()),
+                &Value::Str("ab".to_string()),
+                span()
+            )
+            .unwrap(),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn number_against_string_is_a_type_error() {
+        let err = compare(&num(1), &Value::Str("1".to_string()), span()).unwrap_err();
+        match err {
+            RuntimeError::TypeMismatchDetail {
+                expected, actual, ..
+            } => {
+                assert_eq!(expected, "number");
+                assert_eq!(actual, "string");
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+}
@@ -1,10 +1,10 @@
-use crate::core::fixed64::Fixed64;
+use crate::core::fixed64::{thread_saturation_count, Fixed64};
 use crate::core::state::Key;
 use crate::core::trace::Trace;
 use crate::core::unit::{eval_unit_expr, format_dim, temperature_dim, UnitDim, UnitExpr};
 use crate::core::value::{
-    AssertionValue, DiceValue, LambdaValue, ListValue, MapEntry, MapValue, PackValue, Quantity,
-    SetValue, TemplateValue, Value,
+    AssertionValue, DiceValue, LambdaValue, ListValue, MapEntries, MapEntry, MapValue, PackValue,
+    Quantity, SetValue, TemplateValue, Value,
 };
 use crate::core::State;
 use crate::lang::ast::{
@@ -19,15 +19,20 @@ use crate::runtime::formula::{
     analyze_formula, eval_formula_body, format_formula_body, FormulaError,
 };
 use crate::runtime::open::{OpenCheckpoint, OpenRuntime, OpenSolverOp, OpenSolverReply};
+use crate::runtime::profile::SeedProfile;
 use crate::runtime::template::{match_template, render_template};
-use ddonirang_core::ResourceHandle;
+use ddonirang_core::{
+    ArithmeticFaultKind, FaultContext, FaultLimitStop, ResourceHandle, Scheduler, Signal,
+    SignalSink, VecSignalSink,
+};
 use regex::{Regex, RegexBuilder};
 use std::cell::Cell;
 use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::sync::atomic::{AtomicU64, Ordering};
 
 const PROOF_GUARD_REGISTRY_KEY: &str = "__proof.guard_registry";
-const CALL_TAILS: &[&str] = &["하면서", "면서", "하기", "기", "하고", "고", "하면", "면"];
+pub(crate) const CALL_TAILS: &[&str] =
+    &["하면서", "면서", "하기", "기", "하고", "고", "하면", "면"];
 const BOGAE_SHOW_LINES_TAG: &str = "보개_출력_줄들";
 const BOGAE_GRAPH_POINTS_F_TAG: &str = "보개_그래프_점목록_f";
 const EXACT_NUMERIC_KIND_FIELD: &str = "__정확수종류";
@@ -97,7 +102,15 @@ pub struct Evaluator {
     open: OpenRuntime,
     user_seeds: BTreeMap<String, UserSeed>,
     import_aliases: BTreeMap<String, String>,
+    /// 가지 꾸러미를 심은 `쓰임` 별명. 이 별명의 씨앗은 `별명.이름`으로만 부른다.
+    gaji_aliases: BTreeSet<String>,
+    /// 씨앗 호출마다 그 씨앗이 속한 가지 별명을 쌓는다. 가지 밖 씨앗은 `None`이다.
+    seed_modules: Vec<Option<String>>,
     current_entity_stack: Vec<String>,
+    /// 꼬리 자기 호출을 고리로 풀 수 있는 씨앗 호출 틀. 임자/밝히기나 검사 본문은 `None`을 쌓는다.
+    tail_call_frames: Vec<Option<String>>,
+    /// `돌려줘`가 지금 씨앗을 꼬리 호출할 때 다음 바퀴에 묶을 인자.
+    pending_tail_call: Option<Vec<Value>>,
     const_scopes: Vec<BTreeSet<Key>>,
     pending_signals: VecDeque<PendingSignal>,
     processing_signal_queue: bool,
@@ -112,6 +125,17 @@ pub struct Evaluator {
     lifecycle_madang_name_to_index: BTreeMap<String, usize>,
     lifecycle_active_pan: Option<usize>,
     lifecycle_active_madang: Option<usize>,
+    profile: Option<SeedProfile>,
+    /// `run --env`로 받은 읽기 전용 값. 실행 시작에 한 번 찍어 두고 바꾸지 않는다.
+    run_env: BTreeMap<String, String>,
+    /// `나중에`로 걸어 둔 일. 마디를 시작할 때 (마디, 건 순서)대로 부른다.
+    scheduled: Scheduler<Callable>,
+    /// `run --max-faults` 한도. 있으면 마디마다 산술고장을 `faults`에 모은다.
+    max_faults: Option<usize>,
+    faults: VecSignalSink,
+    /// 마지막으로 산술고장을 옮겨 적은 때의 스레드 포화 수.
+    saturation_mark: u64,
+    fault_stop: Option<FaultLimitStop>,
 }
 
 pub struct EvalFailure {
@@ -317,6 +341,7 @@ struct ContractFrame {
     pending_signals_len: usize,
     deferred_assign_frames: Vec<Vec<DeferredAssign>>,
     flow_assigns: Vec<FlowAssignRecord>,
+    scheduled: Scheduler<Callable>,
     open_checkpoint: OpenCheckpoint,
     depth: u32,
 }
@@ -329,6 +354,7 @@ struct NuriResetSnapshot {
     deferred_assign_frames: Vec<Vec<DeferredAssign>>,
     flow_assigns: Vec<FlowAssignRecord>,
     current_entity_stack: Vec<String>,
+    scheduled: Scheduler<Callable>,
     aborted: bool,
     bogae_requested: bool,
     bogae_requested_tick: bool,
@@ -433,7 +459,11 @@ impl Evaluator {
             open,
             user_seeds: BTreeMap::new(),
             import_aliases: BTreeMap::new(),
+            gaji_aliases: BTreeSet::new(),
+            seed_modules: Vec::new(),
             current_entity_stack: Vec::new(),
+            tail_call_frames: Vec::new(),
+            pending_tail_call: None,
             const_scopes: vec![BTreeSet::new()],
             pending_signals: VecDeque::new(),
             processing_signal_queue: false,
@@ -448,9 +478,60 @@ impl Evaluator {
             lifecycle_madang_name_to_index: BTreeMap::new(),
             lifecycle_active_pan: None,
             lifecycle_active_madang: None,
+            profile: None,
+            run_env: BTreeMap::new(),
+            scheduled: Scheduler::new(),
+            max_faults: None,
+            faults: VecSignalSink::default(),
+            saturation_mark: 0,
+            fault_stop: None,
         }
     }
 
+    /// 씨앗별 호출·문장 수를 세어 `EvalOutput::profile`로 돌려준다.
+    pub fn with_profile(mut self) -> Self {
+        self.profile = Some(SeedProfile::default());
+        self
+    }
+
+    /// `환경("키")`로 읽을 값을 심는다.
+    pub fn with_run_env(mut self, run_env: BTreeMap<String, String>) -> Self {
+        self.run_env = run_env;
+        self
+    }
+
+    /// 산술고장이 `limit`번 쌓인 마디까지만 돌고 멈춘다. 모은 고장은 `EvalOutput::faults`로 돌려준다.
+    pub fn with_max_faults(mut self, limit: usize) -> Self {
+        self.max_faults = Some(limit);
+        self
+    }
+
+    /// 가지 꾸러미의 씨앗 정의를 `별명.이름`으로 심는다. 꾸러미의 다른 최상위 문장은 실행하지 않는다.
+    pub fn with_gaji_module(mut self, alias: &str, program: &Program) -> Self {
+        for stmt in &program.stmts {
+            let Stmt::SeedDef {
+                name,
+                params,
+                kind,
+                body,
+                ..
+            } = stmt
+            else {
+                continue;
+            };
+            self.user_seeds.insert(
+                format!("{}.{}", alias, name),
+                UserSeed {
+                    kind: kind.clone(),
+                    params: params.clone(),
+                    body: body.clone(),
+                },
+            );
+        }
+        self.gaji_aliases.insert(alias.to_string());
+        self
+    }
+
     #[allow(dead_code)]
     pub fn run(self, program: &Program) -> Result<EvalOutput, RuntimeError> {
         self.run_with_ticks(program, 1)
@@ -635,6 +716,7 @@ impl Evaluator {
         H: FnMut(u64, &State) -> bool,
     {
         self.capture_nuri_reset_snapshot();
+        self.saturation_mark = thread_saturation_count();
         self.lifecycle_pan_units.clear();
         self.lifecycle_madang_units.clear();
         self.lifecycle_pan_name_to_index.clear();
@@ -768,6 +850,11 @@ impl Evaluator {
             if should_stop(madi, &self.state) {
                 break;
             }
+            for action in self.scheduled.take_due(madi) {
+                if let Err(error) = self.eval_callable(&action, &[], tick_span) {
+                    return Err(self.into_failure(error));
+                }
+            }
             for hook in &every_hooks {
                 let flow = match self.eval_block(hook) {
                     Ok(flow) => flow,
@@ -846,6 +933,17 @@ impl Evaluator {
             let tick_requested = self.bogae_requested_tick;
             on_tick(madi, &self.state, tick_requested);
             self.bogae_requested_tick = false;
+            if let Some(limit) = self.max_faults {
+                self.record_saturation_faults(madi, limit);
+                let faults = self.faults.arithmetic_fault_count();
+                if faults >= limit {
+                    self.fault_stop = Some(FaultLimitStop {
+                        tick_id: madi,
+                        faults,
+                    });
+                    break;
+                }
+            }
         }
 
         for hook in end_hooks {
@@ -861,6 +959,27 @@ impl Evaluator {
         Ok(self.into_output())
     }
 
+    /// 이 마디에서 새로 난 포화를 `Overflow` 산술고장으로 적는다. 한도를 넘겨서는 쌓지 않는다.
+    fn record_saturation_faults(&mut self, madi: u64, limit: usize) {
+        let now = thread_saturation_count();
+        let fresh = now.saturating_sub(self.saturation_mark);
+        self.saturation_mark = now;
+        let room = limit.saturating_sub(self.faults.arithmetic_fault_count());
+        for _ in 0..fresh.min(room as u64) {
+            self.faults.emit(Signal::ArithmeticFault {
+                ctx: FaultContext {
+                    tick_id: madi,
+                    location: "teul:fixed64_saturate",
+                    source_span: None,
+                    expr: None,
+                    seed: None,
+                    call_depth: 0,
+                },
+                kind: ArithmeticFaultKind::Overflow,
+            });
+        }
+    }
+
     fn into_output(self) -> EvalOutput {
         EvalOutput {
             state: self.state,
@@ -870,6 +989,9 @@ impl Evaluator {
             diagnostics: self.diagnostics,
             diagnostic_failures: self.diagnostic_failures,
             proof_runtime: self.proof_runtime,
+            profile: self.profile,
+            faults: self.faults.signals,
+            fault_stop: self.fault_stop,
         }
     }
 
@@ -884,6 +1006,9 @@ impl Evaluator {
         if self.aborted {
             return Ok(FlowControl::Continue);
         }
+        if let Some(profile) = self.profile.as_mut() {
+            profile.count_stmt();
+        }
         match stmt {
             Stmt::ImportBlock { items, .. } => {
                 for item in items {
@@ -1038,6 +1163,10 @@ impl Evaluator {
                 Ok(FlowControl::Continue)
             }
             Stmt::Return { value, span } => {
+                if let Some(next_args) = self.eval_tail_self_call(value)? {
+                    self.pending_tail_call = Some(next_args);
+                    return Ok(FlowControl::Return(Value::None, *span));
+                }
                 let val = self.eval_expr(value)?;
                 Ok(FlowControl::Return(val, *span))
             }
@@ -1456,7 +1585,9 @@ impl Evaluator {
         let mut point = BTreeMap::new();
         insert_value_map_entry(&mut point, "x", Value::Num(quantity_plain(x)));
         insert_value_map_entry(&mut point, "y", Value::Num(quantity_plain(y)));
-        points.push(Value::Map(MapValue { entries: point }));
+        points.push(Value::Map(MapValue {
+            entries: point.into(),
+        }));
         self.state.set(
             Key::new(BOGAE_GRAPH_POINTS_F_TAG),
             Value::List(ListValue { items: points }),
@@ -1640,6 +1771,7 @@ impl Evaluator {
             deferred_assign_frames: self.deferred_assign_frames.clone(),
             flow_assigns: self.flow_assigns.clone(),
             current_entity_stack: self.current_entity_stack.clone(),
+            scheduled: self.scheduled.clone(),
             aborted: self.aborted,
             bogae_requested: self.bogae_requested,
             bogae_requested_tick: self.bogae_requested_tick,
@@ -1669,6 +1801,7 @@ impl Evaluator {
         self.deferred_assign_frames = snapshot.deferred_assign_frames;
         self.flow_assigns = snapshot.flow_assigns;
         self.current_entity_stack = snapshot.current_entity_stack;
+        self.scheduled = snapshot.scheduled;
         self.aborted = snapshot.aborted;
         self.lifecycle_pan_name_to_index = snapshot.lifecycle_pan_name_to_index;
         self.lifecycle_madang_name_to_index = snapshot.lifecycle_madang_name_to_index;
@@ -1869,6 +2002,7 @@ impl Evaluator {
             pending_signals_len: self.pending_signals.len(),
             deferred_assign_frames: self.deferred_assign_frames.clone(),
             flow_assigns: self.flow_assigns.clone(),
+            scheduled: self.scheduled.clone(),
             open_checkpoint: self.open.checkpoint(span)?,
             depth: (self.contract_stack.len() as u32).saturating_add(1),
         };
@@ -1902,6 +2036,7 @@ impl Evaluator {
         self.pending_signals.truncate(frame.pending_signals_len);
         self.deferred_assign_frames = frame.deferred_assign_frames;
         self.flow_assigns = frame.flow_assigns;
+        self.scheduled = frame.scheduled;
         self.open.restore(frame.open_checkpoint, span)?;
         Ok(())
     }
@@ -2399,6 +2534,7 @@ impl Evaluator {
         span: crate::lang::span::Span,
     ) -> Result<Value, RuntimeError> {
         let resolved_name = self.resolve_module_call_name(name);
+        let resolved_name = self.resolve_in_seed_module(resolved_name);
         let canon_name = Self::canonicalize_stdlib_alias(&resolved_name);
         if matches!(
             canon_name,
@@ -2417,15 +2553,7 @@ impl Evaluator {
             let bound = self.bind_seed_args(&seed, args, &values, span)?;
             return self.eval_user_seed(&resolved_name, &seed, &bound, span);
         }
-        // Try stripping call tails (short and long forms), but never guess on ambiguity.
-        let mut tail_candidates = Vec::new();
-        for tail in CALL_TAILS {
-            if let Some(stem) = resolved_name.strip_suffix(tail) {
-                if self.user_seeds.contains_key(stem) {
-                    tail_candidates.push(stem.to_string());
-                }
-            }
-        }
+        let tail_candidates = self.call_tail_candidates(&resolved_name);
         if tail_candidates.len() == 1 {
             let stem = &tail_candidates[0];
             let seed = self.user_seeds.get(stem).cloned().expect("candidate seed");
@@ -2445,6 +2573,60 @@ impl Evaluator {
         })
     }
 
+    /// Try stripping call tails (short and long forms), but never guess on ambiguity.
+    fn call_tail_candidates(&self, resolved_name: &str) -> Vec<String> {
+        let mut tail_candidates = Vec::new();
+        for tail in CALL_TAILS {
+            if let Some(stem) = resolved_name.strip_suffix(tail) {
+                if self.user_seeds.contains_key(stem) {
+                    tail_candidates.push(stem.to_string());
+                }
+            }
+        }
+        tail_candidates
+    }
+
+    /// `(n - 1) 세기 돌려줘.`처럼 돌려줄 값이 곧 지금 씨앗의 호출이면 인자만 계산해 묶어 돌려준다.
+    /// 그 밖의 `돌려줘`는 `None`이다.
+    fn eval_tail_self_call(&mut self, value: &Expr) -> Result<Option<Vec<Value>>, RuntimeError> {
+        let Expr::Call { name, args, span } = value else {
+            return Ok(None);
+        };
+        let Some(Some(current)) = self.tail_call_frames.last() else {
+            return Ok(None);
+        };
+        let resolved_name = self.resolve_module_call_name(name);
+        let resolved_name = self.resolve_in_seed_module(resolved_name);
+        if Self::is_builtin_name(Self::canonicalize_stdlib_alias(&resolved_name)) {
+            return Ok(None);
+        }
+        let target = if self.user_seeds.contains_key(&resolved_name) {
+            resolved_name
+        } else {
+            let mut candidates = self.call_tail_candidates(&resolved_name);
+            if candidates.len() != 1 {
+                return Ok(None);
+            }
+            candidates.remove(0)
+        };
+        if &target != current {
+            return Ok(None);
+        }
+        let mut values = Vec::new();
+        for arg in args {
+            values.push(self.eval_expr(&arg.expr)?);
+        }
+        let seed = self.user_seeds.get(&target).expect("current seed");
+        let bound = self.bind_seed_args(seed, args, &values, *span)?;
+        if bound.len() != seed.params.len() {
+            return Err(RuntimeError::TypeMismatch {
+                expected: "seed arguments",
+                span: *span,
+            });
+        }
+        Ok(Some(bound))
+    }
+
     fn resolve_module_call_name(&self, name: &str) -> String {
         let Some((alias, rest)) = name.split_once('.') else {
             return name.to_string();
@@ -2454,7 +2636,7 @@ impl Evaluator {
         };
         // V1: module alias call is syntactic namespace only.
         //      런타임은 alias 접두를 제거한 함수 이름으로 해석한다.
-        if rest.is_empty() {
+        if rest.is_empty() || self.gaji_aliases.contains(alias) {
             return name.to_string();
         }
         if path.starts_with("./")
@@ -2468,6 +2650,17 @@ impl Evaluator {
         name.to_string()
     }
 
+    /// 가지 씨앗 본문에서 부른 이름은 같은 꾸러미의 씨앗을 먼저 찾는다.
+    fn resolve_in_seed_module(&self, name: String) -> String {
+        if let Some(Some(alias)) = self.seed_modules.last() {
+            let local = format!("{}.{}", alias, name);
+            if self.user_seeds.contains_key(&local) {
+                return local;
+            }
+        }
+        name
+    }
+
     fn eval_dice_call(
         &mut self,
         name: &str,
@@ -3712,6 +3905,29 @@ impl Evaluator {
                 let items = keyed.into_iter().map(|(_, _, item)| item).collect();
                 Ok(Value::List(ListValue { items }))
             }
+            "목록.정렬" => {
+                let (list, comparator) = match values {
+                    [Value::List(list)] => (list.clone(), None),
+                    [Value::List(list), func] => (list.clone(), Some(expect_callable(func, span)?)),
+                    [value, ..] if !matches!(value, Value::List(_)) => {
+                        return Err(type_mismatch_detail("list", value, span))
+                    }
+                    _ => {
+                        return Err(RuntimeError::TypeMismatch {
+                            expected: "list, comparator?",
+                            span,
+                        })
+                    }
+                };
+                let items = stable_sort_values(list.items, |a, b| match &comparator {
+                    Some(func) => {
+                        let verdict = self.eval_callable(func, &[a.clone(), b.clone()], span)?;
+                        comparator_ordering(&verdict, span)
+                    }
+                    None => crate::runtime::compare(a, b, span),
+                })?;
+                Ok(Value::List(ListValue { items }))
+            }
             "거르기" => {
                 let (list, func) = expect_list_and_func(values, span)?;
                 let mut items = Vec::new();
@@ -4419,6 +4635,33 @@ impl Evaluator {
                     || read_state_flag(&self.state, &format!("입력상태.키_눌림.{}", key));
                 Ok(Value::Bool(pressed))
             }
+            "나중에" => {
+                if values.len() != 2 {
+                    return Err(RuntimeError::TypeMismatch {
+                        expected: "madi count, function",
+                        span,
+                    });
+                }
+                let after = expect_int(&values[0], span)?;
+                if after < 1 {
+                    return Err(RuntimeError::MathDomain {
+                        message: "나중에 마디 수는 1 이상이어야 합니다",
+                        span,
+                    });
+                }
+                let action = expect_callable(&values[1], span)?;
+                let at = self.current_madi.get().saturating_add(after as u64);
+                self.scheduled.schedule(at, action);
+                Ok(Value::None)
+            }
+            "환경" => {
+                let key = expect_single_string(values, span)?;
+                Ok(self
+                    .run_env
+                    .get(&key)
+                    .map(|value| Value::Str(value.clone()))
+                    .unwrap_or(Value::None))
+            }
             "입력사상.만들기" => {
                 if values.is_empty() {
                     return Ok(make_std_input_map(BTreeMap::new()));
@@ -5508,7 +5751,9 @@ impl Evaluator {
                         let key = Value::Str(name.clone());
                         entries.insert(key.canon(), MapEntry { key, value });
                     }
-                    Ok(Value::Map(MapValue { entries }))
+                    Ok(Value::Map(MapValue {
+                        entries: entries.into(),
+                    }))
                 } else {
                     Ok(Value::None)
                 }
@@ -5617,7 +5862,7 @@ impl Evaluator {
                         span,
                     });
                 }
-                let mut entries = BTreeMap::new();
+                let mut entries = MapEntries::new();
                 for chunk in values.chunks(2) {
                     let key_value = chunk[0].clone();
                     let entry = MapEntry {
@@ -5640,6 +5885,22 @@ impl Evaluator {
                 let (map, key, value) = expect_map_key_and_value(values, span)?;
                 Ok(Value::Map(map.map_set(key, value)))
             }
+            "짝.넣어" => {
+                let (map, key, value) = expect_map_key_and_value(values, span)?;
+                Ok(Value::Map(map.map_set(key, value)))
+            }
+            "짝.값" => {
+                let (map, key) = expect_map_and_key(values, span)?;
+                Ok(map.map_get(&key))
+            }
+            "짝.있나" => {
+                let (map, key) = expect_map_and_key(values, span)?;
+                Ok(Value::Bool(map.map_contains(&key)))
+            }
+            "짝.빼" => {
+                let (map, key) = expect_map_and_key(values, span)?;
+                Ok(Value::Map(map.map_remove(&key)))
+            }
             "묶음값" => {
                 let (pack, key) = expect_pack_and_key(&values, span)?;
                 Ok(pack.fields.get(&key).cloned().unwrap_or(Value::None))
@@ -5801,7 +6062,7 @@ impl Evaluator {
         ddonirang_lang::stdlib::canonicalize_stdlib_alias(name)
     }
 
-    fn is_builtin_name(name: &str) -> bool {
+    pub(crate) fn is_builtin_name(name: &str) -> bool {
         matches!(
             name,
             "abs"
@@ -5942,6 +6203,8 @@ impl Evaluator {
                 | "입력사상.방향"
                 | "입력사상.동작"
                 | "눌렸나"
+                | "환경"
+                | "나중에"
                 | "다듬기"
                 | "대문자로바꾸기"
                 | "되풀이하기"
@@ -5970,6 +6233,7 @@ impl Evaluator {
                 | "맞추기"
                 | "모음"
                 | "목록"
+                | "목록.정렬"
                 | "무작위"
                 | "무작위선택"
                 | "무작위정수"
@@ -6033,6 +6297,10 @@ impl Evaluator {
                 | "짝맞춤.값"
                 | "짝맞춤.필수값"
                 | "짝맞춤.바꾼값"
+                | "짝.넣어"
+                | "짝.값"
+                | "짝.있나"
+                | "짝.빼"
                 | "차림"
                 | "차림.값"
                 | "차림.바꾼값"
@@ -6284,7 +6552,9 @@ impl Evaluator {
             self.state.set(Key::new(name.clone()), value.clone());
         }
 
+        self.tail_call_frames.push(None);
         let result = self.eval_block(&body);
+        self.tail_call_frames.pop();
         let new_contracts = self.contract_diags.len().saturating_sub(saved_contract_len);
         let new_failures = self
             .diagnostic_failures
@@ -6365,7 +6635,9 @@ impl Evaluator {
         let saved_failure_len = self.diagnostic_failures.len();
 
         self.aborted = false;
+        self.tail_call_frames.push(None);
         let result = self.eval_block(&body);
+        self.tail_call_frames.pop();
         let new_contracts = &self.contract_diags[saved_contract_len..];
         let new_failures = self
             .diagnostic_failures
@@ -6614,8 +6886,16 @@ impl Evaluator {
         let mut evaluator = Evaluator::with_state_and_seed(state, self.rng_state.get());
         evaluator.user_seeds = self.user_seeds.clone();
         evaluator.import_aliases = self.import_aliases.clone();
+        evaluator.gaji_aliases = self.gaji_aliases.clone();
+        evaluator.seed_modules = self.seed_modules.last().cloned().into_iter().collect();
         evaluator.current_madi.set(self.current_madi.get());
-        let result = evaluator.eval_expr(&lambda.body)?;
+        evaluator.run_env = self.run_env.clone();
+        evaluator.scheduled = std::mem::take(&mut self.scheduled);
+        evaluator.profile = self.profile.take();
+        let result = evaluator.eval_expr(&lambda.body);
+        self.scheduled = std::mem::take(&mut evaluator.scheduled);
+        self.profile = evaluator.profile.take();
+        let result = result?;
         self.rng_state.set(evaluator.rng_state.get());
         Ok(result)
     }
@@ -6744,9 +7024,38 @@ impl Evaluator {
         if is_imja {
             self.current_entity_stack.push(seed_name.to_string());
         }
+        if let Some(profile) = self.profile.as_mut() {
+            profile.enter(seed_name);
+        }
+        let tail_calls = !is_imja && !is_immediate_proof;
+        self.tail_call_frames
+            .push(tail_calls.then(|| seed_name.to_string()));
+        let module = seed_name
+            .split_once('.')
+            .map(|(alias, _)| alias)
+            .filter(|alias| self.gaji_aliases.contains(*alias))
+            .map(str::to_string);
+        self.seed_modules.push(module);
         self.enter_const_scope();
-        let flow = self.eval_block(&seed.body);
+        let mut flow = self.eval_block(&seed.body);
         self.exit_const_scope();
+        // 꼬리 자기 호출은 Rust 스택을 쌓지 않고 핀만 바꿔 본문을 다시 돈다.
+        while let Some(next_args) = self.pending_tail_call.take() {
+            if !matches!(flow, Ok(FlowControl::Return(_, _))) {
+                break;
+            }
+            for (param, value) in seed.params.iter().zip(next_args) {
+                self.state.set(Key::new(param.name.clone()), value);
+            }
+            self.enter_const_scope();
+            flow = self.eval_block(&seed.body);
+            self.exit_const_scope();
+        }
+        self.tail_call_frames.pop();
+        self.seed_modules.pop();
+        if let Some(profile) = self.profile.as_mut() {
+            profile.exit();
+        }
         if is_imja {
             self.current_entity_stack.pop();
         }
@@ -7213,11 +7522,7 @@ impl Evaluator {
         if let Some(result) = eval_exact_compare(op, &left, &right, span)? {
             return Ok(Value::Bool(result));
         }
-        let (l, r) = self.require_numbers(left, right, span)?;
-        if l.dim != r.dim {
-            return Err(RuntimeError::UnitMismatch { span });
-        }
-        let ordering = l.raw.raw().cmp(&r.raw.raw());
+        let ordering = crate::runtime::compare(&left, &right, span)?;
         let result = match op {
             BinaryOp::Eq => ordering == std::cmp::Ordering::Equal,
             BinaryOp::NotEq => ordering != std::cmp::Ordering::Equal,
@@ -7543,7 +7848,9 @@ fn stream_to_value(stream: &RuntimeStream) -> Value {
             items: stream.buffer.clone(),
         }),
     );
-    Value::Map(MapValue { entries })
+    Value::Map(MapValue {
+        entries: entries.into(),
+    })
 }
 
 fn stream_push(mut stream: RuntimeStream, value: Value) -> RuntimeStream {
@@ -9659,6 +9966,10 @@ pub struct EvalOutput {
     pub diagnostics: Vec<DiagnosticRecord>,
     pub diagnostic_failures: Vec<DiagnosticFailure>,
     pub proof_runtime: Vec<ProofRuntimeEvent>,
+    pub profile: Option<SeedProfile>,
+    /// `with_max_faults`로 모은 산술고장 신호.
+    pub faults: Vec<Signal>,
+    pub fault_stop: Option<FaultLimitStop>,
 }
 
 fn map_formula_error(err: FormulaError, span: crate::lang::span::Span) -> RuntimeError {
@@ -12490,7 +12801,7 @@ fn type_mismatch_detail(
     }
 }
 
-fn value_type_name(value: &Value) -> String {
+pub(crate) fn value_type_name(value: &Value) -> String {
     match value {
         Value::None => "none".to_string(),
         Value::Bool(_) => "boolean".to_string(),
@@ -13452,7 +13763,7 @@ fn regex_capture_first(regex: &Regex, text: &str) -> Vec<String> {
 fn regex_named_capture_first(regex: &Regex, text: &str) -> MapValue {
     let Some(captures) = regex.captures(text) else {
         return MapValue {
-            entries: BTreeMap::new(),
+            entries: MapEntries::new(),
         };
     };
     let mut entries = BTreeMap::new();
@@ -13466,7 +13777,9 @@ fn regex_named_capture_first(regex: &Regex, text: &str) -> MapValue {
         );
         entries.insert(key.canon(), MapEntry { key, value });
     }
-    MapValue { entries }
+    MapValue {
+        entries: entries.into(),
+    }
 }
 
 fn expect_list(values: &[Value], span: crate::lang::span::Span) -> Result<ListValue, RuntimeError> {
@@ -13832,6 +14145,53 @@ fn expect_list_and_delim(
     Ok((list, delim))
 }
 
+/// 병합 정렬로 안정·결정적으로 정렬한다. 비교가 실패하면 그 오류를 그대로 돌려주고,
+/// 비교 셈이 일관되지 않아도 `slice::sort_by`처럼 멈추지 않는다.
+fn stable_sort_values<F>(items: Vec<Value>, mut cmp: F) -> Result<Vec<Value>, RuntimeError>
+where
+    F: FnMut(&Value, &Value) -> Result<std::cmp::Ordering, RuntimeError>,
+{
+    let mut items = items;
+    let mut width = 1usize;
+    while width < items.len() {
+        let mut merged = Vec::with_capacity(items.len());
+        let mut rest = items.into_iter();
+        loop {
+            let left: Vec<Value> = rest.by_ref().take(width).collect();
+            if left.is_empty() {
+                break;
+            }
+            let right: Vec<Value> = rest.by_ref().take(width).collect();
+            let mut left = left.into_iter().peekable();
+            let mut right = right.into_iter().peekable();
+            while let (Some(a), Some(b)) = (left.peek(), right.peek()) {
+                // 같으면 왼쪽을 먼저 꺼내야 원래 순서가 유지된다.
+                if cmp(a, b)? == std::cmp::Ordering::Greater {
+                    merged.extend(right.next());
+                } else {
+                    merged.extend(left.next());
+                }
+            }
+            merged.extend(left);
+            merged.extend(right);
+        }
+        items = merged;
+        width = width.saturating_mul(2);
+    }
+    Ok(items)
+}
+
+/// 비교 셈의 결과는 수다: 음수면 앞 값이 먼저, 0이면 원래 순서, 양수면 뒤 값이 먼저.
+fn comparator_ordering(
+    value: &Value,
+    span: crate::lang::span::Span,
+) -> Result<std::cmp::Ordering, RuntimeError> {
+    match value {
+        Value::Num(qty) => Ok(qty.raw.raw().cmp(&0)),
+        other => Err(type_mismatch_detail("number", other, span)),
+    }
+}
+
 fn expect_callable(value: &Value, span: crate::lang::span::Span) -> Result<Callable, RuntimeError> {
     match value {
         Value::Str(text) => {
@@ -15897,6 +16257,128 @@ mod tests {
         assert_eq!(state_num(&output, "주기"), Fixed64::from_int(3));
     }
 
+    #[test]
+    fn scheduled_action_fires_exactly_three_madi_later() {
+        let source = r#"
+발사:움직씨 = {
+  발사마디 <- 지난마디.
+  발사횟수 <- 발사횟수 + 1.
+}.
+
+지난마디 <- 0.
+발사마디 <- -1.
+발사횟수 <- 0.
+(3, "발사") 나중에.
+(매마디)마다 {
+  지난마디 <- 지난마디 + 1.
+}.
+"#;
+        let output = run_source_ticks(source, 6).expect("run");
+        assert_eq!(state_num(&output, "발사마디"), Fixed64::from_int(3));
+        assert_eq!(state_num(&output, "발사횟수"), Fixed64::from_int(1));
+
+        let replay = run_source_ticks(source, 6).expect("replay");
+        assert_eq!(
+            state_num(&replay, "발사마디"),
+            state_num(&output, "발사마디")
+        );
+
+        let early = run_source_ticks(source, 3).expect("run before due");
+        assert_eq!(state_num(&early, "발사횟수"), Fixed64::from_int(0));
+    }
+
+    #[test]
+    fn tick_rollback_keeps_scheduled_action_pending_for_next_madi() {
+        let source = r#"
+발사:움직씨 = {
+  발사마디 <- 지난마디.
+  발사횟수 <- 발사횟수 + 1.
+}.
+
+막기 <- 0.
+지난마디 <- 0.
+발사마디 <- -1.
+발사횟수 <- 0.
+검사 <- 세움{
+  { 막기 <= 0 }인것 바탕으로(물림) 아니면 {
+    없음.
+  }.
+}.
+(시작)할때 {
+  검사 지키기.
+}.
+(3, "발사") 나중에.
+(매마디)마다 {
+  지난마디 <- 지난마디 + 1.
+}.
+"#;
+        let program = parse_program(source);
+        let output = Evaluator::with_state_and_seed(State::new(), 42)
+            .run_with_ticks_observe_and_inject(
+                &program,
+                6,
+                |madi, state| {
+                    state.set(Key::new("막기"), dimensionless_num(i64::from(madi == 3)));
+                    Ok(())
+                },
+                |_, _, _| {},
+            )
+            .expect("run");
+        // 3마디는 물려서 지난마디 증가와 발사가 함께 되돌려지지만, 걸어 둔 일은 되살아나 4마디에 한 번 불린다.
+        assert_eq!(state_num(&output, "발사마디"), Fixed64::from_int(3));
+        assert_eq!(state_num(&output, "발사횟수"), Fixed64::from_int(1));
+        assert_eq!(state_num(&output, "지난마디"), Fixed64::from_int(5));
+    }
+
+    #[test]
+    fn contract_abort_drops_action_scheduled_inside_it() {
+        let source = r#"
+발사:움직씨 = {
+  발사횟수 <- 발사횟수 + 1.
+}.
+
+발사횟수 <- 0.
+{ 거짓 }인것 바탕으로(물림) 아니면 {
+  (1, "발사") 나중에.
+}.
+"#;
+        let output = run_source_ticks(source, 3).expect("run");
+        assert_eq!(state_num(&output, "발사횟수"), Fixed64::from_int(0));
+    }
+
+    #[test]
+    fn nuri_reset_restores_scheduled_actions_to_snapshot() {
+        let source = r#"
+발사:움직씨 = {
+  발사횟수 <- 발사횟수 + 1.
+}.
+
+다시 <- 0.
+발사횟수 <- 0.
+(2, "발사") 나중에.
+(매마디)마다 {
+  (1, "발사") 나중에.
+  { 다시 == 1 }인것 일때 {
+    누리다시.
+  }.
+}.
+"#;
+        let program = parse_program(source);
+        let output = Evaluator::with_state_and_seed(State::new(), 42)
+            .run_with_ticks_observe_and_inject(
+                &program,
+                3,
+                |madi, state| {
+                    state.set(Key::new("다시"), dimensionless_num(i64::from(madi == 0)));
+                    Ok(())
+                },
+                |_, _, _| {},
+            )
+            .expect("run");
+        // 0마디에 건 일은 누리다시로 사라지고, 처음 모습에 있던 2마디 일과 1마디에 건 일만 남는다.
+        assert_eq!(state_num(&output, "발사횟수"), Fixed64::from_int(2));
+    }
+
     #[test]
     fn condition_hooks_becomes_and_while_work_on_tick_flow() {
         let source = r#"
@@ -20157,6 +20639,22 @@ dt <- 1.
         assert_eq!(state_num(&output, "횟수"), fixed("2"));
     }
 
+    #[test]
+    fn tail_recursive_seed_runs_without_growing_stack() {
+        let source = r#"
+(n:수, 합:수) 세기:셈씨 = {
+  { n == 0 }인것 일때 {
+    합 돌려줘.
+  }.
+  (n - 1, 합 + 1) 세기 돌려줘.
+}.
+
+살림.결과 <- (1000000, 0) 세기.
+"#;
+        let output = run_source_once(source).expect("run");
+        assert_eq!(state_num(&output, "결과"), fixed("1000000"));
+    }
+
     #[test]
     fn call_tail_ambiguous_seed_stems_fail_without_guessing() {
         let source = r#"
@@ -21030,6 +21528,28 @@ f1 <- (#ascii) 수식{x}.
         assert_eq!(state_str(&out, "상태"), "공집합");
     }
 
+    #[test]
+    fn list_sort_orders_ascending_or_by_comparator() {
+        let source = r#"
+오름 <- ([3, 1, 2]) 목록.정렬.
+내림 <- ([3, 1, 2], {(앞, 뒤)| 뒤 - 앞}) 목록.정렬.
+"#;
+        let out = run_source_once(source).expect("run");
+        assert_eq!(state_display(&out, "오름"), "차림[1, 2, 3]");
+        assert_eq!(state_display(&out, "내림"), "차림[3, 2, 1]");
+    }
+
+    #[test]
+    fn list_sort_rejects_mixed_types() {
+        let source = r#"
+섞임 <- ([1, "가"]) 목록.정렬.
+"#;
+        let Err(err) = run_source_once(source) else {
+            panic!("mixed types must fail");
+        };
+        assert!(matches!(err, RuntimeError::TypeMismatchDetail { .. }));
+    }
+
     #[test]
     fn logic_builtins_are_callable() {
         let source = r##"
@@ -21147,6 +21667,48 @@ f1 <- (#ascii) 수식{x}.
         assert!(matches!(value, Value::None));
     }
 
+    #[test]
+    fn jjak_functions_keep_insertion_order_for_lookup_and_remove() {
+        let source = r#"
+살림.표 <- () 짝맞춤.
+살림.표 <- (살림.표, "다", 3) 짝.넣어.
+살림.표 <- (살림.표, "가", 1) 짝.넣어.
+살림.표 <- (살림.표, "나", 2) 짝.넣어.
+살림.표 <- (살림.표, "다", 3) 짝.넣어.
+순서 <- 살림.표.
+차례값 <- 0.
+(쌍) 살림.표에 대해 {
+  차례값 <- 차례값 * 10 + 쌍[1].
+}.
+가값 <- (살림.표, "가") 짝.값.
+없는값 <- (살림.표, "라") 짝.값.
+나있음 <- (살림.표, "나") 짝.있나.
+살림.표 <- (살림.표, "나") 짝.빼.
+나뺀뒤 <- (살림.표, "나") 짝.있나.
+남은 <- 살림.표.
+"#;
+        let output = run_source_once(source).expect("run");
+        assert_eq!(
+            state_display(&output, "순서"),
+            "짝맞춤{다=>3, 가=>1, 나=>2}"
+        );
+        assert_eq!(state_num(&output, "차례값"), fixed("312"));
+        assert_eq!(state_num(&output, "가값"), fixed("1"));
+        assert!(matches!(
+            output.state.get(&Key::new("없는값".to_string())),
+            Some(Value::None)
+        ));
+        assert_eq!(state_display(&output, "나있음"), "참");
+        assert_eq!(state_display(&output, "나뺀뒤"), "거짓");
+        assert_eq!(state_display(&output, "남은"), "짝맞춤{다=>3, 가=>1}");
+
+        let again = run_source_once(source).expect("run again");
+        assert_eq!(
+            state_display(&again, "순서"),
+            state_display(&output, "순서")
+        );
+    }
+
     #[test]
     fn module_alias_call_resolves_to_builtin_function() {
         let source = r#"
@@ -1,13 +1,17 @@
+pub mod compare;
 pub mod detmath;
 pub mod error;
 pub mod eval;
 pub mod formula;
 pub mod open;
+pub mod profile;
 pub mod template;
 
+pub use compare::compare;
 pub use error::RuntimeError;
 pub use eval::{
     ContractDiag, DiagnosticFailure, DiagnosticRecord, EvalFailure, EvalOutput, Evaluator,
     ProofRuntimeEvent,
 };
 pub use open::{OpenDiagConfig, OpenInputFrame, OpenMode, OpenPolicy, OpenRuntime};
+pub use profile::SeedProfile;
@@ -0,0 +1,66 @@
+use std::collections::BTreeMap;
+
+/// 씨앗별 실행 계수. 벽시계 대신 호출·문장 수를 세어 같은 입력이면 늘 같은 값이 나온다.
+///
+/// 문장 수는 그 씨앗 몸에서 직접 실행한 것만 센다(안에서 부른 씨앗의 문장은 그 씨앗 몫).
+/// 씨앗 밖(최상위·훅)의 문장은 `top_level_stmts`에 모은다.
+#[derive(Clone, Debug, Default)]
+pub struct SeedProfile {
+    pub seeds: BTreeMap<String, SeedProfileEntry>,
+    pub top_level_stmts: u64,
+    stack: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SeedProfileEntry {
+    pub calls: u64,
+    pub stmts: u64,
+}
+
+impl SeedProfile {
+    pub(crate) fn enter(&mut self, seed_name: &str) {
+        self.seeds.entry(seed_name.to_string()).or_default().calls += 1;
+        self.stack.push(seed_name.to_string());
+    }
+
+    pub(crate) fn exit(&mut self) {
+        self.stack.pop();
+    }
+
+    pub(crate) fn count_stmt(&mut self) {
+        match self.stack.last() {
+            Some(name) => {
+                if let Some(entry) = self.seeds.get_mut(name) {
+                    entry.stmts += 1;
+                }
+            }
+            None => self.top_level_stmts += 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn statements_are_charged_to_the_innermost_seed() {
+        let mut profile = SeedProfile::default();
+        profile.count_stmt();
+        profile.enter("바깥");
+        profile.count_stmt();
+        profile.enter("안");
+        profile.count_stmt();
+        profile.count_stmt();
+        profile.exit();
+        profile.count_stmt();
+        profile.exit();
+
+        assert_eq!(profile.top_level_stmts, 1);
+        assert_eq!(
+            profile.seeds["바깥"],
+            SeedProfileEntry { calls: 1, stmts: 2 }
+        );
+        assert_eq!(profile.seeds["안"], SeedProfileEntry { calls: 1, stmts: 2 });
+    }
+}